@@ -1,12 +1,24 @@
 #!/bin/sh
 
-# builds all wasm targets
+# builds all wasm targets, through the meta crates
+# prints the bytecode sizes to stdout, one "<contract> <size>" line for each contract
+# extra arguments are passed on to the meta crates, e.g. `./build-wasm.sh --wasm-opt`
+
+root=$(pwd)
 
 set -e
 SMART_CONTRACT_JSONS=$(find . -name "elrond.json")
 for smart_contract_json in $SMART_CONTRACT_JSONS
 do
+    cd $root
     smart_contract_folder=$(dirname $smart_contract_json)
-    echo ""
-    (set -x; erdpy --verbose contract build $smart_contract_folder)
+    meta_folder="$smart_contract_folder/meta"
+
+    if [ -f "$meta_folder/src/main.rs" ]; then
+        echo "$meta_folder building ..." >&2
+        cd $meta_folder
+        cargo run -q build --target-dir "$root/target/wasm" "$@"
+    else
+        echo "$meta_folder MISSING!!!!!." >&2
+    fi
 done
@@ -0,0 +1,53 @@
+#![no_std]
+
+elrond_wasm::imports!();
+
+pub mod message;
+
+use elrond_wasm::elrond_codec::{dep_decode_borrowed_from_byte_slice, dep_decode_from_byte_slice};
+use message::{Message, MessageView};
+
+const PAYLOAD_ARG_ID: &[u8] = b"payload";
+
+/// Compares decoding the same payload into owned and borrowed structs.
+/// The payload is loaded once, the endpoints only borrow it.
+/// All endpoints return the total length of the decoded bytes, so the decoding is not optimized away.
+#[elrond_wasm::contract]
+pub trait DecodeRepeat {
+    #[init]
+    fn init(&self) {}
+
+    /// Allocates and copies the message contents on every decode.
+    #[endpoint(decodeOwned)]
+    fn decode_owned(&self, payload: &[u8], num_repeats: usize) -> usize {
+        let mut total_len = 0;
+        for _ in 0..num_repeats {
+            let message: Message = dep_decode_from_byte_slice(payload)
+                .unwrap_or_else(|err| self.signal_payload_error(err));
+            total_len += message.sender.len() + message.text.len();
+        }
+        total_len
+    }
+
+    /// Decodes views into the payload, without allocating.
+    #[endpoint(decodeBorrowed)]
+    fn decode_borrowed(&self, payload: &[u8], num_repeats: usize) -> usize {
+        let mut total_len = 0;
+        for _ in 0..num_repeats {
+            let message: MessageView = dep_decode_borrowed_from_byte_slice(payload)
+                .unwrap_or_else(|err| self.signal_payload_error(err));
+            total_len += message.sender.len() + message.text.len();
+        }
+        total_len
+    }
+
+    /// Same as one iteration of `decodeBorrowed`, but the view is decoded by the endpoint argument loader.
+    #[endpoint(decodeBorrowedArg)]
+    fn decode_borrowed_arg(&self, message: MessageView<'_>) -> usize {
+        message.sender.len() + message.text.len()
+    }
+
+    fn signal_payload_error(&self, err: DecodeError) -> ! {
+        signal_arg_de_error(self.raw_vm_api(), ArgId::from(PAYLOAD_ARG_ID), err)
+    }
+}
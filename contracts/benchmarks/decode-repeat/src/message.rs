@@ -0,0 +1,20 @@
+elrond_wasm::derive_imports!();
+
+use elrond_wasm::{String, Vec};
+
+/// Decoding copies `sender` and `text` to the heap.
+#[derive(NestedEncode, NestedDecode)]
+pub struct Message {
+    pub sender: Vec<u8>,
+    pub text: String,
+    pub nonce: u64,
+}
+
+/// Same encoding as `Message`, decoding only references the input.
+/// Can also be taken directly as an endpoint argument, which then borrows from the argument bytes.
+#[derive(TopEncode, NestedEncode, NestedDecodeBorrowed, TypeAbi)]
+pub struct MessageView<'a> {
+    pub sender: &'a [u8],
+    pub text: &'a str,
+    pub nonce: u64,
+}
@@ -0,0 +1,83 @@
+//! Counts the heap allocations performed when decoding the benchmark message,
+//! to show what the borrowed decoding saves.
+
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    cell::Cell,
+};
+
+use decode_repeat::message::{Message, MessageView};
+use elrond_wasm::elrond_codec::{
+    dep_decode_borrowed_from_byte_slice, dep_decode_from_byte_slice, dep_encode_to_vec,
+};
+
+struct CountingAllocator;
+
+thread_local! {
+    // only allocations from the current test thread are counted
+    static ALLOCATION_COUNT: Cell<usize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let _ = ALLOCATION_COUNT.try_with(|count| count.set(count.get() + 1));
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+fn count_allocations<R, F: FnOnce() -> R>(f: F) -> (R, usize) {
+    let before = ALLOCATION_COUNT.with(Cell::get);
+    let result = f();
+    let after = ALLOCATION_COUNT.with(Cell::get);
+    (result, after - before)
+}
+
+const NUM_REPEATS: usize = 100;
+
+fn payload() -> Vec<u8> {
+    let message = Message {
+        sender: b"alice".to_vec(),
+        text: "hello".into(),
+        nonce: 7,
+    };
+    dep_encode_to_vec(&message).unwrap()
+}
+
+#[test]
+fn decode_owned_allocations_test() {
+    let payload = payload();
+    let (total_len, allocations) = count_allocations(|| {
+        let mut total_len = 0;
+        for _ in 0..NUM_REPEATS {
+            let message: Message = dep_decode_from_byte_slice(payload.as_slice()).unwrap();
+            total_len += message.sender.len() + message.text.len();
+        }
+        total_len
+    });
+    assert_eq!(total_len, 10 * NUM_REPEATS);
+    // one for the sender, one for the text
+    assert_eq!(allocations, 2 * NUM_REPEATS);
+}
+
+#[test]
+fn decode_borrowed_allocations_test() {
+    let payload = payload();
+    let (total_len, allocations) = count_allocations(|| {
+        let mut total_len = 0;
+        for _ in 0..NUM_REPEATS {
+            let message: MessageView =
+                dep_decode_borrowed_from_byte_slice(payload.as_slice()).unwrap();
+            total_len += message.sender.len() + message.text.len();
+        }
+        total_len
+    });
+    assert_eq!(total_len, 10 * NUM_REPEATS);
+    assert_eq!(allocations, 0);
+}
@@ -0,0 +1,4 @@
+#[test]
+fn decode_repeat_go() {
+    elrond_wasm_debug::mandos_go("mandos/decode_repeat.scen.json");
+}
@@ -0,0 +1,15 @@
+use elrond_wasm_debug::*;
+
+fn contract_map() -> BlockchainMock {
+    let mut blockchain = BlockchainMock::new();
+    blockchain.register_contract(
+        "file:output/decode-repeat.wasm",
+        Box::new(|context| Box::new(decode_repeat::contract_obj(context))),
+    );
+    blockchain
+}
+
+#[test]
+fn test_decode_repeat_mandos_rs() {
+    elrond_wasm_debug::mandos_rs("mandos/decode_repeat.scen.json", contract_map());
+}
@@ -0,0 +1,33 @@
+////////////////////////////////////////////////////
+////////////////// AUTO-GENERATED //////////////////
+////////////////////////////////////////////////////
+
+#![no_std]
+#![allow(non_snake_case)]
+
+pub use elrond_wasm_output;
+
+#[no_mangle]
+pub fn init() {
+    decode_repeat::endpoints::init(elrond_wasm_node::arwen_api());
+}
+
+#[no_mangle]
+pub fn decodeOwned() {
+    decode_repeat::endpoints::decodeOwned(elrond_wasm_node::arwen_api());
+}
+
+#[no_mangle]
+pub fn decodeBorrowed() {
+    decode_repeat::endpoints::decodeBorrowed(elrond_wasm_node::arwen_api());
+}
+
+#[no_mangle]
+pub fn decodeBorrowedArg() {
+    decode_repeat::endpoints::decodeBorrowedArg(elrond_wasm_node::arwen_api());
+}
+
+#[no_mangle]
+pub fn callBack() {
+    decode_repeat::endpoints::callBack(elrond_wasm_node::arwen_api());
+}
@@ -0,0 +1,38 @@
+use elrond_wasm::contract_base::ContractAbiProvider;
+
+const EXPECTED_SNIPPETS: &str = r#"ALICE="${USERS}/alice.pem"
+ADDRESS=$(erdpy data load --key=address-devnet)
+DEPLOY_TRANSACTION=$(erdpy data load --key=deployTransaction-devnet)
+
+deploy() {
+    read -p "Enter initial_value (BigInt): " INITIAL_VALUE
+    erdpy --verbose contract deploy --project=${PROJECT} --recall-nonce --pem=${ALICE} --gas-limit=50000000 --arguments ${INITIAL_VALUE} --send --outfile="deploy-devnet.interaction.json" || return
+
+    TRANSACTION=$(erdpy data parse --file="deploy-devnet.interaction.json" --expression="data['emitted_tx']['hash']")
+    ADDRESS=$(erdpy data parse --file="deploy-devnet.interaction.json" --expression="data['emitted_tx']['address']")
+
+    erdpy data store --key=address-devnet --value=${ADDRESS}
+    erdpy data store --key=deployTransaction-devnet --value=${TRANSACTION}
+
+    echo ""
+    echo "Smart contract address: ${ADDRESS}"
+}
+
+getSum() {
+    erdpy --verbose contract query ${ADDRESS} --function="getSum"
+}
+
+add() {
+    read -p "Enter value (BigInt): " VALUE
+    erdpy --verbose contract call ${ADDRESS} --recall-nonce --pem=${ALICE} --gas-limit=50000000 --function="add" --arguments ${VALUE} --send
+}
+"#;
+
+#[test]
+fn adder_snippets() {
+    let abi = adder::AbiProvider::abi();
+    assert_eq!(
+        elrond_wasm_debug::meta::snippets_source(&abi),
+        EXPECTED_SNIPPETS
+    );
+}
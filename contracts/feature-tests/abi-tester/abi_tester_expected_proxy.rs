@@ -0,0 +1,257 @@
+////////////////////////////////////////////////////
+////////////////// AUTO-GENERATED //////////////////
+////////////////////////////////////////////////////
+
+elrond_wasm::imports!();
+elrond_wasm::derive_imports!();
+
+use alloc::collections::{BTreeMap, BTreeSet};
+
+/// Contract whose sole purpose is to verify that
+/// the ABI generation framework works sa expected.
+///
+/// Note: any change in this contract must also be reflected in `abi_test_expected.abi.json`,
+/// including Rust docs.
+#[elrond_wasm::proxy]
+pub trait AbiTester {
+    /// Contract constructor.
+    #[init]
+    #[payable("EGLD")]
+    fn init(
+        &self,
+        #[payment] payment: BigUint,
+        _constructor_arg_1: i32,
+        _constructor_arg_2: OnlyShowsUpInConstructor,
+    );
+
+    /// Example endpoint docs.
+    #[endpoint]
+    fn echo_abi_test_type(&self, att: AbiTestType) -> AbiTestType;
+
+    #[endpoint]
+    fn echo_enum(&self, e: AbiEnum) -> AbiEnum;
+
+    #[endpoint]
+    fn echo_codec_attributes(&self, arg: AbiCodecAttributesType) -> AbiCodecAttributesType;
+
+    #[endpoint]
+    fn echo_managed_type(&self, arg: AbiManagedEnum<Self::Api>) -> AbiManagedEnum<Self::Api>;
+
+    #[endpoint]
+    fn echo_ordered_collections(
+        &self,
+        set: BTreeSet<u128>,
+        map: BTreeMap<u32, i128>,
+    ) -> MultiResult2<BTreeSet<u128>, BTreeMap<u32, i128>>;
+
+    #[endpoint]
+    fn multi_result_3(&self) -> MultiResult3<i32, [u8; 3], ManagedBuffer>;
+
+    #[endpoint]
+    fn multi_result_4(&self) -> MultiResult4<i32, [u8; 3], ManagedBuffer, OnlyShowsUpAsNested03>;
+
+    #[endpoint]
+    fn var_args(
+        &self,
+        _simple_arg: u32,
+        #[var_args] _var_args: VarArgs<MultiArg2<OnlyShowsUpAsNested04, i32>>,
+    );
+
+    #[endpoint]
+    fn multi_result_vec(&self) -> MultiResultVec<MultiResult3<OnlyShowsUpAsNested05, bool, ()>>;
+
+    #[endpoint]
+    fn optional_arg(
+        &self,
+        _simple_arg: u32,
+        #[var_args] _opt_args: OptionalArg<OnlyShowsUpAsNested06>,
+    );
+
+    #[endpoint]
+    fn optional_result(&self) -> OptionalResult<OnlyShowsUpAsNested07>;
+
+    #[endpoint]
+    fn address_vs_h256(
+        &self,
+        address: ManagedAddress,
+        h256: H256,
+    ) -> MultiResult2<ManagedAddress, H256>;
+
+    #[endpoint]
+    fn managed_address_vs_byte_array(
+        &self,
+        address: ManagedAddress,
+        byte_array: [u8; 32],
+    ) -> MultiResult2<ManagedAddress, [u8; 32]>;
+
+    #[endpoint]
+    fn esdt_local_role(&self) -> EsdtLocalRole;
+
+    #[endpoint]
+    fn esdt_token_payment(&self) -> EsdtTokenPayment<Self::Api>;
+
+    #[endpoint]
+    fn esdt_token_data(&self) -> EsdtTokenData<Self::Api>;
+
+    #[view]
+    fn sample_storage_mapper(&self) -> OnlyShowsUpAsNested10;
+
+    #[endpoint]
+    #[payable("EGLD")]
+    fn payable_egld(&self, #[payment] payment: BigUint);
+
+    #[endpoint]
+    #[payable("TOKEN-FOR-ABI")]
+    fn payable_some_token(
+        &self,
+        #[payment_token] payment_token: TokenIdentifier,
+        #[payment_nonce] payment_nonce: u64,
+        #[payment] payment: BigUint,
+    );
+
+    #[endpoint]
+    #[payable("*")]
+    fn payable_any_token(
+        &self,
+        #[payment_token] payment_token: TokenIdentifier,
+        #[payment_nonce] payment_nonce: u64,
+        #[payment] payment: BigUint,
+    );
+}
+
+/// Tests that the ABI reflects the `#[codec(...)]` annotations.
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, TypeAbi)]
+pub struct AbiCodecAttributesType {
+    pub status: AbiReorderedEnum,
+    /// Encoded as a u32.
+    pub custom_codec: u32,
+    pub versioned: AbiVersionedType,
+}
+
+/// Its only purpose is to test that the ABI generator works fine.
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, TypeAbi)]
+pub enum AbiEnum {
+    Nothing,
+    Something(i32),
+    SomethingMore(u8, OnlyShowsUpAsNested08),
+    SomeStruct {
+        a: u16,
+        b: OnlyShowsUpAsNested09,
+    },
+}
+
+/// Managed types can be nested in other types.
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, TypeAbi)]
+pub enum AbiManagedEnum<M: ManagedTypeApi> {
+    Nothing,
+    Something(AbiManagedType<M>),
+}
+
+/// Tests that managed types make the types containing them generic over the API
+/// in generated proxies.
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, TypeAbi)]
+pub struct AbiManagedType<M: ManagedTypeApi> {
+    pub big_uint: BigUint<M>,
+    pub integer: i32,
+    pub managed_buffer: ManagedBuffer<M>,
+    pub payments: Vec<EsdtTokenPayment<M>>,
+}
+
+/// Variants keep their discriminants when reordered.
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, TypeAbi)]
+pub enum AbiReorderedEnum {
+    #[codec(discriminant = 2)]
+    Third,
+    #[codec(discriminant = 0)]
+    First,
+    Second,
+}
+
+/// Its only purpose is to test that the ABI generator works fine.
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, TypeAbi)]
+pub struct AbiTestType {
+    /// This type should only appear here.
+    pub nested: OnlyShowsUpAsNested01,
+    /// Tests that recursive types will not send the ABI generator into an infinite loop.
+    pub next: Option<Box<AbiTestType>>,
+    /// Tests that tuples tell the ABI of their component types even if they appear nowhere else.
+    /// Also, just like above, recursive types need to work even when nested into a tuple.
+    pub tuple_madness: (OnlyShowsUpAsNested02, Option<Box<AbiTestType>>),
+}
+
+/// The version byte shows up as a first field, optional fields are marked as such.
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, TypeAbi)]
+pub struct AbiVersionedType {
+    /// Encoding version, always 2.
+    pub version: u8,
+    pub amount: u32,
+    /// Missing from data encoded before it was added.
+    #[codec(optional)]
+    pub added_later: u64,
+}
+
+/// Tests that the ABI generator also fetches types that only appear as fields.
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, TypeAbi)]
+pub struct OnlyShowsUpAsNested01;
+
+/// Tests that the ABI generator also fetches types that only appear as fields.
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, TypeAbi)]
+pub struct OnlyShowsUpAsNested02 {
+    pub something: [u8; 0],
+}
+
+/// Tests that the ABI generator also fetches types that only appear as fields.
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, TypeAbi)]
+pub struct OnlyShowsUpAsNested03;
+
+/// Tests that the ABI generator also fetches types that only appear as fields.
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, TypeAbi)]
+pub struct OnlyShowsUpAsNested04;
+
+/// Tests that the ABI generator also fetches types that only appear as fields.
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, TypeAbi)]
+pub struct OnlyShowsUpAsNested05;
+
+/// Tests that the ABI generator also fetches types that only appear as fields.
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, TypeAbi)]
+pub struct OnlyShowsUpAsNested06;
+
+/// Tests that the ABI generator also fetches types that only appear as fields.
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, TypeAbi)]
+pub struct OnlyShowsUpAsNested07;
+
+/// Tests that the ABI generator also fetches types that only appear as fields.
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, TypeAbi)]
+pub struct OnlyShowsUpAsNested08;
+
+/// Tests that the ABI generator also fetches types that only appear as fields.
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, TypeAbi)]
+pub struct OnlyShowsUpAsNested09;
+
+/// Tests that the ABI generator also fetches types that only appear as fields.
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, TypeAbi)]
+pub struct OnlyShowsUpAsNested10;
+
+/// Tests that the ABI generator also fetches types that only appear as fields.
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, TypeAbi)]
+pub struct OnlyShowsUpInConstructor {
+    pub something: (),
+}
+
+/// Tests that the ABI generator also fetches types that only appear as event data.
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, TypeAbi)]
+pub struct OnlyShowsUpInEventData {
+    pub field: u64,
+}
+
+/// Tests that the ABI generator also fetches types that only appear as event topics.
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, TypeAbi)]
+pub struct OnlyShowsUpInEventTopic {
+    pub field: u8,
+}
+
+/// Tests that the ABI generator also fetches types that only appear in storage.
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, TypeAbi)]
+pub struct OnlyShowsUpInStorage {
+    pub field: u32,
+}
@@ -0,0 +1,52 @@
+elrond_wasm::derive_imports!();
+
+/// Tests that the ABI reflects the `#[codec(...)]` annotations.
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, TypeAbi)]
+pub struct AbiCodecAttributesType {
+    pub status: AbiReorderedEnum,
+
+    /// Skipped fields do not show up in the ABI.
+    #[codec(skip)]
+    pub skipped: u64,
+
+    /// Encoded as a u32.
+    #[codec(with = "u64_as_u32", abi_type = "u32")]
+    pub custom_codec: u64,
+
+    pub versioned: AbiVersionedType,
+}
+
+/// The version byte shows up as a first field, optional fields are marked as such.
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, TypeAbi)]
+#[codec(version = 2)]
+pub struct AbiVersionedType {
+    pub amount: u32,
+
+    /// Missing from data encoded before it was added.
+    #[codec(optional)]
+    pub added_later: u64,
+}
+
+/// Variants keep their discriminants when reordered.
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, TypeAbi)]
+pub enum AbiReorderedEnum {
+    #[codec(discriminant = 2)]
+    Third,
+    #[codec(discriminant = 0)]
+    First,
+    Second,
+}
+
+mod u64_as_u32 {
+    use elrond_wasm::elrond_codec::{
+        DecodeError, EncodeError, NestedDecode, NestedDecodeInput, NestedEncode, NestedEncodeOutput,
+    };
+
+    pub fn dep_encode<O: NestedEncodeOutput>(value: &u64, dest: &mut O) -> Result<(), EncodeError> {
+        (*value as u32).dep_encode(dest)
+    }
+
+    pub fn dep_decode<I: NestedDecodeInput>(input: &mut I) -> Result<u64, DecodeError> {
+        Ok(u32::dep_decode(input)? as u64)
+    }
+}
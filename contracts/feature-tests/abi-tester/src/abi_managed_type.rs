@@ -0,0 +1,19 @@
+elrond_wasm::imports!();
+elrond_wasm::derive_imports!();
+
+/// Tests that managed types make the types containing them generic over the API
+/// in generated proxies.
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, TypeAbi)]
+pub struct AbiManagedType<M: ManagedTypeApi> {
+    pub big_uint: BigUint<M>,
+    pub integer: i32,
+    pub managed_buffer: ManagedBuffer<M>,
+    pub payments: ManagedVec<M, EsdtTokenPayment<M>>,
+}
+
+/// Managed types can be nested in other types.
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, TypeAbi)]
+pub enum AbiManagedEnum<M: ManagedTypeApi> {
+    Nothing,
+    Something(AbiManagedType<M>),
+}
@@ -0,0 +1,35 @@
+elrond_wasm::imports!();
+elrond_wasm::derive_imports!();
+
+use elrond_wasm::{
+    api::{StorageReadApi, StorageWriteApi},
+    storage::{mappers::StorageMapper, StorageKey},
+};
+
+/// Only used as key argument of storage left out of the ABI, so it does not need to implement `TypeAbi`.
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode)]
+pub struct NotInAbiKey {
+    pub something: u32,
+}
+
+/// Custom storage mapper that does not implement `StorageMapperAbi`,
+/// so it can only be used for storage left out of the ABI, which is the default.
+pub struct NotInAbiMapper<SA>
+where
+    SA: ManagedTypeApi + ErrorApi + 'static,
+{
+    _api: SA,
+    _base_key: StorageKey<SA>,
+}
+
+impl<SA> StorageMapper<SA> for NotInAbiMapper<SA>
+where
+    SA: StorageReadApi + StorageWriteApi + ManagedTypeApi + ErrorApi + Clone + 'static,
+{
+    fn new(api: SA, base_key: StorageKey<SA>) -> Self {
+        NotInAbiMapper {
+            _api: api,
+            _base_key: base_key,
+        }
+    }
+}
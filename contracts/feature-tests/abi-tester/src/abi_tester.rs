@@ -2,12 +2,21 @@
 
 elrond_wasm::imports!();
 
+extern crate alloc;
+
+mod abi_codec_attributes;
 mod abi_enum;
+mod abi_managed_type;
+mod abi_not_in_abi;
 mod abi_test_type;
 mod only_nested;
 
+use abi_codec_attributes::*;
 use abi_enum::*;
+use abi_managed_type::*;
+use abi_not_in_abi::*;
 use abi_test_type::*;
+use alloc::collections::{BTreeMap, BTreeSet};
 use only_nested::*;
 
 /// Contract whose sole purpose is to verify that
@@ -36,6 +45,25 @@ pub trait AbiTester {
         e
     }
 
+    #[endpoint]
+    fn echo_codec_attributes(&self, arg: AbiCodecAttributesType) -> AbiCodecAttributesType {
+        arg
+    }
+
+    #[endpoint]
+    fn echo_managed_type(&self, arg: AbiManagedEnum<Self::Api>) -> AbiManagedEnum<Self::Api> {
+        arg
+    }
+
+    #[endpoint]
+    fn echo_ordered_collections(
+        &self,
+        set: BTreeSet<u128>,
+        map: BTreeMap<u32, i128>,
+    ) -> MultiResult2<BTreeSet<u128>, BTreeMap<u32, i128>> {
+        (set, map).into()
+    }
+
     #[endpoint]
     #[output_name("multi-result-1")]
     #[output_name("multi-result-2")]
@@ -108,9 +136,39 @@ pub trait AbiTester {
     }
 
     #[view]
-    #[storage_mapper("sample_storage_mapper")]
+    #[storage_mapper("sample_storage_mapper", abi = true)]
     fn sample_storage_mapper(&self) -> SingleValueMapper<OnlyShowsUpAsNested10>;
 
+    /// Storage getters and setters for the same key produce a single storage ABI entry.
+    #[storage_get("sample_storage_get", abi = true)]
+    fn sample_storage_get(&self, key_arg: u32) -> OnlyShowsUpInStorage;
+
+    #[storage_set("sample_storage_get", abi = true)]
+    fn set_sample_storage_get(&self, key_arg: u32, value: &OnlyShowsUpInStorage);
+
+    #[storage_mapper("sample_map_mapper", abi = true)]
+    fn sample_map_mapper(&self, key_arg: &ManagedAddress) -> MapMapper<u64, BigUint>;
+
+    /// Does not show up in the ABI.
+    #[storage_mapper("sample_not_in_abi_mapper")]
+    fn sample_not_in_abi_mapper(&self, key_arg: &NotInAbiKey) -> NotInAbiMapper<Self::Api>;
+
+    /// Does not show up in the ABI.
+    #[storage_get("sample_not_in_abi_get")]
+    fn sample_not_in_abi_get(&self, key_arg: &NotInAbiKey) -> u32;
+
+    /// Event docs.
+    #[event("abi_test_event")]
+    fn abi_test_event(
+        &self,
+        #[indexed] caller: &ManagedAddress,
+        #[indexed] topic: &OnlyShowsUpInEventTopic,
+        data: &OnlyShowsUpInEventData,
+    );
+
+    #[event("event_without_data")]
+    fn event_without_data(&self, #[indexed] amount: &BigUint);
+
     #[endpoint]
     #[payable("EGLD")]
     fn payable_egld(&self, #[payment] _payment: BigUint, #[payment_token] _token: TokenIdentifier) {
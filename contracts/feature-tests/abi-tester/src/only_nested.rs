@@ -47,3 +47,21 @@ pub struct OnlyShowsUpAsNested09;
 /// Tests that the ABI generator also fetches types that only appear as fields.
 #[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, TypeAbi)]
 pub struct OnlyShowsUpAsNested10;
+
+/// Tests that the ABI generator also fetches types that only appear in storage.
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, TypeAbi)]
+pub struct OnlyShowsUpInStorage {
+    pub field: u32,
+}
+
+/// Tests that the ABI generator also fetches types that only appear as event topics.
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, TypeAbi)]
+pub struct OnlyShowsUpInEventTopic {
+    pub field: u8,
+}
+
+/// Tests that the ABI generator also fetches types that only appear as event data.
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, TypeAbi)]
+pub struct OnlyShowsUpInEventData {
+    pub field: u64,
+}
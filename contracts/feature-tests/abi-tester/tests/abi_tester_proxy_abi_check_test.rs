@@ -0,0 +1,88 @@
+extern crate alloc;
+
+use elrond_wasm::{
+    contract_base::ProxyObjBase,
+    types::{ManagedAddress, OptionalArg},
+};
+use elrond_wasm_debug::DebugApi;
+
+#[allow(dead_code)]
+mod abi_tester_proxy {
+    include!("../abi_tester_expected_proxy.rs");
+}
+
+/// Handwritten proxy, covering only some of the endpoints.
+/// It only compiles if it matches the expected ABI.
+#[allow(dead_code)]
+mod checked_proxy {
+    elrond_wasm::imports!();
+
+    use super::abi_tester_proxy::{
+        AbiEnum, AbiManagedEnum, OnlyShowsUpAsNested03, OnlyShowsUpAsNested04,
+        OnlyShowsUpAsNested06,
+    };
+    use alloc::collections::{BTreeMap, BTreeSet};
+
+    #[elrond_wasm::proxy(abi = "abi_tester_expected.abi.json")]
+    pub trait AbiTester {
+        #[endpoint]
+        fn echo_enum(&self, e: AbiEnum) -> AbiEnum;
+
+        #[endpoint]
+        fn echo_managed_type(&self, arg: AbiManagedEnum<Self::Api>) -> AbiManagedEnum<Self::Api>;
+
+        #[endpoint]
+        fn echo_ordered_collections(
+            &self,
+            set: BTreeSet<u128>,
+            map: BTreeMap<u32, i128>,
+        ) -> MultiResult2<BTreeSet<u128>, BTreeMap<u32, i128>>;
+
+        #[endpoint]
+        fn multi_result_4(
+            &self,
+        ) -> SCResult<MultiResult4<i32, [u8; 3], ManagedBuffer, OnlyShowsUpAsNested03>>;
+
+        #[endpoint(var_args)]
+        fn var_args_renamed(
+            &self,
+            simple_arg: usize,
+            #[var_args] var_args: ManagedVarArgs<MultiArg2<OnlyShowsUpAsNested04, i32>>,
+        );
+
+        #[endpoint]
+        fn optional_arg(
+            &self,
+            simple_arg: u32,
+            #[var_args] opt_args: OptionalArg<OnlyShowsUpAsNested06>,
+        );
+
+        #[endpoint]
+        fn managed_address_vs_byte_array(
+            &self,
+            address: ManagedAddress,
+            byte_array: ManagedByteArray<32>,
+        ) -> MultiResult2<ManagedAddress, [u8; 32]>;
+
+        #[endpoint]
+        fn esdt_token_payment(&self) -> EsdtTokenPayment<Self::Api>;
+
+        #[endpoint]
+        #[payable("EGLD")]
+        fn payable_egld(&self, #[payment] payment: BigUint);
+    }
+}
+
+use checked_proxy::ProxyTrait;
+
+#[test]
+fn abi_tester_checked_proxy_calls() {
+    let api = DebugApi::dummy();
+    let proxy = || {
+        checked_proxy::Proxy::new_proxy_obj(api.clone()).contract(ManagedAddress::zero(api.clone()))
+    };
+
+    let _ = proxy().echo_enum(abi_tester_proxy::AbiEnum::Something(5));
+    let _ = proxy().optional_arg(5, OptionalArg::None);
+    let _ = proxy().payable_egld(elrond_wasm::types::BigUint::zero(api.clone()));
+}
@@ -0,0 +1,51 @@
+extern crate alloc;
+
+use std::fs;
+
+use elrond_wasm::{contract_base::ProxyObjBase, types::ManagedAddress};
+use elrond_wasm_debug::{proxy_gen, DebugApi};
+
+/// The expected proxy is compiled here, to check that the generated code is valid.
+#[allow(dead_code)]
+mod abi_tester_proxy {
+    include!("../abi_tester_expected_proxy.rs");
+}
+
+use abi_tester_proxy::ProxyTrait;
+
+#[test]
+fn abi_tester_proxy_generated_ok() {
+    // load expected from disk
+    let expected_proxy = fs::read_to_string("./abi_tester_expected_proxy.rs").unwrap();
+
+    // generate the proxy from the expected ABI,
+    // saved to disk for easier comparison in case something is off
+    proxy_gen::write_proxy_from_abi_file(
+        "./abi_tester_expected.abi.json",
+        "./abi_tester_generated_proxy.rs",
+    );
+    let generated_proxy = fs::read_to_string("./abi_tester_generated_proxy.rs").unwrap();
+
+    // check!
+    assert_eq!(generated_proxy, expected_proxy);
+}
+
+#[test]
+fn abi_tester_proxy_calls() {
+    let api = DebugApi::dummy();
+    let proxy = || {
+        abi_tester_proxy::Proxy::new_proxy_obj(api.clone())
+            .contract(ManagedAddress::zero(api.clone()))
+    };
+
+    let _ = proxy().echo_enum(abi_tester_proxy::AbiEnum::Something(5));
+    let _ = proxy().echo_managed_type(abi_tester_proxy::AbiManagedEnum::Something(
+        abi_tester_proxy::AbiManagedType {
+            big_uint: elrond_wasm::types::BigUint::zero(api.clone()),
+            integer: -1,
+            managed_buffer: elrond_wasm::types::ManagedBuffer::new(api.clone()),
+            payments: Vec::new(),
+        },
+    ));
+    let _ = proxy().payable_egld(elrond_wasm::types::BigUint::zero(api.clone()));
+}
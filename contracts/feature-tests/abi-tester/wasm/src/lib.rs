@@ -22,6 +22,21 @@ pub fn echo_enum() {
     abi_tester::endpoints::echo_enum(elrond_wasm_node::arwen_api());
 }
 
+#[no_mangle]
+pub fn echo_codec_attributes() {
+    abi_tester::endpoints::echo_codec_attributes(elrond_wasm_node::arwen_api());
+}
+
+#[no_mangle]
+pub fn echo_managed_type() {
+    abi_tester::endpoints::echo_managed_type(elrond_wasm_node::arwen_api());
+}
+
+#[no_mangle]
+pub fn echo_ordered_collections() {
+    abi_tester::endpoints::echo_ordered_collections(elrond_wasm_node::arwen_api());
+}
+
 #[no_mangle]
 pub fn multi_result_3() {
     abi_tester::endpoints::multi_result_3(elrond_wasm_node::arwen_api());
@@ -16,11 +16,15 @@ pub mod event_features;
 pub mod macro_features;
 pub mod managed_buffer_features;
 pub mod managed_vec_features;
+pub mod readonly_features;
 pub mod storage_direct_load;
 pub mod storage_direct_store;
+pub mod storage_mapper_fungible_token;
 pub mod storage_mapper_linked_list;
 pub mod storage_mapper_map;
 pub mod storage_mapper_map_storage;
+pub mod storage_mapper_merkle_tree;
+pub mod storage_mapper_non_fungible_token;
 pub mod storage_mapper_queue;
 pub mod storage_mapper_set;
 pub mod storage_mapper_single;
@@ -45,16 +49,20 @@ pub trait BasicFeatures:
     + macro_features::Macros
     + managed_buffer_features::ManagedBufferFeatures
     + managed_vec_features::ManagedVecFeatures
+    + readonly_features::ReadonlyFeatures
     + storage_direct_load::StorageLoadFeatures
     + storage_direct_store::StorageStoreFeatures
     + storage_mapper_linked_list::LinkedListMapperFeatures
     + storage_mapper_queue::QueueMapperFeatures
     + storage_mapper_map::MapMapperFeatures
     + storage_mapper_map_storage::MapStorageMapperFeatures
+    + storage_mapper_merkle_tree::MerkleTreeMapperFeatures
     + storage_mapper_set::SetMapperFeatures
     + storage_mapper_single::SingleValueMapperFeatures
     + storage_mapper_vec::VecMapperFeatures
     + storage_mapper_token_attributes::TokenAttributesMapperFeatures
+    + storage_mapper_fungible_token::FungibleTokenMapperFeatures
+    + storage_mapper_non_fungible_token::NonFungibleTokenMapperFeatures
     + token_identifier_features::TokenIdentifierFeatures
     + type_features::TypeFeatures
 {
@@ -19,6 +19,16 @@ pub trait EchoTypes {
         i
     }
 
+    #[endpoint]
+    fn echo_u128(&self, i: u128) -> u128 {
+        i
+    }
+
+    #[endpoint]
+    fn echo_i128(&self, i: i128) -> i128 {
+        i
+    }
+
     #[endpoint]
     fn echo_i32(&self, i: i32) -> i32 {
         i
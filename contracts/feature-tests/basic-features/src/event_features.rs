@@ -19,6 +19,27 @@ pub trait EventFeatures {
     #[event("event_b")]
     fn event_b(&self, #[indexed] arg1: &BigUint, #[indexed] arg2: &Address, data: &[BoxedBytes]);
 
+    #[endpoint(logEventC)]
+    fn log_event_c(
+        &self,
+        caller: ManagedAddress,
+        amount: BigUint,
+        nonce: u64,
+        name: ManagedBuffer,
+    ) {
+        self.event_c(&caller, &amount, nonce, &name);
+    }
+
+    /// Several data fields, encoded together as if they were a tuple.
+    #[event("event_c")]
+    fn event_c(
+        &self,
+        #[indexed] caller: &ManagedAddress,
+        amount: &BigUint,
+        nonce: u64,
+        name: &ManagedBuffer,
+    );
+
     // Legacy:
 
     #[endpoint(logLegacyEventA)]
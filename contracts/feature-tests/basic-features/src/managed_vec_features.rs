@@ -1,4 +1,13 @@
 elrond_wasm::imports!();
+elrond_wasm::derive_imports!();
+
+/// Can be stored in a `ManagedVec` because of the `ManagedVecItem` derive.
+#[derive(ManagedVecItem, NestedEncode, NestedDecode, TopEncode, TopDecode, TypeAbi)]
+pub struct TokenAmount<M: ManagedTypeApi> {
+    pub token_id: TokenIdentifier<M>,
+    pub nonce: u64,
+    pub amount: BigUint<M>,
+}
 
 #[elrond_wasm::module]
 pub trait ManagedVecFeatures {
@@ -33,4 +42,24 @@ pub trait ManagedVecFeatures {
         result.push(item);
         result
     }
+
+    #[endpoint]
+    fn managed_vec_struct_push(
+        &self,
+        mv: ManagedVec<TokenAmount<Self::Api>>,
+        item: TokenAmount<Self::Api>,
+    ) -> ManagedVec<TokenAmount<Self::Api>> {
+        let mut result = mv;
+        result.push(item);
+        result
+    }
+
+    #[endpoint]
+    fn managed_vec_struct_total_amount(&self, mv: ManagedVec<TokenAmount<Self::Api>>) -> BigUint {
+        let mut total = BigUint::zero();
+        for item in mv.iter() {
+            total += item.amount;
+        }
+        total
+    }
 }
@@ -0,0 +1,59 @@
+elrond_wasm::imports!();
+
+/// Tests that views cannot change the blockchain state.
+/// The state changes are hidden in helper methods, so they are only caught at runtime.
+#[elrond_wasm::module]
+pub trait ReadonlyFeatures {
+    #[view]
+    fn readonly_storage_read(&self) -> u64 {
+        self.readonly_value().get()
+    }
+
+    #[view]
+    fn readonly_storage_write(&self) {
+        self.write_readonly_value();
+    }
+
+    #[view]
+    fn readonly_egld_transfer(&self) {
+        self.transfer_egld_to_caller();
+    }
+
+    #[view]
+    fn readonly_event(&self) {
+        self.log_readonly_event();
+    }
+
+    #[view]
+    fn readonly_local_mint(&self, token: TokenIdentifier) {
+        self.mint_local_token(&token);
+    }
+
+    #[endpoint]
+    fn mutable_storage_write(&self) {
+        self.write_readonly_value();
+    }
+
+    fn write_readonly_value(&self) {
+        self.readonly_value().set(&1);
+    }
+
+    fn transfer_egld_to_caller(&self) {
+        let caller = self.blockchain().get_caller();
+        self.send().direct_egld(&caller, &BigUint::from(1u32), &[]);
+    }
+
+    fn mint_local_token(&self, token: &TokenIdentifier) {
+        self.send().esdt_local_mint(token, 0, &BigUint::from(1u32));
+    }
+
+    fn log_readonly_event(&self) {
+        self.readonly_event_log(&1);
+    }
+
+    #[storage_mapper("readonly_value")]
+    fn readonly_value(&self) -> SingleValueMapper<u64>;
+
+    #[event("readonly_event")]
+    fn readonly_event_log(&self, data: &u64);
+}
@@ -0,0 +1,34 @@
+elrond_wasm::imports!();
+
+#[elrond_wasm::module]
+pub trait FungibleTokenMapperFeatures {
+    #[view(getFungibleTokenId)]
+    #[storage_mapper("fungibleTokenMapper")]
+    fn fungible_token_mapper(&self) -> FungibleTokenMapper;
+
+    #[endpoint]
+    fn fungible_token_mapper_set_token_id(&self, token_id: &TokenIdentifier) {
+        self.fungible_token_mapper().set_token_id(token_id);
+    }
+
+    #[endpoint]
+    fn fungible_token_mapper_mint(&self, amount: BigUint) -> EsdtTokenPayment<Self::Api> {
+        self.fungible_token_mapper().mint(&amount)
+    }
+
+    #[endpoint]
+    fn fungible_token_mapper_burn(&self, amount: BigUint) {
+        self.fungible_token_mapper().burn(&amount);
+    }
+
+    #[view]
+    fn fungible_token_mapper_get_balance(&self) -> BigUint {
+        self.fungible_token_mapper().get_balance()
+    }
+
+    #[payable("*")]
+    #[endpoint]
+    fn fungible_token_mapper_require_same_token(&self) {
+        self.fungible_token_mapper().require_same_token();
+    }
+}
@@ -0,0 +1,43 @@
+elrond_wasm::imports!();
+
+#[elrond_wasm::module]
+pub trait MerkleTreeMapperFeatures {
+    #[view(getMerkleRoot)]
+    #[storage_mapper("merkleTree")]
+    fn merkle_tree(&self) -> MerkleTreeMapper;
+
+    #[view(getMerkleRootSha256)]
+    #[storage_mapper("merkleTreeSha256")]
+    fn merkle_tree_sha256(&self) -> MerkleTreeMapper<Sha256MerkleHash>;
+
+    #[endpoint]
+    fn merkle_tree_push_leaf(&self, leaf: ManagedByteArray<32>) -> u64 {
+        self.merkle_tree().push_leaf(&leaf)
+    }
+
+    #[endpoint]
+    fn merkle_tree_verify_proof(
+        &self,
+        leaf: ManagedByteArray<32>,
+        leaf_index: u64,
+        proof: ManagedVec<ManagedByteArray<32>>,
+    ) -> bool {
+        self.merkle_tree().verify_proof(&leaf, leaf_index, &proof)
+    }
+
+    #[endpoint]
+    fn merkle_tree_sha256_push_leaf(&self, leaf: ManagedByteArray<32>) -> u64 {
+        self.merkle_tree_sha256().push_leaf(&leaf)
+    }
+
+    #[endpoint]
+    fn merkle_tree_sha256_verify_proof(
+        &self,
+        leaf: ManagedByteArray<32>,
+        leaf_index: u64,
+        proof: ManagedVec<ManagedByteArray<32>>,
+    ) -> bool {
+        self.merkle_tree_sha256()
+            .verify_proof(&leaf, leaf_index, &proof)
+    }
+}
@@ -0,0 +1,52 @@
+elrond_wasm::imports!();
+elrond_wasm::derive_imports!();
+
+#[derive(TopEncode, TopDecode, TypeAbi)]
+pub struct RgbColor {
+    r: u8,
+    g: u8,
+    b: u8,
+}
+
+#[elrond_wasm::module]
+pub trait NonFungibleTokenMapperFeatures {
+    #[view(getNonFungibleTokenId)]
+    #[storage_mapper("nonFungibleTokenMapper")]
+    fn non_fungible_token_mapper(&self) -> NonFungibleTokenMapper;
+
+    #[endpoint]
+    fn non_fungible_token_mapper_set_token_id(&self, token_id: &TokenIdentifier) {
+        self.non_fungible_token_mapper().set_token_id(token_id);
+    }
+
+    #[endpoint]
+    fn non_fungible_token_mapper_nft_create(
+        &self,
+        amount: BigUint,
+        color: RgbColor,
+    ) -> EsdtTokenPayment<Self::Api> {
+        self.non_fungible_token_mapper().nft_create(&amount, &color)
+    }
+
+    #[endpoint]
+    fn non_fungible_token_mapper_nft_add_quantity(
+        &self,
+        token_nonce: u64,
+        amount: BigUint,
+    ) -> EsdtTokenPayment<Self::Api> {
+        self.non_fungible_token_mapper()
+            .nft_add_quantity(token_nonce, &amount)
+    }
+
+    #[view]
+    fn non_fungible_token_mapper_get_attributes(&self, token_nonce: u64) -> RgbColor {
+        self.non_fungible_token_mapper()
+            .get_token_attributes(token_nonce)
+    }
+
+    #[payable("*")]
+    #[endpoint]
+    fn non_fungible_token_mapper_require_same_token(&self) {
+        self.non_fungible_token_mapper().require_same_token();
+    }
+}
@@ -1,7 +1,9 @@
-use elrond_wasm::types::{SCResult, StaticSCError};
+use elrond_wasm::types::{
+    Address, BigUint, BoxedBytes, ManagedAddress, ManagedBuffer, SCResult, StaticSCError,
+};
 use elrond_wasm_debug::*;
 
-use basic_features::macro_features::Macros;
+use basic_features::{event_features::EventFeatures, macro_features::Macros};
 
 #[test]
 fn test_sc_error() {
@@ -12,3 +14,32 @@ fn test_sc_error() {
         result
     );
 }
+
+#[test]
+fn test_event_with_several_data_fields() {
+    let api = DebugApi::dummy();
+    let bf = basic_features::contract_obj(api.clone());
+    let caller = Address::from([5u8; 32]);
+    bf.log_event_c(
+        ManagedAddress::from_address(api.clone(), &caller),
+        BigUint::from_bytes_be(api.clone(), &[1, 0]),
+        7,
+        ManagedBuffer::new_from_bytes(api.clone(), b"abc"),
+    );
+
+    let tx_result = api.into_tx_result();
+    assert_eq!(tx_result.result_logs.len(), 1);
+    let log = &tx_result.result_logs[0];
+    assert_eq!(
+        log.decode_topic::<BoxedBytes>(0).unwrap().as_slice(),
+        b"event_c"
+    );
+    assert_eq!(log.decode_topic::<Address>(1).unwrap(), caller);
+
+    // the BigUint nested encoding is the same as the one for bytes
+    let (amount, nonce, name) = log.decode_data::<(Vec<u8>, u64, BoxedBytes)>().unwrap();
+    assert_eq!(amount, vec![1, 0]);
+    assert_eq!(nonce, 7);
+    assert_eq!(name.as_slice(), b"abc");
+    assert!(log.decode_data::<(Vec<u8>, u64)>().is_err());
+}
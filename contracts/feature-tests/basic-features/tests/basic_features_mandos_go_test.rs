@@ -153,6 +153,11 @@ fn echo_tuple_into_multiresult_go() {
     elrond_wasm_debug::mandos_go("mandos/echo_tuple_into_multiresult.scen.json");
 }
 
+#[test]
+fn echo_u128_go() {
+    elrond_wasm_debug::mandos_go("mandos/echo_u128.scen.json");
+}
+
 #[test]
 fn echo_u64_go() {
     elrond_wasm_debug::mandos_go("mandos/echo_u64.scen.json");
@@ -243,6 +248,11 @@ fn managed_vec_biguint_push_go() {
     elrond_wasm_debug::mandos_go("mandos/managed_vec_biguint_push.scen.json");
 }
 
+#[test]
+fn managed_vec_struct_push_go() {
+    elrond_wasm_debug::mandos_go("mandos/managed_vec_struct_push.scen.json");
+}
+
 #[test]
 fn only_owner_go() {
     elrond_wasm_debug::mandos_go("mandos/only_owner.scen.json");
@@ -258,6 +268,11 @@ fn panic_go() {
     elrond_wasm_debug::mandos_go("mandos/panic.scen.json");
 }
 
+#[test]
+fn readonly_mode_go() {
+    elrond_wasm_debug::mandos_go("mandos/readonly_mode.scen.json");
+}
+
 #[test]
 fn return_codes_go() {
     elrond_wasm_debug::mandos_go("mandos/return_codes.scen.json");
@@ -323,6 +338,11 @@ fn storage_map3_go() {
     elrond_wasm_debug::mandos_go("mandos/storage_map3.scen.json");
 }
 
+#[test]
+fn storage_mapper_fungible_token_go() {
+    elrond_wasm_debug::mandos_go("mandos/storage_mapper_fungible_token.scen.json");
+}
+
 #[test]
 fn storage_mapper_linked_list_go() {
     elrond_wasm_debug::mandos_go("mandos/storage_mapper_linked_list.scen.json");
@@ -338,6 +358,11 @@ fn storage_mapper_map_storage_go() {
     elrond_wasm_debug::mandos_go("mandos/storage_mapper_map_storage.scen.json");
 }
 
+#[test]
+fn storage_mapper_non_fungible_token_go() {
+    elrond_wasm_debug::mandos_go("mandos/storage_mapper_non_fungible_token.scen.json");
+}
+
 #[test]
 fn storage_mapper_queue_go() {
     elrond_wasm_debug::mandos_go("mandos/storage_mapper_queue.scen.json");
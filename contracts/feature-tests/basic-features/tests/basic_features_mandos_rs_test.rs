@@ -173,6 +173,11 @@ fn echo_tuple_into_multiresult_rs() {
     );
 }
 
+#[test]
+fn echo_u128_rs() {
+    elrond_wasm_debug::mandos_rs("mandos/echo_u128.scen.json", contract_map());
+}
+
 #[test]
 fn echo_u64_rs() {
     elrond_wasm_debug::mandos_rs("mandos/echo_u64.scen.json", contract_map());
@@ -203,6 +208,14 @@ fn events_rs() {
     elrond_wasm_debug::mandos_rs("mandos/events.scen.json", contract_map());
 }
 
+#[test]
+#[should_panic(
+    expected = "Logs do not match. Tx id: C1. Endpoint: logEventC. Log data field 1 does not match. Want: \"u64:8\". Have: 0x0000000000000007"
+)]
+fn events_data_field_mismatch_rs() {
+    elrond_wasm_debug::mandos_rs("mandos/events_data_field_mismatch.err.json", contract_map());
+}
+
 #[test]
 fn events_legacy_rs() {
     elrond_wasm_debug::mandos_rs("mandos/events_legacy.scen.json", contract_map());
@@ -270,6 +283,11 @@ fn managed_vec_biguint_push_rs() {
     elrond_wasm_debug::mandos_rs("mandos/managed_vec_biguint_push.scen.json", contract_map());
 }
 
+#[test]
+fn managed_vec_struct_push_rs() {
+    elrond_wasm_debug::mandos_rs("mandos/managed_vec_struct_push.scen.json", contract_map());
+}
+
 #[test]
 fn only_owner_rs() {
     elrond_wasm_debug::mandos_rs("mandos/only_owner.scen.json", contract_map());
@@ -286,6 +304,11 @@ fn panic_rs() {
     elrond_wasm_debug::mandos_rs("mandos/panic.scen.json", contract_map());
 }
 
+#[test]
+fn readonly_mode_rs() {
+    elrond_wasm_debug::mandos_rs("mandos/readonly_mode.scen.json", contract_map());
+}
+
 #[test]
 fn return_codes_rs() {
     elrond_wasm_debug::mandos_rs("mandos/return_codes.scen.json", contract_map());
@@ -351,6 +374,14 @@ fn storage_map3_rs() {
     elrond_wasm_debug::mandos_rs("mandos/storage_map3.scen.json", contract_map());
 }
 
+#[test]
+fn storage_mapper_fungible_token_rs() {
+    elrond_wasm_debug::mandos_rs(
+        "mandos/storage_mapper_fungible_token.scen.json",
+        contract_map(),
+    );
+}
+
 #[test]
 fn storage_mapper_linked_list_rs() {
     elrond_wasm_debug::mandos_rs(
@@ -359,6 +390,14 @@ fn storage_mapper_linked_list_rs() {
     );
 }
 
+#[test]
+fn storage_mapper_non_fungible_token_rs() {
+    elrond_wasm_debug::mandos_rs(
+        "mandos/storage_mapper_non_fungible_token.scen.json",
+        contract_map(),
+    );
+}
+
 #[test]
 fn storage_mapper_queue_rs() {
     elrond_wasm_debug::mandos_rs("mandos/storage_mapper_queue.scen.json", contract_map());
@@ -0,0 +1,141 @@
+use elrond_wasm::{
+    storage::mappers::{
+        merkle_hash_leaf, merkle_hash_pair, Keccak256MerkleHash, MerkleHashBytes, Sha256MerkleHash,
+    },
+    types::ManagedVec,
+};
+use elrond_wasm_debug::*;
+
+use basic_features::storage_mapper_merkle_tree::MerkleTreeMapperFeatures;
+
+fn leaf(i: u8) -> MerkleNode {
+    [i; 32]
+}
+
+#[test]
+fn test_merkle_tree_root() {
+    let context = DebugApi::dummy();
+    let bf = basic_features::contract_obj(context.clone());
+    let mut builder = MerkleTreeBuilder::<Keccak256MerkleHash>::new();
+
+    assert_eq!(bf.merkle_tree().get_root().to_byte_array(), builder.root());
+
+    for i in 1..=9u8 {
+        let index =
+            bf.merkle_tree_push_leaf(MerkleHashBytes::new_from_bytes(context.clone(), &leaf(i)));
+        assert_eq!(index, builder.push_leaf(leaf(i)));
+        assert_eq!(bf.merkle_tree().get_root().to_byte_array(), builder.root());
+        assert_eq!(bf.merkle_tree().depth(), builder.depth());
+    }
+}
+
+#[test]
+fn test_merkle_tree_verify_proof() {
+    let context = DebugApi::dummy();
+    let bf = basic_features::contract_obj(context.clone());
+    let mut builder = MerkleTreeBuilder::<Keccak256MerkleHash>::new();
+
+    for i in 1..=5u8 {
+        bf.merkle_tree_push_leaf(MerkleHashBytes::new_from_bytes(context.clone(), &leaf(i)));
+        builder.push_leaf(leaf(i));
+    }
+
+    for index in 0..5u64 {
+        let leaf_bytes = MerkleHashBytes::new_from_bytes(context.clone(), &leaf(index as u8 + 1));
+        let proof = builder.managed_proof(context.clone(), index);
+        assert!(bf.merkle_tree_verify_proof(leaf_bytes.clone(), index, proof.clone()));
+
+        // wrong index
+        assert!(!bf.merkle_tree_verify_proof(leaf_bytes, (index + 1) % 5, proof.clone()));
+
+        // wrong leaf
+        let wrong_leaf = MerkleHashBytes::new_from_bytes(context.clone(), &leaf(100));
+        assert!(!bf.merkle_tree_verify_proof(wrong_leaf, index, proof));
+    }
+
+    // index out of range
+    let zero_leaf = MerkleHashBytes::new_from_bytes(context.clone(), &[0u8; 32]);
+    let proof = builder.managed_proof(context.clone(), 4);
+    assert!(!bf.merkle_tree_verify_proof(zero_leaf, 5, proof));
+
+    // truncated proof
+    let mut truncated_proof = ManagedVec::new(context.clone());
+    truncated_proof.push(MerkleHashBytes::new_from_bytes(
+        context.clone(),
+        &builder.proof(0)[0],
+    ));
+    let first_leaf = MerkleHashBytes::new_from_bytes(context, &leaf(1));
+    assert!(!bf.merkle_tree_verify_proof(first_leaf, 0, truncated_proof));
+}
+
+#[test]
+fn test_merkle_tree_verify_proof_against_root() {
+    let context = DebugApi::dummy();
+    let bf = basic_features::contract_obj(context.clone());
+    let mut builder = MerkleTreeBuilder::<Keccak256MerkleHash>::new();
+    for i in 1..=4u8 {
+        builder.push_leaf(leaf(i));
+    }
+    let root = MerkleHashBytes::new_from_bytes(context.clone(), &builder.root());
+    let leaf_bytes = MerkleHashBytes::new_from_bytes(context.clone(), &leaf(3));
+    let proof = builder.managed_proof(context.clone(), 2);
+
+    assert!(bf
+        .merkle_tree()
+        .verify_proof_against_root(&root, 2, &leaf_bytes, 2, &proof));
+
+    // wrong depth
+    assert!(!bf
+        .merkle_tree()
+        .verify_proof_against_root(&root, 3, &leaf_bytes, 2, &proof));
+
+    // index out of range for the depth
+    assert!(!bf
+        .merkle_tree()
+        .verify_proof_against_root(&root, 2, &leaf_bytes, 6, &proof));
+
+    // a branch node does not pass as a leaf, neither by proof length, nor by hash
+    let branch_node = merkle_hash_pair::<DebugApi, Keccak256MerkleHash>(
+        &context,
+        &merkle_hash_leaf::<DebugApi, Keccak256MerkleHash>(&context, &leaf(3)),
+        &merkle_hash_leaf::<DebugApi, Keccak256MerkleHash>(&context, &leaf(4)),
+    );
+    let mut short_proof = ManagedVec::new(context.clone());
+    short_proof.push(MerkleHashBytes::new_from_bytes(
+        context.clone(),
+        &builder.proof(2)[1],
+    ));
+    let branch_as_leaf = MerkleHashBytes::new_from_bytes(context.clone(), &branch_node);
+    assert!(!bf.merkle_tree().verify_proof_against_root(
+        &root,
+        1,
+        &branch_as_leaf,
+        1,
+        &short_proof
+    ));
+    assert_ne!(
+        bf.merkle_tree()
+            .compute_root_from_proof(&branch_as_leaf, 1, &short_proof),
+        root
+    );
+}
+
+#[test]
+fn test_merkle_tree_sha256() {
+    let context = DebugApi::dummy();
+    let bf = basic_features::contract_obj(context.clone());
+    let mut builder = MerkleTreeBuilder::<Sha256MerkleHash>::new();
+
+    for i in 1..=3u8 {
+        bf.merkle_tree_sha256_push_leaf(MerkleHashBytes::new_from_bytes(context.clone(), &leaf(i)));
+        builder.push_leaf(leaf(i));
+    }
+    assert_eq!(
+        bf.merkle_tree_sha256().get_root().to_byte_array(),
+        builder.root()
+    );
+
+    let leaf_bytes = MerkleHashBytes::new_from_bytes(context.clone(), &leaf(3));
+    let proof = builder.managed_proof(context, 2);
+    assert!(bf.merkle_tree_sha256_verify_proof(leaf_bytes, 2, proof));
+}
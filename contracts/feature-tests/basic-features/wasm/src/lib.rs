@@ -667,6 +667,16 @@ pub fn echo_i64() {
     basic_features::endpoints::echo_i64(elrond_wasm_node::arwen_api());
 }
 
+#[no_mangle]
+pub fn echo_u128() {
+    basic_features::endpoints::echo_u128(elrond_wasm_node::arwen_api());
+}
+
+#[no_mangle]
+pub fn echo_i128() {
+    basic_features::endpoints::echo_i128(elrond_wasm_node::arwen_api());
+}
+
 #[no_mangle]
 pub fn echo_i32() {
     basic_features::endpoints::echo_i32(elrond_wasm_node::arwen_api());
@@ -892,6 +902,11 @@ pub fn logEventB() {
     basic_features::endpoints::logEventB(elrond_wasm_node::arwen_api());
 }
 
+#[no_mangle]
+pub fn logEventC() {
+    basic_features::endpoints::logEventC(elrond_wasm_node::arwen_api());
+}
+
 #[no_mangle]
 pub fn logLegacyEventA() {
     basic_features::endpoints::logLegacyEventA(elrond_wasm_node::arwen_api());
@@ -1047,6 +1062,46 @@ pub fn managed_vec_address_push() {
     basic_features::endpoints::managed_vec_address_push(elrond_wasm_node::arwen_api());
 }
 
+#[no_mangle]
+pub fn managed_vec_struct_push() {
+    basic_features::endpoints::managed_vec_struct_push(elrond_wasm_node::arwen_api());
+}
+
+#[no_mangle]
+pub fn managed_vec_struct_total_amount() {
+    basic_features::endpoints::managed_vec_struct_total_amount(elrond_wasm_node::arwen_api());
+}
+
+#[no_mangle]
+pub fn readonly_storage_read() {
+    basic_features::endpoints::readonly_storage_read(elrond_wasm_node::arwen_api());
+}
+
+#[no_mangle]
+pub fn readonly_storage_write() {
+    basic_features::endpoints::readonly_storage_write(elrond_wasm_node::arwen_api());
+}
+
+#[no_mangle]
+pub fn readonly_egld_transfer() {
+    basic_features::endpoints::readonly_egld_transfer(elrond_wasm_node::arwen_api());
+}
+
+#[no_mangle]
+pub fn readonly_event() {
+    basic_features::endpoints::readonly_event(elrond_wasm_node::arwen_api());
+}
+
+#[no_mangle]
+pub fn readonly_local_mint() {
+    basic_features::endpoints::readonly_local_mint(elrond_wasm_node::arwen_api());
+}
+
+#[no_mangle]
+pub fn mutable_storage_write() {
+    basic_features::endpoints::mutable_storage_write(elrond_wasm_node::arwen_api());
+}
+
 #[no_mangle]
 pub fn load_big_uint() {
     basic_features::endpoints::load_big_uint(elrond_wasm_node::arwen_api());
@@ -1412,6 +1467,36 @@ pub fn map_storage_mapper_entry_or_default_update() {
     basic_features::endpoints::map_storage_mapper_entry_or_default_update(elrond_wasm_node::arwen_api());
 }
 
+#[no_mangle]
+pub fn getMerkleRoot() {
+    basic_features::endpoints::getMerkleRoot(elrond_wasm_node::arwen_api());
+}
+
+#[no_mangle]
+pub fn getMerkleRootSha256() {
+    basic_features::endpoints::getMerkleRootSha256(elrond_wasm_node::arwen_api());
+}
+
+#[no_mangle]
+pub fn merkle_tree_push_leaf() {
+    basic_features::endpoints::merkle_tree_push_leaf(elrond_wasm_node::arwen_api());
+}
+
+#[no_mangle]
+pub fn merkle_tree_verify_proof() {
+    basic_features::endpoints::merkle_tree_verify_proof(elrond_wasm_node::arwen_api());
+}
+
+#[no_mangle]
+pub fn merkle_tree_sha256_push_leaf() {
+    basic_features::endpoints::merkle_tree_sha256_push_leaf(elrond_wasm_node::arwen_api());
+}
+
+#[no_mangle]
+pub fn merkle_tree_sha256_verify_proof() {
+    basic_features::endpoints::merkle_tree_sha256_verify_proof(elrond_wasm_node::arwen_api());
+}
+
 #[no_mangle]
 pub fn set_mapper() {
     basic_features::endpoints::set_mapper(elrond_wasm_node::arwen_api());
@@ -1522,6 +1607,66 @@ pub fn token_attributes_has_attributes() {
     basic_features::endpoints::token_attributes_has_attributes(elrond_wasm_node::arwen_api());
 }
 
+#[no_mangle]
+pub fn getFungibleTokenId() {
+    basic_features::endpoints::getFungibleTokenId(elrond_wasm_node::arwen_api());
+}
+
+#[no_mangle]
+pub fn fungible_token_mapper_set_token_id() {
+    basic_features::endpoints::fungible_token_mapper_set_token_id(elrond_wasm_node::arwen_api());
+}
+
+#[no_mangle]
+pub fn fungible_token_mapper_mint() {
+    basic_features::endpoints::fungible_token_mapper_mint(elrond_wasm_node::arwen_api());
+}
+
+#[no_mangle]
+pub fn fungible_token_mapper_burn() {
+    basic_features::endpoints::fungible_token_mapper_burn(elrond_wasm_node::arwen_api());
+}
+
+#[no_mangle]
+pub fn fungible_token_mapper_get_balance() {
+    basic_features::endpoints::fungible_token_mapper_get_balance(elrond_wasm_node::arwen_api());
+}
+
+#[no_mangle]
+pub fn fungible_token_mapper_require_same_token() {
+    basic_features::endpoints::fungible_token_mapper_require_same_token(elrond_wasm_node::arwen_api());
+}
+
+#[no_mangle]
+pub fn getNonFungibleTokenId() {
+    basic_features::endpoints::getNonFungibleTokenId(elrond_wasm_node::arwen_api());
+}
+
+#[no_mangle]
+pub fn non_fungible_token_mapper_set_token_id() {
+    basic_features::endpoints::non_fungible_token_mapper_set_token_id(elrond_wasm_node::arwen_api());
+}
+
+#[no_mangle]
+pub fn non_fungible_token_mapper_nft_create() {
+    basic_features::endpoints::non_fungible_token_mapper_nft_create(elrond_wasm_node::arwen_api());
+}
+
+#[no_mangle]
+pub fn non_fungible_token_mapper_nft_add_quantity() {
+    basic_features::endpoints::non_fungible_token_mapper_nft_add_quantity(elrond_wasm_node::arwen_api());
+}
+
+#[no_mangle]
+pub fn non_fungible_token_mapper_get_attributes() {
+    basic_features::endpoints::non_fungible_token_mapper_get_attributes(elrond_wasm_node::arwen_api());
+}
+
+#[no_mangle]
+pub fn non_fungible_token_mapper_require_same_token() {
+    basic_features::endpoints::non_fungible_token_mapper_require_same_token(elrond_wasm_node::arwen_api());
+}
+
 #[no_mangle]
 pub fn token_identifier_egld() {
     basic_features::endpoints::token_identifier_egld(elrond_wasm_node::arwen_api());
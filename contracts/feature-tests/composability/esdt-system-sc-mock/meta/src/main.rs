@@ -0,0 +1,3 @@
+fn main() {
+    elrond_wasm_debug::meta::perform::<esdt_system_sc_mock::AbiProvider>();
+}
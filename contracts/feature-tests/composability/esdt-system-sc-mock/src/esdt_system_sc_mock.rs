@@ -0,0 +1,55 @@
+#![no_std]
+
+elrond_wasm::imports!();
+
+/// The only issue cost accepted by the mock, 0.05 EGLD.
+const ISSUE_COST: u64 = 50_000_000_000_000_000;
+
+/// Mocks the token issue endpoints of the ESDT system smart contract,
+/// for testing contracts that issue tokens.
+/// It gets deployed at the system SC address in test scenarios.
+///
+/// The issued token identifiers are the ticker, followed by `-123456`.
+#[elrond_wasm::contract]
+pub trait EsdtSystemScMock {
+    #[init]
+    fn init(&self) {}
+
+    #[payable("EGLD")]
+    #[endpoint]
+    fn issue(
+        &self,
+        #[payment] issue_cost: BigUint,
+        _token_display_name: ManagedBuffer,
+        token_ticker: ManagedBuffer,
+        _initial_supply: BigUint,
+        _num_decimals: usize,
+        #[var_args] _token_properties: ManagedVarArgs<ManagedBuffer>,
+    ) -> SCResult<TokenIdentifier> {
+        self.new_token_identifier(&issue_cost, token_ticker)
+    }
+
+    #[payable("EGLD")]
+    #[endpoint(registerAndSetAllRoles)]
+    fn register_and_set_all_roles(
+        &self,
+        #[payment] issue_cost: BigUint,
+        _token_display_name: ManagedBuffer,
+        token_ticker: ManagedBuffer,
+        _token_type_name: ManagedBuffer,
+        _num_decimals: usize,
+    ) -> SCResult<TokenIdentifier> {
+        self.new_token_identifier(&issue_cost, token_ticker)
+    }
+
+    fn new_token_identifier(
+        &self,
+        issue_cost: &BigUint,
+        mut token_ticker: ManagedBuffer,
+    ) -> SCResult<TokenIdentifier> {
+        require!(*issue_cost == ISSUE_COST, "invalid issue cost");
+
+        token_ticker.append_bytes(b"-123456");
+        Ok(TokenIdentifier::from(token_ticker))
+    }
+}
@@ -0,0 +1,28 @@
+////////////////////////////////////////////////////
+////////////////// AUTO-GENERATED //////////////////
+////////////////////////////////////////////////////
+
+#![no_std]
+#![allow(non_snake_case)]
+
+pub use elrond_wasm_output;
+
+#[no_mangle]
+pub fn init() {
+    esdt_system_sc_mock::endpoints::init(elrond_wasm_node::arwen_api());
+}
+
+#[no_mangle]
+pub fn issue() {
+    esdt_system_sc_mock::endpoints::issue(elrond_wasm_node::arwen_api());
+}
+
+#[no_mangle]
+pub fn registerAndSetAllRoles() {
+    esdt_system_sc_mock::endpoints::registerAndSetAllRoles(elrond_wasm_node::arwen_api());
+}
+
+#[no_mangle]
+pub fn callBack() {
+    esdt_system_sc_mock::endpoints::callBack(elrond_wasm_node::arwen_api());
+}
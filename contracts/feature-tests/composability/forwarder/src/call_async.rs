@@ -106,6 +106,139 @@ pub trait ForwarderAsyncCallModule {
         #[indexed] payment: &BigUint,
     );
 
+    #[endpoint]
+    #[payable("*")]
+    fn forward_async_accept_funds_with_context(
+        &self,
+        to: ManagedAddress,
+        #[payment_token] token: TokenIdentifier,
+        #[payment_amount] payment: BigUint,
+        #[payment_nonce] token_nonce: u64,
+    ) -> AsyncCall {
+        let caller = self.blockchain().get_caller();
+        self.vault_proxy()
+            .contract(to)
+            .accept_funds_echo_payment(token, payment, token_nonce)
+            .async_call()
+            .with_callback(self.callbacks().echo_payment_context_callback(&caller))
+    }
+
+    #[endpoint]
+    #[payable("*")]
+    fn forward_async_reject_funds_with_context(
+        &self,
+        to: ManagedAddress,
+        #[payment_token] token: TokenIdentifier,
+        #[payment_amount] payment: BigUint,
+    ) -> AsyncCall {
+        let caller = self.blockchain().get_caller();
+        self.vault_proxy()
+            .contract(to)
+            .reject_funds(token, payment)
+            .async_call()
+            .with_callback(self.callbacks().echo_payment_context_callback(&caller))
+    }
+
+    /// The results of `echo_arguments` only decode as the expected echo
+    /// if exactly the right arguments are given.
+    #[endpoint]
+    fn forward_async_echo_arguments_with_context(
+        &self,
+        to: ManagedAddress,
+        #[var_args] args: ManagedVarArgs<ManagedBuffer>,
+    ) -> AsyncCall {
+        let caller = self.blockchain().get_caller();
+        self.vault_proxy()
+            .contract(to)
+            .echo_arguments(args)
+            .async_call()
+            .with_callback(self.callbacks().echo_payment_context_callback(&caller))
+    }
+
+    #[callback]
+    fn echo_payment_context_callback(
+        &self,
+        caller: &ManagedAddress,
+        #[callback_context] context: CallbackContext<
+            MultiResult4<TokenIdentifier, ManagedBuffer, BigUint, u64>,
+        >,
+    ) {
+        match context.result {
+            CallbackResult::Ok(echo) => {
+                let (token, token_type, amount, nonce) = echo.into_tuple();
+                self.callback_context_ok_event(&token, &token_type, &amount, nonce);
+            },
+            CallbackResult::CalleeError(err) => {
+                // the payments are back in the contract, return them to the caller
+                for payment in context.original_payments.iter() {
+                    self.send().direct(
+                        caller,
+                        &payment.token_identifier,
+                        payment.token_nonce,
+                        &payment.amount,
+                        &[],
+                    );
+                }
+                self.callback_context_callee_error_event(
+                    err.err_code,
+                    &err.err_msg,
+                    context.original_payments.len(),
+                );
+            },
+            CallbackResult::DecodeError { raw_results, .. } => {
+                self.callback_context_decode_error_event(raw_results.len());
+            },
+        }
+    }
+
+    #[endpoint]
+    fn forward_async_retrieve_funds_with_context(
+        &self,
+        to: ManagedAddress,
+        token: TokenIdentifier,
+        token_nonce: u64,
+        amount: BigUint,
+    ) -> AsyncCall {
+        self.vault_proxy()
+            .contract(to)
+            .retrieve_funds(token, token_nonce, amount, OptionalArg::None)
+            .async_call()
+            .with_callback(self.callbacks().retrieve_funds_context_callback())
+    }
+
+    #[callback]
+    fn retrieve_funds_context_callback(&self, #[callback_context] context: CallbackContext<()>) {
+        if context.result.is_ok() {
+            for payment in context.returned_payments.iter() {
+                self.retrieve_funds_callback_event(
+                    &payment.token_identifier,
+                    payment.token_nonce,
+                    &payment.amount,
+                );
+            }
+        }
+    }
+
+    #[event("callback_context_ok")]
+    fn callback_context_ok_event(
+        &self,
+        #[indexed] token: &TokenIdentifier,
+        #[indexed] token_type: &ManagedBuffer,
+        #[indexed] amount: &BigUint,
+        #[indexed] nonce: u64,
+    );
+
+    #[event("callback_context_callee_error")]
+    fn callback_context_callee_error_event(
+        &self,
+        #[indexed] err_code: u32,
+        #[indexed] err_msg: &ManagedBuffer,
+        #[indexed] num_refunds: usize,
+    );
+
+    #[event("callback_context_decode_error")]
+    fn callback_context_decode_error_event(&self, #[indexed] num_raw_results: usize);
+
     #[endpoint]
     fn send_funds_twice(
         &self,
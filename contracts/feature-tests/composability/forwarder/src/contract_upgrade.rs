@@ -13,8 +13,9 @@ pub trait UpgradeContractModule {
         #[var_args] opt_arg: OptionalArg<ManagedBuffer>,
     ) {
         self.vault_proxy(child_sc_address)
-            .init(opt_arg)
-            .upgrade_contract(&new_code, CodeMetadata::UPGRADEABLE);
+            .upgrade(opt_arg)
+            .with_code_metadata(CodeMetadata::UPGRADEABLE)
+            .upgrade_contract(&new_code);
     }
 
     #[endpoint]
@@ -25,7 +26,7 @@ pub trait UpgradeContractModule {
         #[var_args] opt_arg: OptionalArg<ManagedBuffer>,
     ) {
         self.vault_proxy(child_sc_address)
-            .init(opt_arg)
-            .upgrade_from_source(&source_address, CodeMetadata::DEFAULT)
+            .upgrade(opt_arg)
+            .upgrade_from_source(&source_address)
     }
 }
@@ -77,6 +77,26 @@ pub fn forward_async_retrieve_funds() {
     forwarder::endpoints::forward_async_retrieve_funds(elrond_wasm_node::arwen_api());
 }
 
+#[no_mangle]
+pub fn forward_async_accept_funds_with_context() {
+    forwarder::endpoints::forward_async_accept_funds_with_context(elrond_wasm_node::arwen_api());
+}
+
+#[no_mangle]
+pub fn forward_async_reject_funds_with_context() {
+    forwarder::endpoints::forward_async_reject_funds_with_context(elrond_wasm_node::arwen_api());
+}
+
+#[no_mangle]
+pub fn forward_async_echo_arguments_with_context() {
+    forwarder::endpoints::forward_async_echo_arguments_with_context(elrond_wasm_node::arwen_api());
+}
+
+#[no_mangle]
+pub fn forward_async_retrieve_funds_with_context() {
+    forwarder::endpoints::forward_async_retrieve_funds_with_context(elrond_wasm_node::arwen_api());
+}
+
 #[no_mangle]
 pub fn send_funds_twice() {
     forwarder::endpoints::send_funds_twice(elrond_wasm_node::arwen_api());
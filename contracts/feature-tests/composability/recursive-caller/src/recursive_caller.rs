@@ -58,6 +58,88 @@ pub trait RecursiveCaller {
         }
     }
 
+    /// Calls itself synchronously, `counter - 1` times.
+    /// Being `#[non_reentrant]`, the first nested call fails.
+    #[endpoint]
+    #[non_reentrant]
+    fn non_reentrant_recursive_call(&self, counter: u32) -> SCResult<()> {
+        require!(counter > 0, "counter must be positive");
+        self.call_count().update(|call_count| *call_count += 1);
+
+        if counter > 1 {
+            self.self_proxy()
+                .contract(self.blockchain().get_sc_address())
+                .non_reentrant_recursive_call(counter - 1)
+                .execute_on_dest_context();
+        }
+        Ok(())
+    }
+
+    /// Calls `non_reentrant_group_b`, which calls back `non_reentrant_group_a`, and so on, `counter - 1` times.
+    /// Endpoints from different lock groups can call each other, so only the third call fails.
+    #[endpoint]
+    #[non_reentrant("group_a")]
+    fn non_reentrant_group_a(&self, counter: u32) {
+        self.call_count().update(|call_count| *call_count += 1);
+
+        if counter > 1 {
+            self.self_proxy()
+                .contract(self.blockchain().get_sc_address())
+                .non_reentrant_group_b(counter - 1)
+                .execute_on_dest_context();
+        }
+    }
+
+    #[endpoint]
+    #[non_reentrant("group_b")]
+    fn non_reentrant_group_b(&self, counter: u32) {
+        self.call_count().update(|call_count| *call_count += 1);
+
+        if counter > 1 {
+            self.self_proxy()
+                .contract(self.blockchain().get_sc_address())
+                .non_reentrant_group_a(counter - 1)
+                .execute_on_dest_context();
+        }
+    }
+
+    /// Forwards the payment to the vault with a raw async call, which ends the execution right away.
+    /// The async call releases the lock, so the endpoint can be called again afterwards.
+    #[payable("EGLD")]
+    #[endpoint]
+    #[non_reentrant]
+    fn non_reentrant_async_call(&self, to: ManagedAddress, #[payment] payment: BigUint) {
+        self.call_count().update(|call_count| *call_count += 1);
+
+        self.raw_vm_api().async_call_raw(
+            &to,
+            &payment,
+            &self.types().managed_buffer_from(&b"accept_funds"[..]),
+            &ManagedArgBuffer::new_empty(self.type_manager()),
+        )
+    }
+
+    /// Upgrades the target contract with its own code.
+    /// The upgrade releases the lock, so the endpoint can be called again afterwards.
+    #[endpoint]
+    #[non_reentrant]
+    fn non_reentrant_upgrade(&self, to: ManagedAddress) {
+        self.call_count().update(|call_count| *call_count += 1);
+
+        self.raw_vm_api().upgrade_from_source_contract(
+            &to,
+            self.blockchain().get_gas_left(),
+            &self.types().big_uint_zero(),
+            &to,
+            CodeMetadata::UPGRADEABLE,
+            &ManagedArgBuffer::new_empty(self.type_manager()),
+        )
+    }
+
+    #[view(getCallCount)]
+    #[storage_mapper("callCount")]
+    fn call_count(&self) -> SingleValueMapper<u32>;
+
     #[event("recursive_send_funds")]
     fn recursive_send_funds_event(
         &self,
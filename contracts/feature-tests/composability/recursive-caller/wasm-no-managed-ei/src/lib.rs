@@ -17,6 +17,36 @@ pub fn recursive_send_funds() {
     recursive_caller::endpoints::recursive_send_funds(elrond_wasm_node::arwen_api());
 }
 
+#[no_mangle]
+pub fn non_reentrant_recursive_call() {
+    recursive_caller::endpoints::non_reentrant_recursive_call(elrond_wasm_node::arwen_api());
+}
+
+#[no_mangle]
+pub fn non_reentrant_group_a() {
+    recursive_caller::endpoints::non_reentrant_group_a(elrond_wasm_node::arwen_api());
+}
+
+#[no_mangle]
+pub fn non_reentrant_group_b() {
+    recursive_caller::endpoints::non_reentrant_group_b(elrond_wasm_node::arwen_api());
+}
+
+#[no_mangle]
+pub fn non_reentrant_async_call() {
+    recursive_caller::endpoints::non_reentrant_async_call(elrond_wasm_node::arwen_api());
+}
+
+#[no_mangle]
+pub fn non_reentrant_upgrade() {
+    recursive_caller::endpoints::non_reentrant_upgrade(elrond_wasm_node::arwen_api());
+}
+
+#[no_mangle]
+pub fn getCallCount() {
+    recursive_caller::endpoints::getCallCount(elrond_wasm_node::arwen_api());
+}
+
 #[no_mangle]
 pub fn callBack() {
     recursive_caller::endpoints::callBack(elrond_wasm_node::arwen_api());
@@ -120,6 +120,11 @@ fn forwarder_call_async_accept_nft_go() {
     elrond_wasm_debug::mandos_go("mandos/forwarder_call_async_accept_nft.scen.json");
 }
 
+#[test]
+fn forwarder_call_async_callback_context_go() {
+    elrond_wasm_debug::mandos_go("mandos/forwarder_call_async_callback_context.scen.json");
+}
+
 #[test]
 fn forwarder_call_async_multi_transfer_go() {
     elrond_wasm_debug::mandos_go("mandos/forwarder_call_async_multi_transfer.scen.json");
@@ -247,6 +252,12 @@ fn forwarder_contract_upgrade_go() {
     elrond_wasm_debug::mandos_go("mandos/forwarder_contract_upgrade.scen.json");
 }
 
+// Arwen calls `init` on upgrade, the `#[upgrade]` method needs a VM with the `vm-upgrade-endpoint` feature
+// #[test]
+// fn forwarder_contract_upgrade_method_go() {
+//     elrond_wasm_debug::mandos_go("mandos/forwarder_contract_upgrade_method.scen.json");
+// }
+
 #[test]
 fn forwarder_nft_create_and_send_go() {
     elrond_wasm_debug::mandos_go("mandos/forwarder_nft_create_and_send.scen.json");
@@ -357,6 +368,11 @@ fn recursive_caller_esdt_1_go() {
     elrond_wasm_debug::mandos_go("mandos/recursive_caller_esdt_1.scen.json");
 }
 
+#[test]
+fn recursive_caller_non_reentrant_go() {
+    elrond_wasm_debug::mandos_go("mandos/recursive_caller_non_reentrant.scen.json");
+}
+
 #[test]
 fn send_egld_go() {
     elrond_wasm_debug::mandos_go("mandos/send_egld.scen.json");
@@ -366,3 +382,8 @@ fn send_egld_go() {
 fn send_esdt_go() {
     elrond_wasm_debug::mandos_go("mandos/send_esdt.scen.json");
 }
+
+#[test]
+fn token_mapper_issue_go() {
+    elrond_wasm_debug::mandos_go("mandos/token_mapper_issue.scen.json");
+}
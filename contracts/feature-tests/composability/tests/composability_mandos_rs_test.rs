@@ -5,6 +5,10 @@ fn contract_map() -> BlockchainMock {
     let mut blockchain = BlockchainMock::new();
     blockchain.set_current_dir_from_workspace("contracts/feature-tests/composability");
 
+    blockchain.register_contract(
+        "file:esdt-system-sc-mock/output/esdt-system-sc-mock.wasm",
+        Box::new(|context| Box::new(esdt_system_sc_mock::contract_obj(context))),
+    );
     blockchain.register_contract(
         "file:forwarder/output/forwarder.wasm",
         Box::new(|context| Box::new(forwarder::contract_obj(context))),
@@ -25,6 +29,10 @@ fn contract_map() -> BlockchainMock {
         "file:recursive-caller/output/recursive-caller.wasm",
         Box::new(|context| Box::new(recursive_caller::contract_obj(context))),
     );
+    blockchain.register_contract(
+        "file:token-mapper-issuer/output/token-mapper-issuer.wasm",
+        Box::new(|context| Box::new(token_mapper_issuer::contract_obj(context))),
+    );
     blockchain.register_contract(
         "file:vault/output/vault.wasm",
         Box::new(|context| Box::new(vault::contract_obj(context))),
@@ -194,6 +202,14 @@ fn forwarder_call_async_accept_nft_rs() {
     );
 }
 
+#[test]
+fn forwarder_call_async_callback_context_rs() {
+    elrond_wasm_debug::mandos_rs(
+        "mandos/forwarder_call_async_callback_context.scen.json",
+        contract_map(),
+    );
+}
+
 #[test]
 fn forwarder_call_async_multi_transfer_rs() {
     elrond_wasm_debug::mandos_rs(
@@ -210,21 +226,21 @@ fn forwarder_call_async_multi_transfer_rs() {
 //     );
 // }
 
-// #[test]
-// fn forwarder_call_async_retrieve_esdt_rs() {
-//     elrond_wasm_debug::mandos_rs(
-//         "mandos/forwarder_call_async_retrieve_esdt.scen.json",
-//         contract_map(),
-//     );
-// }
+#[test]
+fn forwarder_call_async_retrieve_esdt_rs() {
+    elrond_wasm_debug::mandos_rs(
+        "mandos/forwarder_call_async_retrieve_esdt.scen.json",
+        contract_map(),
+    );
+}
 
-// #[test]
-// fn forwarder_call_async_retrieve_nft_rs() {
-//     elrond_wasm_debug::mandos_rs(
-//         "mandos/forwarder_call_async_retrieve_nft.scen.json",
-//         contract_map(),
-//     );
-// }
+#[test]
+fn forwarder_call_async_retrieve_nft_rs() {
+    elrond_wasm_debug::mandos_rs(
+        "mandos/forwarder_call_async_retrieve_nft.scen.json",
+        contract_map(),
+    );
+}
 
 #[test]
 fn forwarder_call_sync_accept_egld_rs() {
@@ -391,6 +407,14 @@ fn forwarder_contract_upgrade_rs() {
     );
 }
 
+#[test]
+fn forwarder_contract_upgrade_method_rs() {
+    elrond_wasm_debug::mandos_rs(
+        "mandos/forwarder_contract_upgrade_method.scen.json",
+        contract_map(),
+    );
+}
+
 #[test]
 fn forwarder_nft_create_and_send_rs() {
     elrond_wasm_debug::mandos_rs(
@@ -543,6 +567,14 @@ fn recursive_caller_esdt_1_rs() {
     elrond_wasm_debug::mandos_rs("mandos/recursive_caller_esdt_1.scen.json", contract_map());
 }
 
+#[test]
+fn recursive_caller_non_reentrant_rs() {
+    elrond_wasm_debug::mandos_rs(
+        "mandos/recursive_caller_non_reentrant.scen.json",
+        contract_map(),
+    );
+}
+
 #[test]
 fn send_egld_rs() {
     elrond_wasm_debug::mandos_rs("mandos/send_egld.scen.json", contract_map());
@@ -552,3 +584,8 @@ fn send_egld_rs() {
 fn send_esdt_rs() {
     elrond_wasm_debug::mandos_rs("mandos/send_esdt.scen.json", contract_map());
 }
+
+#[test]
+fn token_mapper_issue_rs() {
+    elrond_wasm_debug::mandos_rs("mandos/token_mapper_issue.scen.json", contract_map());
+}
@@ -0,0 +1,68 @@
+#![no_std]
+
+elrond_wasm::imports!();
+
+use elrond_wasm::esdt::FungibleTokenProperties;
+
+/// Issues tokens via the token storage mappers.
+/// The token identifiers get saved by the default issue callback, from `TokenMapperCallbacksModule`.
+#[elrond_wasm::contract]
+pub trait TokenMapperIssuer:
+    elrond_wasm_module_esdt::token_mapper_callbacks::TokenMapperCallbacksModule
+{
+    #[init]
+    fn init(&self) {}
+
+    #[payable("EGLD")]
+    #[endpoint(issueFungible)]
+    fn issue_fungible(
+        &self,
+        #[payment] issue_cost: BigUint,
+        token_display_name: ManagedBuffer,
+        token_ticker: ManagedBuffer,
+        initial_supply: BigUint,
+    ) -> AsyncCall {
+        self.fungible_token().issue(
+            issue_cost,
+            &token_display_name,
+            &token_ticker,
+            &initial_supply,
+            FungibleTokenProperties {
+                num_decimals: 18,
+                can_freeze: false,
+                can_wipe: false,
+                can_pause: false,
+                can_mint: true,
+                can_burn: true,
+                can_change_owner: false,
+                can_upgrade: false,
+                can_add_special_roles: true,
+            },
+        )
+    }
+
+    #[payable("EGLD")]
+    #[endpoint(issueNonFungibleAndSetAllRoles)]
+    fn issue_non_fungible_and_set_all_roles(
+        &self,
+        #[payment] issue_cost: BigUint,
+        token_display_name: ManagedBuffer,
+        token_ticker: ManagedBuffer,
+    ) -> AsyncCall {
+        self.non_fungible_token().issue_and_set_all_roles(
+            EsdtTokenType::NonFungible,
+            issue_cost,
+            &token_display_name,
+            &token_ticker,
+            0,
+        )
+    }
+
+    #[view(getFungibleTokenId)]
+    #[storage_mapper("fungibleToken")]
+    fn fungible_token(&self) -> FungibleTokenMapper;
+
+    #[view(getNonFungibleTokenId)]
+    #[storage_mapper("nonFungibleToken")]
+    fn non_fungible_token(&self) -> NonFungibleTokenMapper;
+}
@@ -0,0 +1,38 @@
+////////////////////////////////////////////////////
+////////////////// AUTO-GENERATED //////////////////
+////////////////////////////////////////////////////
+
+#![no_std]
+#![allow(non_snake_case)]
+
+pub use elrond_wasm_output;
+
+#[no_mangle]
+pub fn init() {
+    token_mapper_issuer::endpoints::init(elrond_wasm_node::arwen_api());
+}
+
+#[no_mangle]
+pub fn issueFungible() {
+    token_mapper_issuer::endpoints::issueFungible(elrond_wasm_node::arwen_api());
+}
+
+#[no_mangle]
+pub fn issueNonFungibleAndSetAllRoles() {
+    token_mapper_issuer::endpoints::issueNonFungibleAndSetAllRoles(elrond_wasm_node::arwen_api());
+}
+
+#[no_mangle]
+pub fn getFungibleTokenId() {
+    token_mapper_issuer::endpoints::getFungibleTokenId(elrond_wasm_node::arwen_api());
+}
+
+#[no_mangle]
+pub fn getNonFungibleTokenId() {
+    token_mapper_issuer::endpoints::getNonFungibleTokenId(elrond_wasm_node::arwen_api());
+}
+
+#[no_mangle]
+pub fn callBack() {
+    token_mapper_issuer::endpoints::callBack(elrond_wasm_node::arwen_api());
+}
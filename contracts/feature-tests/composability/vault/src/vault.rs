@@ -15,6 +15,16 @@ pub trait Vault {
         opt_arg_to_echo
     }
 
+    /// Runs instead of `init` when the contract code gets upgraded.
+    #[upgrade]
+    fn upgrade(
+        &self,
+        #[var_args] opt_arg_to_echo: OptionalArg<ManagedBuffer>,
+    ) -> OptionalResult<ManagedBuffer> {
+        self.call_counts(b"upgrade").update(|c| *c += 1);
+        opt_arg_to_echo
+    }
+
     #[payable("*")]
     #[endpoint]
     fn just_accept_funds(&self) {
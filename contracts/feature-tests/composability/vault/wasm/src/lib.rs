@@ -12,6 +12,11 @@ pub fn init() {
     vault::endpoints::init(elrond_wasm_node::arwen_api());
 }
 
+#[no_mangle]
+pub fn upgrade() {
+    vault::endpoints::upgrade(elrond_wasm_node::arwen_api());
+}
+
 #[no_mangle]
 pub fn just_accept_funds() {
     vault::endpoints::just_accept_funds(elrond_wasm_node::arwen_api());
@@ -0,0 +1,38 @@
+#![no_std]
+
+elrond_wasm::imports!();
+
+/// Built into several contracts, see `multicontract.toml`:
+/// the full contract, a view-only contract for queries
+/// and a contract without the admin endpoints.
+#[elrond_wasm::contract]
+pub trait MultiContractFeatures {
+    #[init]
+    fn init(&self, sample_value: BigUint) {
+        self.sample_value().set(&sample_value);
+    }
+
+    #[label("view")]
+    #[view(getSampleValue)]
+    #[storage_mapper("sample_value")]
+    fn sample_value(&self) -> SingleValueMapper<BigUint>;
+
+    #[label("view")]
+    #[view(sampleValueTimes)]
+    fn sample_value_times(&self, factor: u32) -> BigUint {
+        self.sample_value().get() * factor
+    }
+
+    #[endpoint(incrementSampleValue)]
+    fn increment_sample_value(&self) {
+        self.sample_value().update(|value| *value += 1u32);
+    }
+
+    #[label("admin")]
+    #[only_owner]
+    #[endpoint(setSampleValue)]
+    fn set_sample_value(&self, sample_value: BigUint) -> SCResult<()> {
+        self.sample_value().set(&sample_value);
+        Ok(())
+    }
+}
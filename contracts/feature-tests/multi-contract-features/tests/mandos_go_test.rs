@@ -0,0 +1,9 @@
+#[test]
+fn multi_contract_features_go() {
+    elrond_wasm_debug::mandos_go("mandos/multi-contract-features.scen.json");
+}
+
+#[test]
+fn multi_contract_features_view_go() {
+    elrond_wasm_debug::mandos_go("mandos/multi-contract-features-view.scen.json");
+}
@@ -0,0 +1,31 @@
+use elrond_wasm::*;
+use elrond_wasm_debug::*;
+
+fn contract_map() -> BlockchainMock {
+    let mut blockchain = BlockchainMock::new();
+    blockchain.set_current_dir_from_workspace("contracts/feature-tests/multi-contract-features");
+
+    blockchain.register_contract(
+        "file:output/multi-contract-features.wasm",
+        Box::new(|context| Box::new(multi_contract_features::contract_obj(context))),
+    );
+    // same Rust contract, the endpoint selection only applies to the wasm outputs
+    blockchain.register_contract(
+        "file:output/multi-contract-features-view.wasm",
+        Box::new(|context| Box::new(multi_contract_features::contract_obj(context))),
+    );
+    blockchain
+}
+
+#[test]
+fn multi_contract_features_rs() {
+    elrond_wasm_debug::mandos_rs("mandos/multi-contract-features.scen.json", contract_map());
+}
+
+#[test]
+fn multi_contract_features_view_rs() {
+    elrond_wasm_debug::mandos_rs(
+        "mandos/multi-contract-features-view.scen.json",
+        contract_map(),
+    );
+}
@@ -0,0 +1,69 @@
+use elrond_wasm::contract_base::ContractAbiProvider;
+use elrond_wasm_debug::meta::OutputContractConfig;
+
+fn load_config() -> OutputContractConfig {
+    OutputContractConfig::load_from_file_or_default(
+        "multicontract.toml",
+        &multi_contract_features::AbiProvider::abi(),
+    )
+}
+
+#[test]
+fn test_main_contract() {
+    let config = load_config();
+    let main = config.main_contract();
+    assert!(main.main);
+    assert_eq!(main.public_name, "multi-contract-features");
+    assert_eq!(main.wasm_crate_name, "multi-contract-features-wasm");
+    assert_eq!(main.wasm_crate_dir(), "../wasm");
+    assert_eq!(
+        main.endpoint_names(),
+        vec![
+            "getSampleValue",
+            "sampleValueTimes",
+            "incrementSampleValue",
+            "setSampleValue"
+        ]
+    );
+}
+
+#[test]
+fn test_view_contract() {
+    let config = load_config();
+    let view = config
+        .get_contract_by_name("multi-contract-features-view")
+        .unwrap();
+    assert!(!view.main);
+    assert_eq!(view.wasm_crate_name, "multi-contract-features-view-wasm");
+    assert_eq!(
+        view.wasm_crate_dir(),
+        "../wasm-multi-contract-features-view"
+    );
+    assert_eq!(
+        view.endpoint_names(),
+        vec!["getSampleValue", "sampleValueTimes"]
+    );
+}
+
+#[test]
+fn test_no_admin_contract() {
+    let config = load_config();
+    assert_eq!(config.contracts.len(), 3);
+    let no_admin = config
+        .get_contract_by_name("multi-contract-features-no-admin")
+        .unwrap();
+    assert_eq!(
+        no_admin.endpoint_names(),
+        vec!["getSampleValue", "sampleValueTimes", "incrementSampleValue"]
+    );
+}
+
+#[test]
+fn test_missing_config_file() {
+    let config = OutputContractConfig::load_from_file_or_default(
+        "missing-multicontract.toml",
+        &multi_contract_features::AbiProvider::abi(),
+    );
+    assert_eq!(config.contracts.len(), 1);
+    assert_eq!(config.main_contract().endpoint_names().len(), 4);
+}
@@ -0,0 +1,28 @@
+////////////////////////////////////////////////////
+////////////////// AUTO-GENERATED //////////////////
+////////////////////////////////////////////////////
+
+#![no_std]
+#![allow(non_snake_case)]
+
+pub use elrond_wasm_output;
+
+#[no_mangle]
+pub fn init() {
+    multi_contract_features::endpoints::init(elrond_wasm_node::arwen_api());
+}
+
+#[no_mangle]
+pub fn getSampleValue() {
+    multi_contract_features::endpoints::getSampleValue(elrond_wasm_node::arwen_api());
+}
+
+#[no_mangle]
+pub fn sampleValueTimes() {
+    multi_contract_features::endpoints::sampleValueTimes(elrond_wasm_node::arwen_api());
+}
+
+#[no_mangle]
+pub fn callBack() {
+    multi_contract_features::endpoints::callBack(elrond_wasm_node::arwen_api());
+}
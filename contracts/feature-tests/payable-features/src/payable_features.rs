@@ -139,4 +139,47 @@ pub trait PayableFeatures {
         let token = self.call_value().token();
         (payment, token).into()
     }
+
+    #[endpoint]
+    #[payable("EGLD", "PAYABLE-FEATURES-TOKEN", token_from = "accepted_token")]
+    fn payable_token_list(
+        &self,
+        #[payment] payment: BigUint,
+        #[payment_token] token: TokenIdentifier,
+    ) -> MultiResult2<BigUint, TokenIdentifier> {
+        (payment, token).into()
+    }
+
+    #[endpoint]
+    #[payable("EGLD", "PAYABLE-FEATURES-TOKEN", min_payments = 0)]
+    fn payable_token_list_optional(
+        &self,
+        #[payment] payment: BigUint,
+        #[payment_token] token: TokenIdentifier,
+    ) -> MultiResult2<BigUint, TokenIdentifier> {
+        (payment, token).into()
+    }
+
+    #[storage_get("acceptedToken")]
+    fn accepted_token(&self) -> TokenIdentifier;
+
+    #[endpoint]
+    #[payable("*", min_payments = 2, max_payments = 2)]
+    fn payable_two_payments(
+        &self,
+        #[payment_multi] payments: ManagedVec<Self::Api, EsdtTokenPayment<Self::Api>>,
+    ) -> ManagedVec<Self::Api, EsdtTokenPayment<Self::Api>> {
+        payments
+    }
+
+    #[endpoint]
+    #[payable("*", token_type = "NonFungible")]
+    fn payable_non_fungible(
+        &self,
+        #[payment] payment: BigUint,
+        #[payment_token] token: TokenIdentifier,
+        #[payment_nonce] nonce: u64,
+    ) -> MultiResult3<BigUint, TokenIdentifier, u64> {
+        (payment, token, nonce).into()
+    }
 }
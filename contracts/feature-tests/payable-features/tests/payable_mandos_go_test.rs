@@ -62,3 +62,18 @@ fn payable_token_3_go() {
 fn payable_token_4_go() {
     elrond_wasm_debug::mandos_go("mandos/payable_token_4.scen.json");
 }
+
+#[test]
+fn payable_payment_count_go() {
+    elrond_wasm_debug::mandos_go("mandos/payable_payment_count.scen.json");
+}
+
+#[test]
+fn payable_token_list_go() {
+    elrond_wasm_debug::mandos_go("mandos/payable_token_list.scen.json");
+}
+
+#[test]
+fn payable_token_type_go() {
+    elrond_wasm_debug::mandos_go("mandos/payable_token_type.scen.json");
+}
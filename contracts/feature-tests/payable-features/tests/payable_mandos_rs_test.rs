@@ -80,3 +80,18 @@ fn payable_token_3_rs() {
 fn payable_token_4_rs() {
     elrond_wasm_debug::mandos_rs("mandos/payable_token_4.scen.json", contract_map());
 }
+
+#[test]
+fn payable_payment_count_rs() {
+    elrond_wasm_debug::mandos_rs("mandos/payable_payment_count.scen.json", contract_map());
+}
+
+#[test]
+fn payable_token_list_rs() {
+    elrond_wasm_debug::mandos_rs("mandos/payable_token_list.scen.json", contract_map());
+}
+
+#[test]
+fn payable_token_type_rs() {
+    elrond_wasm_debug::mandos_rs("mandos/payable_token_type.scen.json", contract_map());
+}
@@ -82,6 +82,26 @@ pub fn payable_token_4() {
     payable_features::endpoints::payable_token_4(elrond_wasm_node::arwen_api());
 }
 
+#[no_mangle]
+pub fn payable_token_list() {
+    payable_features::endpoints::payable_token_list(elrond_wasm_node::arwen_api());
+}
+
+#[no_mangle]
+pub fn payable_token_list_optional() {
+    payable_features::endpoints::payable_token_list_optional(elrond_wasm_node::arwen_api());
+}
+
+#[no_mangle]
+pub fn payable_two_payments() {
+    payable_features::endpoints::payable_two_payments(elrond_wasm_node::arwen_api());
+}
+
+#[no_mangle]
+pub fn payable_non_fungible() {
+    payable_features::endpoints::payable_non_fungible(elrond_wasm_node::arwen_api());
+}
+
 #[no_mangle]
 pub fn callBack() {
     payable_features::endpoints::callBack(elrond_wasm_node::arwen_api());
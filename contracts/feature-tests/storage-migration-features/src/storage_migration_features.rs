@@ -0,0 +1,98 @@
+#![no_std]
+
+elrond_wasm::imports!();
+
+use elrond_wasm::storage::migration::{get_storage_version, run_while_gas_left, MigrationStatus};
+
+/// Amounts used to be expressed in whole units, they are now expressed with 3 decimals.
+const AMOUNT_MULTIPLIER: u64 = 1_000;
+
+/// The amount conversion stops when less gas than this is left, to be resumed later.
+const MIN_GAS_LEFT_FOR_CONVERSION: u64 = 5_000_000;
+
+/// Explores storage migrations, on upgrade.
+///
+/// Version 1 of this contract stored a fee under the `fee` key and amounts in whole units.
+/// The current version (3) stores the fee under `feePercent` and amounts with 3 decimals.
+#[elrond_wasm::contract]
+pub trait StorageMigrationFeatures {
+    #[init]
+    fn init(&self) {}
+
+    /// Only gets called once all migrations are completed, so the maximum amount has 3 decimals,
+    /// like the converted amounts.
+    #[upgrade]
+    fn upgrade(&self, max_amount: u64) {
+        self.max_amount().set(&max_amount);
+        self.upgrade_count().update(|count| *count += 1);
+    }
+
+    /// Resumes migrations that did not complete during the upgrade.
+    #[only_owner]
+    #[endpoint(migrateStorage)]
+    fn migrate_storage(&self) -> MigrationStatus {
+        self.run_storage_migrations()
+    }
+
+    #[view(getStorageVersion)]
+    fn get_storage_version(&self) -> u32 {
+        get_storage_version(self.raw_vm_api())
+    }
+
+    #[endpoint(addAmount)]
+    fn add_amount(&self, amount: u64) {
+        self.amounts().push(&amount);
+    }
+
+    // migrations
+
+    #[migration(from = 1, to = 2)]
+    fn rename_fee_key(&self) {
+        let fee = self.legacy_fee().get();
+        self.legacy_fee().clear();
+        self.fee_percent().set(&fee);
+    }
+
+    #[migration(from = 2, to = 3)]
+    fn convert_amounts(&self) -> MigrationStatus {
+        let status = run_while_gas_left(&self.raw_vm_api(), MIN_GAS_LEFT_FOR_CONVERSION, || {
+            let next_index = self.conversion_cursor().get() + 1;
+            if next_index > self.amounts().len() {
+                return MigrationStatus::Completed;
+            }
+            let amount = self.amounts().get(next_index);
+            self.amounts()
+                .set(next_index, &(amount * AMOUNT_MULTIPLIER));
+            self.conversion_cursor().set(&next_index);
+            MigrationStatus::InProgress
+        });
+        if status.is_completed() {
+            self.conversion_cursor().clear();
+        }
+        status
+    }
+
+    // storage
+
+    #[storage_mapper("fee")]
+    fn legacy_fee(&self) -> SingleValueMapper<u32>;
+
+    #[view(getFeePercent)]
+    #[storage_mapper("feePercent")]
+    fn fee_percent(&self) -> SingleValueMapper<u32>;
+
+    #[view(getAmounts)]
+    #[storage_mapper("amounts")]
+    fn amounts(&self) -> VecMapper<u64>;
+
+    #[view(getMaxAmount)]
+    #[storage_mapper("maxAmount")]
+    fn max_amount(&self) -> SingleValueMapper<u64>;
+
+    #[view(getUpgradeCount)]
+    #[storage_mapper("upgradeCount")]
+    fn upgrade_count(&self) -> SingleValueMapper<u32>;
+
+    #[storage_mapper("conversionCursor")]
+    fn conversion_cursor(&self) -> SingleValueMapper<usize>;
+}
@@ -0,0 +1,5 @@
+// Arwen calls `init` on upgrade, the `#[upgrade]` method needs a VM with the `vm-upgrade-endpoint` feature
+// #[test]
+// fn upgrade_migration_go() {
+//     elrond_wasm_debug::mandos_go("mandos/upgrade_migration.scen.json");
+// }
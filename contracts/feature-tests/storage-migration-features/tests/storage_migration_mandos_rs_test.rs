@@ -0,0 +1,17 @@
+use elrond_wasm::*;
+use elrond_wasm_debug::*;
+
+fn contract_map() -> BlockchainMock {
+    let mut blockchain = BlockchainMock::new();
+    blockchain.set_current_dir_from_workspace("contracts/feature-tests/storage-migration-features");
+    blockchain.register_contract(
+        "file:output/storage-migration-features.wasm",
+        Box::new(|context| Box::new(storage_migration_features::contract_obj(context))),
+    );
+    blockchain
+}
+
+#[test]
+fn upgrade_migration_rs() {
+    elrond_wasm_debug::mandos_rs("mandos/upgrade_migration.scen.json", contract_map());
+}
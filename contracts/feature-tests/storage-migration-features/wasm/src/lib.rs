@@ -0,0 +1,58 @@
+////////////////////////////////////////////////////
+////////////////// AUTO-GENERATED //////////////////
+////////////////////////////////////////////////////
+
+#![no_std]
+#![allow(non_snake_case)]
+
+pub use elrond_wasm_output;
+
+#[no_mangle]
+pub fn init() {
+    storage_migration_features::endpoints::init(elrond_wasm_node::arwen_api());
+}
+
+#[no_mangle]
+pub fn upgrade() {
+    storage_migration_features::endpoints::upgrade(elrond_wasm_node::arwen_api());
+}
+
+#[no_mangle]
+pub fn migrateStorage() {
+    storage_migration_features::endpoints::migrateStorage(elrond_wasm_node::arwen_api());
+}
+
+#[no_mangle]
+pub fn getStorageVersion() {
+    storage_migration_features::endpoints::getStorageVersion(elrond_wasm_node::arwen_api());
+}
+
+#[no_mangle]
+pub fn addAmount() {
+    storage_migration_features::endpoints::addAmount(elrond_wasm_node::arwen_api());
+}
+
+#[no_mangle]
+pub fn getFeePercent() {
+    storage_migration_features::endpoints::getFeePercent(elrond_wasm_node::arwen_api());
+}
+
+#[no_mangle]
+pub fn getAmounts() {
+    storage_migration_features::endpoints::getAmounts(elrond_wasm_node::arwen_api());
+}
+
+#[no_mangle]
+pub fn getMaxAmount() {
+    storage_migration_features::endpoints::getMaxAmount(elrond_wasm_node::arwen_api());
+}
+
+#[no_mangle]
+pub fn getUpgradeCount() {
+    storage_migration_features::endpoints::getUpgradeCount(elrond_wasm_node::arwen_api());
+}
+
+#[no_mangle]
+pub fn callBack() {
+    storage_migration_features::endpoints::callBack(elrond_wasm_node::arwen_api());
+}
@@ -8,6 +8,7 @@ elrond_wasm::imports!();
 
 /// Contract that tests that using modules works correctly.
 /// Also provides testing for the most common modules:
+/// - AccessControlModule
 /// - DnsModule
 /// - FeaturesModule
 /// - EsdtModule
@@ -18,6 +19,7 @@ pub trait UseModule:
     internal_mod_a::InternalModuleA
     + internal_mod_b::InternalModuleB
     + internal_mod_c::InternalModuleC
+    + elrond_wasm_module_access_control::AccessControlModule
     + elrond_wasm_module_dns::DnsModule
     + elrond_wasm_module_esdt::EsdtModule
     + elrond_wasm_module_features::FeaturesModule
@@ -35,6 +37,24 @@ pub trait UseModule:
         self.check_feature_on(b"featureName", true);
     }
 
+    /// Gives the owner the default admin role.
+    /// Contracts would normally do this in their constructor.
+    #[only_owner]
+    #[endpoint(setUpAccessControl)]
+    fn set_up_access_control(&self) {
+        let caller = self.blockchain().get_caller();
+        self.grant_role(
+            &self
+                .types()
+                .managed_buffer_from(elrond_wasm_module_access_control::DEFAULT_ADMIN_ROLE),
+            &caller,
+        );
+    }
+
+    #[only_role("MINTER")]
+    #[endpoint(checkOnlyRole)]
+    fn check_only_role(&self) {}
+
     #[endpoint(checkPause)]
     fn check_pause(&self) -> SCResult<bool> {
         Ok(self.is_paused())
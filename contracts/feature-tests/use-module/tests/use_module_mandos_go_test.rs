@@ -1,3 +1,8 @@
+#[test]
+fn use_module_access_control_go() {
+    elrond_wasm_debug::mandos_go("mandos/use_module_access_control.scen.json");
+}
+
 #[test]
 fn use_module_dns_register_go() {
     elrond_wasm_debug::mandos_go("mandos/use_module_dns_register.scen.json");
@@ -54,6 +54,11 @@ fn _gov_contract_map() -> BlockchainMock {
     blockchain
 }
 
+#[test]
+fn use_module_access_control_rs() {
+    elrond_wasm_debug::mandos_rs("mandos/use_module_access_control.scen.json", contract_map());
+}
+
 #[test]
 fn use_module_dns_register_rs() {
     elrond_wasm_debug::mandos_rs("mandos/use_module_dns_register.scen.json", contract_map());
@@ -17,6 +17,16 @@ pub fn checkFeatureGuard() {
     use_module::endpoints::checkFeatureGuard(elrond_wasm_node::arwen_api());
 }
 
+#[no_mangle]
+pub fn setUpAccessControl() {
+    use_module::endpoints::setUpAccessControl(elrond_wasm_node::arwen_api());
+}
+
+#[no_mangle]
+pub fn checkOnlyRole() {
+    use_module::endpoints::checkOnlyRole(elrond_wasm_node::arwen_api());
+}
+
 #[no_mangle]
 pub fn checkPause() {
     use_module::endpoints::checkPause(elrond_wasm_node::arwen_api());
@@ -37,6 +47,41 @@ pub fn call_mod_c() {
     use_module::endpoints::call_mod_c(elrond_wasm_node::arwen_api());
 }
 
+#[no_mangle]
+pub fn hasRole() {
+    use_module::endpoints::hasRole(elrond_wasm_node::arwen_api());
+}
+
+#[no_mangle]
+pub fn getRoleAdmin() {
+    use_module::endpoints::getRoleAdmin(elrond_wasm_node::arwen_api());
+}
+
+#[no_mangle]
+pub fn grantRole() {
+    use_module::endpoints::grantRole(elrond_wasm_node::arwen_api());
+}
+
+#[no_mangle]
+pub fn revokeRole() {
+    use_module::endpoints::revokeRole(elrond_wasm_node::arwen_api());
+}
+
+#[no_mangle]
+pub fn renounceRole() {
+    use_module::endpoints::renounceRole(elrond_wasm_node::arwen_api());
+}
+
+#[no_mangle]
+pub fn setRoleAdmin() {
+    use_module::endpoints::setRoleAdmin(elrond_wasm_node::arwen_api());
+}
+
+#[no_mangle]
+pub fn getRoleMembers() {
+    use_module::endpoints::getRoleMembers(elrond_wasm_node::arwen_api());
+}
+
 #[no_mangle]
 pub fn dnsRegister() {
     use_module::endpoints::dnsRegister(elrond_wasm_node::arwen_api());
@@ -0,0 +1,155 @@
+#![no_std]
+
+elrond_wasm::imports!();
+
+/// The admin role of all roles that have no other admin role configured.
+pub const DEFAULT_ADMIN_ROLE: &[u8] = b"DEFAULT_ADMIN";
+
+/// Standard module for role-based access control.
+///
+/// Roles are identified by name. Each role has a set of members and an admin role.
+/// Members of the admin role can grant and revoke the role.
+/// Unless configured otherwise, the admin role is `DEFAULT_ADMIN`.
+///
+/// Nobody has any role initially, the contract should grant the first roles in its constructor.
+///
+/// Endpoints can be restricted to the members of a role by annotating them with `#[only_role("ROLE")]`.
+#[elrond_wasm::module]
+pub trait AccessControlModule {
+    // views
+
+    #[view(hasRole)]
+    fn has_role(&self, role: &ManagedBuffer, address: &ManagedAddress) -> bool {
+        self.role_members(role).contains(address)
+    }
+
+    #[view(getRoleAdmin)]
+    fn get_role_admin(&self, role: &ManagedBuffer) -> ManagedBuffer {
+        let admin_role_mapper = self.role_admin(role);
+        if admin_role_mapper.is_empty() {
+            self.types().managed_buffer_from(DEFAULT_ADMIN_ROLE)
+        } else {
+            admin_role_mapper.get()
+        }
+    }
+
+    // endpoints
+
+    #[endpoint(grantRole)]
+    fn grant_role_endpoint(&self, role: ManagedBuffer, address: ManagedAddress) -> SCResult<()> {
+        self.require_caller_is_role_admin(&role)?;
+        self.grant_role(&role, &address);
+        Ok(())
+    }
+
+    #[endpoint(revokeRole)]
+    fn revoke_role_endpoint(&self, role: ManagedBuffer, address: ManagedAddress) -> SCResult<()> {
+        self.require_caller_is_role_admin(&role)?;
+        self.revoke_role(&role, &address);
+        Ok(())
+    }
+
+    /// Allows the caller to give up one of their own roles.
+    #[endpoint(renounceRole)]
+    fn renounce_role_endpoint(&self, role: ManagedBuffer) -> SCResult<()> {
+        let caller = self.blockchain().get_caller();
+        require!(self.has_role(&role, &caller), "caller does not have role");
+        self.revoke_role(&role, &caller);
+        Ok(())
+    }
+
+    #[endpoint(setRoleAdmin)]
+    fn set_role_admin_endpoint(
+        &self,
+        role: ManagedBuffer,
+        admin_role: ManagedBuffer,
+    ) -> SCResult<()> {
+        self.require_caller_is_role_admin(&role)?;
+        self.set_role_admin(&role, &admin_role);
+        Ok(())
+    }
+
+    // private
+
+    /// Gives the role to an address. Does nothing if the address already has the role.
+    /// Performs no access checks, use it from the contract constructor to grant the first roles.
+    fn grant_role(&self, role: &ManagedBuffer, address: &ManagedAddress) {
+        if self.role_members(role).insert(address.clone()) {
+            let caller = self.blockchain().get_caller();
+            self.role_granted_event(role, address, &caller);
+        }
+    }
+
+    /// Takes the role from an address. Does nothing if the address does not have the role.
+    /// Performs no access checks.
+    fn revoke_role(&self, role: &ManagedBuffer, address: &ManagedAddress) {
+        if self.role_members(role).remove(address) {
+            let caller = self.blockchain().get_caller();
+            self.role_revoked_event(role, address, &caller);
+        }
+    }
+
+    /// Changes the role whose members can grant and revoke a role.
+    /// Performs no access checks.
+    fn set_role_admin(&self, role: &ManagedBuffer, admin_role: &ManagedBuffer) {
+        let previous_admin_role = self.get_role_admin(role);
+        self.role_admin(role).set(admin_role);
+        self.role_admin_changed_event(role, &previous_admin_role, admin_role);
+    }
+
+    fn require_caller_is_role_admin(&self, role: &ManagedBuffer) -> SCResult<()> {
+        let caller = self.blockchain().get_caller();
+        require!(
+            self.has_role(&self.get_role_admin(role), &caller),
+            "caller is not the role admin"
+        );
+        Ok(())
+    }
+
+    /// Stops execution if the caller does not have the role.
+    /// Called by the code generated for the `#[only_role]` annotation.
+    fn require_caller_has_role(&self, role: &[u8]) {
+        let caller = self.blockchain().get_caller();
+        if !self.has_role(&self.types().managed_buffer_from(role), &caller) {
+            let mut err = self.error().new_error();
+            err.append_bytes(&b"caller does not have role "[..]);
+            err.append_bytes(role);
+            err.exit_now()
+        }
+    }
+
+    // storage
+
+    #[view(getRoleMembers)]
+    #[storage_mapper("access_control:members", abi = true)]
+    fn role_members(&self, role: &ManagedBuffer) -> SetMapper<ManagedAddress>;
+
+    #[storage_mapper("access_control:admin", abi = true)]
+    fn role_admin(&self, role: &ManagedBuffer) -> SingleValueMapper<ManagedBuffer>;
+
+    // events
+
+    #[event("roleGranted")]
+    fn role_granted_event(
+        &self,
+        #[indexed] role: &ManagedBuffer,
+        #[indexed] address: &ManagedAddress,
+        #[indexed] sender: &ManagedAddress,
+    );
+
+    #[event("roleRevoked")]
+    fn role_revoked_event(
+        &self,
+        #[indexed] role: &ManagedBuffer,
+        #[indexed] address: &ManagedAddress,
+        #[indexed] sender: &ManagedAddress,
+    );
+
+    #[event("roleAdminChanged")]
+    fn role_admin_changed_event(
+        &self,
+        #[indexed] role: &ManagedBuffer,
+        #[indexed] previous_admin_role: &ManagedBuffer,
+        #[indexed] new_admin_role: &ManagedBuffer,
+    );
+}
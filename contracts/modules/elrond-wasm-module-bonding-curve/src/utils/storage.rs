@@ -5,18 +5,18 @@ use super::structs::{BondingCurve, TokenOwnershipData};
 
 #[elrond_wasm::module]
 pub trait StorageModule {
-    #[storage_mapper("token_details")]
+    #[storage_mapper("token_details", abi = true)]
     fn token_details(
         &self,
         token: &TokenIdentifier,
     ) -> SingleValueMapper<TokenOwnershipData<Self::Api>>;
 
-    #[storage_mapper("bonding_curve")]
+    #[storage_mapper("bonding_curve", abi = true)]
     fn bonding_curve(&self, token: &TokenIdentifier) -> SingleValueMapper<BondingCurve<Self::Api>>;
 
-    #[storage_mapper("owned_tokens")]
+    #[storage_mapper("owned_tokens", abi = true)]
     fn owned_tokens(&self, owner: &ManagedAddress) -> SetMapper<TokenIdentifier>;
 
-    #[storage_mapper("nonce_amount")]
+    #[storage_mapper("nonce_amount", abi = true)]
     fn nonce_amount(&self, identifier: &TokenIdentifier, nonce: u64) -> SingleValueMapper<BigUint>;
 }
@@ -2,10 +2,12 @@
 
 elrond_wasm::imports!();
 
+pub mod token_mapper_callbacks;
+
 /// Standard module for managing a single ESDT.
 #[elrond_wasm::module]
 pub trait EsdtModule {
-    #[storage_mapper("token_id")]
+    #[storage_mapper("token_id", abi = true)]
     fn token_id(&self) -> SingleValueMapper<TokenIdentifier>;
 
     #[payable("EGLD")]
@@ -0,0 +1,34 @@
+elrond_wasm::imports!();
+
+use elrond_wasm::storage::StorageKey;
+
+/// Provides the default issue callback used by `FungibleTokenMapper` and `NonFungibleTokenMapper`.
+/// Contracts that issue tokens via these mappers need to include this module.
+#[elrond_wasm::module]
+pub trait TokenMapperCallbacksModule {
+    /// Saves the issued token identifier under the storage key of the mapper that issued it.
+    /// On failure, the issue cost is returned to the caller that initiated the issue.
+    #[callback]
+    fn default_issue_cb(
+        &self,
+        initial_caller: &ManagedAddress,
+        storage_key: ManagedBuffer,
+        #[call_result] result: ManagedAsyncCallResult<TokenIdentifier>,
+    ) {
+        match result {
+            ManagedAsyncCallResult::Ok(token_id) => {
+                elrond_wasm::storage_set(
+                    self.raw_vm_api(),
+                    &StorageKey::from(storage_key),
+                    &token_id,
+                );
+            },
+            ManagedAsyncCallResult::Err(_) => {
+                let egld_returned = self.call_value().egld_value();
+                if egld_returned > 0u32 {
+                    self.send().direct_egld(initial_caller, &egld_returned, &[]);
+                }
+            },
+        }
+    }
+}
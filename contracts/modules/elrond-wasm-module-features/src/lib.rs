@@ -9,10 +9,10 @@ pub const FEATURE_OFF: u8 = 2;
 /// Standard module for managing feature flags.
 #[elrond_wasm::module]
 pub trait FeaturesModule {
-    #[storage_get("feat:")]
+    #[storage_get("feat:", abi = true)]
     fn get_feature_flag(&self, feature_name: FeatureName) -> u8;
 
-    #[storage_set("feat:")]
+    #[storage_set("feat:", abi = true)]
     fn set_feature_flag(&self, feature_name: FeatureName, value: u8);
 
     fn check_feature_on(&self, feature_name: &'static [u8], default: bool) {
@@ -68,3 +68,10 @@ impl<'a> NestedEncode for FeatureName<'a> {
         dest.write(self.0);
     }
 }
+
+/// The feature name is appended to the storage key as raw bytes.
+impl<'a> elrond_wasm::abi::TypeAbi for FeatureName<'a> {
+    fn type_name() -> elrond_wasm::abi::TypeName {
+        "bytes".into()
+    }
+}
@@ -169,32 +169,32 @@ pub trait GovernanceConfigurablePropertiesModule {
     // storage - fixed parameters
 
     #[view(getGovernanceTokenId)]
-    #[storage_mapper("governance:governanceTokenId")]
+    #[storage_mapper("governance:governanceTokenId", abi = true)]
     fn governance_token_id(&self) -> SingleValueMapper<TokenIdentifier>;
 
     // storage - configurable parameters
 
     #[view(getQuorum)]
-    #[storage_mapper("governance:quorum")]
+    #[storage_mapper("governance:quorum", abi = true)]
     fn quorum(&self) -> SingleValueMapper<BigUint>;
 
     #[view(getMinTokenBalanceForProposing)]
-    #[storage_mapper("governance:minTokenBalanceForProposing")]
+    #[storage_mapper("governance:minTokenBalanceForProposing", abi = true)]
     fn min_token_balance_for_proposing(&self) -> SingleValueMapper<BigUint>;
 
     #[view(getMaxActionsPerProposal)]
-    #[storage_mapper("governance:maxActionsPerProposal")]
+    #[storage_mapper("governance:maxActionsPerProposal", abi = true)]
     fn max_actions_per_proposal(&self) -> SingleValueMapper<usize>;
 
     #[view(getVotingDelayInBlocks)]
-    #[storage_mapper("governance:votingDelayInBlocks")]
+    #[storage_mapper("governance:votingDelayInBlocks", abi = true)]
     fn voting_delay_in_blocks(&self) -> SingleValueMapper<u64>;
 
     #[view(getVotingPeriodInBlocks)]
-    #[storage_mapper("governance:votingPeriodInBlocks")]
+    #[storage_mapper("governance:votingPeriodInBlocks", abi = true)]
     fn voting_period_in_blocks(&self) -> SingleValueMapper<u64>;
 
     #[view(getLockTimeAfterVotingEndsInBlocks)]
-    #[storage_mapper("governance:lockTimeAfterVotingEndsInBlocks")]
+    #[storage_mapper("governance:lockTimeAfterVotingEndsInBlocks", abi = true)]
     fn lock_time_after_voting_ends_in_blocks(&self) -> SingleValueMapper<u64>;
 }
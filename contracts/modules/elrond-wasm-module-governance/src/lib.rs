@@ -453,29 +453,29 @@ pub trait GovernanceModule:
 
     // storage - general
 
-    #[storage_mapper("governance:proposals")]
+    #[storage_mapper("governance:proposals", abi = true)]
     fn proposals(&self) -> VecMapper<GovernanceProposal<Self::Api>>;
 
     /// Not stored under "proposals", as that would require deserializing the whole struct
-    #[storage_mapper("governance:proposalStartBlock")]
+    #[storage_mapper("governance:proposalStartBlock", abi = true)]
     fn proposal_start_block(&self, proposal_id: usize) -> SingleValueMapper<u64>;
 
-    #[storage_mapper("governance:proposalQueueBlock")]
+    #[storage_mapper("governance:proposalQueueBlock", abi = true)]
     fn proposal_queue_block(&self, proposal_id: usize) -> SingleValueMapper<u64>;
 
-    #[storage_mapper("governance:votes")]
+    #[storage_mapper("governance:votes", abi = true)]
     fn votes(&self, proposal_id: usize) -> MapMapper<ManagedAddress, BigUint>;
 
-    #[storage_mapper("governance:downvotes")]
+    #[storage_mapper("governance:downvotes", abi = true)]
     fn downvotes(&self, proposal_id: usize) -> MapMapper<ManagedAddress, BigUint>;
 
     /// Could be calculated by iterating over the "votes" mapper, but that costs a lot of gas
     #[view(getTotalVotes)]
-    #[storage_mapper("governance:totalVotes")]
+    #[storage_mapper("governance:totalVotes", abi = true)]
     fn total_votes(&self, proposal_id: usize) -> SingleValueMapper<BigUint>;
 
     /// Could be calculated by iterating over the "downvotes" mapper, but that costs a lot of gas
     #[view(getTotalDownvotes)]
-    #[storage_mapper("governance:totalDownvotes")]
+    #[storage_mapper("governance:totalDownvotes", abi = true)]
     fn total_downvotes(&self, proposal_id: usize) -> SingleValueMapper<BigUint>;
 }
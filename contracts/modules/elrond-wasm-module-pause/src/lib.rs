@@ -8,14 +8,14 @@ elrond_wasm::imports!();
 #[elrond_wasm::module]
 pub trait PauseModule {
     #[view(isPaused)]
-    #[storage_get("pause_module:paused")]
+    #[storage_get("pause_module:paused", abi = true)]
     fn is_paused(&self) -> bool;
 
     fn not_paused(&self) -> bool {
         !self.is_paused()
     }
 
-    #[storage_set("pause_module:paused")]
+    #[storage_set("pause_module:paused", abi = true)]
     fn set_paused(&self, paused: bool);
 
     #[endpoint(pause)]
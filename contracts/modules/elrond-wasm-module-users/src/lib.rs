@@ -12,28 +12,28 @@ pub trait UsersModule {
     /// The key is the bytes "user_id" concatenated with their public key.
     /// The value is the user id.
     #[view(getUserId)]
-    #[storage_get("user_id")]
+    #[storage_get("user_id", abi = true)]
     fn get_user_id(&self, address: &ManagedAddress) -> usize;
 
-    #[storage_set("user_id")]
+    #[storage_set("user_id", abi = true)]
     fn set_user_id(&self, address: &ManagedAddress, user_id: usize);
 
     #[view(getUserAddress)]
-    #[storage_get("user_address")]
+    #[storage_get("user_address", abi = true)]
     fn get_user_address(&self, user_id: usize) -> ManagedAddress;
 
-    #[storage_set("user_address")]
+    #[storage_set("user_address", abi = true)]
     fn set_user_address(&self, user_id: usize, address: &ManagedAddress);
 
     /// Retrieves the number of delegtors, including the owner,
     /// even if they no longer have anything in the contract.
     #[view(getNumUsers)]
-    #[storage_get("num_users")]
+    #[storage_get("num_users", abi = true)]
     fn get_num_users(&self) -> usize;
 
     /// Yields how accounts are registered in the contract.
     /// Note that not all of them must have stakes greater than zero.
-    #[storage_set("num_users")]
+    #[storage_set("num_users", abi = true)]
     fn set_num_users(&self, num_users: usize);
 
     fn get_or_create_user(&self, address: &ManagedAddress) -> usize {
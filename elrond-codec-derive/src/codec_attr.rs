@@ -0,0 +1,244 @@
+const ATTR_CODEC: &str = "codec";
+
+/// Field settings, given as `#[codec(skip)]`, `#[codec(optional)]` or `#[codec(with = "module")]`.
+#[derive(Default)]
+pub struct FieldCodecAttributes {
+    /// The field is neither encoded nor decoded, it gets `Default::default()` on decode.
+    pub skip: bool,
+
+    /// Top-level decoding fills in `Default::default()` if the input ends before the field.
+    /// Allows adding new trailing fields to structs that are already in storage.
+    pub optional: bool,
+
+    /// Module providing `dep_encode` and `dep_decode` functions that replace the field type codec.
+    pub with: Option<syn::Path>,
+}
+
+/// Flattens the arguments of all `#[codec(...)]` attributes.
+fn codec_attribute_args(attrs: &[syn::Attribute]) -> Vec<syn::NestedMeta> {
+    attrs
+        .iter()
+        .filter(|attr| attr.path.is_ident(ATTR_CODEC))
+        .flat_map(|attr| match attr.parse_meta() {
+            Ok(syn::Meta::List(meta_list)) => meta_list.nested.into_iter(),
+            _ => panic!("codec attribute expected in the form `#[codec(...)]`"),
+        })
+        .collect()
+}
+
+fn lit_str_value(lit: &syn::Lit, arg_name: &str) -> String {
+    match lit {
+        syn::Lit::Str(lit_str) => lit_str.value(),
+        _ => panic!("codec attribute `{}` expects a string literal", arg_name),
+    }
+}
+
+pub fn field_codec_attributes(field: &syn::Field) -> FieldCodecAttributes {
+    let mut result = FieldCodecAttributes::default();
+    for arg in codec_attribute_args(field.attrs.as_slice()) {
+        match arg {
+            syn::NestedMeta::Meta(syn::Meta::Path(path)) if path.is_ident("skip") => {
+                result.skip = true;
+            },
+            syn::NestedMeta::Meta(syn::Meta::Path(path)) if path.is_ident("optional") => {
+                result.optional = true;
+            },
+            syn::NestedMeta::Meta(syn::Meta::NameValue(name_value))
+                if name_value.path.is_ident("with") =>
+            {
+                let module = lit_str_value(&name_value.lit, "with");
+                result.with = Some(
+                    syn::parse_str(module.as_str())
+                        .expect("codec attribute `with` expects a module path"),
+                );
+            },
+            syn::NestedMeta::Meta(syn::Meta::NameValue(name_value))
+                if name_value.path.is_ident("abi_type") =>
+            {
+                // only relevant for the TypeAbi derive
+            },
+            _ => panic!(
+                "unknown codec field attribute, allowed are `skip`, `optional`, `with` and `abi_type`"
+            ),
+        }
+    }
+    assert!(
+        !(result.skip && (result.with.is_some() || result.optional)),
+        "codec attribute `skip` cannot be used together with `optional` or `with`"
+    );
+    result
+}
+
+/// Struct settings, given as `#[codec(version = N)]` or `#[codec(version = N, legacy_decode = "function")]`.
+#[derive(Default)]
+pub struct StructCodecAttributes {
+    /// Version byte, encoded before all the fields.
+    pub version: Option<u8>,
+
+    /// Function that decodes data with a different version byte, of the form
+    /// `fn<I: NestedDecodeInput>(version: u8, input: &mut I) -> Result<Self, DecodeError>`.
+    /// Without it, other versions cannot be decoded.
+    pub legacy_decode: Option<syn::Path>,
+}
+
+pub fn struct_codec_attributes(ast: &syn::DeriveInput) -> StructCodecAttributes {
+    let mut result = StructCodecAttributes::default();
+    for arg in codec_attribute_args(ast.attrs.as_slice()) {
+        match arg {
+            syn::NestedMeta::Meta(syn::Meta::NameValue(name_value))
+                if name_value.path.is_ident("version") =>
+            {
+                result.version = match &name_value.lit {
+                    syn::Lit::Int(lit_int) => Some(
+                        lit_int
+                            .base10_parse::<u8>()
+                            .expect("struct versions must fit in a u8"),
+                    ),
+                    _ => panic!("codec attribute `version` expects an integer literal"),
+                };
+            },
+            syn::NestedMeta::Meta(syn::Meta::NameValue(name_value))
+                if name_value.path.is_ident("legacy_decode") =>
+            {
+                let function = lit_str_value(&name_value.lit, "legacy_decode");
+                result.legacy_decode = Some(
+                    syn::parse_str(function.as_str())
+                        .expect("codec attribute `legacy_decode` expects a function path"),
+                );
+            },
+            _ => {
+                panic!("unknown codec struct attribute, allowed are `version` and `legacy_decode`")
+            },
+        }
+    }
+    assert!(
+        result.version.is_none() || matches!(ast.data, syn::Data::Struct(_)),
+        "codec attribute `version` is only allowed on structs"
+    );
+    assert!(
+        result.legacy_decode.is_none() || result.version.is_some(),
+        "codec attribute `legacy_decode` requires a `version`"
+    );
+    result
+}
+
+/// Optional fields can only be followed by other optional fields.
+pub fn validate_optional_fields(fields: &syn::Fields) {
+    let mut optional_found = false;
+    for field in fields.iter() {
+        if field_codec_attributes(field).optional {
+            optional_found = true;
+        } else {
+            assert!(
+                !optional_found,
+                "only the last fields of a struct can be `#[codec(optional)]`"
+            );
+        }
+    }
+}
+
+/// Enum variants are decoded field by field, like structs, but they have no notion of optional fields.
+pub fn validate_no_optional_variant_fields(data_enum: &syn::DataEnum) {
+    for variant in data_enum.variants.iter() {
+        for field in variant.fields.iter() {
+            assert!(
+                !field_codec_attributes(field).optional,
+                "`#[codec(optional)]` is only allowed on struct fields, not on enum variant fields (variant `{}`)",
+                variant.ident
+            );
+        }
+    }
+}
+
+fn explicit_discriminant(variant: &syn::Variant) -> Option<u8> {
+    let mut result = None;
+    for arg in codec_attribute_args(variant.attrs.as_slice()) {
+        match arg {
+            syn::NestedMeta::Meta(syn::Meta::NameValue(name_value))
+                if name_value.path.is_ident("discriminant") =>
+            {
+                result = match &name_value.lit {
+                    syn::Lit::Int(lit_int) => Some(
+                        lit_int
+                            .base10_parse::<u8>()
+                            .expect("enum discriminants must fit in a u8"),
+                    ),
+                    _ => panic!("codec attribute `discriminant` expects an integer literal"),
+                };
+            },
+            _ => panic!("unknown codec variant attribute, only `discriminant` is allowed"),
+        }
+    }
+    result
+}
+
+/// Discriminants of all variants, in declaration order.
+/// Same as in Rust, variants without an explicit `#[codec(discriminant = N)]`
+/// get the discriminant of the previous variant plus 1, starting from 0.
+pub fn variant_discriminants(data_enum: &syn::DataEnum) -> Vec<u8> {
+    let mut discriminants: Vec<u8> = Vec::with_capacity(data_enum.variants.len());
+    let mut next_discriminant = 0u16;
+    for variant in data_enum.variants.iter() {
+        let discriminant = if let Some(explicit) = explicit_discriminant(variant) {
+            explicit
+        } else {
+            assert!(
+                next_discriminant <= u8::MAX as u16,
+                "enum discriminants must fit in a u8"
+            );
+            next_discriminant as u8
+        };
+        assert!(
+            !discriminants.contains(&discriminant),
+            "duplicate enum discriminant {} (variant `{}`)",
+            discriminant,
+            variant.ident
+        );
+        discriminants.push(discriminant);
+        next_discriminant = discriminant as u16 + 1;
+    }
+    discriminants
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn data_enum(ast: syn::DeriveInput) -> syn::DataEnum {
+        match ast.data {
+            syn::Data::Enum(data_enum) => data_enum,
+            _ => panic!("enum expected"),
+        }
+    }
+
+    #[test]
+    fn test_variant_fields_without_optional() {
+        validate_no_optional_variant_fields(&data_enum(syn::parse_quote! {
+            enum Action {
+                Stop,
+                Move {
+                    x: u32,
+                    #[codec(skip)]
+                    cached: u32,
+                },
+            }
+        }));
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "`#[codec(optional)]` is only allowed on struct fields, not on enum variant fields (variant `Move`)"
+    )]
+    fn test_optional_variant_field_rejected() {
+        validate_no_optional_variant_fields(&data_enum(syn::parse_quote! {
+            enum Action {
+                Stop,
+                Move {
+                    x: u32,
+                    #[codec(optional)]
+                    y: u32,
+                },
+            }
+        }));
+    }
+}
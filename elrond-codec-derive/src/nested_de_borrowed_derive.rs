@@ -0,0 +1,62 @@
+use proc_macro::TokenStream;
+use quote::quote;
+
+use crate::{
+    codec_attr::*,
+    nested_de_derive::{field_context_expr, field_value_decl},
+    util::*,
+};
+
+fn dep_decode_borrowed_snippet(
+    index: usize,
+    field: &syn::Field,
+    lifetime: &syn::Lifetime,
+) -> proc_macro2::TokenStream {
+    let ty = &field.ty;
+    let codec_attributes = field_codec_attributes(field);
+    let context = field_context_expr(index, field, None);
+    let value_expr = if codec_attributes.skip {
+        quote! { <#ty as core::default::Default>::default() }
+    } else if let Some(with_module) = &codec_attributes.with {
+        quote! { #with_module::dep_decode(input).map_err(|e| e.with_context(#context))? }
+    } else {
+        quote! {
+            <#ty as elrond_codec::NestedDecodeBorrowed<#lifetime>>::dep_decode_borrowed(input)
+                .map_err(|e| e.with_context(#context))?
+        }
+    };
+    field_value_decl(field, value_expr)
+}
+
+/// Only for structs, whose first lifetime parameter is the lifetime of the input.
+pub fn nested_decode_borrowed_impl(ast: &syn::DeriveInput) -> TokenStream {
+    let name = &ast.ident;
+    let data_struct = match &ast.data {
+        syn::Data::Struct(data_struct) => data_struct,
+        _ => panic!("NestedDecodeBorrowed can only be derived for structs"),
+    };
+    let lifetime = &ast
+        .generics
+        .lifetimes()
+        .next()
+        .expect("NestedDecodeBorrowed requires a lifetime parameter, for the borrowed fields")
+        .lifetime;
+    assert!(
+        struct_codec_attributes(ast).version.is_none(),
+        "versioned structs cannot derive NestedDecodeBorrowed"
+    );
+    let (impl_generics, ty_generics, where_clause) = &ast.generics.split_for_impl();
+    let field_dep_decode_snippets = fields_decl_syntax(&data_struct.fields, |index, field| {
+        dep_decode_borrowed_snippet(index, field, lifetime)
+    });
+    let gen = quote! {
+        impl #impl_generics elrond_codec::NestedDecodeBorrowed<#lifetime> for #name #ty_generics #where_clause {
+            fn dep_decode_borrowed(input: &mut &#lifetime [u8]) -> core::result::Result<Self, elrond_codec::DecodeError> {
+                core::result::Result::Ok(
+                    #name #field_dep_decode_snippets
+                )
+            }
+        }
+    };
+    gen.into()
+}
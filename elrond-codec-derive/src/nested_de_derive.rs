@@ -1,39 +1,219 @@
 use proc_macro::TokenStream;
 use quote::quote;
 
-use crate::util::*;
+use crate::{codec_attr::*, util::*};
 
-pub fn dep_decode_snippet(
-    _index: usize,
+/// Prefixes the field value expression with the field name, for named fields.
+pub fn field_value_decl(
+    field: &syn::Field,
+    value_expr: proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    if let Some(ident) = &field.ident {
+        quote! {
+            #ident: #value_expr
+        }
+    } else {
+        value_expr
+    }
+}
+
+/// Where the field is, in case decoding fails. Only recorded with the `decode-error-context` codec feature.
+pub fn field_context_expr(
+    index: usize,
     field: &syn::Field,
+    variant: Option<&syn::Ident>,
+) -> proc_macro2::TokenStream {
+    let field_name_str = if let Some(ident) = &field.ident {
+        ident.to_string()
+    } else {
+        index.to_string()
+    };
+    if let Some(variant_ident) = variant {
+        let variant_name_str = variant_ident.to_string();
+        quote! {
+            elrond_codec::DecodeErrorContext::VariantField {
+                variant: #variant_name_str,
+                field: #field_name_str,
+            }
+        }
+    } else {
+        quote! { elrond_codec::DecodeErrorContext::Field(#field_name_str) }
+    }
+}
+
+fn dep_decode_value_expr(
+    field: &syn::Field,
+    codec_attributes: &FieldCodecAttributes,
     input_value: &proc_macro2::TokenStream,
+    context: proc_macro2::TokenStream,
 ) -> proc_macro2::TokenStream {
     let ty = &field.ty;
-    if let Some(ident) = &field.ident {
+    if codec_attributes.skip {
+        quote! { <#ty as core::default::Default>::default() }
+    } else if let Some(with_module) = &codec_attributes.with {
+        quote! { #with_module::dep_decode(#input_value).map_err(|e| e.with_context(#context))? }
+    } else {
+        quote! { elrond_codec::dep_decode_with_context::<#ty, _>(#input_value, #context)? }
+    }
+}
+
+fn dep_decode_or_exit_value_expr(
+    field: &syn::Field,
+    codec_attributes: &FieldCodecAttributes,
+    input_value: &proc_macro2::TokenStream,
+    context: proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    let ty = &field.ty;
+    if codec_attributes.skip {
+        quote! { <#ty as core::default::Default>::default() }
+    } else if let Some(with_module) = &codec_attributes.with {
         quote! {
-            #ident: <#ty as elrond_codec::NestedDecode>::dep_decode(#input_value)?
+            match #with_module::dep_decode(#input_value) {
+                core::result::Result::Ok(value) => value,
+                core::result::Result::Err(e) => exit(c.clone(), e.with_context(#context)),
+            }
         }
     } else {
         quote! {
-            <#ty as elrond_codec::NestedDecode>::dep_decode(#input_value)?
+            elrond_codec::dep_decode_or_exit_with_context::<#ty, _, _>(#input_value, c.clone(), exit, #context)
         }
     }
 }
 
-pub fn dep_decode_or_exit_snippet(
-    _index: usize,
+/// Optional fields get the default value when the input has ended.
+/// Only used for top-level decoding, where the end of the input is also the end of the object.
+fn default_if_depleted(
     field: &syn::Field,
     input_value: &proc_macro2::TokenStream,
+    value_expr: proc_macro2::TokenStream,
 ) -> proc_macro2::TokenStream {
     let ty = &field.ty;
-    if let Some(ident) = &field.ident {
+    quote! {
+        if elrond_codec::NestedDecodeInput::is_depleted(&*#input_value) {
+            <#ty as core::default::Default>::default()
+        } else {
+            #value_expr
+        }
+    }
+}
+
+pub fn dep_decode_snippet(
+    index: usize,
+    field: &syn::Field,
+    input_value: &proc_macro2::TokenStream,
+    variant: Option<&syn::Ident>,
+) -> proc_macro2::TokenStream {
+    let codec_attributes = field_codec_attributes(field);
+    let context = field_context_expr(index, field, variant);
+    field_value_decl(
+        field,
+        dep_decode_value_expr(field, &codec_attributes, input_value, context),
+    )
+}
+
+pub fn dep_decode_or_exit_snippet(
+    index: usize,
+    field: &syn::Field,
+    input_value: &proc_macro2::TokenStream,
+    variant: Option<&syn::Ident>,
+) -> proc_macro2::TokenStream {
+    let codec_attributes = field_codec_attributes(field);
+    let context = field_context_expr(index, field, variant);
+    field_value_decl(
+        field,
+        dep_decode_or_exit_value_expr(field, &codec_attributes, input_value, context),
+    )
+}
+
+/// Same as `dep_decode_snippet`, but tolerates missing optional fields.
+pub fn top_field_dep_decode_snippet(
+    index: usize,
+    field: &syn::Field,
+    input_value: &proc_macro2::TokenStream,
+    variant: Option<&syn::Ident>,
+) -> proc_macro2::TokenStream {
+    let codec_attributes = field_codec_attributes(field);
+    let context = field_context_expr(index, field, variant);
+    let mut value_expr = dep_decode_value_expr(field, &codec_attributes, input_value, context);
+    if codec_attributes.optional {
+        value_expr = default_if_depleted(field, input_value, value_expr);
+    }
+    field_value_decl(field, value_expr)
+}
+
+/// Same as `dep_decode_or_exit_snippet`, but tolerates missing optional fields.
+pub fn top_field_dep_decode_or_exit_snippet(
+    index: usize,
+    field: &syn::Field,
+    input_value: &proc_macro2::TokenStream,
+    variant: Option<&syn::Ident>,
+) -> proc_macro2::TokenStream {
+    let codec_attributes = field_codec_attributes(field);
+    let context = field_context_expr(index, field, variant);
+    let mut value_expr =
+        dep_decode_or_exit_value_expr(field, &codec_attributes, input_value, context);
+    if codec_attributes.optional {
+        value_expr = default_if_depleted(field, input_value, value_expr);
+    }
+    field_value_decl(field, value_expr)
+}
+
+/// Versioned structs start with the version byte.
+/// Other versions are handled by the `legacy_decode` function, if there is one.
+pub fn versioned_struct_decode_expr(
+    struct_attributes: &StructCodecAttributes,
+    construct_expr: proc_macro2::TokenStream,
+    input_value: &proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    if let Some(version) = struct_attributes.version {
+        let other_version_expr = if let Some(legacy_decode) = &struct_attributes.legacy_decode {
+            quote! { #legacy_decode(version, #input_value)? }
+        } else {
+            quote! { return core::result::Result::Err(elrond_codec::DecodeError::UNSUPPORTED_VERSION) }
+        };
         quote! {
-            #ident: <#ty as elrond_codec::NestedDecode>::dep_decode_or_exit(#input_value, c.clone(), exit)
+            {
+                let version = <u8 as elrond_codec::NestedDecode>::dep_decode(#input_value)?;
+                if version == #version {
+                    #construct_expr
+                } else {
+                    #other_version_expr
+                }
+            }
         }
     } else {
+        construct_expr
+    }
+}
+
+pub fn versioned_struct_decode_or_exit_expr(
+    struct_attributes: &StructCodecAttributes,
+    construct_expr: proc_macro2::TokenStream,
+    input_value: &proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    if let Some(version) = struct_attributes.version {
+        let other_version_expr = if let Some(legacy_decode) = &struct_attributes.legacy_decode {
+            quote! {
+                match #legacy_decode(version, #input_value) {
+                    core::result::Result::Ok(value) => value,
+                    core::result::Result::Err(e) => exit(c.clone(), e),
+                }
+            }
+        } else {
+            quote! { exit(c.clone(), elrond_codec::DecodeError::UNSUPPORTED_VERSION) }
+        };
         quote! {
-            <#ty as elrond_codec::NestedDecode>::dep_decode_or_exit(#input_value, c.clone(), exit)
+            {
+                let version = <u8 as elrond_codec::NestedDecode>::dep_decode_or_exit(#input_value, c.clone(), exit);
+                if version == #version {
+                    #construct_expr
+                } else {
+                    #other_version_expr
+                }
+            }
         }
+    } else {
+        construct_expr
     }
 }
 
@@ -45,15 +225,14 @@ pub fn variant_dep_decode_snippets(
     data_enum
 		.variants
 		.iter()
-		.enumerate()
-		.map(|(variant_index, variant)| {
-			let variant_index_u8 = variant_index as u8;
+		.zip(variant_discriminants(data_enum))
+		.map(|(variant, discriminant)| {
 			let variant_ident = &variant.ident;
 			let variant_field_snippets = fields_decl_syntax(&variant.fields, |index, field| {
-				dep_decode_snippet(index, field, input_value)
+				dep_decode_snippet(index, field, input_value, Some(variant_ident))
 			});
 			quote! {
-				#variant_index_u8 => core::result::Result::Ok( #name::#variant_ident #variant_field_snippets ),
+				#discriminant => core::result::Result::Ok( #name::#variant_ident #variant_field_snippets ),
 			}
 		})
 		.collect()
@@ -67,15 +246,14 @@ pub fn variant_dep_decode_or_exit_snippets(
     data_enum
         .variants
         .iter()
-        .enumerate()
-        .map(|(variant_index, variant)| {
-            let variant_index_u8 = variant_index as u8;
+        .zip(variant_discriminants(data_enum))
+        .map(|(variant, discriminant)| {
             let variant_ident = &variant.ident;
             let variant_field_snippets = fields_decl_syntax(&variant.fields, |index, field| {
-                dep_decode_or_exit_snippet(index, field, input_value)
+                dep_decode_or_exit_snippet(index, field, input_value, Some(variant_ident))
             });
             quote! {
-                #variant_index_u8 => #name::#variant_ident #variant_field_snippets ,
+                #discriminant => #name::#variant_ident #variant_field_snippets ,
             }
         })
         .collect()
@@ -86,19 +264,31 @@ pub fn nested_decode_impl(ast: &syn::DeriveInput) -> TokenStream {
     let (impl_generics, ty_generics, where_clause) = &ast.generics.split_for_impl();
     let gen = match &ast.data {
         syn::Data::Struct(data_struct) => {
+            let struct_attributes = struct_codec_attributes(ast);
+            validate_optional_fields(&data_struct.fields);
             let field_dep_decode_snippets =
                 fields_decl_syntax(&data_struct.fields, |index, field| {
-                    dep_decode_snippet(index, field, &quote! {input})
+                    dep_decode_snippet(index, field, &quote! {input}, None)
                 });
             let field_dep_encode_or_exit_snippets =
                 fields_decl_syntax(&data_struct.fields, |index, field| {
-                    dep_decode_or_exit_snippet(index, field, &quote! {input})
+                    dep_decode_or_exit_snippet(index, field, &quote! {input}, None)
                 });
+            let struct_decode_expr = versioned_struct_decode_expr(
+                &struct_attributes,
+                quote! { #name #field_dep_decode_snippets },
+                &quote! {input},
+            );
+            let struct_decode_or_exit_expr = versioned_struct_decode_or_exit_expr(
+                &struct_attributes,
+                quote! { #name #field_dep_encode_or_exit_snippets },
+                &quote! {input},
+            );
             quote! {
                 impl #impl_generics elrond_codec::NestedDecode for #name #ty_generics #where_clause {
                     fn dep_decode<I: elrond_codec::NestedDecodeInput>(input: &mut I) -> core::result::Result<Self, elrond_codec::DecodeError> {
                         core::result::Result::Ok(
-                            #name #field_dep_decode_snippets
+                            #struct_decode_expr
                         )
                     }
 
@@ -107,7 +297,7 @@ pub fn nested_decode_impl(ast: &syn::DeriveInput) -> TokenStream {
                         c: ExitCtx,
                         exit: fn(ExitCtx, elrond_codec::DecodeError) -> !,
                     ) -> Self {
-                        #name #field_dep_encode_or_exit_snippets
+                        #struct_decode_or_exit_expr
                     }
                 }
             }
@@ -117,6 +307,7 @@ pub fn nested_decode_impl(ast: &syn::DeriveInput) -> TokenStream {
                 data_enum.variants.len() < 256,
                 "enums with more than 256 variants not supported"
             );
+            validate_no_optional_variant_fields(data_enum);
             let variant_dep_decode_snippets =
                 variant_dep_decode_snippets(name, data_enum, &quote! {input});
             let variant_dep_decode_or_exit_snippets =
@@ -1,16 +1,60 @@
-use crate::util::*;
+use crate::{codec_attr::*, util::*};
 use proc_macro::TokenStream;
 use quote::quote;
 
-pub fn dep_encode_snippet(value: &proc_macro2::TokenStream) -> proc_macro2::TokenStream {
-    quote! {
-        elrond_codec::NestedEncode::dep_encode(&#value, dest)?;
+pub fn dep_encode_snippet(
+    field: &syn::Field,
+    value: &proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    let codec_attributes = field_codec_attributes(field);
+    if codec_attributes.skip {
+        quote! {}
+    } else if let Some(with_module) = &codec_attributes.with {
+        quote! {
+            #with_module::dep_encode(&#value, dest)?;
+        }
+    } else {
+        quote! {
+            elrond_codec::NestedEncode::dep_encode(&#value, dest)?;
+        }
     }
 }
 
-pub fn dep_encode_or_exit_snippet(value: &proc_macro2::TokenStream) -> proc_macro2::TokenStream {
-    quote! {
-        elrond_codec::NestedEncode::dep_encode_or_exit(&#value, dest, c.clone(), exit);
+pub fn dep_encode_or_exit_snippet(
+    field: &syn::Field,
+    value: &proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    let codec_attributes = field_codec_attributes(field);
+    if codec_attributes.skip {
+        quote! {}
+    } else if let Some(with_module) = &codec_attributes.with {
+        quote! {
+            if let core::result::Result::Err(e) = #with_module::dep_encode(&#value, dest) {
+                exit(c.clone(), e);
+            }
+        }
+    } else {
+        quote! {
+            elrond_codec::NestedEncode::dep_encode_or_exit(&#value, dest, c.clone(), exit);
+        }
+    }
+}
+
+/// Versioned structs start with the version byte.
+pub fn version_dep_encode_snippets(
+    struct_attributes: &StructCodecAttributes,
+) -> (proc_macro2::TokenStream, proc_macro2::TokenStream) {
+    if let Some(version) = struct_attributes.version {
+        (
+            quote! {
+                elrond_codec::NestedEncode::dep_encode(&#version, dest)?;
+            },
+            quote! {
+                elrond_codec::NestedEncode::dep_encode_or_exit(&#version, dest, c.clone(), exit);
+            },
+        )
+    } else {
+        (quote! {}, quote! {})
     }
 }
 
@@ -21,18 +65,17 @@ fn variant_dep_encode_snippets(
     data_enum
         .variants
         .iter()
-        .enumerate()
-        .map(|(variant_index, variant)| {
-            let variant_index_u8 = variant_index as u8;
+        .zip(variant_discriminants(data_enum))
+        .map(|(variant, discriminant)| {
             let variant_ident = &variant.ident;
             let local_var_declarations =
-                fields_decl_syntax(&variant.fields, local_variable_for_field);
+                fields_decl_syntax(&variant.fields, local_variable_pattern_for_field);
             let variant_field_snippets = fields_snippets(&variant.fields, |index, field| {
-                dep_encode_snippet(&local_variable_for_field(index, field))
+                dep_encode_snippet(field, &local_variable_for_field(index, field))
             });
             quote! {
                 #name::#variant_ident #local_var_declarations => {
-                    elrond_codec::NestedEncode::dep_encode(&#variant_index_u8, dest)?;
+                    elrond_codec::NestedEncode::dep_encode(&#discriminant, dest)?;
                     #(#variant_field_snippets)*
                 },
             }
@@ -47,18 +90,17 @@ fn variant_dep_encode_or_exit_snippets(
     data_enum
 		.variants
 		.iter()
-		.enumerate()
-		.map(|(variant_index, variant)| {
-			let variant_index_u8 = variant_index as u8;
+		.zip(variant_discriminants(data_enum))
+		.map(|(variant, discriminant)| {
 			let variant_ident = &variant.ident;
 			let local_var_declarations =
-				fields_decl_syntax(&variant.fields, local_variable_for_field);
+				fields_decl_syntax(&variant.fields, local_variable_pattern_for_field);
 			let variant_field_snippets = fields_snippets(&variant.fields, |index, field| {
-				dep_encode_or_exit_snippet(&local_variable_for_field(index, field))
+				dep_encode_or_exit_snippet(field, &local_variable_for_field(index, field))
 			});
 			quote! {
 				#name::#variant_ident #local_var_declarations => {
-					elrond_codec::NestedEncode::dep_encode_or_exit(&#variant_index_u8, dest, c.clone(), exit);
+					elrond_codec::NestedEncode::dep_encode_or_exit(&#discriminant, dest, c.clone(), exit);
 					#(#variant_field_snippets)*
 				},
 			}
@@ -71,16 +113,19 @@ pub fn nested_encode_impl(ast: &syn::DeriveInput) -> TokenStream {
     let (impl_generics, ty_generics, where_clause) = &ast.generics.split_for_impl();
     let gen = match &ast.data {
         syn::Data::Struct(data_struct) => {
+            let (version_dep_encode_snippet, version_dep_encode_or_exit_snippet) =
+                version_dep_encode_snippets(&struct_codec_attributes(ast));
             let field_dep_encode_snippets = fields_snippets(&data_struct.fields, |index, field| {
-                dep_encode_snippet(&self_field_expr(index, field))
+                dep_encode_snippet(field, &self_field_expr(index, field))
             });
             let field_dep_encode_or_exit_snippets =
                 fields_snippets(&data_struct.fields, |index, field| {
-                    dep_encode_or_exit_snippet(&self_field_expr(index, field))
+                    dep_encode_or_exit_snippet(field, &self_field_expr(index, field))
                 });
             quote! {
                 impl #impl_generics elrond_codec::NestedEncode for #name #ty_generics #where_clause {
                     fn dep_encode<O: elrond_codec::NestedEncodeOutput>(&self, dest: &mut O) -> core::result::Result<(), elrond_codec::EncodeError> {
+                        #version_dep_encode_snippet
                         #(#field_dep_encode_snippets)*
                         core::result::Result::Ok(())
                     }
@@ -91,6 +136,7 @@ pub fn nested_encode_impl(ast: &syn::DeriveInput) -> TokenStream {
                         c: ExitCtx,
                         exit: fn(ExitCtx, elrond_codec::EncodeError) -> !,
                     ) {
+                        #version_dep_encode_or_exit_snippet
                         #(#field_dep_encode_or_exit_snippets)*
                     }
                 }
@@ -101,6 +147,7 @@ pub fn nested_encode_impl(ast: &syn::DeriveInput) -> TokenStream {
                 data_enum.variants.len() < 256,
                 "enums with more than 256 variants not supported"
             );
+            validate_no_optional_variant_fields(data_enum);
             let variant_dep_encode_snippets = variant_dep_encode_snippets(name, data_enum);
             let variant_dep_encode_or_exit_snippets =
                 variant_dep_encode_or_exit_snippets(name, data_enum);
@@ -1,7 +1,7 @@
 use proc_macro::TokenStream;
 use quote::quote;
 
-use crate::{nested_de_derive::*, util::*};
+use crate::{codec_attr::*, nested_de_derive::*, util::*};
 
 fn fieldless_enum_match_arm_result_ok(
     name: &syn::Ident,
@@ -10,12 +10,11 @@ fn fieldless_enum_match_arm_result_ok(
     data_enum
         .variants
         .iter()
-        .enumerate()
-        .map(|(variant_index, variant)| {
-            let variant_index_u8 = variant_index as u8;
+        .zip(variant_discriminants(data_enum))
+        .map(|(variant, discriminant)| {
             let variant_ident = &variant.ident;
             quote! {
-                #variant_index_u8 => core::result::Result::Ok( #name::#variant_ident ),
+                #discriminant => core::result::Result::Ok( #name::#variant_ident ),
             }
         })
         .collect()
@@ -28,19 +27,18 @@ fn fieldless_enum_match_arm(
     data_enum
         .variants
         .iter()
-        .enumerate()
-        .map(|(variant_index, variant)| {
-            let variant_index_u8 = variant_index as u8;
+        .zip(variant_discriminants(data_enum))
+        .map(|(variant, discriminant)| {
             let variant_ident = &variant.ident;
             quote! {
-                #variant_index_u8 => #name::#variant_ident ,
+                #discriminant => #name::#variant_ident ,
             }
         })
         .collect()
 }
 
 /// Generates a default-value deserializer snippet automatically.
-/// Currently only does so for enums whose first variant is fieldless.
+/// Currently only does so for enums whose variant with discriminant 0 is fieldless.
 /// Also generates the snippet for `top_decode_or_exit`.
 /// Not called for TopDecodeOrDefault, since that one already provides an explicit default.
 fn auto_default(ast: &syn::DeriveInput) -> (proc_macro2::TokenStream, proc_macro2::TokenStream) {
@@ -50,17 +48,22 @@ fn auto_default(ast: &syn::DeriveInput) -> (proc_macro2::TokenStream, proc_macro
             !data_enum.variants.is_empty(),
             "cannot deserialize enums without variants"
         );
-        let first_variant = &data_enum.variants[0];
-        if first_variant.fields.is_empty() {
-            let first_variant_ident = &first_variant.ident;
+        let zero_variant = data_enum
+            .variants
+            .iter()
+            .zip(variant_discriminants(data_enum))
+            .find(|(_, discriminant)| *discriminant == 0)
+            .map(|(variant, _)| variant);
+        if let Some(zero_variant) = zero_variant.filter(|variant| variant.fields.is_empty()) {
+            let zero_variant_ident = &zero_variant.ident;
             let auto_default = quote! {
                 if top_input.byte_len() == 0 {
-                    return core::result::Result::Ok(#name::#first_variant_ident);
+                    return core::result::Result::Ok(#name::#zero_variant_ident);
                 }
             };
             let auto_default_or_exit = quote! {
                 if top_input.byte_len() == 0 {
-                    return #name::#first_variant_ident;
+                    return #name::#zero_variant_ident;
                 }
             };
             return (auto_default, auto_default_or_exit);
@@ -78,18 +81,35 @@ fn top_decode_method_bodies(
     let name = &ast.ident;
     match &ast.data {
         syn::Data::Struct(data_struct) => {
+            let struct_attributes = struct_codec_attributes(ast);
+            validate_optional_fields(&data_struct.fields);
             let field_dep_decode_snippets =
                 fields_decl_syntax(&data_struct.fields, |index, field| {
-                    dep_decode_snippet(index, field, &quote! {&mut nested_buffer})
+                    top_field_dep_decode_snippet(index, field, &quote! {&mut nested_buffer}, None)
                 });
             let field_dep_encode_or_exit_snippets =
                 fields_decl_syntax(&data_struct.fields, |index, field| {
-                    dep_decode_or_exit_snippet(index, field, &quote! {&mut nested_buffer})
+                    top_field_dep_decode_or_exit_snippet(
+                        index,
+                        field,
+                        &quote! {&mut nested_buffer},
+                        None,
+                    )
                 });
+            let struct_decode_expr = versioned_struct_decode_expr(
+                &struct_attributes,
+                quote! { #name #field_dep_decode_snippets },
+                &quote! {&mut nested_buffer},
+            );
+            let struct_decode_or_exit_expr = versioned_struct_decode_or_exit_expr(
+                &struct_attributes,
+                quote! { #name #field_dep_encode_or_exit_snippets },
+                &quote! {&mut nested_buffer},
+            );
 
             let top_decode_body = quote! {
                 let mut nested_buffer = top_input.into_nested_buffer();
-                let result = #name #field_dep_decode_snippets ;
+                let result = #struct_decode_expr ;
                 if !elrond_codec::NestedDecodeInput::is_depleted(&nested_buffer) {
                     return core::result::Result::Err(elrond_codec::DecodeError::INPUT_TOO_LONG);
                 }
@@ -97,7 +117,7 @@ fn top_decode_method_bodies(
             };
             let top_decode_or_exit_body = quote! {
                 let mut nested_buffer = top_input.into_nested_buffer();
-                let result = #name #field_dep_encode_or_exit_snippets ;
+                let result = #struct_decode_or_exit_expr ;
                 if !elrond_codec::NestedDecodeInput::is_depleted(&nested_buffer) {
                     exit(c, elrond_codec::DecodeError::INPUT_TOO_LONG);
                 }
@@ -110,6 +130,7 @@ fn top_decode_method_bodies(
                 data_enum.variants.len() < 256,
                 "enums with more than 256 variants not supported"
             );
+            validate_no_optional_variant_fields(data_enum);
             if is_fieldless_enum(data_enum) {
                 // fieldless enums are special, they can be top-decoded as u8 directly
                 let top_decode_arms = fieldless_enum_match_arm_result_ok(name, data_enum);
@@ -2,7 +2,12 @@ use proc_macro::TokenStream;
 use quote::quote;
 
 use crate::{
-    nested_en_derive::{dep_encode_or_exit_snippet, dep_encode_snippet},
+    codec_attr::{
+        struct_codec_attributes, validate_no_optional_variant_fields, variant_discriminants,
+    },
+    nested_en_derive::{
+        dep_encode_or_exit_snippet, dep_encode_snippet, version_dep_encode_snippets,
+    },
     util::*,
 };
 
@@ -13,28 +18,27 @@ pub fn variant_top_encode_snippets(
     data_enum
         .variants
         .iter()
-        .enumerate()
-        .map(|(variant_index, variant)| {
-            let variant_index_u8 = variant_index as u8;
+        .zip(variant_discriminants(data_enum))
+        .map(|(variant, discriminant)| {
             let variant_ident = &variant.ident;
             if variant.fields.is_empty() {
                 // top-encode discriminant directly
                 quote! {
                     #name::#variant_ident =>
-                        elrond_codec::TopEncode::top_encode(&#variant_index_u8, output),
+                        elrond_codec::TopEncode::top_encode(&#discriminant, output),
                 }
             } else {
                 // dep-encode to buffer first
                 let local_var_declarations =
-                    fields_decl_syntax(&variant.fields, local_variable_for_field);
+                    fields_decl_syntax(&variant.fields, local_variable_pattern_for_field);
                 let variant_field_snippets = fields_snippets(&variant.fields, |index, field| {
-                    dep_encode_snippet(&local_variable_for_field(index, field))
+                    dep_encode_snippet(field, &local_variable_for_field(index, field))
                 });
                 quote! {
                     #name::#variant_ident #local_var_declarations => {
                         let mut buffer = output.start_nested_encode();
                         let dest = &mut buffer;
-                        elrond_codec::NestedEncode::dep_encode(&#variant_index_u8, dest)?;
+                        elrond_codec::NestedEncode::dep_encode(&#discriminant, dest)?;
                         #(#variant_field_snippets)*
                         output.finalize_nested_encode(buffer);
                         core::result::Result::Ok(())
@@ -52,28 +56,27 @@ pub fn variant_top_encode_or_exit_snippets(
     data_enum
 		.variants
 		.iter()
-		.enumerate()
-		.map(|(variant_index, variant)| {
-			let variant_index_u8 = variant_index as u8;
+		.zip(variant_discriminants(data_enum))
+		.map(|(variant, discriminant)| {
 			let variant_ident = &variant.ident;
 			if variant.fields.is_empty() {
 				// top-encode discriminant directly
 				quote! {
 					#name::#variant_ident =>
-						elrond_codec::TopEncode::top_encode_or_exit(&#variant_index_u8, output, c.clone(), exit),
+						elrond_codec::TopEncode::top_encode_or_exit(&#discriminant, output, c.clone(), exit),
 				}
 			} else {
 				// dep-encode to buffer first
 				let local_var_declarations =
-					fields_decl_syntax(&variant.fields, local_variable_for_field);
+					fields_decl_syntax(&variant.fields, local_variable_pattern_for_field);
 				let variant_field_snippets = fields_snippets(&variant.fields, |index, field| {
-					dep_encode_or_exit_snippet(&local_variable_for_field(index, field))
+					dep_encode_or_exit_snippet(field, &local_variable_for_field(index, field))
 				});
 				quote! {
 					#name::#variant_ident #local_var_declarations => {
 						let mut buffer = output.start_nested_encode();
 						let dest = &mut buffer;
-						elrond_codec::NestedEncode::dep_encode_or_exit(&#variant_index_u8, dest, c.clone(), exit);
+						elrond_codec::NestedEncode::dep_encode_or_exit(&#discriminant, dest, c.clone(), exit);
 						#(#variant_field_snippets)*
 						output.finalize_nested_encode(buffer);
 					},
@@ -90,16 +93,19 @@ fn top_encode_method_bodies(
     let name = &ast.ident;
     match &ast.data {
         syn::Data::Struct(data_struct) => {
+            let (version_dep_encode_snippet, version_dep_encode_or_exit_snippet) =
+                version_dep_encode_snippets(&struct_codec_attributes(ast));
             let field_dep_encode_snippets = fields_snippets(&data_struct.fields, |index, field| {
-                dep_encode_snippet(&self_field_expr(index, field))
+                dep_encode_snippet(field, &self_field_expr(index, field))
             });
             let field_dep_encode_or_exit_snippets =
                 fields_snippets(&data_struct.fields, |index, field| {
-                    dep_encode_or_exit_snippet(&self_field_expr(index, field))
+                    dep_encode_or_exit_snippet(field, &self_field_expr(index, field))
                 });
             let top_encode_body = quote! {
                 let mut buffer = output.start_nested_encode();
                 let dest = &mut buffer;
+                #version_dep_encode_snippet
                 #(#field_dep_encode_snippets)*
                 output.finalize_nested_encode(buffer);
                 core::result::Result::Ok(())
@@ -107,6 +113,7 @@ fn top_encode_method_bodies(
             let top_encode_or_exit_body = quote! {
                 let mut buffer = output.start_nested_encode();
                 let dest = &mut buffer;
+                #version_dep_encode_or_exit_snippet
                 #(#field_dep_encode_or_exit_snippets)*
                 output.finalize_nested_encode(buffer);
             };
@@ -117,6 +124,7 @@ fn top_encode_method_bodies(
                 data_enum.variants.len() < 256,
                 "enums with more than 256 variants not supported"
             );
+            validate_no_optional_variant_fields(data_enum);
             let variant_top_encode_snippets = variant_top_encode_snippets(name, data_enum);
             let variant_top_encode_or_exit_snippets =
                 variant_top_encode_or_exit_snippets(name, data_enum);
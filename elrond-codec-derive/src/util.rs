@@ -1,3 +1,4 @@
+use crate::codec_attr::field_codec_attributes;
 use quote::quote;
 
 pub fn is_fieldless_enum(data_enum: &syn::DataEnum) -> bool {
@@ -34,6 +35,25 @@ pub fn local_variable_for_field(index: usize, field: &syn::Field) -> proc_macro2
     }
 }
 
+/// Same as `local_variable_for_field`, but skipped fields are not bound, to avoid unused variables.
+pub fn local_variable_pattern_for_field(
+    index: usize,
+    field: &syn::Field,
+) -> proc_macro2::TokenStream {
+    if !field_codec_attributes(field).skip {
+        return local_variable_for_field(index, field);
+    }
+    if let Some(ident) = &field.ident {
+        quote! {
+            #ident: _
+        }
+    } else {
+        quote! {
+            _
+        }
+    }
+}
+
 pub fn fields_snippets<F>(fields: &syn::Fields, field_mapper: F) -> Vec<proc_macro2::TokenStream>
 where
     F: Fn(usize, &syn::Field) -> proc_macro2::TokenStream,
@@ -0,0 +1,22 @@
+#![no_main]
+
+use elrond_codec::test_util::check_codec_properties_arbitrary;
+use libfuzzer_sys::fuzz_target;
+use std::collections::{BTreeMap, BTreeSet};
+
+fuzz_target!(|data: &[u8]| {
+    check_codec_properties_arbitrary::<u64>(data);
+    check_codec_properties_arbitrary::<i64>(data);
+    check_codec_properties_arbitrary::<u128>(data);
+    check_codec_properties_arbitrary::<i128>(data);
+    check_codec_properties_arbitrary::<i16>(data);
+    check_codec_properties_arbitrary::<bool>(data);
+    check_codec_properties_arbitrary::<Vec<u8>>(data);
+    check_codec_properties_arbitrary::<Vec<u32>>(data);
+    check_codec_properties_arbitrary::<String>(data);
+    check_codec_properties_arbitrary::<Option<Vec<i32>>>(data);
+    check_codec_properties_arbitrary::<[u16; 5]>(data);
+    check_codec_properties_arbitrary::<(u8, Vec<u8>, Option<u64>)>(data);
+    check_codec_properties_arbitrary::<BTreeSet<u16>>(data);
+    check_codec_properties_arbitrary::<BTreeMap<u32, Vec<u8>>>(data);
+});
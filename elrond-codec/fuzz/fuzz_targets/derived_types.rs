@@ -0,0 +1,40 @@
+#![no_main]
+
+use arbitrary::Arbitrary;
+use elrond_codec::{
+    elrond_codec_derive::{NestedDecode, NestedEncode, TopDecode, TopEncode},
+    test_util::check_codec_properties_arbitrary,
+};
+use libfuzzer_sys::fuzz_target;
+
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, Arbitrary, PartialEq, Debug)]
+enum Status {
+    Pending,
+    Open,
+    Closed,
+}
+
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, Arbitrary, PartialEq, Debug)]
+enum Payment {
+    None,
+    Egld(u64),
+    Esdt { token: Vec<u8>, nonce: u64, amount: u128 },
+}
+
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, Arbitrary, PartialEq, Debug)]
+struct Offer {
+    id: u32,
+    status: Status,
+    payments: Vec<Payment>,
+    label: Option<String>,
+}
+
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, Arbitrary, PartialEq, Debug)]
+struct Pair(i16, [u8; 3]);
+
+fuzz_target!(|data: &[u8]| {
+    check_codec_properties_arbitrary::<Status>(data);
+    check_codec_properties_arbitrary::<Payment>(data);
+    check_codec_properties_arbitrary::<Offer>(data);
+    check_codec_properties_arbitrary::<Pair>(data);
+});
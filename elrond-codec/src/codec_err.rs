@@ -1,3 +1,6 @@
+use crate::codec_err_context::DecodeErrorContext;
+use alloc::vec::Vec;
+
 #[derive(Debug, PartialEq, Eq)]
 pub struct EncodeError(&'static [u8]);
 
@@ -17,27 +20,94 @@ impl EncodeError {
     pub const UNSUPPORTED_OPERATION: EncodeError = EncodeError(b"unsupported operation");
 }
 
-#[derive(Debug, PartialEq, Eq)]
-pub struct DecodeError(&'static [u8]);
+/// Decode errors are normally just a static message, to keep the contract code small.
+///
+/// With the `decode-error-context` feature enabled, they also record where the error occurred
+/// (struct fields, enum variants, collection indices), see [`DecodeErrorContext`].
+/// This is intended for debugging and tests, not for contracts deployed on-chain.
+#[derive(Debug)]
+#[cfg_attr(not(feature = "decode-error-context"), derive(PartialEq, Eq))]
+pub struct DecodeError {
+    message_bytes: &'static [u8],
+
+    /// Innermost context first.
+    #[cfg(feature = "decode-error-context")]
+    context: Vec<DecodeErrorContext>,
+}
 
 impl From<&'static [u8]> for DecodeError {
     #[inline]
     fn from(message_bytes: &'static [u8]) -> Self {
-        DecodeError(message_bytes)
+        DecodeError::new(message_bytes)
+    }
+}
+
+/// Errors are equal if they have the same message, context is ignored.
+/// Note: this also means that the error constants cannot be used in patterns when the feature is enabled.
+#[cfg(feature = "decode-error-context")]
+impl PartialEq for DecodeError {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.message_bytes == other.message_bytes
     }
 }
 
+#[cfg(feature = "decode-error-context")]
+impl Eq for DecodeError {}
+
 impl DecodeError {
+    const fn new(message_bytes: &'static [u8]) -> Self {
+        DecodeError {
+            message_bytes,
+            #[cfg(feature = "decode-error-context")]
+            context: Vec::new(),
+        }
+    }
+
     #[inline]
     pub fn message_bytes(&self) -> &'static [u8] {
-        self.0
+        self.message_bytes
+    }
+
+    /// Records where the error occurred. Does nothing unless the `decode-error-context` feature is enabled.
+    #[inline]
+    #[allow(unused_mut)]
+    pub fn with_context(mut self, context: DecodeErrorContext) -> Self {
+        #[cfg(feature = "decode-error-context")]
+        self.context.push(context);
+        #[cfg(not(feature = "decode-error-context"))]
+        let _ = context;
+        self
+    }
+
+    /// Where the error occurred, innermost context first.
+    /// Always empty unless the `decode-error-context` feature is enabled.
+    pub fn context(&self) -> &[DecodeErrorContext] {
+        #[cfg(feature = "decode-error-context")]
+        return self.context.as_slice();
+        #[cfg(not(feature = "decode-error-context"))]
+        return &[];
+    }
+
+    /// Formats the context as a path, e.g. `offer.tokens[2]::Fungible.amount`.
+    /// Empty if there is no context.
+    pub fn context_path(&self) -> Vec<u8> {
+        let mut path = Vec::new();
+        for context in self.context().iter().rev() {
+            context.append_to_path(&mut path);
+        }
+        if path.first() == Some(&b'.') {
+            path.remove(0);
+        }
+        path
     }
 
-    pub const INPUT_TOO_SHORT: DecodeError = DecodeError(b"input too short");
-    pub const INPUT_TOO_LONG: DecodeError = DecodeError(b"input too long");
-    pub const INPUT_OUT_OF_RANGE: DecodeError = DecodeError(b"input out of range");
-    pub const INVALID_VALUE: DecodeError = DecodeError(b"invalid value");
-    pub const UNSUPPORTED_OPERATION: DecodeError = DecodeError(b"unsupported operation");
-    pub const ARRAY_DECODE_ERROR: DecodeError = DecodeError(b"array decode error");
-    pub const UTF8_DECODE_ERROR: DecodeError = DecodeError(b"utf-8 decode error");
+    pub const INPUT_TOO_SHORT: DecodeError = DecodeError::new(b"input too short");
+    pub const INPUT_TOO_LONG: DecodeError = DecodeError::new(b"input too long");
+    pub const INPUT_OUT_OF_RANGE: DecodeError = DecodeError::new(b"input out of range");
+    pub const INVALID_VALUE: DecodeError = DecodeError::new(b"invalid value");
+    pub const UNSUPPORTED_OPERATION: DecodeError = DecodeError::new(b"unsupported operation");
+    pub const ARRAY_DECODE_ERROR: DecodeError = DecodeError::new(b"array decode error");
+    pub const UTF8_DECODE_ERROR: DecodeError = DecodeError::new(b"utf-8 decode error");
+    pub const UNSUPPORTED_VERSION: DecodeError = DecodeError::new(b"unsupported version");
 }
@@ -0,0 +1,86 @@
+use crate::{codec_err::DecodeError, nested_de::NestedDecode, nested_de_input::NestedDecodeInput};
+use alloc::vec::Vec;
+
+/// Part of the path to a value that failed to decode.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DecodeErrorContext {
+    /// Struct field, tuple struct fields are named by their index.
+    Field(&'static str),
+
+    /// Enum variant field.
+    VariantField {
+        variant: &'static str,
+        field: &'static str,
+    },
+
+    /// Position in a collection.
+    Index(usize),
+}
+
+impl DecodeErrorContext {
+    pub(crate) fn append_to_path(&self, path: &mut Vec<u8>) {
+        match self {
+            DecodeErrorContext::Field(field) => {
+                path.push(b'.');
+                path.extend_from_slice(field.as_bytes());
+            },
+            DecodeErrorContext::VariantField { variant, field } => {
+                path.extend_from_slice(b"::");
+                path.extend_from_slice(variant.as_bytes());
+                path.push(b'.');
+                path.extend_from_slice(field.as_bytes());
+            },
+            DecodeErrorContext::Index(index) => {
+                path.push(b'[');
+                append_usize(path, *index);
+                path.push(b']');
+            },
+        }
+    }
+}
+
+fn append_usize(path: &mut Vec<u8>, n: usize) {
+    if n >= 10 {
+        append_usize(path, n / 10);
+    }
+    path.push(b'0' + (n % 10) as u8);
+}
+
+/// Used by `dep_decode_or_exit_with_context` to add context to the error before exiting.
+pub fn exit_with_context<ExitCtx: Clone>(
+    c: (ExitCtx, fn(ExitCtx, DecodeError) -> !, DecodeErrorContext),
+    err: DecodeError,
+) -> ! {
+    let (inner_c, inner_exit, context) = c;
+    inner_exit(inner_c, err.with_context(context))
+}
+
+/// Nested decoding that records the context in case of error.
+/// Without the `decode-error-context` feature it is the same as `dep_decode`.
+#[inline]
+pub fn dep_decode_with_context<T: NestedDecode, I: NestedDecodeInput>(
+    input: &mut I,
+    context: DecodeErrorContext,
+) -> Result<T, DecodeError> {
+    T::dep_decode(input).map_err(|err| err.with_context(context))
+}
+
+/// Nested decoding that records the context in case of error.
+/// Without the `decode-error-context` feature it is the same as `dep_decode_or_exit`,
+/// the context only changes the exit function when the feature is enabled, to keep the code small.
+#[inline]
+pub fn dep_decode_or_exit_with_context<T: NestedDecode, I: NestedDecodeInput, ExitCtx: Clone>(
+    input: &mut I,
+    c: ExitCtx,
+    exit: fn(ExitCtx, DecodeError) -> !,
+    context: DecodeErrorContext,
+) -> T {
+    #[cfg(feature = "decode-error-context")]
+    return T::dep_decode_or_exit(input, (c, exit, context), exit_with_context::<ExitCtx>);
+
+    #[cfg(not(feature = "decode-error-context"))]
+    {
+        let _ = context;
+        T::dep_decode_or_exit(input, c, exit)
+    }
+}
@@ -0,0 +1,311 @@
+use crate::{
+    codec_err::{DecodeError, EncodeError},
+    codec_err_context::{
+        dep_decode_or_exit_with_context, dep_decode_with_context, DecodeErrorContext,
+    },
+    nested_de::NestedDecode,
+    nested_de_input::NestedDecodeInput,
+    nested_ser::NestedEncode,
+    nested_ser_output::NestedEncodeOutput,
+    top_de::TopDecode,
+    top_de_input::TopDecodeInput,
+    top_ser::TopEncode,
+    top_ser_output::TopEncodeOutput,
+};
+use alloc::collections::{BTreeMap, BTreeSet};
+
+// Sets and maps are encoded like `Vec`s of their items, in ascending key order:
+// nested encoding is length-prefixed, top-level encoding is just the concatenated items.
+// Map items are encoded as the key, followed by the value.
+// To keep the encoding canonical, decoding requires strictly ascending keys.
+
+fn insert_set_item<T: Ord>(set: &mut BTreeSet<T>, item: T) -> Result<(), DecodeError> {
+    if let Some(last) = set.iter().next_back() {
+        if item <= *last {
+            return Err(DecodeError::INVALID_VALUE);
+        }
+    }
+    set.insert(item);
+    Ok(())
+}
+
+fn insert_map_item<K: Ord, V>(
+    map: &mut BTreeMap<K, V>,
+    key: K,
+    value: V,
+) -> Result<(), DecodeError> {
+    if let Some(last) = map.keys().next_back() {
+        if key <= *last {
+            return Err(DecodeError::INVALID_VALUE);
+        }
+    }
+    map.insert(key, value);
+    Ok(())
+}
+
+impl<T: NestedEncode> NestedEncode for BTreeSet<T> {
+    fn dep_encode<O: NestedEncodeOutput>(&self, dest: &mut O) -> Result<(), EncodeError> {
+        self.len().dep_encode(dest)?;
+        for item in self.iter() {
+            item.dep_encode(dest)?;
+        }
+        Ok(())
+    }
+
+    fn dep_encode_or_exit<O: NestedEncodeOutput, ExitCtx: Clone>(
+        &self,
+        dest: &mut O,
+        c: ExitCtx,
+        exit: fn(ExitCtx, EncodeError) -> !,
+    ) {
+        self.len().dep_encode_or_exit(dest, c.clone(), exit);
+        for item in self.iter() {
+            item.dep_encode_or_exit(dest, c.clone(), exit);
+        }
+    }
+}
+
+impl<T: NestedEncode> TopEncode for BTreeSet<T> {
+    fn top_encode<O: TopEncodeOutput>(&self, output: O) -> Result<(), EncodeError> {
+        let mut buffer = output.start_nested_encode();
+        for item in self.iter() {
+            item.dep_encode(&mut buffer)?;
+        }
+        output.finalize_nested_encode(buffer);
+        Ok(())
+    }
+
+    fn top_encode_or_exit<O: TopEncodeOutput, ExitCtx: Clone>(
+        &self,
+        output: O,
+        c: ExitCtx,
+        exit: fn(ExitCtx, EncodeError) -> !,
+    ) {
+        let mut buffer = output.start_nested_encode();
+        for item in self.iter() {
+            item.dep_encode_or_exit(&mut buffer, c.clone(), exit);
+        }
+        output.finalize_nested_encode(buffer);
+    }
+}
+
+impl<T: NestedDecode + Ord> NestedDecode for BTreeSet<T> {
+    fn dep_decode<I: NestedDecodeInput>(input: &mut I) -> Result<Self, DecodeError> {
+        let size = usize::dep_decode(input)?;
+        let mut result = BTreeSet::new();
+        for index in 0..size {
+            let context = DecodeErrorContext::Index(index);
+            insert_set_item(&mut result, dep_decode_with_context(input, context)?)?;
+        }
+        Ok(result)
+    }
+
+    fn dep_decode_or_exit<I: NestedDecodeInput, ExitCtx: Clone>(
+        input: &mut I,
+        c: ExitCtx,
+        exit: fn(ExitCtx, DecodeError) -> !,
+    ) -> Self {
+        let size = usize::dep_decode_or_exit(input, c.clone(), exit);
+        let mut result = BTreeSet::new();
+        for index in 0..size {
+            let context = DecodeErrorContext::Index(index);
+            let item = dep_decode_or_exit_with_context(input, c.clone(), exit, context);
+            if let Err(e) = insert_set_item(&mut result, item) {
+                exit(c, e);
+            }
+        }
+        result
+    }
+}
+
+impl<T: NestedDecode + Ord> TopDecode for BTreeSet<T> {
+    fn top_decode<I: TopDecodeInput>(input: I) -> Result<Self, DecodeError> {
+        let mut result = BTreeSet::new();
+        let mut nested_buffer = input.into_nested_buffer();
+        while !nested_buffer.is_depleted() {
+            let context = DecodeErrorContext::Index(result.len());
+            insert_set_item(
+                &mut result,
+                dep_decode_with_context(&mut nested_buffer, context)?,
+            )?;
+        }
+        Ok(result)
+    }
+
+    fn top_decode_or_exit<I: TopDecodeInput, ExitCtx: Clone>(
+        input: I,
+        c: ExitCtx,
+        exit: fn(ExitCtx, DecodeError) -> !,
+    ) -> Self {
+        let mut result = BTreeSet::new();
+        let mut nested_buffer = input.into_nested_buffer();
+        while !nested_buffer.is_depleted() {
+            let context = DecodeErrorContext::Index(result.len());
+            let item =
+                dep_decode_or_exit_with_context(&mut nested_buffer, c.clone(), exit, context);
+            if let Err(e) = insert_set_item(&mut result, item) {
+                exit(c, e);
+            }
+        }
+        result
+    }
+}
+
+impl<K: NestedEncode, V: NestedEncode> NestedEncode for BTreeMap<K, V> {
+    fn dep_encode<O: NestedEncodeOutput>(&self, dest: &mut O) -> Result<(), EncodeError> {
+        self.len().dep_encode(dest)?;
+        for (key, value) in self.iter() {
+            key.dep_encode(dest)?;
+            value.dep_encode(dest)?;
+        }
+        Ok(())
+    }
+
+    fn dep_encode_or_exit<O: NestedEncodeOutput, ExitCtx: Clone>(
+        &self,
+        dest: &mut O,
+        c: ExitCtx,
+        exit: fn(ExitCtx, EncodeError) -> !,
+    ) {
+        self.len().dep_encode_or_exit(dest, c.clone(), exit);
+        for (key, value) in self.iter() {
+            key.dep_encode_or_exit(dest, c.clone(), exit);
+            value.dep_encode_or_exit(dest, c.clone(), exit);
+        }
+    }
+}
+
+impl<K: NestedEncode, V: NestedEncode> TopEncode for BTreeMap<K, V> {
+    fn top_encode<O: TopEncodeOutput>(&self, output: O) -> Result<(), EncodeError> {
+        let mut buffer = output.start_nested_encode();
+        for (key, value) in self.iter() {
+            key.dep_encode(&mut buffer)?;
+            value.dep_encode(&mut buffer)?;
+        }
+        output.finalize_nested_encode(buffer);
+        Ok(())
+    }
+
+    fn top_encode_or_exit<O: TopEncodeOutput, ExitCtx: Clone>(
+        &self,
+        output: O,
+        c: ExitCtx,
+        exit: fn(ExitCtx, EncodeError) -> !,
+    ) {
+        let mut buffer = output.start_nested_encode();
+        for (key, value) in self.iter() {
+            key.dep_encode_or_exit(&mut buffer, c.clone(), exit);
+            value.dep_encode_or_exit(&mut buffer, c.clone(), exit);
+        }
+        output.finalize_nested_encode(buffer);
+    }
+}
+
+impl<K: NestedDecode + Ord, V: NestedDecode> NestedDecode for BTreeMap<K, V> {
+    fn dep_decode<I: NestedDecodeInput>(input: &mut I) -> Result<Self, DecodeError> {
+        let size = usize::dep_decode(input)?;
+        let mut result = BTreeMap::new();
+        for index in 0..size {
+            let key = dep_decode_with_context(input, DecodeErrorContext::Index(index))?;
+            let value = dep_decode_with_context(input, DecodeErrorContext::Index(index))?;
+            insert_map_item(&mut result, key, value)?;
+        }
+        Ok(result)
+    }
+
+    fn dep_decode_or_exit<I: NestedDecodeInput, ExitCtx: Clone>(
+        input: &mut I,
+        c: ExitCtx,
+        exit: fn(ExitCtx, DecodeError) -> !,
+    ) -> Self {
+        let size = usize::dep_decode_or_exit(input, c.clone(), exit);
+        let mut result = BTreeMap::new();
+        for index in 0..size {
+            let context = DecodeErrorContext::Index(index);
+            let key = dep_decode_or_exit_with_context(input, c.clone(), exit, context);
+            let value = dep_decode_or_exit_with_context(input, c.clone(), exit, context);
+            if let Err(e) = insert_map_item(&mut result, key, value) {
+                exit(c, e);
+            }
+        }
+        result
+    }
+}
+
+impl<K: NestedDecode + Ord, V: NestedDecode> TopDecode for BTreeMap<K, V> {
+    fn top_decode<I: TopDecodeInput>(input: I) -> Result<Self, DecodeError> {
+        let mut result = BTreeMap::new();
+        let mut nested_buffer = input.into_nested_buffer();
+        while !nested_buffer.is_depleted() {
+            let index = result.len();
+            let key =
+                dep_decode_with_context(&mut nested_buffer, DecodeErrorContext::Index(index))?;
+            let value =
+                dep_decode_with_context(&mut nested_buffer, DecodeErrorContext::Index(index))?;
+            insert_map_item(&mut result, key, value)?;
+        }
+        Ok(result)
+    }
+
+    fn top_decode_or_exit<I: TopDecodeInput, ExitCtx: Clone>(
+        input: I,
+        c: ExitCtx,
+        exit: fn(ExitCtx, DecodeError) -> !,
+    ) -> Self {
+        let mut result = BTreeMap::new();
+        let mut nested_buffer = input.into_nested_buffer();
+        while !nested_buffer.is_depleted() {
+            let context = DecodeErrorContext::Index(result.len());
+            let key = dep_decode_or_exit_with_context(&mut nested_buffer, c.clone(), exit, context);
+            let value =
+                dep_decode_or_exit_with_context(&mut nested_buffer, c.clone(), exit, context);
+            if let Err(e) = insert_map_item(&mut result, key, value) {
+                exit(c, e);
+            }
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use crate::{
+        test_util::{check_dep_encode_decode, check_top_encode_decode},
+        DecodeError, NestedDecode, TopDecode,
+    };
+    use alloc::collections::{BTreeMap, BTreeSet};
+
+    #[test]
+    fn test_btree_set() {
+        let set: BTreeSet<u16> = [3u16, 1, 2].iter().cloned().collect();
+        check_top_encode_decode(set.clone(), &[0, 1, 0, 2, 0, 3]);
+        check_dep_encode_decode(set, &[0, 0, 0, 3, 0, 1, 0, 2, 0, 3]);
+        check_top_encode_decode(BTreeSet::<u16>::new(), &[]);
+        check_dep_encode_decode(BTreeSet::<u16>::new(), &[0, 0, 0, 0]);
+    }
+
+    #[test]
+    fn test_btree_map() {
+        let mut map = BTreeMap::<u8, u16>::new();
+        map.insert(2, 20);
+        map.insert(1, 10);
+        check_top_encode_decode(map.clone(), &[1, 0, 10, 2, 0, 20]);
+        check_dep_encode_decode(map, &[0, 0, 0, 2, 1, 0, 10, 2, 0, 20]);
+    }
+
+    #[test]
+    fn test_btree_not_ascending() {
+        assert_eq!(
+            BTreeSet::<u8>::top_decode(&[2, 1][..]),
+            Err(DecodeError::INVALID_VALUE)
+        );
+        assert_eq!(
+            BTreeSet::<u8>::dep_decode(&mut &[0, 0, 0, 2, 1, 1][..]),
+            Err(DecodeError::INVALID_VALUE)
+        );
+        assert_eq!(
+            BTreeMap::<u8, u8>::top_decode(&[1, 10, 1, 20][..]),
+            Err(DecodeError::INVALID_VALUE)
+        );
+    }
+}
@@ -0,0 +1,181 @@
+use crate::{
+    dep_encode_from_no_err, num_conv::top_encoded_offset_128, top_encode_from_no_err,
+    top_ser::TopEncodeNoErr, DecodeError, EncodeError, NestedDecode, NestedDecodeInput,
+    NestedEncode, NestedEncodeNoErr, NestedEncodeOutput, TopDecode, TopDecodeInput, TopEncode,
+    TopEncodeOutput, TypeInfo,
+};
+
+// 128-bit numbers do not fit in the u64/i64 used by the smaller types,
+// so they get their own implementation.
+// Same rules as for the other numbers apply:
+// nested encoding is fixed width (16 bytes), top-level encoding is compact.
+
+macro_rules! dep_encode_num_128 {
+    ($num_type:ty, $type_info:expr) => {
+        impl NestedEncodeNoErr for $num_type {
+            #[inline(never)]
+            fn dep_encode_no_err<O: NestedEncodeOutput>(&self, dest: &mut O) {
+                dest.write(&self.to_be_bytes()[..]);
+            }
+        }
+
+        dep_encode_from_no_err! {$num_type, $type_info}
+    };
+}
+
+dep_encode_num_128! {u128, TypeInfo::U128}
+dep_encode_num_128! {i128, TypeInfo::I128}
+
+macro_rules! top_encode_num_128 {
+    ($num_type:ty, $signed:expr, $type_info:expr) => {
+        impl TopEncodeNoErr for $num_type {
+            #[inline(never)]
+            fn top_encode_no_err<O: TopEncodeOutput>(&self, output: O) {
+                let bytes_be = self.to_be_bytes();
+                let offset = top_encoded_offset_128(&bytes_be, $signed);
+                output.set_slice_u8(&bytes_be[offset..]);
+            }
+        }
+
+        top_encode_from_no_err! {$num_type, $type_info}
+    };
+}
+
+top_encode_num_128! {u128, false, TypeInfo::U128}
+top_encode_num_128! {i128, true, TypeInfo::I128}
+
+macro_rules! dep_decode_num_128 {
+    ($num_type:ty, $type_info:expr) => {
+        impl NestedDecode for $num_type {
+            const TYPE_INFO: TypeInfo = $type_info;
+
+            fn dep_decode<I: NestedDecodeInput>(input: &mut I) -> Result<Self, DecodeError> {
+                let mut bytes = [0u8; 16];
+                input.read_into(&mut bytes[..])?;
+                Ok(<$num_type>::from_be_bytes(bytes))
+            }
+
+            fn dep_decode_or_exit<I: NestedDecodeInput, ExitCtx: Clone>(
+                input: &mut I,
+                c: ExitCtx,
+                exit: fn(ExitCtx, DecodeError) -> !,
+            ) -> Self {
+                let mut bytes = [0u8; 16];
+                input.read_into_or_exit(&mut bytes[..], c, exit);
+                <$num_type>::from_be_bytes(bytes)
+            }
+        }
+    };
+}
+
+dep_decode_num_128!(u128, TypeInfo::U128);
+dep_decode_num_128!(i128, TypeInfo::I128);
+
+/// Reads the compact representation into the last bytes of a 16-byte buffer,
+/// filling the leading bytes with 1s for negative signed numbers.
+fn top_decode_bytes_128<I: TopDecodeInput>(
+    input: I,
+    signed: bool,
+) -> Result<[u8; 16], DecodeError> {
+    let len = input.byte_len();
+    if len > 16 {
+        return Err(DecodeError::INPUT_TOO_LONG);
+    }
+    let mut bytes = [0u8; 16];
+    let offset = 16 - len;
+    let mut nested_buffer = input.into_nested_buffer();
+    nested_buffer.read_into(&mut bytes[offset..])?;
+    if signed && len > 0 && bytes[offset] >> 7 == 1 {
+        for byte in bytes[..offset].iter_mut() {
+            *byte = 0xffu8;
+        }
+    }
+    Ok(bytes)
+}
+
+macro_rules! top_decode_num_128 {
+    ($num_type:ty, $signed:expr, $type_info:expr) => {
+        impl TopDecode for $num_type {
+            const TYPE_INFO: TypeInfo = $type_info;
+
+            fn top_decode<I: TopDecodeInput>(input: I) -> Result<Self, DecodeError> {
+                let bytes = top_decode_bytes_128(input, $signed)?;
+                Ok(<$num_type>::from_be_bytes(bytes))
+            }
+
+            fn top_decode_or_exit<I: TopDecodeInput, ExitCtx: Clone>(
+                input: I,
+                c: ExitCtx,
+                exit: fn(ExitCtx, DecodeError) -> !,
+            ) -> Self {
+                match top_decode_bytes_128(input, $signed) {
+                    Ok(bytes) => <$num_type>::from_be_bytes(bytes),
+                    Err(e) => exit(c, e),
+                }
+            }
+        }
+    };
+}
+
+top_decode_num_128!(u128, false, TypeInfo::U128);
+top_decode_num_128!(i128, true, TypeInfo::I128);
+
+#[cfg(test)]
+pub mod tests {
+    use crate::{
+        test_util::{check_dep_encode_decode, check_top_encode_decode},
+        DecodeError, TopDecode,
+    };
+
+    #[test]
+    fn test_top() {
+        check_top_encode_decode(0u128, &[]);
+        check_top_encode_decode(0i128, &[]);
+        check_top_encode_decode(5u128, &[5]);
+        check_top_encode_decode(5i128, &[5]);
+        check_top_encode_decode(-5i128, &[251]);
+        check_top_encode_decode(-1i128, &[255]);
+        check_top_encode_decode(128u128, &[128]);
+        check_top_encode_decode(128i128, &[0, 128]);
+        check_top_encode_decode(-128i128, &[128]);
+        check_top_encode_decode(-129i128, &[255, 127]);
+        check_top_encode_decode(u128::MAX, &[255; 16]);
+        check_top_encode_decode(
+            i128::MAX,
+            &[
+                127, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
+            ],
+        );
+        check_top_encode_decode(
+            i128::MIN,
+            &[128, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+        );
+        check_top_encode_decode(1u128 << 64, &[1, 0, 0, 0, 0, 0, 0, 0, 0]);
+    }
+
+    #[test]
+    fn test_top_too_long() {
+        assert_eq!(
+            u128::top_decode(&[1u8; 17][..]),
+            Err(DecodeError::INPUT_TOO_LONG)
+        );
+        assert_eq!(
+            i128::top_decode(&[1u8; 17][..]),
+            Err(DecodeError::INPUT_TOO_LONG)
+        );
+    }
+
+    #[test]
+    fn test_dep() {
+        check_dep_encode_decode(0u128, &[0; 16]);
+        check_dep_encode_decode(0i128, &[0; 16]);
+        check_dep_encode_decode(5u128, &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 5]);
+        check_dep_encode_decode(
+            -5i128,
+            &[
+                255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 251,
+            ],
+        );
+        check_dep_encode_decode(u128::MAX, &[255; 16]);
+    }
+}
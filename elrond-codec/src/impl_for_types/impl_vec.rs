@@ -1,6 +1,9 @@
 use crate::{
     boxed_slice_into_vec,
     codec_err::{DecodeError, EncodeError},
+    codec_err_context::{
+        dep_decode_or_exit_with_context, dep_decode_with_context, DecodeErrorContext,
+    },
     nested_de::NestedDecode,
     nested_de_input::NestedDecodeInput,
     nested_ser::NestedEncode,
@@ -41,7 +44,8 @@ impl<T: NestedDecode> TopDecode for Vec<T> {
             let mut result: Vec<T> = Vec::new();
             let mut nested_buffer = input.into_nested_buffer();
             while !nested_buffer.is_depleted() {
-                result.push(T::dep_decode(&mut nested_buffer)?);
+                let context = DecodeErrorContext::Index(result.len());
+                result.push(dep_decode_with_context(&mut nested_buffer, context)?);
             }
             if !nested_buffer.is_depleted() {
                 return Err(DecodeError::INPUT_TOO_LONG);
@@ -64,7 +68,13 @@ impl<T: NestedDecode> TopDecode for Vec<T> {
             let mut result: Vec<T> = Vec::new();
             let mut nested_buffer = input.into_nested_buffer();
             while !nested_buffer.is_depleted() {
-                result.push(T::dep_decode_or_exit(&mut nested_buffer, c.clone(), exit));
+                let context = DecodeErrorContext::Index(result.len());
+                result.push(dep_decode_or_exit_with_context(
+                    &mut nested_buffer,
+                    c.clone(),
+                    exit,
+                    context,
+                ));
             }
             if !nested_buffer.is_depleted() {
                 exit(c, DecodeError::INPUT_TOO_LONG);
@@ -91,20 +101,33 @@ impl<T: NestedEncode> NestedEncode for Vec<T> {
     }
 }
 
+/// Items take at least 1 byte, except for some zero-sized types,
+/// so there is no point in reserving more than the remaining input length.
+/// Avoids huge allocations when decoding malformed input.
+#[inline]
+fn capacity_hint<I: NestedDecodeInput>(size: usize, input: &I) -> usize {
+    core::cmp::min(size, input.remaining_len())
+}
+
 impl<T: NestedDecode> NestedDecode for Vec<T> {
     fn dep_decode<I: NestedDecodeInput>(input: &mut I) -> Result<Self, DecodeError> {
         let size = usize::dep_decode(input)?;
         match T::TYPE_INFO {
             TypeInfo::U8 => {
+                // the size comes from the input, it is checked before allocating
+                if size > input.remaining_len() {
+                    return Err(DecodeError::INPUT_TOO_SHORT);
+                }
                 let mut vec_u8: Vec<u8> = alloc::vec![0; size];
                 input.read_into(vec_u8.as_mut_slice())?;
                 let cast_vec: Vec<T> = unsafe { core::mem::transmute(vec_u8) };
                 Ok(cast_vec)
             },
             _ => {
-                let mut result: Vec<T> = Vec::with_capacity(size);
-                for _ in 0..size {
-                    result.push(T::dep_decode(input)?);
+                let mut result: Vec<T> = Vec::with_capacity(capacity_hint(size, input));
+                for index in 0..size {
+                    let context = DecodeErrorContext::Index(index);
+                    result.push(dep_decode_with_context(input, context)?);
                 }
                 Ok(result)
             },
@@ -119,15 +142,24 @@ impl<T: NestedDecode> NestedDecode for Vec<T> {
         let size = usize::dep_decode_or_exit(input, c.clone(), exit);
         match T::TYPE_INFO {
             TypeInfo::U8 => {
+                if size > input.remaining_len() {
+                    exit(c, DecodeError::INPUT_TOO_SHORT);
+                }
                 let mut vec_u8: Vec<u8> = alloc::vec![0; size];
                 input.read_into_or_exit(vec_u8.as_mut_slice(), c, exit);
                 let cast_vec: Vec<T> = unsafe { core::mem::transmute(vec_u8) };
                 cast_vec
             },
             _ => {
-                let mut result: Vec<T> = Vec::with_capacity(size);
-                for _ in 0..size {
-                    result.push(T::dep_decode_or_exit(input, c.clone(), exit));
+                let mut result: Vec<T> = Vec::with_capacity(capacity_hint(size, input));
+                for index in 0..size {
+                    let context = DecodeErrorContext::Index(index);
+                    result.push(dep_decode_or_exit_with_context(
+                        input,
+                        c.clone(),
+                        exit,
+                        context,
+                    ));
                 }
                 result
             },
@@ -137,7 +169,11 @@ impl<T: NestedDecode> NestedDecode for Vec<T> {
 
 #[cfg(test)]
 pub mod tests {
-    use crate::test_util::check_top_encode_decode;
+    use crate::{
+        test_util::{check_top_encode_decode, dep_decode_from_byte_slice_or_panic},
+        DecodeError, NestedDecode,
+    };
+    use alloc::vec::Vec;
 
     #[test]
     fn test_top_vec_i32_compacted() {
@@ -150,4 +186,32 @@ pub mod tests {
     fn test_top_vec_u8_compacted() {
         check_top_encode_decode([1u8, 2u8, 3u8].to_vec(), &[1u8, 2u8, 3u8]);
     }
+
+    #[test]
+    fn test_dep_vec_huge_size() {
+        // must fail without attempting to allocate the declared size
+        let input: &[u8] = &[0xff, 0xff, 0xff, 0xff, 1, 2];
+        assert_eq!(
+            Vec::<u8>::dep_decode(&mut &*input),
+            Err(DecodeError::INPUT_TOO_SHORT)
+        );
+        assert_eq!(
+            Vec::<u64>::dep_decode(&mut &*input),
+            Err(DecodeError::INPUT_TOO_SHORT)
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "input too short")]
+    fn test_dep_vec_u8_huge_size_or_exit() {
+        let input: &[u8] = &[0xff, 0xff, 0xff, 0xff, 1, 2];
+        let _: Vec<u8> = dep_decode_from_byte_slice_or_panic(input);
+    }
+
+    #[test]
+    #[should_panic(expected = "input too short")]
+    fn test_dep_vec_u64_huge_size_or_exit() {
+        let input: &[u8] = &[0xff, 0xff, 0xff, 0xff, 1, 2];
+        let _: Vec<u64> = dep_decode_from_byte_slice_or_panic(input);
+    }
 }
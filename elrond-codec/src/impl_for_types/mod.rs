@@ -1,8 +1,10 @@
 mod impl_array;
 mod impl_bool;
+mod impl_btree;
 mod impl_bytes;
 mod impl_empty;
 mod impl_non_zero_usize;
+mod impl_num_128;
 mod impl_num_signed;
 mod impl_num_unsigned;
 mod impl_option;
@@ -11,9 +11,11 @@ pub use alloc::vec::Vec;
 // TODO: group into smaller sub-modules
 
 mod codec_err;
+mod codec_err_context;
 mod default_traits;
 mod impl_for_types;
 mod nested_de;
+mod nested_de_borrowed;
 mod nested_de_input;
 mod nested_de_input_owned;
 mod nested_de_input_slice;
@@ -37,8 +39,15 @@ pub use crate::{
     },
 };
 pub use codec_err::{DecodeError, EncodeError};
+pub use codec_err_context::{
+    dep_decode_or_exit_with_context, dep_decode_with_context, exit_with_context, DecodeErrorContext,
+};
 pub use default_traits::{DecodeDefault, EncodeDefault};
 pub use nested_de::NestedDecode;
+pub use nested_de_borrowed::{
+    dep_decode_borrowed_from_byte_slice, dep_decode_borrowed_from_byte_slice_or_exit,
+    NestedDecodeBorrowed,
+};
 pub use nested_de_input_owned::OwnedBytesNestedDecodeInput;
 pub use nested_de_input_slice::{dep_decode_from_byte_slice, dep_decode_from_byte_slice_or_exit};
 pub use nested_ser::{dep_encode_to_vec, NestedEncode, NestedEncodeNoErr};
@@ -69,6 +78,8 @@ pub enum TypeInfo {
     ISIZE,
     U64,
     I64,
+    U128,
+    I128,
     Bool,
     Unit,
 }
@@ -0,0 +1,114 @@
+use crate::{DecodeError, NestedDecode};
+
+/// Trait for types that can be deserialized from a byte slice without copying,
+/// by keeping references into the input, for instance `&[u8]` or `&str`.
+///
+/// All types implementing `NestedDecode` also implement this trait, by copying, as usual.
+/// Structs containing borrowed fields can derive it.
+///
+/// Same encoding as `NestedDecode`: `&'a [u8]` and `&'a str` decode the same data as `Vec<u8>` and `String`.
+pub trait NestedDecodeBorrowed<'a>: Sized {
+    fn dep_decode_borrowed(input: &mut &'a [u8]) -> Result<Self, DecodeError>;
+}
+
+impl<'a, T: NestedDecode> NestedDecodeBorrowed<'a> for T {
+    #[inline]
+    fn dep_decode_borrowed(input: &mut &'a [u8]) -> Result<Self, DecodeError> {
+        T::dep_decode(input)
+    }
+}
+
+impl<'a> NestedDecodeBorrowed<'a> for &'a [u8] {
+    fn dep_decode_borrowed(input: &mut &'a [u8]) -> Result<Self, DecodeError> {
+        let size = usize::dep_decode(input)?;
+        if size > input.len() {
+            return Err(DecodeError::INPUT_TOO_SHORT);
+        }
+        let (result, rest) = input.split_at(size);
+        *input = rest;
+        Ok(result)
+    }
+}
+
+impl<'a> NestedDecodeBorrowed<'a> for &'a str {
+    fn dep_decode_borrowed(input: &mut &'a [u8]) -> Result<Self, DecodeError> {
+        let bytes = <&'a [u8]>::dep_decode_borrowed(input)?;
+        core::str::from_utf8(bytes).map_err(|_| DecodeError::UTF8_DECODE_ERROR)
+    }
+}
+
+/// Deserializes an object that borrows from the given slice.
+/// Also checks that the entire slice was used.
+pub fn dep_decode_borrowed_from_byte_slice<'a, D: NestedDecodeBorrowed<'a>>(
+    input: &'a [u8],
+) -> Result<D, DecodeError> {
+    let mut_slice = &mut &*input;
+    let result = D::dep_decode_borrowed(mut_slice)?;
+    if !mut_slice.is_empty() {
+        return Err(DecodeError::INPUT_TOO_LONG);
+    }
+    Ok(result)
+}
+
+pub fn dep_decode_borrowed_from_byte_slice_or_exit<'a, D, ExitCtx>(
+    input: &'a [u8],
+    c: ExitCtx,
+    exit: fn(ExitCtx, DecodeError) -> !,
+) -> D
+where
+    D: NestedDecodeBorrowed<'a>,
+    ExitCtx: Clone,
+{
+    match dep_decode_borrowed_from_byte_slice(input) {
+        Ok(result) => result,
+        Err(e) => exit(c, e),
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn test_borrowed_slice() {
+        let bytes = [0, 0, 0, 3, 1, 2, 3];
+        let slice: &[u8] = dep_decode_borrowed_from_byte_slice(&bytes[..]).unwrap();
+        assert_eq!(slice, &[1, 2, 3]);
+        assert_eq!(slice.as_ptr(), bytes[4..].as_ptr());
+    }
+
+    #[test]
+    fn test_borrowed_str() {
+        let bytes = [0, 0, 0, 3, b'a', b'b', b'c'];
+        let s: &str = dep_decode_borrowed_from_byte_slice(&bytes[..]).unwrap();
+        assert_eq!(s, "abc");
+        assert_eq!(
+            dep_decode_borrowed_from_byte_slice::<&str>(&[0, 0, 0, 1, 0xff][..]),
+            Err(DecodeError::UTF8_DECODE_ERROR)
+        );
+    }
+
+    #[test]
+    fn test_borrowed_errors() {
+        assert_eq!(
+            dep_decode_borrowed_from_byte_slice::<&[u8]>(&[0, 0, 0, 3, 1, 2][..]),
+            Err(DecodeError::INPUT_TOO_SHORT)
+        );
+        assert_eq!(
+            dep_decode_borrowed_from_byte_slice::<&[u8]>(&[0, 0, 0, 1, 1, 2][..]),
+            Err(DecodeError::INPUT_TOO_LONG)
+        );
+    }
+
+    #[test]
+    fn test_owned_also_borrowed() {
+        let bytes = [0, 0, 0, 2, 1, 2, 0, 5];
+        let (v, n): (Vec<u8>, u16) = (
+            dep_decode_borrowed_from_byte_slice(&bytes[..6]).unwrap(),
+            dep_decode_borrowed_from_byte_slice(&bytes[6..]).unwrap(),
+        );
+        assert_eq!(v, [1, 2]);
+        assert_eq!(n, 5);
+    }
+}
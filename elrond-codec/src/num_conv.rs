@@ -96,3 +96,27 @@ pub fn bytes_to_number(bytes: &[u8], signed: bool) -> u64 {
     }
     result
 }
+
+/// Index of the first byte of the top-level (compact) encoding of a 128-bit number,
+/// given its big endian representation. Zero results in an empty slice.
+pub fn top_encoded_offset_128(bytes_be: &[u8; 16], signed: bool) -> usize {
+    let negative = signed && bytes_be[0] > 0x7fu8;
+    let irrelevant_byte = if negative { 0xffu8 } else { 0x00u8 };
+
+    let mut offset = 0usize;
+    while offset < 16 && bytes_be[offset] == irrelevant_byte {
+        offset += 1;
+    }
+
+    if negative && offset == 16 {
+        // -1 is a special case
+        return 15;
+    }
+
+    if signed && offset < 16 && bytes_be[offset] >> 7 != negative as u8 {
+        // keep one irrelevant byte, to preserve the sign
+        offset -= 1;
+    }
+
+    offset
+}
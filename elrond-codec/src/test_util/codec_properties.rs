@@ -0,0 +1,137 @@
+use super::*;
+use alloc::vec::Vec;
+use core::fmt::Debug;
+
+/// Bytes appended after encodings, to check that decoders stop where they should.
+const TRAILING_BYTES: &[u8] = &[0xab, 0xcd];
+
+/// Checks the properties that all codec implementations should have, for one value:
+/// - top-level and nested round-trips, via both the `Result` and the fast exit paths;
+/// - the nested encoding is self-delimiting: decoding stops exactly where the encoding ends;
+/// - nested-vs-top consistency: the nested encoding is the one used by tuples and slices
+///   at top level, and it decodes the same from a top-level input;
+/// - trailing bytes after a nested encoding are rejected;
+/// - every truncation of the nested encoding is rejected;
+/// - top-level decoding of truncated or extended input does not panic.
+///
+/// Panics if any property is not satisfied.
+/// Meant for hand-written implementations, for derived ones it is mostly a sanity check.
+pub fn check_codec_properties<T>(value: &T)
+where
+    T: TopEncode + TopDecode + NestedEncode + NestedDecode + PartialEq + Debug,
+{
+    // round-trips
+    let top_bytes = check_top_encode(value);
+    assert_eq!(
+        &check_top_decode::<T>(top_bytes.as_slice()),
+        value,
+        "top-level round-trip failed, encoded as {:?}",
+        top_bytes
+    );
+    let nested_bytes = check_dep_encode(value);
+    assert_eq!(
+        &check_dep_decode::<T>(nested_bytes.as_slice()),
+        value,
+        "nested round-trip failed, encoded as {:?}",
+        nested_bytes
+    );
+
+    // self-delimiting
+    let mut extended = nested_bytes.clone();
+    extended.extend_from_slice(TRAILING_BYTES);
+    let mut input = extended.as_slice();
+    let decoded = T::dep_decode(&mut input).unwrap();
+    assert_eq!(
+        (&decoded, input),
+        (value, TRAILING_BYTES),
+        "nested decoding did not stop at the end of {:?}",
+        nested_bytes
+    );
+    let mut input = extended.as_slice();
+    let decoded = T::dep_decode_or_exit(&mut input, (), decode_panic_exit);
+    assert_eq!(
+        (&decoded, input),
+        (value, TRAILING_BYTES),
+        "nested fast exit decoding did not stop at the end of {:?}",
+        nested_bytes
+    );
+
+    // nested-vs-top
+    let mut nested_twice = nested_bytes.clone();
+    nested_twice.extend_from_slice(nested_bytes.as_slice());
+    assert_eq!(
+        check_top_encode(&[value, value].as_ref()),
+        nested_twice,
+        "slice top-level encoding differs from the nested encodings"
+    );
+    let mut tuple_bytes = nested_bytes.clone();
+    tuple_bytes.push(7);
+    assert_eq!(
+        check_top_encode(&(value, 7u8)),
+        tuple_bytes,
+        "tuple top-level encoding differs from the nested encoding"
+    );
+    let (decoded, last) = <(T, u8)>::top_decode(tuple_bytes.as_slice()).unwrap();
+    assert_eq!((&decoded, last), (value, 7u8));
+    if !nested_bytes.is_empty() {
+        // a top-level Vec cannot know how many empty encodings there are
+        let decoded_twice = Vec::<T>::top_decode(nested_twice.as_slice()).unwrap();
+        assert!(
+            decoded_twice.len() == 2 && decoded_twice.iter().all(|item| item == value),
+            "decoding 2 nested encodings from a top-level input yielded {:?}",
+            decoded_twice
+        );
+    }
+
+    // trailing bytes
+    extended.truncate(nested_bytes.len() + 1);
+    assert_eq!(
+        dep_decode_from_byte_slice::<T>(extended.as_slice()),
+        Err(DecodeError::INPUT_TOO_LONG),
+        "trailing byte not rejected after {:?}",
+        nested_bytes
+    );
+
+    // truncation
+    for len in 0..nested_bytes.len() {
+        let truncated = &nested_bytes[..len];
+        assert!(
+            dep_decode_from_byte_slice::<T>(truncated).is_err(),
+            "truncated nested input {:?} was accepted",
+            truncated
+        );
+    }
+    for len in 0..top_bytes.len() {
+        let _ = T::top_decode(&top_bytes[..len]);
+    }
+    let mut top_extended = top_bytes;
+    top_extended.extend_from_slice(TRAILING_BYTES);
+    let _ = T::top_decode(top_extended.as_slice());
+}
+
+/// Decodes arbitrary bytes, both top-level and nested.
+/// Decoding is allowed to fail, but must not panic.
+/// Whatever is decoded successfully must survive a round-trip.
+pub fn check_decode_any_bytes<T>(bytes: &[u8])
+where
+    T: TopEncode + TopDecode + NestedEncode + NestedDecode + PartialEq + Debug,
+{
+    if let Ok(value) = T::top_decode(bytes) {
+        let reencoded = check_top_encode(&value);
+        assert_eq!(check_top_decode::<T>(reencoded.as_slice()), value);
+    }
+    if let Ok(value) = dep_decode_from_byte_slice::<T>(bytes) {
+        // nested encodings are unique, unlike the top-level ones (e.g. compacted numbers)
+        assert_eq!(
+            check_dep_encode(&value),
+            bytes,
+            "nested encoding is not unique for {:?}",
+            value
+        );
+    }
+    let mut input = bytes;
+    if let Ok(value) = T::dep_decode(&mut input) {
+        let consumed = &bytes[..bytes.len() - input.len()];
+        assert_eq!(check_dep_encode(&value), consumed);
+    }
+}
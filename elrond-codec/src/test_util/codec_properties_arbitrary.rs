@@ -0,0 +1,53 @@
+use super::*;
+use alloc::vec::Vec;
+use arbitrary::{Arbitrary, Unstructured};
+use core::fmt::Debug;
+
+/// Builds a value out of the given bytes and checks all codec properties on it,
+/// see `check_codec_properties`. Also decodes the raw bytes, see `check_decode_any_bytes`.
+///
+/// Meant as the body of fuzz targets, e.g.
+/// `fuzz_target!(|data: &[u8]| check_codec_properties_arbitrary::<MyType>(data));`
+pub fn check_codec_properties_arbitrary<'a, T>(data: &'a [u8])
+where
+    T: Arbitrary<'a> + TopEncode + TopDecode + NestedEncode + NestedDecode + PartialEq + Debug,
+{
+    if let Ok(value) = T::arbitrary(&mut Unstructured::new(data)) {
+        check_codec_properties(&value);
+    }
+    check_decode_any_bytes::<T>(data);
+}
+
+/// Same as `check_codec_properties_arbitrary`, but on a number of pseudo-random inputs.
+/// The inputs are always the same, so that it can be used in regular tests.
+pub fn check_codec_properties_random<T>(num_inputs: usize)
+where
+    T: for<'a> Arbitrary<'a>
+        + TopEncode
+        + TopDecode
+        + NestedEncode
+        + NestedDecode
+        + PartialEq
+        + Debug,
+{
+    let mut rng = XorShift64(0x2545_f491_4f6c_dd1d);
+    let mut data = Vec::new();
+    for _ in 0..num_inputs {
+        let len = (rng.next() % 256) as usize;
+        data.clear();
+        data.extend((0..len).map(|_| rng.next() as u8));
+        check_codec_properties_arbitrary::<T>(data.as_slice());
+    }
+}
+
+/// Simple pseudo-random generator, good enough to produce test inputs.
+struct XorShift64(u64);
+
+impl XorShift64 {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+}
@@ -118,3 +118,11 @@ where
     let deserialized: V = check_dep_decode::<V>(&serialized_bytes[..]);
     assert_eq!(deserialized, element);
 }
+
+mod codec_properties;
+pub use codec_properties::*;
+
+#[cfg(feature = "arbitrary")]
+mod codec_properties_arbitrary;
+#[cfg(feature = "arbitrary")]
+pub use codec_properties_arbitrary::*;
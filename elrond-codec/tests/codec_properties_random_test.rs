@@ -0,0 +1,75 @@
+#![cfg(feature = "arbitrary")]
+
+extern crate elrond_codec_derive;
+use elrond_codec_derive::*;
+
+use arbitrary::Arbitrary;
+use elrond_codec::test_util::check_codec_properties_random;
+use std::collections::{BTreeMap, BTreeSet};
+
+// to test, run the following command in elrond-codec folder:
+// cargo test --features arbitrary --test codec_properties_random_test
+
+const NUM_INPUTS: usize = 2000;
+
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, Arbitrary, PartialEq, Debug)]
+enum Payment {
+    None,
+    Egld(u64),
+    Esdt {
+        token: Vec<u8>,
+        nonce: u64,
+        amount: u128,
+    },
+}
+
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, Arbitrary, PartialEq, Debug)]
+struct Offer {
+    id: u32,
+    payments: Vec<Payment>,
+    label: Option<String>,
+    tags: BTreeSet<u8>,
+}
+
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, Arbitrary, PartialEq, Debug)]
+struct Pair(i16, [u8; 3]);
+
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, Arbitrary, PartialEq, Debug)]
+struct Empty;
+
+#[test]
+fn random_numbers_test() {
+    check_codec_properties_random::<u8>(NUM_INPUTS);
+    check_codec_properties_random::<u16>(NUM_INPUTS);
+    check_codec_properties_random::<u32>(NUM_INPUTS);
+    check_codec_properties_random::<u64>(NUM_INPUTS);
+    check_codec_properties_random::<u128>(NUM_INPUTS);
+    check_codec_properties_random::<i8>(NUM_INPUTS);
+    check_codec_properties_random::<i16>(NUM_INPUTS);
+    check_codec_properties_random::<i32>(NUM_INPUTS);
+    check_codec_properties_random::<i64>(NUM_INPUTS);
+    check_codec_properties_random::<i128>(NUM_INPUTS);
+    check_codec_properties_random::<bool>(NUM_INPUTS);
+}
+
+#[test]
+fn random_collections_test() {
+    check_codec_properties_random::<Vec<u8>>(NUM_INPUTS);
+    check_codec_properties_random::<Vec<i32>>(NUM_INPUTS);
+    check_codec_properties_random::<Vec<Vec<u16>>>(NUM_INPUTS);
+    check_codec_properties_random::<String>(NUM_INPUTS);
+    check_codec_properties_random::<Option<u64>>(NUM_INPUTS);
+    check_codec_properties_random::<Option<Vec<u8>>>(NUM_INPUTS);
+    check_codec_properties_random::<[u16; 4]>(NUM_INPUTS);
+    check_codec_properties_random::<(u8, i64, bool)>(NUM_INPUTS);
+    check_codec_properties_random::<BTreeSet<u32>>(NUM_INPUTS);
+    check_codec_properties_random::<BTreeMap<u8, Vec<u8>>>(NUM_INPUTS);
+}
+
+#[test]
+fn random_derived_test() {
+    check_codec_properties_random::<Payment>(NUM_INPUTS);
+    check_codec_properties_random::<Offer>(NUM_INPUTS);
+    check_codec_properties_random::<Pair>(NUM_INPUTS);
+    check_codec_properties_random::<Empty>(NUM_INPUTS);
+}
@@ -0,0 +1,141 @@
+extern crate elrond_codec_derive;
+use elrond_codec_derive::*;
+
+use elrond_codec::{
+    test_util::{check_codec_properties, check_decode_any_bytes},
+    DecodeError, EncodeError, NestedDecode, NestedDecodeInput, NestedEncode, NestedEncodeOutput,
+    TopDecode, TopEncode,
+};
+use std::collections::{BTreeMap, BTreeSet};
+
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, PartialEq, Clone, Debug)]
+enum Payment {
+    None,
+    Egld(u64),
+    Esdt {
+        token: Vec<u8>,
+        nonce: u64,
+        amount: u128,
+    },
+}
+
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, PartialEq, Clone, Debug)]
+struct Offer {
+    id: u32,
+    payments: Vec<Payment>,
+    label: Option<String>,
+    tags: BTreeSet<u8>,
+}
+
+#[test]
+fn builtin_properties_test() {
+    for n in [0i64, 1, -1, 127, 128, -129, i64::MIN, i64::MAX] {
+        check_codec_properties(&n);
+        check_codec_properties(&(n as i8));
+        check_codec_properties(&(n as u32));
+        check_codec_properties(&(n as i128));
+    }
+    check_codec_properties(&u128::MAX);
+    check_codec_properties(&true);
+    check_codec_properties(&());
+    check_codec_properties(&Vec::<u8>::new());
+    check_codec_properties(&b"abc".to_vec());
+    check_codec_properties(&String::from("abc"));
+    check_codec_properties(&vec![vec![1u16], Vec::new()]);
+    check_codec_properties(&Some(5u64));
+    check_codec_properties(&Option::<u64>::None);
+    check_codec_properties(&[1i32, -1, 0]);
+    check_codec_properties(&(3u8, String::new(), Some(false)));
+    check_codec_properties(&[1u32, 2].iter().cloned().collect::<BTreeSet<_>>());
+    let mut map = BTreeMap::new();
+    map.insert(1u8, b"one".to_vec());
+    map.insert(2u8, Vec::new());
+    check_codec_properties(&map);
+}
+
+#[test]
+fn derived_properties_test() {
+    check_codec_properties(&Payment::None);
+    check_codec_properties(&Payment::Egld(0));
+    check_codec_properties(&Payment::Esdt {
+        token: b"TOKEN-123456".to_vec(),
+        nonce: 5,
+        amount: 1 << 70,
+    });
+    check_codec_properties(&Offer {
+        id: 7,
+        payments: vec![Payment::None, Payment::Egld(1)],
+        label: Some(String::from("offer")),
+        tags: [3u8, 1].iter().cloned().collect(),
+    });
+}
+
+#[test]
+fn decode_any_bytes_test() {
+    let inputs: &[&[u8]] = &[
+        &[],
+        &[0],
+        &[1],
+        &[2, 0, 0, 0, 1, 0xff],
+        &[0, 0, 0, 2, 1, 2],
+        &[0xff; 20],
+        &[0, 0, 0, 0, 0, 0, 0, 1, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    ];
+    for input in inputs {
+        check_decode_any_bytes::<u64>(input);
+        check_decode_any_bytes::<i16>(input);
+        check_decode_any_bytes::<bool>(input);
+        check_decode_any_bytes::<Vec<u8>>(input);
+        check_decode_any_bytes::<String>(input);
+        check_decode_any_bytes::<Option<u32>>(input);
+        check_decode_any_bytes::<BTreeSet<u8>>(input);
+        check_decode_any_bytes::<Payment>(input);
+        check_decode_any_bytes::<Offer>(input);
+    }
+}
+
+/// A common mistake in hand-written implementations:
+/// the last field is decoded as if at top level, consuming the rest of the input.
+#[derive(PartialEq, Debug)]
+struct Greedy {
+    id: u8,
+    data: Vec<u8>,
+}
+
+impl NestedEncode for Greedy {
+    fn dep_encode<O: NestedEncodeOutput>(&self, dest: &mut O) -> Result<(), EncodeError> {
+        self.id.dep_encode(dest)?;
+        self.data.dep_encode(dest)
+    }
+}
+
+impl NestedDecode for Greedy {
+    fn dep_decode<I: NestedDecodeInput>(input: &mut I) -> Result<Self, DecodeError> {
+        let id = u8::dep_decode(input)?;
+        let _ = u32::dep_decode(input)?;
+        let mut data = vec![0u8; input.remaining_len()];
+        input.read_into(data.as_mut_slice())?;
+        Ok(Greedy { id, data })
+    }
+}
+
+impl TopEncode for Greedy {
+    fn top_encode<O: elrond_codec::TopEncodeOutput>(&self, output: O) -> Result<(), EncodeError> {
+        elrond_codec::top_encode_from_nested(self, output)
+    }
+}
+
+impl TopDecode for Greedy {
+    fn top_decode<I: elrond_codec::TopDecodeInput>(input: I) -> Result<Self, DecodeError> {
+        elrond_codec::top_decode_from_nested(input)
+    }
+}
+
+#[test]
+#[should_panic(expected = "nested decoding did not stop at the end of")]
+fn greedy_nested_decode_is_caught_test() {
+    check_codec_properties(&Greedy {
+        id: 1,
+        data: b"abc".to_vec(),
+    });
+}
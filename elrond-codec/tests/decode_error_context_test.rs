@@ -0,0 +1,86 @@
+#![cfg(feature = "decode-error-context")]
+
+extern crate elrond_codec_derive;
+use elrond_codec_derive::*;
+
+use elrond_codec::{
+    dep_decode_from_byte_slice_or_exit, DecodeError, DecodeErrorContext, NestedDecode, TopDecode,
+};
+
+// to test, run the following command in elrond-codec folder:
+// cargo test --features decode-error-context --test decode_error_context_test
+
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, PartialEq, Clone, Debug)]
+enum Payment {
+    Egld(u64),
+    Esdt { token: Vec<u8>, amount: u32 },
+}
+
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, PartialEq, Clone, Debug)]
+struct Offer {
+    price: u32,
+    payments: Vec<Payment>,
+}
+
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, PartialEq, Clone, Debug)]
+struct Wrapper(u8, Offer);
+
+#[rustfmt::skip]
+const BAD_OFFER_BYTES: &[u8] = &[
+    /* price */ 0, 0, 0, 100,
+    /* payments length */ 0, 0, 0, 2,
+    /* payments[0] */ 0, 0, 0, 0, 0, 0, 0, 0, 5,
+    /* payments[1] */ 1, 0, 0, 0, 1, b'X', 0, 0, // amount too short
+];
+
+fn context_path_str(err: &DecodeError) -> String {
+    String::from_utf8(err.context_path()).unwrap()
+}
+
+#[test]
+fn top_decode_context_test() {
+    let err = Offer::top_decode(BAD_OFFER_BYTES).unwrap_err();
+    assert_eq!(err, DecodeError::INPUT_TOO_SHORT);
+    assert_eq!(
+        err.context(),
+        &[
+            DecodeErrorContext::VariantField {
+                variant: "Esdt",
+                field: "amount"
+            },
+            DecodeErrorContext::Index(1),
+            DecodeErrorContext::Field("payments"),
+        ]
+    );
+    assert_eq!(context_path_str(&err), "payments[1]::Esdt.amount");
+}
+
+#[test]
+fn nested_decode_context_test() {
+    let mut bytes = vec![7u8];
+    bytes.extend_from_slice(BAD_OFFER_BYTES);
+    let err = Wrapper::dep_decode(&mut &bytes[..]).unwrap_err();
+    assert_eq!(context_path_str(&err), "1.payments[1]::Esdt.amount");
+}
+
+#[test]
+fn decode_or_exit_context_test() {
+    fn exit(expected_path: &'static str, err: DecodeError) -> ! {
+        assert_eq!(context_path_str(&err), expected_path);
+        panic!("decode error as expected");
+    }
+
+    let result = std::panic::catch_unwind(|| {
+        let _: Offer =
+            dep_decode_from_byte_slice_or_exit(BAD_OFFER_BYTES, "payments[1]::Esdt.amount", exit);
+    });
+    let panic_message = *result.unwrap_err().downcast::<&str>().unwrap();
+    assert_eq!(panic_message, "decode error as expected");
+}
+
+#[test]
+fn no_context_test() {
+    let err = u32::top_decode(&[1, 2, 3, 4, 5][..]).unwrap_err();
+    assert!(err.context().is_empty());
+    assert!(err.context_path().is_empty());
+}
@@ -0,0 +1,65 @@
+extern crate elrond_codec_derive;
+use elrond_codec_derive::*;
+
+use elrond_codec::{dep_decode_borrowed_from_byte_slice, test_util::check_dep_encode, DecodeError};
+
+#[derive(NestedEncode, NestedDecode, PartialEq, Clone, Debug)]
+pub struct Message {
+    pub sender: Vec<u8>,
+    pub text: String,
+    pub nonce: u64,
+}
+
+/// Same encoding as `Message`, but decoding it does not allocate.
+#[derive(NestedDecodeBorrowed, PartialEq, Debug)]
+pub struct MessageView<'a> {
+    pub sender: &'a [u8],
+    pub text: &'a str,
+    pub nonce: u64,
+}
+
+#[derive(NestedDecodeBorrowed, PartialEq, Debug)]
+pub struct TupleView<'a>(u8, #[codec(skip)] u32, &'a [u8]);
+
+fn message() -> Message {
+    Message {
+        sender: b"alice".to_vec(),
+        text: String::from("hello"),
+        nonce: 7,
+    }
+}
+
+#[test]
+fn borrowed_struct_test() {
+    let bytes = check_dep_encode(&message());
+    let view: MessageView = dep_decode_borrowed_from_byte_slice(bytes.as_slice()).unwrap();
+    assert_eq!(
+        view,
+        MessageView {
+            sender: b"alice",
+            text: "hello",
+            nonce: 7,
+        }
+    );
+
+    // the fields point into the input
+    let input_range = bytes.as_ptr_range();
+    assert!(input_range.contains(&view.sender.as_ptr()));
+    assert!(input_range.contains(&view.text.as_ptr()));
+}
+
+#[test]
+fn borrowed_tuple_struct_test() {
+    let bytes = [5, 0, 0, 0, 2, 1, 2];
+    let view: TupleView = dep_decode_borrowed_from_byte_slice(&bytes[..]).unwrap();
+    assert_eq!(view, TupleView(5, 0, &[1, 2]));
+}
+
+#[test]
+fn borrowed_struct_error_test() {
+    let bytes = check_dep_encode(&message());
+    assert_eq!(
+        dep_decode_borrowed_from_byte_slice::<MessageView>(&bytes[..bytes.len() - 1]),
+        Err(DecodeError::INPUT_TOO_SHORT)
+    );
+}
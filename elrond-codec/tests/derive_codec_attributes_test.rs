@@ -0,0 +1,160 @@
+extern crate elrond_codec_derive;
+use elrond_codec_derive::*;
+
+use elrond_codec::{
+    test_util::{
+        check_dep_decode, check_dep_encode, check_dep_encode_decode, check_top_decode,
+        check_top_encode_decode, dep_encode_to_vec_or_panic,
+    },
+    DecodeError, EncodeError, NestedDecode, NestedDecodeInput, NestedEncode, NestedEncodeOutput,
+    TopDecode,
+};
+
+/// Custom codec, stores a u64 in 4 bytes.
+mod u64_as_u32 {
+    use super::*;
+
+    pub fn dep_encode<O: NestedEncodeOutput>(value: &u64, dest: &mut O) -> Result<(), EncodeError> {
+        if *value > u32::MAX as u64 {
+            return Err(EncodeError::UNSUPPORTED_OPERATION);
+        }
+        (*value as u32).dep_encode(dest)
+    }
+
+    pub fn dep_decode<I: NestedDecodeInput>(input: &mut I) -> Result<u64, DecodeError> {
+        Ok(u32::dep_decode(input)? as u64)
+    }
+}
+
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, PartialEq, Clone, Debug)]
+pub struct StructWithAttributes {
+    pub int: u16,
+    #[codec(skip)]
+    pub cached: Vec<u8>,
+    #[codec(with = "u64_as_u32")]
+    pub timestamp: u64,
+}
+
+#[test]
+fn struct_skip_and_with_test() {
+    let s = StructWithAttributes {
+        int: 0x42,
+        cached: Vec::new(),
+        timestamp: 0x12345678,
+    };
+
+    #[rustfmt::skip]
+    let bytes = &[
+        /* int */ 0, 0x42,
+        /* timestamp */ 0x12, 0x34, 0x56, 0x78,
+    ];
+
+    check_top_encode_decode(s.clone(), bytes);
+    check_dep_encode_decode(s, bytes);
+}
+
+#[test]
+fn struct_skip_is_not_encoded_test() {
+    let s = StructWithAttributes {
+        int: 0x42,
+        cached: vec![1, 2, 3],
+        timestamp: 5,
+    };
+    let bytes = check_dep_encode(&s);
+    assert_eq!(bytes, &[0, 0x42, 0, 0, 0, 5]);
+
+    // the skipped field gets the default value on decode
+    let decoded: StructWithAttributes = check_dep_decode(&bytes);
+    assert_eq!(decoded.cached, Vec::<u8>::new());
+}
+
+#[test]
+fn struct_with_encode_error_test() {
+    let s = StructWithAttributes {
+        int: 0,
+        cached: Vec::new(),
+        timestamp: u64::MAX,
+    };
+    let mut bytes = Vec::<u8>::new();
+    assert_eq!(
+        s.dep_encode(&mut bytes),
+        Err(EncodeError::UNSUPPORTED_OPERATION)
+    );
+}
+
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, PartialEq, Clone, Debug)]
+pub struct TupleStructWithAttributes(u8, #[codec(skip)] u32, u8);
+
+#[test]
+fn tuple_struct_skip_test() {
+    check_top_encode_decode(TupleStructWithAttributes(1, 0, 2), &[1, 2]);
+    check_dep_encode_decode(TupleStructWithAttributes(1, 0, 2), &[1, 2]);
+}
+
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Clone, Debug)]
+enum ReorderedStatus {
+    #[codec(discriminant = 2)]
+    Closed,
+    #[codec(discriminant = 0)]
+    Pending,
+    // 1, same as in Rust
+    Open,
+}
+
+#[test]
+fn fieldless_enum_explicit_discriminant_test() {
+    check_top_encode_decode(ReorderedStatus::Pending, &[]);
+    check_top_encode_decode(ReorderedStatus::Open, &[1]);
+    check_top_encode_decode(ReorderedStatus::Closed, &[2]);
+
+    check_dep_encode_decode(ReorderedStatus::Pending, &[0]);
+    check_dep_encode_decode(ReorderedStatus::Open, &[1]);
+    check_dep_encode_decode(ReorderedStatus::Closed, &[2]);
+
+    assert_eq!(
+        ReorderedStatus::top_decode(&[3][..]),
+        Err(DecodeError::INVALID_VALUE)
+    );
+}
+
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Clone, Debug)]
+enum EnumWithAttributes {
+    #[codec(discriminant = 5)]
+    Amount(#[codec(with = "u64_as_u32")] u64),
+    #[codec(discriminant = 10)]
+    Labeled {
+        label: u8,
+        #[codec(skip)]
+        note: Vec<u8>,
+    },
+    // 11
+    Nothing,
+}
+
+#[test]
+fn field_enum_attributes_test() {
+    check_top_encode_decode(EnumWithAttributes::Amount(7), &[5, 0, 0, 0, 7]);
+    check_dep_encode_decode(EnumWithAttributes::Amount(7), &[5, 0, 0, 0, 7]);
+
+    let labeled = EnumWithAttributes::Labeled {
+        label: 3,
+        note: Vec::new(),
+    };
+    check_top_encode_decode(labeled.clone(), &[10, 3]);
+    check_dep_encode_decode(labeled, &[10, 3]);
+
+    check_top_encode_decode(EnumWithAttributes::Nothing, &[11]);
+    check_dep_encode_decode(EnumWithAttributes::Nothing, &[11]);
+
+    // no variant with discriminant 0, so empty input is not accepted
+    assert_eq!(
+        EnumWithAttributes::top_decode(&[][..]),
+        Err(DecodeError::INPUT_TOO_SHORT)
+    );
+    let note_is_skipped = dep_encode_to_vec_or_panic(&EnumWithAttributes::Labeled {
+        label: 3,
+        note: vec![1, 2],
+    });
+    assert_eq!(note_is_skipped, &[10, 3]);
+    assert_eq!(EnumWithAttributes::Nothing, check_top_decode(&[11]));
+}
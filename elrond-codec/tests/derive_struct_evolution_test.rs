@@ -0,0 +1,202 @@
+extern crate elrond_codec_derive;
+use elrond_codec_derive::*;
+
+use elrond_codec::{
+    test_util::{
+        check_dep_encode_decode, check_top_decode, check_top_encode, check_top_encode_decode,
+        top_decode_from_byte_slice_or_panic,
+    },
+    DecodeError, NestedDecode, NestedDecodeInput, TopDecode,
+};
+
+/// The struct, as it was first deployed.
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, PartialEq, Clone, Debug)]
+pub struct OfferV1 {
+    pub price: u32,
+    pub seller: Vec<u8>,
+}
+
+/// The same struct, after two fields were added.
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, PartialEq, Clone, Debug)]
+pub struct OfferV2 {
+    pub price: u32,
+    pub seller: Vec<u8>,
+    #[codec(optional)]
+    pub deadline: u64,
+    #[codec(optional)]
+    pub accepted_tokens: Vec<Vec<u8>>,
+}
+
+fn offer_v1() -> OfferV1 {
+    OfferV1 {
+        price: 100,
+        seller: b"seller".to_vec(),
+    }
+}
+
+fn offer_v2() -> OfferV2 {
+    OfferV2 {
+        price: 100,
+        seller: b"seller".to_vec(),
+        deadline: 5,
+        accepted_tokens: vec![b"EGLD".to_vec()],
+    }
+}
+
+#[rustfmt::skip]
+const OFFER_V2_BYTES: &[u8] = &[
+    /* price */ 0, 0, 0, 100,
+    /* seller */ 0, 0, 0, 6, b's', b'e', b'l', b'l', b'e', b'r',
+    /* deadline */ 0, 0, 0, 0, 0, 0, 0, 5,
+    /* accepted_tokens */ 0, 0, 0, 1, 0, 0, 0, 4, b'E', b'G', b'L', b'D',
+];
+
+#[test]
+fn optional_fields_round_trip_test() {
+    check_top_encode_decode(offer_v2(), OFFER_V2_BYTES);
+    check_dep_encode_decode(offer_v2(), OFFER_V2_BYTES);
+}
+
+#[test]
+fn optional_fields_missing_test() {
+    let v1_bytes = check_top_encode(&offer_v1());
+    let decoded: OfferV2 = check_top_decode(&v1_bytes);
+    assert_eq!(
+        decoded,
+        OfferV2 {
+            price: 100,
+            seller: b"seller".to_vec(),
+            deadline: 0,
+            accepted_tokens: Vec::new(),
+        }
+    );
+}
+
+#[test]
+fn optional_fields_partially_missing_test() {
+    let bytes = &OFFER_V2_BYTES[..OFFER_V2_BYTES.len() - 12];
+    let decoded: OfferV2 = check_top_decode(bytes);
+    assert_eq!(decoded.deadline, 5);
+    assert_eq!(decoded.accepted_tokens, Vec::<Vec<u8>>::new());
+}
+
+#[test]
+fn optional_fields_truncated_test() {
+    // an optional field that is started must be complete
+    let bytes = &OFFER_V2_BYTES[..OFFER_V2_BYTES.len() - 14];
+    assert_eq!(
+        OfferV2::top_decode(bytes),
+        Err(DecodeError::INPUT_TOO_SHORT)
+    );
+}
+
+#[test]
+fn optional_fields_required_when_nested_test() {
+    // nested decoding cannot know where the object ends, so optional fields are still required
+    let v1_bytes = check_top_encode(&offer_v1());
+    assert_eq!(
+        OfferV2::dep_decode(&mut &v1_bytes[..]),
+        Err(DecodeError::INPUT_TOO_SHORT)
+    );
+}
+
+/// Prefixed by a version byte, does not accept other versions.
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, PartialEq, Clone, Debug)]
+#[codec(version = 1)]
+pub struct PositionV1 {
+    pub amount: u32,
+}
+
+/// Breaking change: `amount` becomes a u64, plus a new field in between.
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, PartialEq, Clone, Debug)]
+#[codec(version = 2, legacy_decode = "decode_position_v1")]
+pub struct PositionV2 {
+    pub owner: u8,
+    pub amount: u64,
+}
+
+fn decode_position_v1<I: NestedDecodeInput>(
+    version: u8,
+    input: &mut I,
+) -> Result<PositionV2, DecodeError> {
+    if version != 1 {
+        return Err(DecodeError::UNSUPPORTED_VERSION);
+    }
+    let amount = u32::dep_decode(input)?;
+    Ok(PositionV2 {
+        owner: 0,
+        amount: amount as u64,
+    })
+}
+
+#[test]
+fn versioned_round_trip_test() {
+    check_top_encode_decode(PositionV1 { amount: 7 }, &[1, 0, 0, 0, 7]);
+    check_dep_encode_decode(PositionV1 { amount: 7 }, &[1, 0, 0, 0, 7]);
+
+    let v2 = PositionV2 {
+        owner: 3,
+        amount: 7,
+    };
+    #[rustfmt::skip]
+    let v2_bytes = &[
+        /* version */ 2,
+        /* owner */ 3,
+        /* amount */ 0, 0, 0, 0, 0, 0, 0, 7,
+    ];
+    check_top_encode_decode(v2.clone(), v2_bytes);
+    check_dep_encode_decode(v2, v2_bytes);
+}
+
+#[test]
+fn versioned_legacy_decode_test() {
+    let v1_bytes = check_top_encode(&PositionV1 { amount: 7 });
+    let expected = PositionV2 {
+        owner: 0,
+        amount: 7,
+    };
+    assert_eq!(expected, check_top_decode::<PositionV2>(&v1_bytes));
+    assert_eq!(Ok(expected), PositionV2::dep_decode(&mut &v1_bytes[..]));
+}
+
+#[test]
+fn versioned_unsupported_test() {
+    assert_eq!(
+        PositionV1::top_decode(&[2, 0, 0, 0, 7][..]),
+        Err(DecodeError::UNSUPPORTED_VERSION)
+    );
+    assert_eq!(
+        PositionV2::top_decode(&[0, 0, 0, 0, 7][..]),
+        Err(DecodeError::UNSUPPORTED_VERSION)
+    );
+    // the legacy decoder also needs to consume the entire input
+    assert_eq!(
+        PositionV2::top_decode(&[1, 0, 0, 0, 7, 0][..]),
+        Err(DecodeError::INPUT_TOO_LONG)
+    );
+}
+
+#[test]
+#[should_panic(expected = "decode panicked: unsupported version")]
+fn versioned_unsupported_or_exit_test() {
+    let _ = top_decode_from_byte_slice_or_panic::<PositionV1>(&[2, 0, 0, 0, 7]);
+}
+
+/// Both mechanisms combined.
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, PartialEq, Clone, Debug)]
+#[codec(version = 1)]
+pub struct VersionedWithOptional {
+    pub a: u16,
+    #[codec(optional)]
+    pub b: u16,
+}
+
+#[test]
+fn versioned_with_optional_test() {
+    check_top_encode_decode(VersionedWithOptional { a: 1, b: 2 }, &[1, 0, 1, 0, 2]);
+    check_dep_encode_decode(VersionedWithOptional { a: 1, b: 2 }, &[1, 0, 1, 0, 2]);
+    assert_eq!(
+        VersionedWithOptional { a: 1, b: 0 },
+        check_top_decode(&[1, 0, 1])
+    );
+}
@@ -8,12 +8,22 @@ use std::collections::BTreeMap;
 #[serde(rename_all = "camelCase")]
 pub struct ContractAbiJson {
     pub build_info: BuildInfoAbiJson,
+    #[serde(default)]
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub docs: Vec<String>,
     pub name: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub constructor: Option<ConstructorAbiJson>,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub upgrade_constructor: Option<ConstructorAbiJson>,
     pub endpoints: Vec<EndpointAbiJson>,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub storage: Vec<StorageAbiJson>,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub events: Vec<EventAbiJson>,
     pub types: BTreeMap<String, TypeDescriptionJson>,
 }
 
@@ -24,7 +34,13 @@ impl From<&ContractAbi> for ContractAbiJson {
             docs: abi.docs.iter().map(|d| d.to_string()).collect(),
             name: abi.name.to_string(),
             constructor: abi.constructor.as_ref().map(ConstructorAbiJson::from),
+            upgrade_constructor: abi
+                .upgrade_constructor
+                .as_ref()
+                .map(ConstructorAbiJson::from),
             endpoints: Vec::new(),
+            storage: Vec::new(),
+            events: abi.events.iter().map(EventAbiJson::from).collect(),
             types: BTreeMap::new(),
         };
         for endpoint in &abi.endpoints {
@@ -32,6 +48,15 @@ impl From<&ContractAbi> for ContractAbiJson {
                 .endpoints
                 .push(EndpointAbiJson::from(endpoint));
         }
+        // the same key can be declared by several methods, e.g. a getter and a setter
+        for (i, storage) in abi.storage.iter().enumerate() {
+            if abi.storage[..i]
+                .iter()
+                .all(|previous| previous.key != storage.key)
+            {
+                contract_json.storage.push(StorageAbiJson::from(storage));
+            }
+        }
         for (type_name, type_description) in abi.type_descriptions.0.iter() {
             if type_description.contents.is_specified() {
                 contract_json.types.insert(
@@ -53,3 +78,7 @@ pub fn serialize_abi_to_json(abi_json: &ContractAbiJson) -> String {
     serialized.push('\n');
     serialized
 }
+
+pub fn deserialize_abi_from_json(input: &str) -> Result<ContractAbiJson, serde_json::Error> {
+    serde_json::from_str(input)
+}
@@ -26,6 +26,7 @@ impl From<&InputAbi> for InputAbiJson {
 #[derive(Serialize, Deserialize)]
 pub struct OutputAbiJson {
     #[serde(rename = "name")]
+    #[serde(default)]
     #[serde(skip_serializing_if = "String::is_empty")]
     pub output_name: String,
     #[serde(rename = "type")]
@@ -56,9 +57,11 @@ pub enum EndpointMutabilityAbiJson {
 /// Same as EndpointAbiJson but ignores the name
 #[derive(Serialize, Deserialize)]
 pub struct ConstructorAbiJson {
+    #[serde(default)]
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub docs: Vec<String>,
     #[serde(rename = "payableInTokens")]
+    #[serde(default)]
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub payable_in_tokens: Vec<String>,
     pub inputs: Vec<InputAbiJson>,
@@ -82,14 +85,22 @@ impl From<&EndpointAbi> for ConstructorAbiJson {
 
 #[derive(Serialize, Deserialize)]
 pub struct EndpointAbiJson {
+    #[serde(default)]
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub docs: Vec<String>,
     pub name: String,
     #[serde(rename = "onlyOwner")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub only_owner: Option<bool>,
+    #[serde(rename = "onlyRole")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub only_role: Option<String>,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub labels: Vec<String>,
     pub mutability: EndpointMutabilityAbiJson,
     #[serde(rename = "payableInTokens")]
+    #[serde(default)]
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub payable_in_tokens: Vec<String>,
     pub inputs: Vec<InputAbiJson>,
@@ -102,6 +113,8 @@ impl From<&EndpointAbi> for EndpointAbiJson {
             docs: abi.docs.iter().map(|d| d.to_string()).collect(),
             name: abi.name.to_string(),
             only_owner: if abi.only_owner { Some(true) } else { None },
+            only_role: abi.only_role.map(|role| role.to_string()),
+            labels: abi.labels.iter().map(|label| label.to_string()).collect(),
             mutability: match abi.mutability {
                 EndpointMutabilityAbi::Mutable => EndpointMutabilityAbiJson::Mutable,
                 EndpointMutabilityAbi::Readonly => EndpointMutabilityAbiJson::Readonly,
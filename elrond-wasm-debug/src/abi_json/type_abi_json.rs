@@ -7,12 +7,15 @@ pub struct TypeDescriptionJson {
     #[serde(rename = "type")]
     pub content_type: String,
 
+    #[serde(default)]
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub docs: Vec<String>,
 
+    #[serde(default)]
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub variants: Vec<EnumVariantDescriptionJson>,
 
+    #[serde(default)]
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub fields: Vec<StructFieldDescriptionJson>,
 }
@@ -54,11 +57,15 @@ impl From<&TypeDescription> for TypeDescriptionJson {
 
 #[derive(Serialize, Deserialize)]
 pub struct StructFieldDescriptionJson {
+    #[serde(default)]
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub docs: Vec<String>,
     pub name: String,
     #[serde(rename = "type")]
     pub field_type: String,
+    /// Bool that is only serialized when true
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub optional: Option<bool>,
 }
 
 impl From<&StructFieldDescription> for StructFieldDescriptionJson {
@@ -67,16 +74,19 @@ impl From<&StructFieldDescription> for StructFieldDescriptionJson {
             docs: abi.docs.iter().map(|d| d.to_string()).collect(),
             name: abi.name.to_string(),
             field_type: abi.field_type.clone(),
+            optional: if abi.optional { Some(true) } else { None },
         }
     }
 }
 
 #[derive(Serialize, Deserialize)]
 pub struct EnumVariantDescriptionJson {
+    #[serde(default)]
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub docs: Vec<String>,
     pub name: String,
     pub discriminant: usize,
+    #[serde(default)]
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub fields: Vec<StructFieldDescriptionJson>,
 }
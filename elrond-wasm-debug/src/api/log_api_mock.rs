@@ -1,5 +1,6 @@
 use elrond_wasm::{
-    api::{Handle, LogApi},
+    api::{Handle, LogApi, ReadonlyApi},
+    err_msg,
     types::{
         managed_vec_of_buffers_to_arg_buffer, ArgBuffer, ManagedBuffer, ManagedType, ManagedVec,
     },
@@ -11,6 +12,7 @@ use crate::{tx_mock::TxLog, DebugApi};
 /// The smart contract code doesn't have access to these methods directly.
 impl LogApi for DebugApi {
     fn write_event_log(&self, topics_buffer: &ArgBuffer, data: &[u8]) {
+        self.check_not_readonly(err_msg::READONLY_LOG);
         let arg_data_buffer = topics_buffer.arg_data();
         let arg_data_lengths = topics_buffer.arg_lengths();
 
@@ -35,6 +37,7 @@ impl LogApi for DebugApi {
     }
 
     fn write_legacy_log(&self, topics: &[[u8; 32]], data: &[u8]) {
+        self.check_not_readonly(err_msg::READONLY_LOG);
         let topics_vec = topics.iter().map(|array| array.to_vec()).collect();
 
         let mut tx_result = self.result_borrow_mut();
@@ -6,6 +6,8 @@ mod endpoint_finish_api_mock;
 mod error_api_mock;
 mod log_api_mock;
 mod managed_types;
+mod readonly_api_mock;
+mod reentrancy_lock_api_mock;
 mod send_api_mock;
 mod storage_api_mock;
 mod vm_api_mock;
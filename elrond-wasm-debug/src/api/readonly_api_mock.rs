@@ -0,0 +1,13 @@
+use crate::DebugApi;
+use elrond_wasm::api::ReadonlyApi;
+
+/// The flag lives in the tx context, so nested calls start out in regular mode.
+impl ReadonlyApi for DebugApi {
+    fn set_readonly(&self, readonly: bool) {
+        self.readonly.set(readonly);
+    }
+
+    fn is_readonly(&self) -> bool {
+        self.readonly.get()
+    }
+}
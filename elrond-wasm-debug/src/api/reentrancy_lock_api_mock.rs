@@ -0,0 +1,13 @@
+use crate::DebugApi;
+use elrond_wasm::api::ReentrancyLockApi;
+
+/// Kept in the tx context, since nested calls run in their own context.
+impl ReentrancyLockApi for DebugApi {
+    fn set_held_reentrancy_lock(&self, group: Option<&'static [u8]>) {
+        self.held_reentrancy_lock.set(group);
+    }
+
+    fn held_reentrancy_lock(&self) -> Option<&'static [u8]> {
+        self.held_reentrancy_lock.get()
+    }
+}
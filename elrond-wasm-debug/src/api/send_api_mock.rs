@@ -5,10 +5,13 @@ use crate::{
 };
 use elrond_wasm::{
     api::{
-        BlockchainApi, SendApi, StorageReadApi, StorageWriteApi, ESDT_MULTI_TRANSFER_FUNC_NAME,
-        ESDT_NFT_TRANSFER_FUNC_NAME, ESDT_TRANSFER_FUNC_NAME, UPGRADE_CONTRACT_FUNC_NAME,
+        BlockchainApi, ReadonlyApi, SendApi, StorageReadApi, StorageWriteApi,
+        ESDT_MULTI_TRANSFER_FUNC_NAME, ESDT_NFT_TRANSFER_FUNC_NAME, ESDT_TRANSFER_FUNC_NAME,
+        UPGRADE_CONTRACT_FUNC_NAME,
     },
     elrond_codec::top_encode_to_vec_u8,
+    err_msg,
+    storage::reentrancy_guard::release_held_reentrancy_lock,
     types::{
         Address, BigUint, CodeMetadata, EsdtTokenPayment, ManagedAddress, ManagedArgBuffer,
         ManagedBuffer, ManagedFrom, ManagedInto, ManagedVec, TokenIdentifier,
@@ -120,6 +123,7 @@ impl DebugApi {
     }
 
     fn perform_async_call(&self, call: AsyncCallTxData) -> ! {
+        release_held_reentrancy_lock(self.clone());
         // the cell is no longer needed, since we end in a panic
         let mut tx_result = self.extract_result();
         tx_result.result_calls.async_call = Some(call);
@@ -168,6 +172,7 @@ impl SendApi for DebugApi {
     where
         D: ManagedInto<Self, ManagedBuffer<Self>>,
     {
+        self.check_not_readonly(err_msg::READONLY_TRANSFER);
         let amount_value = self.big_uint_value(amount);
         let available_egld_balance =
             self.with_contract_account(|account| account.egld_balance.clone());
@@ -195,6 +200,7 @@ impl SendApi for DebugApi {
         endpoint_name: &ManagedBuffer<Self>,
         arg_buffer: &ManagedArgBuffer<Self>,
     ) -> Result<(), &'static [u8]> {
+        self.check_not_readonly(err_msg::READONLY_TRANSFER);
         let egld_value = self.big_uint_value(amount);
         let recipient = to.to_address();
 
@@ -217,6 +223,7 @@ impl SendApi for DebugApi {
         endpoint_name: &ManagedBuffer<Self>,
         arg_buffer: &ManagedArgBuffer<Self>,
     ) -> Result<(), &'static [u8]> {
+        self.check_not_readonly(err_msg::READONLY_TRANSFER);
         let recipient = to.to_address();
         let token_bytes = top_encode_to_vec_u8(token).unwrap();
         let amount_bytes = top_encode_to_vec_u8(amount).unwrap();
@@ -244,6 +251,7 @@ impl SendApi for DebugApi {
         endpoint_name: &ManagedBuffer<Self>,
         arg_buffer: &ManagedArgBuffer<Self>,
     ) -> Result<(), &'static [u8]> {
+        self.check_not_readonly(err_msg::READONLY_TRANSFER);
         let contract_address = self.input_ref().to.clone();
         let recipient = to.to_address();
 
@@ -278,6 +286,7 @@ impl SendApi for DebugApi {
         endpoint_name: &ManagedBuffer<Self>,
         arg_buffer: &ManagedArgBuffer<Self>,
     ) -> Result<(), &'static [u8]> {
+        self.check_not_readonly(err_msg::READONLY_TRANSFER);
         let contract_address = self.input_ref().to.clone();
         let recipient = to.to_address();
 
@@ -321,6 +330,7 @@ impl SendApi for DebugApi {
         endpoint_name: &ManagedBuffer<Self>,
         arg_buffer: &ManagedArgBuffer<Self>,
     ) -> ! {
+        self.check_not_readonly(err_msg::READONLY_CONTRACT_CALL);
         let amount_value = self.big_uint_value(amount);
         let contract_address = self.input_ref().to.clone();
         let recipient = to.to_address();
@@ -344,6 +354,7 @@ impl SendApi for DebugApi {
         _code_metadata: CodeMetadata,
         arg_buffer: &ManagedArgBuffer<Self>,
     ) -> (ManagedAddress<Self>, ManagedVec<Self, ManagedBuffer<Self>>) {
+        self.check_not_readonly(err_msg::READONLY_CONTRACT_CALL);
         let egld_value = self.big_uint_value(amount);
         let contract_code = code.to_boxed_bytes().into_vec();
         let (new_address, result) =
@@ -363,6 +374,7 @@ impl SendApi for DebugApi {
         _code_metadata: CodeMetadata,
         arg_buffer: &ManagedArgBuffer<Self>,
     ) -> (ManagedAddress<Self>, ManagedVec<Self, ManagedBuffer<Self>>) {
+        self.check_not_readonly(err_msg::READONLY_CONTRACT_CALL);
         let egld_value = self.big_uint_value(amount);
         let source_contract_code = self.get_contract_code(&source_contract_address.to_address());
         let (new_address, result) = self.perform_deploy(
@@ -386,6 +398,8 @@ impl SendApi for DebugApi {
         code_metadata: CodeMetadata,
         arg_buffer: &ManagedArgBuffer<Self>,
     ) {
+        self.check_not_readonly(err_msg::READONLY_CONTRACT_CALL);
+        release_held_reentrancy_lock(self.clone());
         let contract_code = code.to_boxed_bytes().into_vec();
         self.perform_upgrade_contract(sc_address, amount, contract_code, code_metadata, arg_buffer)
     }
@@ -399,6 +413,8 @@ impl SendApi for DebugApi {
         code_metadata: CodeMetadata,
         arg_buffer: &ManagedArgBuffer<Self>,
     ) {
+        self.check_not_readonly(err_msg::READONLY_CONTRACT_CALL);
+        release_held_reentrancy_lock(self.clone());
         let contract_code = self.get_contract_code(&source_contract_address.to_address());
         self.perform_upgrade_contract(sc_address, amount, contract_code, code_metadata, arg_buffer)
     }
@@ -411,6 +427,7 @@ impl SendApi for DebugApi {
         endpoint_name: &ManagedBuffer<Self>,
         arg_buffer: &ManagedArgBuffer<Self>,
     ) -> ManagedVec<Self, ManagedBuffer<Self>> {
+        self.check_not_readonly(err_msg::READONLY_CONTRACT_CALL);
         let egld_value = self.big_uint_value(value);
         let recipient = to.to_address();
 
@@ -436,6 +453,7 @@ impl SendApi for DebugApi {
     where
         F: FnOnce(usize, usize) -> (usize, usize),
     {
+        self.check_not_readonly(err_msg::READONLY_CONTRACT_CALL);
         let egld_value = self.big_uint_value(value);
         let recipient = to.to_address();
 
@@ -468,6 +486,7 @@ impl SendApi for DebugApi {
         _endpoint_name: &ManagedBuffer<Self>,
         _arg_buffer: &ManagedArgBuffer<Self>,
     ) -> ManagedVec<Self, ManagedBuffer<Self>> {
+        self.check_not_readonly(err_msg::READONLY_CONTRACT_CALL);
         panic!("execute_on_dest_context_by_caller_raw not implemented yet!");
     }
 
@@ -479,6 +498,7 @@ impl SendApi for DebugApi {
         _endpoint_name: &ManagedBuffer<Self>,
         _arg_buffer: &ManagedArgBuffer<Self>,
     ) -> ManagedVec<Self, ManagedBuffer<Self>> {
+        self.check_not_readonly(err_msg::READONLY_CONTRACT_CALL);
         panic!("execute_on_same_context_raw not implemented yet!");
     }
 
@@ -509,6 +529,8 @@ impl SendApi for DebugApi {
         function_name: &ManagedBuffer<Self>,
         arg_buffer: &ManagedArgBuffer<Self>,
     ) -> ManagedVec<Self, ManagedBuffer<Self>> {
+        self.check_not_readonly(err_msg::READONLY_CONTRACT_CALL);
+
         let contract_address = &self.input_ref().to;
 
         let result = self.perform_execute_on_dest_context(
@@ -1,6 +1,9 @@
 use crate::{tx_mock::TxPanic, DebugApi};
 use alloc::vec::Vec;
-use elrond_wasm::api::{BigIntApi, Handle, ManagedBufferApi, StorageReadApi, StorageWriteApi};
+use elrond_wasm::{
+    api::{BigIntApi, Handle, ManagedBufferApi, ReadonlyApi, StorageReadApi, StorageWriteApi},
+    err_msg,
+};
 use num_bigint::{BigInt, BigUint, Sign};
 use num_traits::ToPrimitive;
 
@@ -64,6 +67,7 @@ impl StorageReadApi for DebugApi {
 
 impl StorageWriteApi for DebugApi {
     fn storage_store_slice_u8(&self, key: &[u8], value: &[u8]) {
+        self.check_not_readonly(err_msg::READONLY_STORAGE_WRITE);
         // TODO: extract magic strings somewhere
         if key.starts_with(&b"ELROND"[..]) {
             std::panic::panic_any(TxPanic {
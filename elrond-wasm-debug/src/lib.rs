@@ -8,7 +8,9 @@ mod display_util;
 mod execute_mandos;
 mod managed_test_util;
 mod mandos_step;
+mod merkle_tree_builder;
 pub mod meta;
+pub mod proxy_gen;
 pub mod tx_execution;
 pub mod tx_mock;
 pub mod world_mock;
@@ -17,6 +19,7 @@ pub use contract_map::*;
 pub use display_util::*;
 pub use managed_test_util::*;
 pub use mandos_step::*;
+pub use merkle_tree_builder::*;
 
 pub use arwen_mandos_runner::mandos_go;
 pub use execute_mandos::mandos_rs;
@@ -53,6 +53,16 @@ pub fn check_tx_output(tx_id: &str, tx_expect: &TxExpect, tx_result: &TxResult)
 
             for (expected_log, actual_log) in expected_logs.iter().zip(tx_result.result_logs.iter())
             {
+                if let Some(expected_fields) = &expected_log.data_fields {
+                    if let Some(mismatch) = actual_log.data_fields_mismatch(expected_fields) {
+                        panic!(
+                            "Logs do not match. Tx id: {}. Endpoint: {}. Log {}",
+                            tx_id,
+                            bytes_to_string(&actual_log.endpoint),
+                            mismatch
+                        );
+                    }
+                }
                 assert!(
 					actual_log.mandos_check(expected_log),
 					"Logs do not match. Tx id: {}.\nWant: Address: {}, Endpoint: {}, Topics: {:?}, Data: {}\nHave: Address: {}, Endpoint: {}, Topics: {:?}, Data: {}",
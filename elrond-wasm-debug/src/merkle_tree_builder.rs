@@ -0,0 +1,106 @@
+use core::marker::PhantomData;
+
+use elrond_wasm::{
+    storage::mappers::{
+        merkle_hash_leaf, merkle_hash_pair, merkle_tree_depth, Keccak256MerkleHash, MerkleHash,
+        MerkleHashBytes, MERKLE_HASH_LENGTH,
+    },
+    types::ManagedVec,
+};
+
+use crate::DebugApi;
+
+pub type MerkleNode = [u8; MERKLE_HASH_LENGTH];
+
+/// Builds Merkle trees off-chain, with the same layout and hashing as the `MerkleTreeMapper`.
+/// Used in tests to produce the roots and inclusion proofs that contracts expect.
+pub struct MerkleTreeBuilder<H: MerkleHash = Keccak256MerkleHash> {
+    leaves: Vec<MerkleNode>,
+    _phantom: PhantomData<H>,
+}
+
+impl<H: MerkleHash> Default for MerkleTreeBuilder<H> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<H: MerkleHash> MerkleTreeBuilder<H> {
+    pub fn new() -> Self {
+        MerkleTreeBuilder {
+            leaves: Vec::new(),
+            _phantom: PhantomData,
+        }
+    }
+
+    pub fn from_leaves(leaves: Vec<MerkleNode>) -> Self {
+        MerkleTreeBuilder {
+            leaves,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Appends a leaf, returns its index.
+    pub fn push_leaf(&mut self, leaf: MerkleNode) -> u64 {
+        self.leaves.push(leaf);
+        (self.leaves.len() - 1) as u64
+    }
+
+    pub fn leaf_count(&self) -> u64 {
+        self.leaves.len() as u64
+    }
+
+    pub fn depth(&self) -> usize {
+        merkle_tree_depth(self.leaf_count())
+    }
+
+    /// All levels of the tree, starting with the (zero-padded) leaf nodes and ending with the root.
+    fn levels(&self) -> Vec<Vec<MerkleNode>> {
+        let api = DebugApi::dummy();
+        let mut level: Vec<MerkleNode> = self
+            .leaves
+            .iter()
+            .map(|leaf| merkle_hash_leaf::<DebugApi, H>(&api, leaf))
+            .collect();
+        level.resize(1 << self.depth(), [0u8; MERKLE_HASH_LENGTH]);
+
+        let mut levels = vec![level];
+        while levels.last().unwrap().len() > 1 {
+            let next_level = levels
+                .last()
+                .unwrap()
+                .chunks(2)
+                .map(|pair| merkle_hash_pair::<DebugApi, H>(&api, &pair[0], &pair[1]))
+                .collect();
+            levels.push(next_level);
+        }
+        levels
+    }
+
+    pub fn root(&self) -> MerkleNode {
+        self.levels().last().unwrap()[0]
+    }
+
+    /// The siblings on the path from the leaf to the root, starting from the bottom.
+    pub fn proof(&self, leaf_index: u64) -> Vec<MerkleNode> {
+        assert!(leaf_index < self.leaf_count(), "leaf index out of range");
+        let levels = self.levels();
+        let depth = levels.len() - 1;
+        (0..depth)
+            .map(|level| levels[level][((leaf_index >> level) ^ 1) as usize])
+            .collect()
+    }
+
+    /// Same as `proof`, but converted to the format expected by `MerkleTreeMapper::verify_proof`.
+    pub fn managed_proof(
+        &self,
+        api: DebugApi,
+        leaf_index: u64,
+    ) -> ManagedVec<DebugApi, MerkleHashBytes<DebugApi>> {
+        let mut result = ManagedVec::new(api.clone());
+        for node in self.proof(leaf_index) {
+            result.push(MerkleHashBytes::new_from_bytes(api.clone(), &node));
+        }
+        result
+    }
+}
@@ -0,0 +1,90 @@
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+    process::{Command, ExitStatus},
+};
+
+use super::{meta_cli::MetaArgs, output_contract::OutputContract};
+
+const WASM_TARGET: &str = "wasm32-unknown-unknown";
+
+/// Paths given as arguments are relative to the meta crate,
+/// but cargo runs in the wasm crate.
+fn absolute_path(path: &str) -> PathBuf {
+    let path = Path::new(path);
+    if path.is_absolute() {
+        path.to_path_buf()
+    } else {
+        env::current_dir().unwrap().join(path)
+    }
+}
+
+fn check_status(tool: &str, status: std::io::Result<ExitStatus>) {
+    let status = status.unwrap_or_else(|err| panic!("failed to run {}: {}", tool, err));
+    assert!(status.success(), "{} failed: {}", tool, status);
+}
+
+/// Builds the wasm crate in release mode and copies the result to the output folder,
+/// optionally passing it through `wasm-opt` and `wasm2wat`.
+///
+/// Prints `<contract name> <wasm size>` at the end, which is the format the size report expects.
+pub fn build_wasm(contract: &OutputContract, args: &MetaArgs) {
+    let wasm_crate_dir = contract.wasm_crate_dir();
+    let target_dir = args
+        .target_dir
+        .as_deref()
+        .map(absolute_path)
+        .unwrap_or_else(|| absolute_path(wasm_crate_dir.as_str()).join("target"));
+
+    let mut cargo = Command::new("cargo");
+    cargo
+        .current_dir(&wasm_crate_dir)
+        .env("RUSTFLAGS", "-C link-arg=-s")
+        .args([
+            "build",
+            "--target",
+            WASM_TARGET,
+            "--release",
+            "--target-dir",
+        ])
+        .arg(&target_dir);
+    check_status("cargo build", cargo.status());
+
+    let built_wasm_path = target_dir.join(WASM_TARGET).join("release").join(format!(
+        "{}.wasm",
+        contract.wasm_crate_name.replace('-', "_")
+    ));
+
+    fs::create_dir_all(&args.output_dir).unwrap();
+    let output_wasm_path = Path::new(&args.output_dir).join(contract.wasm_file_name());
+    fs::copy(&built_wasm_path, &output_wasm_path).unwrap_or_else(|err| {
+        panic!(
+            "could not copy {} to {}: {}",
+            built_wasm_path.display(),
+            output_wasm_path.display(),
+            err
+        )
+    });
+
+    if args.wasm_opt {
+        let status = Command::new("wasm-opt")
+            .arg(&output_wasm_path)
+            .arg("-Oz")
+            .arg("--output")
+            .arg(&output_wasm_path)
+            .status();
+        check_status("wasm-opt", status);
+    }
+
+    if args.wat {
+        let status = Command::new("wasm2wat")
+            .arg(&output_wasm_path)
+            .arg("--output")
+            .arg(Path::new(&args.output_dir).join(contract.wat_file_name()))
+            .status();
+        check_status("wasm2wat", status);
+    }
+
+    let wasm_size = fs::metadata(&output_wasm_path).unwrap().len();
+    println!("{} {}", contract.public_name, wasm_size);
+}
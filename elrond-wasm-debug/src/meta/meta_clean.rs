@@ -0,0 +1,59 @@
+use std::{fs, path::Path, process::Command};
+
+use super::{
+    meta_cli::MetaArgs,
+    meta_snippets::snippets_file_name,
+    meta_test_gen::{MANDOS_GO_TEST_FILE_NAME, MANDOS_RS_TEST_FILE_NAME},
+    output_contract::OutputContractConfig,
+};
+
+/// All the files the meta crate can write to the output folder.
+/// Anything else found there is left alone.
+pub fn generated_file_names(config: &OutputContractConfig) -> Vec<String> {
+    let mut file_names = Vec::new();
+    for contract in &config.contracts {
+        file_names.push(contract.abi_file_name());
+        file_names.push(contract.wasm_file_name());
+        file_names.push(contract.wat_file_name());
+    }
+    file_names.push(snippets_file_name(&config.main_contract().abi));
+    file_names.push(MANDOS_RS_TEST_FILE_NAME.to_string());
+    file_names.push(MANDOS_GO_TEST_FILE_NAME.to_string());
+    file_names
+}
+
+pub fn clean(config: &OutputContractConfig, args: &MetaArgs) {
+    for contract in &config.contracts {
+        let wasm_crate_dir = contract.wasm_crate_dir();
+        if !Path::new(&wasm_crate_dir).exists() {
+            continue;
+        }
+        let mut cargo = Command::new("cargo");
+        cargo.current_dir(&wasm_crate_dir).arg("clean");
+        if let Some(target_dir) = &args.target_dir {
+            cargo
+                .arg("--target-dir")
+                .arg(std::env::current_dir().unwrap().join(target_dir));
+        }
+        let status = cargo
+            .status()
+            .unwrap_or_else(|err| panic!("failed to run cargo clean: {}", err));
+        assert!(status.success(), "cargo clean failed: {}", status);
+    }
+
+    let output_dir = Path::new(&args.output_dir);
+    if !output_dir.exists() {
+        return;
+    }
+    for file_name in generated_file_names(config) {
+        let file_path = output_dir.join(file_name);
+        if file_path.exists() {
+            fs::remove_file(&file_path)
+                .unwrap_or_else(|err| panic!("could not remove {}: {}", file_path.display(), err));
+        }
+    }
+    // only if nothing else was kept in there
+    if fs::read_dir(output_dir).unwrap().next().is_none() {
+        fs::remove_dir(output_dir).unwrap();
+    }
+}
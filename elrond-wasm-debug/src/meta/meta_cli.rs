@@ -0,0 +1,105 @@
+pub const USAGE: &str = "Usage: cargo run [COMMAND] [OPTIONS]
+
+Commands:
+    abi         Validates the contract, writes the ABI and regenerates the wasm crate source (default)
+    build       Same as `abi`, then builds the wasm contract and reports its size
+    clean       Cleans the wasm crate and removes the generated files from the output folder
+    snippets    Generates erdpy snippets for calling the contract
+    test-gen    Generates mandos test functions for all the scenarios of the contract
+    help        Prints this message
+
+Options:
+    --output-dir <path>    Where the ABI, the wasm and the other outputs go (default: ../output)
+    --target-dir <path>    The cargo target folder used when building the wasm crate
+    --mandos-dir <path>    Where test-gen looks for scenarios (default: ../mandos)
+    --wasm-opt             Optimizes the built wasm with wasm-opt, which must be installed
+    --wat                  Also converts the built wasm to wat with wasm2wat, which must be installed";
+
+pub const DEFAULT_OUTPUT_DIR: &str = "../output";
+pub const DEFAULT_MANDOS_DIR: &str = "../mandos";
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum MetaCommand {
+    Abi,
+    Build,
+    Clean,
+    Snippets,
+    TestGen,
+    Help,
+}
+
+impl MetaCommand {
+    fn parse(command: &str) -> Option<Self> {
+        match command {
+            "abi" => Some(MetaCommand::Abi),
+            "build" => Some(MetaCommand::Build),
+            "clean" => Some(MetaCommand::Clean),
+            "snippets" => Some(MetaCommand::Snippets),
+            "test-gen" => Some(MetaCommand::TestGen),
+            "help" | "--help" | "-h" => Some(MetaCommand::Help),
+            _ => None,
+        }
+    }
+}
+
+/// The meta crate arguments. All paths are relative to the meta crate.
+#[derive(Clone, PartialEq, Debug)]
+pub struct MetaArgs {
+    pub command: MetaCommand,
+    pub output_dir: String,
+    pub target_dir: Option<String>,
+    pub mandos_dir: String,
+    pub wasm_opt: bool,
+    pub wat: bool,
+}
+
+impl Default for MetaArgs {
+    fn default() -> Self {
+        MetaArgs {
+            command: MetaCommand::Abi,
+            output_dir: DEFAULT_OUTPUT_DIR.to_string(),
+            target_dir: None,
+            mandos_dir: DEFAULT_MANDOS_DIR.to_string(),
+            wasm_opt: false,
+            wat: false,
+        }
+    }
+}
+
+impl MetaArgs {
+    /// Parses the arguments, without the program name.
+    /// No command means `abi`, so that running the meta crate without arguments works as always.
+    pub fn parse(args: &[String]) -> Result<Self, String> {
+        let mut result = MetaArgs::default();
+        let mut iter = args.iter();
+        let mut command_given = false;
+        while let Some(arg) = iter.next() {
+            let (option, inline_value) = match arg.split_once('=') {
+                Some((option, value)) if option.starts_with("--") => (option, Some(value)),
+                _ => (arg.as_str(), None),
+            };
+            let mut option_value = || -> Result<String, String> {
+                inline_value
+                    .map(|value| value.to_string())
+                    .or_else(|| iter.next().cloned())
+                    .ok_or_else(|| format!("missing value for {}", option))
+            };
+            match option {
+                "--output-dir" => result.output_dir = option_value()?,
+                "--target-dir" => result.target_dir = Some(option_value()?),
+                "--mandos-dir" => result.mandos_dir = option_value()?,
+                "--wasm-opt" => result.wasm_opt = true,
+                "--wat" => result.wat = true,
+                _ => match MetaCommand::parse(option) {
+                    Some(command) if !command_given => {
+                        result.command = command;
+                        command_given = true;
+                    },
+                    Some(_) => return Err(format!("unexpected command: {}", option)),
+                    None => return Err(format!("unknown argument: {}", option)),
+                },
+            }
+        }
+        Ok(result)
+    }
+}
@@ -0,0 +1,141 @@
+use std::{fs, path::Path};
+
+use elrond_wasm::abi::{ContractAbi, EndpointAbi, EndpointMutabilityAbi};
+
+const EGLD: &str = "EGLD";
+
+const PRELUDE: &str = "ALICE=\"${USERS}/alice.pem\"
+ADDRESS=$(erdpy data load --key=address-devnet)
+DEPLOY_TRANSACTION=$(erdpy data load --key=deployTransaction-devnet)
+";
+
+/// The shell variable holding an argument, e.g. `token_id` becomes `TOKEN_ID`.
+fn arg_var_name(arg_name: &str) -> String {
+    arg_name.to_uppercase()
+}
+
+fn push_arg_prompts(code: &mut String, endpoint: &EndpointAbi) {
+    for input in &endpoint.inputs {
+        code.push_str(&format!(
+            "    read -p \"Enter {} ({}): \" {}\n",
+            input.arg_name,
+            input.type_name,
+            arg_var_name(input.arg_name)
+        ));
+    }
+}
+
+fn arguments_snippet(endpoint: &EndpointAbi) -> String {
+    if endpoint.inputs.is_empty() {
+        return String::new();
+    }
+    let vars: Vec<String> = endpoint
+        .inputs
+        .iter()
+        .map(|input| format!("${{{}}}", arg_var_name(input.arg_name)))
+        .collect();
+    format!(" --arguments {}", vars.join(" "))
+}
+
+/// ESDT payments need a specially crafted transaction, only EGLD is handled directly.
+fn push_payment_prompt(code: &mut String, endpoint: &EndpointAbi) -> String {
+    if endpoint.payable_in_tokens.is_empty() {
+        return String::new();
+    }
+    if endpoint.payable_in_tokens.contains(&EGLD) || endpoint.payable_in_tokens.contains(&"*") {
+        code.push_str("    read -p \"Enter EGLD value: \" VALUE\n");
+        return " --value=${VALUE}".into();
+    }
+    code.push_str(&format!(
+        "    # payable in {}, add the ESDT transfer manually\n",
+        endpoint.payable_in_tokens.join(", ")
+    ));
+    String::new()
+}
+
+fn deploy_snippet(code: &mut String, constructor: &EndpointAbi) {
+    code.push_str("\ndeploy() {\n");
+    push_arg_prompts(code, constructor);
+    let value = push_payment_prompt(code, constructor);
+    code.push_str(&format!(
+        "    erdpy --verbose contract deploy --project=${{PROJECT}} --recall-nonce --pem=${{ALICE}} --gas-limit=50000000{}{} --send --outfile=\"deploy-devnet.interaction.json\" || return\n\n",
+        value,
+        arguments_snippet(constructor)
+    ));
+    code.push_str(
+        "    TRANSACTION=$(erdpy data parse --file=\"deploy-devnet.interaction.json\" --expression=\"data['emitted_tx']['hash']\")
+    ADDRESS=$(erdpy data parse --file=\"deploy-devnet.interaction.json\" --expression=\"data['emitted_tx']['address']\")
+
+    erdpy data store --key=address-devnet --value=${ADDRESS}
+    erdpy data store --key=deployTransaction-devnet --value=${TRANSACTION}
+
+    echo \"\"
+    echo \"Smart contract address: ${ADDRESS}\"
+}
+",
+    );
+}
+
+fn upgrade_snippet(code: &mut String, upgrade_constructor: &EndpointAbi) {
+    code.push_str("\nupgrade() {\n");
+    push_arg_prompts(code, upgrade_constructor);
+    let value = push_payment_prompt(code, upgrade_constructor);
+    code.push_str(&format!(
+        "    erdpy --verbose contract upgrade ${{ADDRESS}} --project=${{PROJECT}} --recall-nonce --pem=${{ALICE}} --gas-limit=50000000{}{} --send\n",
+        value,
+        arguments_snippet(upgrade_constructor)
+    ));
+    code.push_str("}\n");
+}
+
+fn endpoint_snippet(code: &mut String, endpoint: &EndpointAbi) {
+    code.push_str(&format!("\n{}() {{\n", endpoint.name));
+    push_arg_prompts(code, endpoint);
+    match endpoint.mutability {
+        EndpointMutabilityAbi::Mutable => {
+            let value = push_payment_prompt(code, endpoint);
+            code.push_str(&format!(
+                "    erdpy --verbose contract call ${{ADDRESS}} --recall-nonce --pem=${{ALICE}} --gas-limit=50000000 --function=\"{}\"{}{} --send\n",
+                endpoint.name,
+                value,
+                arguments_snippet(endpoint)
+            ));
+        },
+        EndpointMutabilityAbi::Readonly | EndpointMutabilityAbi::Pure => {
+            code.push_str(&format!(
+                "    erdpy --verbose contract query ${{ADDRESS}} --function=\"{}\"{}\n",
+                endpoint.name,
+                arguments_snippet(endpoint)
+            ));
+        },
+    }
+    code.push_str("}\n");
+}
+
+/// Generates erdpy shell functions for deploying and calling the contract on the devnet,
+/// in the style of the `interaction/*.snippets.sh` files of the example contracts.
+///
+/// Expects `USERS` and `PROJECT` to be set in the environment.
+pub fn snippets_source(abi: &ContractAbi) -> String {
+    let mut code = String::from(PRELUDE);
+    if let Some(constructor) = &abi.constructor {
+        deploy_snippet(&mut code, constructor);
+    }
+    if let Some(upgrade_constructor) = &abi.upgrade_constructor {
+        upgrade_snippet(&mut code, upgrade_constructor);
+    }
+    for endpoint in &abi.endpoints {
+        endpoint_snippet(&mut code, endpoint);
+    }
+    code
+}
+
+pub fn snippets_file_name(abi: &ContractAbi) -> String {
+    format!("{}.snippets.sh", abi.build_info.contract_crate.name)
+}
+
+pub fn write_snippets(abi: &ContractAbi, output_dir: &str) {
+    fs::create_dir_all(output_dir).unwrap();
+    let snippets_file_path = Path::new(output_dir).join(snippets_file_name(abi));
+    fs::write(snippets_file_path, snippets_source(abi)).unwrap();
+}
@@ -0,0 +1,81 @@
+use std::{fs, path::Path};
+
+const SCENARIO_EXTENSION: &str = ".scen.json";
+
+/// The names of all the scenarios in a folder, without the extension, sorted.
+pub fn scenario_names(mandos_dir: &str) -> Vec<String> {
+    let mut names: Vec<String> = fs::read_dir(mandos_dir)
+        .unwrap_or_else(|err| panic!("could not read {}: {}", mandos_dir, err))
+        .filter_map(|entry| {
+            let file_name = entry.unwrap().file_name().into_string().unwrap();
+            file_name
+                .strip_suffix(SCENARIO_EXTENSION)
+                .map(|name| name.to_string())
+        })
+        .collect();
+    names.sort();
+    names
+}
+
+fn test_fn_name(scenario_name: &str) -> String {
+    scenario_name.replace(['-', '.'], "_").to_lowercase()
+}
+
+pub fn mandos_rs_test_source(names: &[String]) -> String {
+    names
+        .iter()
+        .map(|name| {
+            format!(
+                "#[test]
+fn {}_rs() {{
+    elrond_wasm_debug::mandos_rs(\"mandos/{}{}\", contract_map());
+}}
+",
+                test_fn_name(name),
+                name,
+                SCENARIO_EXTENSION
+            )
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+pub fn mandos_go_test_source(names: &[String]) -> String {
+    names
+        .iter()
+        .map(|name| {
+            format!(
+                "#[test]
+fn {}_go() {{
+    elrond_wasm_debug::mandos_go(\"mandos/{}{}\");
+}}
+",
+                test_fn_name(name),
+                name,
+                SCENARIO_EXTENSION
+            )
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+pub const MANDOS_RS_TEST_FILE_NAME: &str = "mandos_rs_test.rs";
+pub const MANDOS_GO_TEST_FILE_NAME: &str = "mandos_go_test.rs";
+
+/// Writes `mandos_rs_test.rs` and `mandos_go_test.rs` to the output folder,
+/// with one test function for each scenario.
+/// `contract_map()` needs to be defined next to the Rust tests.
+pub fn write_mandos_tests(mandos_dir: &str, output_dir: &str) {
+    let names = scenario_names(mandos_dir);
+    fs::create_dir_all(output_dir).unwrap();
+    fs::write(
+        Path::new(output_dir).join(MANDOS_RS_TEST_FILE_NAME),
+        mandos_rs_test_source(names.as_slice()),
+    )
+    .unwrap();
+    fs::write(
+        Path::new(output_dir).join(MANDOS_GO_TEST_FILE_NAME),
+        mandos_go_test_source(names.as_slice()),
+    )
+    .unwrap();
+}
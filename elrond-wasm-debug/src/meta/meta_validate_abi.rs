@@ -1,8 +1,247 @@
-use elrond_wasm::abi::ContractAbi;
+use elrond_wasm::abi::{ContractAbi, EndpointAbi, EventAbi, StorageAbi};
 
-pub fn validate_abi(abi: &ContractAbi) -> Result<(), &'static str> {
+const INIT_ENDPOINT_NAME: &str = "init";
+const UPGRADE_ENDPOINT_NAME: &str = "upgrade";
+const CALLBACK_ENDPOINT_NAME: &str = "callBack";
+
+/// These mappers store their value directly under the base key.
+const SINGLE_KEY_MAPPERS: &[&str] = &[
+    "SingleValueMapper",
+    "FungibleTokenMapper",
+    "NonFungibleTokenMapper",
+];
+
+/// All other mappers append a suffix starting with `.` to the base key, with these exceptions.
+const MAPPER_KEY_SUFFIXES: &[(&str, &[&str])] = &[(
+    "UserMapper",
+    &["_address_to_id", "_id_to_address", "_count"],
+)];
+
+const DEFAULT_MAPPER_KEY_SUFFIXES: &[&str] = &["."];
+
+/// Key argument types that always encode to the same number of bytes.
+const FIXED_WIDTH_KEY_ARG_TYPES: &[(&str, usize)] = &[
+    ("bool", 1),
+    ("u8", 1),
+    ("i8", 1),
+    ("u16", 2),
+    ("i16", 2),
+    ("u32", 4),
+    ("i32", 4),
+    ("usize", 4),
+    ("isize", 4),
+    ("u64", 8),
+    ("i64", 8),
+    ("Address", 32),
+    ("H256", 32),
+];
+
+/// Checks the ABI of the whole contract, including all its modules.
+///
+/// Each trait is already validated on its own by the procedural macros,
+/// e.g. for reserved endpoint names, but conflicts between modules can only be detected here.
+pub fn validate_abi(abi: &ContractAbi) -> Result<(), String> {
     if abi.constructor.is_none() {
-        return Err("Missing constructor. Add a method annotated with `#[init]`.");
+        return Err("Missing constructor. Add a method annotated with `#[init]`.".into());
+    }
+    validate_endpoint_names(abi)?;
+    validate_event_identifiers(abi.events.as_slice())?;
+    validate_storage_keys(abi.storage.as_slice())?;
+    Ok(())
+}
+
+fn endpoint_origin(endpoint: &EndpointAbi) -> String {
+    format!(
+        "method `{}` in `{}`",
+        endpoint.rust_method_name, endpoint.module_name
+    )
+}
+
+fn event_origin(event: &EventAbi) -> String {
+    format!(
+        "method `{}` in `{}`",
+        event.rust_method_name, event.module_name
+    )
+}
+
+fn storage_origin(storage: &StorageAbi) -> String {
+    format!(
+        "method `{}` in `{}`",
+        storage.rust_method_name, storage.module_name
+    )
+}
+
+/// The `upgrade` endpoint name is only reserved when the contract has an `#[upgrade]` method.
+fn validate_endpoint_names(abi: &ContractAbi) -> Result<(), String> {
+    let endpoints = abi.endpoints.as_slice();
+    for (i, endpoint) in endpoints.iter().enumerate() {
+        if endpoint.name == INIT_ENDPOINT_NAME
+            || endpoint.name == CALLBACK_ENDPOINT_NAME
+            || (endpoint.name == UPGRADE_ENDPOINT_NAME && abi.upgrade_constructor.is_some())
+        {
+            return Err(format!(
+                "Endpoint name `{}` is reserved ({})",
+                endpoint.name,
+                endpoint_origin(endpoint)
+            ));
+        }
+        if let Some(previous) = endpoints[..i]
+            .iter()
+            .find(|previous| previous.name == endpoint.name)
+        {
+            return Err(format!(
+                "Duplicate endpoint name `{}` ({} and {})",
+                endpoint.name,
+                endpoint_origin(previous),
+                endpoint_origin(endpoint)
+            ));
+        }
+    }
+    Ok(())
+}
+
+fn validate_event_identifiers(events: &[EventAbi]) -> Result<(), String> {
+    for (i, event) in events.iter().enumerate() {
+        if let Some(previous) = events[..i]
+            .iter()
+            .find(|previous| previous.identifier == event.identifier)
+        {
+            return Err(format!(
+                "Duplicate event identifier `{}` ({} and {})",
+                event.identifier,
+                event_origin(previous),
+                event_origin(event)
+            ));
+        }
+    }
+    Ok(())
+}
+
+fn is_single_key_mapper(storage: &StorageAbi) -> bool {
+    match storage.mapper {
+        Some(mapper) => SINGLE_KEY_MAPPERS.contains(&mapper),
+        None => true,
+    }
+}
+
+/// The start of all the keys a mapper writes, besides the base key.
+/// Only for mappers without key arguments, the others append the suffix after the arguments.
+fn mapper_key_prefixes(storage: &StorageAbi) -> Vec<String> {
+    if !storage.key_args.is_empty() {
+        return Vec::new();
+    }
+    let mapper = match storage.mapper {
+        Some(mapper) if !SINGLE_KEY_MAPPERS.contains(&mapper) => mapper,
+        _ => return Vec::new(),
+    };
+    let suffixes = MAPPER_KEY_SUFFIXES
+        .iter()
+        .find(|(name, _)| *name == mapper)
+        .map(|(_, suffixes)| *suffixes)
+        .unwrap_or(DEFAULT_MAPPER_KEY_SUFFIXES);
+    suffixes
+        .iter()
+        .map(|suffix| format!("{}{}", storage.key, suffix))
+        .collect()
+}
+
+/// The length of the keys built from a base key and key arguments.
+/// Exact if all key arguments have a fixed width and nothing gets appended after them,
+/// otherwise only the minimum length is known.
+struct KeyLength {
+    min: usize,
+    exact: bool,
+}
+
+fn key_length(storage: &StorageAbi) -> KeyLength {
+    let mut length = KeyLength {
+        min: storage.key.len(),
+        exact: is_single_key_mapper(storage),
+    };
+    for key_arg in storage.key_args.iter() {
+        match FIXED_WIDTH_KEY_ARG_TYPES
+            .iter()
+            .find(|(type_name, _)| *type_name == key_arg.type_name)
+        {
+            Some((_, width)) => length.min += width,
+            None => length.exact = false,
+        }
+    }
+    length
+}
+
+fn key_lengths_overlap(length1: &KeyLength, length2: &KeyLength) -> bool {
+    (!length1.exact || length1.min >= length2.min) && (!length2.exact || length2.min >= length1.min)
+}
+
+/// Plain getters and setters can share a key with each other or with a single value mapper,
+/// even across modules, as long as they describe the same value.
+/// Two mappers never share a key.
+fn is_same_storage(storage1: &StorageAbi, storage2: &StorageAbi) -> bool {
+    (storage1.mapper.is_none() || storage2.mapper.is_none())
+        && is_single_key_mapper(storage1)
+        && is_single_key_mapper(storage2)
+        && storage1.value_types == storage2.value_types
+        && storage1.key_args.len() == storage2.key_args.len()
+        && storage1
+            .key_args
+            .iter()
+            .zip(storage2.key_args.iter())
+            .all(|(arg1, arg2)| arg1.type_name == arg2.type_name)
+}
+
+fn storage_conflict(storage1: &StorageAbi, storage2: &StorageAbi) -> Option<String> {
+    if storage1.key == storage2.key {
+        if is_same_storage(storage1, storage2) {
+            return None;
+        }
+        return Some(format!(
+            "Storage key `{}` declared more than once ({} and {})",
+            storage1.key,
+            storage_origin(storage1),
+            storage_origin(storage2)
+        ));
+    }
+    // key arguments can be any bytes, so keys starting with the base key can collide,
+    // unless the encoded key arguments make them differ in length
+    for (with_args, other) in [(storage1, storage2), (storage2, storage1)] {
+        if !with_args.key_args.is_empty()
+            && other.key.starts_with(with_args.key)
+            && key_lengths_overlap(&key_length(with_args), &key_length(other))
+        {
+            return Some(format!(
+                "Storage key `{}` ({}) overlaps with the keys built from base key `{}` and key arguments ({})",
+                other.key,
+                storage_origin(other),
+                with_args.key,
+                storage_origin(with_args)
+            ));
+        }
+    }
+    for (mapper, other) in [(storage1, storage2), (storage2, storage1)] {
+        for prefix in mapper_key_prefixes(mapper) {
+            if other.key.starts_with(prefix.as_str()) {
+                return Some(format!(
+                    "Storage key `{}` ({}) overlaps with the keys of the {} with base key `{}` ({})",
+                    other.key,
+                    storage_origin(other),
+                    mapper.mapper.unwrap(),
+                    mapper.key,
+                    storage_origin(mapper)
+                ));
+            }
+        }
+    }
+    None
+}
+
+fn validate_storage_keys(storage: &[StorageAbi]) -> Result<(), String> {
+    for (i, storage1) in storage.iter().enumerate() {
+        for storage2 in &storage[i + 1..] {
+            if let Some(conflict) = storage_conflict(storage1, storage2) {
+                return Err(conflict);
+            }
+        }
     }
     Ok(())
 }
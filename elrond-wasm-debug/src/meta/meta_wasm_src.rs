@@ -3,9 +3,8 @@ use std::{
     io::Write,
 };
 
-use elrond_wasm::abi::ContractAbi;
+use super::output_contract::OutputContract;
 
-const WASM_SRC_DIR: &str = "../wasm/src";
 const WASM_SRC_PATH: &str = "../wasm/src/lib.rs";
 const WASM_SRC_PATH_NO_MANAGED_EI: &str = "../wasm-no-managed-ei/src/lib.rs";
 
@@ -32,19 +31,25 @@ pub fn {}() {{
     .unwrap();
 }
 
-pub fn write_wasm_lib(abi: &ContractAbi) {
+pub fn write_wasm_lib(contract: &OutputContract) {
+    let abi = &contract.abi;
     let contract_module_name = abi
         .build_info
         .contract_crate
         .name
         .replace('-', "_")
         .to_lowercase();
-    create_dir_all(WASM_SRC_DIR).unwrap();
-    let mut wasm_lib_file = File::create(WASM_SRC_PATH).unwrap();
+    let wasm_src_dir = format!("{}/src", contract.wasm_crate_dir());
+    create_dir_all(&wasm_src_dir).unwrap();
+    let mut wasm_lib_file = File::create(format!("{}/lib.rs", wasm_src_dir)).unwrap();
     wasm_lib_file.write_all(PRELUDE.as_bytes()).unwrap();
 
     write_endpoint(&mut wasm_lib_file, &contract_module_name, "init");
 
+    if abi.upgrade_constructor.is_some() {
+        write_endpoint(&mut wasm_lib_file, &contract_module_name, "upgrade");
+    }
+
     for endpoint in &abi.endpoints {
         write_endpoint(&mut wasm_lib_file, &contract_module_name, endpoint.name);
     }
@@ -52,6 +57,34 @@ pub fn write_wasm_lib(abi: &ContractAbi) {
     write_endpoint(&mut wasm_lib_file, &contract_module_name, "callBack");
 }
 
+/// The secondary wasm crates are copies of the main one, only the package name differs.
+/// Nothing to do if the main wasm crate has no `Cargo.toml`.
+pub fn write_secondary_wasm_cargo_toml(main: &OutputContract, secondary: &OutputContract) {
+    let main_cargo_toml_path = format!("{}/Cargo.toml", main.wasm_crate_dir());
+    let main_cargo_toml = match fs::read_to_string(&main_cargo_toml_path) {
+        Ok(contents) => contents,
+        Err(_) => return,
+    };
+    let main_package_name = format!("name = \"{}\"", main.wasm_crate_name);
+    assert!(
+        main_cargo_toml.contains(main_package_name.as_str()),
+        "package name `{}` not found in {}",
+        main.wasm_crate_name,
+        main_cargo_toml_path
+    );
+    let secondary_cargo_toml = main_cargo_toml.replacen(
+        main_package_name.as_str(),
+        format!("name = \"{}\"", secondary.wasm_crate_name).as_str(),
+        1,
+    );
+    create_dir_all(secondary.wasm_crate_dir()).unwrap();
+    fs::write(
+        format!("{}/Cargo.toml", secondary.wasm_crate_dir()),
+        secondary_cargo_toml,
+    )
+    .unwrap();
+}
+
 /// This one is useful for some of the special unmanaged EI tests in the framework.
 /// Will do nothing for regular contracts.
 pub fn copy_to_wasm_unmanaged_ei() {
@@ -1,13 +1,73 @@
 mod meta_abi;
+mod meta_build;
+mod meta_clean;
+mod meta_cli;
+mod meta_snippets;
+mod meta_test_gen;
 mod meta_validate_abi;
 mod meta_wasm_src;
+mod output_contract;
+
+pub use meta_clean::{clean, generated_file_names};
+pub use meta_cli::{MetaArgs, MetaCommand, USAGE};
+pub use meta_snippets::{snippets_file_name, snippets_source};
+pub use meta_test_gen::{mandos_go_test_source, mandos_rs_test_source, scenario_names};
+pub use meta_validate_abi::validate_abi;
+pub use output_contract::{
+    MultiContractConfigSerde, OutputContract, OutputContractConfig, DEFAULT_LABEL,
+    MULTI_CONTRACT_CONFIG_PATH,
+};
 
 use elrond_wasm::contract_base::ContractAbiProvider;
 
+/// Entry point of the meta crates, parses the command line arguments.
+/// Without arguments it generates the ABI and the wasm crate source.
 pub fn perform<AbiObj: ContractAbiProvider>() {
-    let abi = <AbiObj as ContractAbiProvider>::abi();
-    meta_validate_abi::validate_abi(&abi).unwrap();
-    meta_abi::write_abi(&abi);
-    meta_wasm_src::write_wasm_lib(&abi);
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    match MetaArgs::parse(args.as_slice()) {
+        Ok(meta_args) => perform_with_args::<AbiObj>(&meta_args),
+        Err(message) => {
+            eprintln!("{}\n\n{}", message, USAGE);
+            std::process::exit(1);
+        },
+    }
+}
+
+pub fn perform_with_args<AbiObj: ContractAbiProvider>(args: &MetaArgs) {
+    let original_abi = <AbiObj as ContractAbiProvider>::abi();
+    if let Err(message) = meta_validate_abi::validate_abi(&original_abi) {
+        eprintln!("Invalid contract ABI: {}", message);
+        std::process::exit(1);
+    }
+    let config =
+        OutputContractConfig::load_from_file_or_default(MULTI_CONTRACT_CONFIG_PATH, &original_abi);
+    match args.command {
+        MetaCommand::Abi => generate_sources(&config, args),
+        MetaCommand::Build => {
+            generate_sources(&config, args);
+            for contract in &config.contracts {
+                meta_build::build_wasm(contract, args);
+            }
+        },
+        MetaCommand::Clean => meta_clean::clean(&config, args),
+        MetaCommand::Snippets => {
+            meta_snippets::write_snippets(&original_abi, args.output_dir.as_str())
+        },
+        MetaCommand::TestGen => {
+            meta_test_gen::write_mandos_tests(args.mandos_dir.as_str(), args.output_dir.as_str())
+        },
+        MetaCommand::Help => println!("{}", USAGE),
+    }
+}
+
+/// Writes an ABI and a wasm crate for each output contract.
+fn generate_sources(config: &OutputContractConfig, args: &MetaArgs) {
+    for contract in &config.contracts {
+        meta_abi::write_abi(contract, args.output_dir.as_str());
+        meta_wasm_src::write_wasm_lib(contract);
+    }
+    for secondary in config.secondary_contracts() {
+        meta_wasm_src::write_secondary_wasm_cargo_toml(config.main_contract(), secondary);
+    }
     meta_wasm_src::copy_to_wasm_unmanaged_ei();
 }
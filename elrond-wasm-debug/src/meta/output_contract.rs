@@ -0,0 +1,239 @@
+use std::{collections::BTreeMap, fs, path::Path};
+
+use elrond_wasm::abi::{ContractAbi, EndpointAbi};
+use serde::Deserialize;
+
+/// Optional, lives in the contract crate, next to `Cargo.toml`.
+pub const MULTI_CONTRACT_CONFIG_PATH: &str = "../multicontract.toml";
+
+/// Endpoints without any `#[label]` are considered to have this label.
+pub const DEFAULT_LABEL: &str = "default";
+
+const MAIN_WASM_CRATE_DIR: &str = "../wasm";
+
+/// The contents of `multicontract.toml`, e.g.:
+///
+/// ```toml
+/// [settings]
+/// main = "main"
+///
+/// [contracts.main]
+///
+/// [contracts.view]
+/// labels = ["view"]
+///
+/// [contracts.no-admin]
+/// exclude-labels = ["admin"]
+/// ```
+#[derive(Deserialize, Default, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct MultiContractConfigSerde {
+    #[serde(default)]
+    pub settings: MultiContractSettingsSerde,
+    #[serde(default)]
+    pub contracts: BTreeMap<String, OutputContractSerde>,
+}
+
+#[derive(Deserialize, Default, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct MultiContractSettingsSerde {
+    /// The id of the contract built in the `wasm` crate.
+    /// If missing, the main contract contains all endpoints.
+    pub main: Option<String>,
+}
+
+#[derive(Deserialize, Default, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct OutputContractSerde {
+    /// Defaults to `<contract crate name>-<contract id>`.
+    /// The main contract defaults to the contract crate name.
+    pub name: Option<String>,
+    /// Only endpoints with at least one of these labels are included. All endpoints if missing.
+    pub labels: Option<Vec<String>>,
+    /// Endpoints with any of these labels are left out.
+    #[serde(default, rename = "exclude-labels")]
+    pub exclude_labels: Vec<String>,
+}
+
+/// One of the wasm contracts produced from the contract crate.
+pub struct OutputContract {
+    /// The main contract is built in the `wasm` crate, the others in `wasm-<name>` crates.
+    pub main: bool,
+    /// Names the ABI and the wasm files in the output folder.
+    pub public_name: String,
+    pub wasm_crate_name: String,
+    /// The ABI of the contract crate, restricted to the selected endpoints.
+    pub abi: ContractAbi,
+}
+
+impl OutputContract {
+    pub fn wasm_crate_dir(&self) -> String {
+        if self.main {
+            MAIN_WASM_CRATE_DIR.to_string()
+        } else {
+            format!("{}-{}", MAIN_WASM_CRATE_DIR, self.public_name)
+        }
+    }
+
+    pub fn abi_file_name(&self) -> String {
+        format!("{}.abi.json", self.public_name)
+    }
+
+    pub fn wasm_file_name(&self) -> String {
+        format!("{}.wasm", self.public_name)
+    }
+
+    /// Only produced by `build --wat`.
+    pub fn wat_file_name(&self) -> String {
+        format!("{}.wat", self.public_name)
+    }
+
+    pub fn endpoint_names(&self) -> Vec<&'static str> {
+        self.abi
+            .endpoints
+            .iter()
+            .map(|endpoint| endpoint.name)
+            .collect()
+    }
+}
+
+/// All the contracts produced from the contract crate, the main one first.
+pub struct OutputContractConfig {
+    pub contracts: Vec<OutputContract>,
+}
+
+fn endpoint_labels(endpoint: &EndpointAbi) -> &'static [&'static str] {
+    if endpoint.labels.is_empty() {
+        &[DEFAULT_LABEL]
+    } else {
+        endpoint.labels
+    }
+}
+
+fn is_endpoint_selected(endpoint: &EndpointAbi, contract_serde: &OutputContractSerde) -> bool {
+    let labels = endpoint_labels(endpoint);
+    let included = match &contract_serde.labels {
+        Some(selected) => labels
+            .iter()
+            .any(|label| selected.iter().any(|s| s == label)),
+        None => true,
+    };
+    let excluded = labels
+        .iter()
+        .any(|label| contract_serde.exclude_labels.iter().any(|s| s == label));
+    included && !excluded
+}
+
+fn check_labels_exist(contract_id: &str, contract_serde: &OutputContractSerde, abi: &ContractAbi) {
+    let config_labels = contract_serde
+        .labels
+        .iter()
+        .flatten()
+        .chain(contract_serde.exclude_labels.iter());
+    for label in config_labels {
+        assert!(
+            abi.endpoints
+                .iter()
+                .any(|endpoint| endpoint_labels(endpoint).contains(&label.as_str())),
+            "label `{}` of contract `{}` is not used by any endpoint",
+            label,
+            contract_id
+        );
+    }
+}
+
+impl OutputContractConfig {
+    /// A single contract, with all endpoints.
+    pub fn default_config(original_abi: &ContractAbi) -> Self {
+        Self::load_from_config(&MultiContractConfigSerde::default(), original_abi)
+    }
+
+    pub fn load_from_config(config: &MultiContractConfigSerde, original_abi: &ContractAbi) -> Self {
+        let crate_name = original_abi.build_info.contract_crate.name;
+        let main_id = config.settings.main.as_deref();
+        if let Some(main_id) = main_id {
+            assert!(
+                config.contracts.contains_key(main_id),
+                "main contract `{}` not found in the contracts list",
+                main_id
+            );
+        }
+
+        let mut contracts = Vec::new();
+        if main_id.is_none() {
+            contracts.push(OutputContract {
+                main: true,
+                public_name: crate_name.to_string(),
+                wasm_crate_name: format!("{}-wasm", crate_name),
+                abi: original_abi.clone(),
+            });
+        }
+        for (contract_id, contract_serde) in &config.contracts {
+            check_labels_exist(contract_id, contract_serde, original_abi);
+            let main = main_id == Some(contract_id.as_str());
+            let public_name = match (&contract_serde.name, main) {
+                (Some(name), _) => name.clone(),
+                (None, true) => crate_name.to_string(),
+                (None, false) => format!("{}-{}", crate_name, contract_id),
+            };
+            // the main wasm crate already exists, its name does not change
+            let wasm_crate_name = if main {
+                format!("{}-wasm", crate_name)
+            } else {
+                format!("{}-wasm", public_name)
+            };
+            let mut abi = original_abi.clone();
+            abi.endpoints
+                .retain(|endpoint| is_endpoint_selected(endpoint, contract_serde));
+            let contract = OutputContract {
+                main,
+                public_name,
+                wasm_crate_name,
+                abi,
+            };
+            if main {
+                contracts.insert(0, contract);
+            } else {
+                contracts.push(contract);
+            }
+        }
+
+        for (i, contract) in contracts.iter().enumerate() {
+            assert!(
+                contracts[..i]
+                    .iter()
+                    .all(|other| other.public_name != contract.public_name),
+                "duplicate output contract name `{}`",
+                contract.public_name
+            );
+        }
+
+        OutputContractConfig { contracts }
+    }
+
+    /// Falls back to the default config if the file is missing.
+    pub fn load_from_file_or_default(path: &str, original_abi: &ContractAbi) -> Self {
+        if !Path::new(path).exists() {
+            return Self::default_config(original_abi);
+        }
+        let config_string = fs::read_to_string(path)
+            .unwrap_or_else(|err| panic!("could not read {}: {}", path, err));
+        let config_serde: MultiContractConfigSerde = toml::from_str(config_string.as_str())
+            .unwrap_or_else(|err| panic!("invalid {}: {}", path, err));
+        Self::load_from_config(&config_serde, original_abi)
+    }
+
+    pub fn main_contract(&self) -> &OutputContract {
+        &self.contracts[0]
+    }
+
+    pub fn secondary_contracts(&self) -> impl Iterator<Item = &OutputContract> {
+        self.contracts.iter().skip(1)
+    }
+
+    pub fn get_contract_by_name(&self, public_name: &str) -> Option<&OutputContract> {
+        self.contracts
+            .iter()
+            .find(|contract| contract.public_name == public_name)
+    }
+}
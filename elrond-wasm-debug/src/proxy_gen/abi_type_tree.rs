@@ -0,0 +1,61 @@
+use alloc::{string::String, vec::Vec};
+
+/// An ABI type name, parsed, e.g. `variadic<multi<u32,List<bytes>>>`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct AbiTypeTree {
+    pub name: String,
+    pub args: Vec<AbiTypeTree>,
+}
+
+impl AbiTypeTree {
+    pub fn parse(type_name: &str) -> Self {
+        let mut remaining = type_name;
+        let tree = parse_tree(&mut remaining, type_name);
+        assert!(
+            remaining.is_empty(),
+            "unexpected characters at the end of ABI type `{}`",
+            type_name
+        );
+        tree
+    }
+
+    /// All the type names that appear in the tree, including the root.
+    pub fn collect_names<'a>(&'a self, names: &mut Vec<&'a str>) {
+        names.push(self.name.as_str());
+        for arg in &self.args {
+            arg.collect_names(names);
+        }
+    }
+}
+
+fn parse_tree(remaining: &mut &str, type_name: &str) -> AbiTypeTree {
+    let name_len = remaining.find(['<', ',', '>']).unwrap_or(remaining.len());
+    let name = remaining[..name_len].trim();
+    assert!(
+        !name.is_empty(),
+        "missing type name in ABI type `{}`",
+        type_name
+    );
+    *remaining = &remaining[name_len..];
+
+    let mut args = Vec::new();
+    if let Some(after_open) = remaining.strip_prefix('<') {
+        *remaining = after_open;
+        loop {
+            args.push(parse_tree(remaining, type_name));
+            if let Some(after_comma) = remaining.strip_prefix(',') {
+                *remaining = after_comma;
+            } else if let Some(after_close) = remaining.strip_prefix('>') {
+                *remaining = after_close;
+                break;
+            } else {
+                panic!("unclosed `<` in ABI type `{}`", type_name);
+            }
+        }
+    }
+
+    AbiTypeTree {
+        name: name.into(),
+        args,
+    }
+}
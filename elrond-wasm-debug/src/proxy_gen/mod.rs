@@ -0,0 +1,57 @@
+mod abi_type_tree;
+mod proxy_gen_trait;
+mod proxy_gen_types;
+mod proxy_gen_util;
+
+pub use abi_type_tree::AbiTypeTree;
+
+use crate::abi_json::{deserialize_abi_from_json, ContractAbiJson};
+use alloc::string::String;
+use proxy_gen_types::ProxyTypes;
+use std::fs;
+
+const PRELUDE: &str = "////////////////////////////////////////////////////
+////////////////// AUTO-GENERATED //////////////////
+////////////////////////////////////////////////////
+
+elrond_wasm::imports!();
+elrond_wasm::derive_imports!();
+";
+
+const COLLECTIONS_IMPORT: &str = "use alloc::collections::{BTreeMap, BTreeSet};\n";
+
+/// Generates the Rust source of a proxy for the contract described by the ABI,
+/// together with definitions for all the custom types in the ABI.
+///
+/// The result is meant to be placed in a module of the calling contract,
+/// with its own imports, e.g. `mod callee_proxy;`.
+/// It needs `extern crate alloc;` in the crate root if the ABI has ordered collections.
+pub fn proxy_source(abi: &ContractAbiJson) -> String {
+    let types = ProxyTypes::new(&abi.types);
+
+    let mut body = String::new();
+    body.push_str(proxy_gen_trait::proxy_trait_code(&types, abi).as_str());
+    for type_name in types.custom_type_names() {
+        body.push('\n');
+        body.push_str(types.type_definition(type_name).as_str());
+    }
+
+    let mut code = String::from(PRELUDE);
+    if body.contains("BTreeSet<") || body.contains("BTreeMap<") {
+        code.push('\n');
+        code.push_str(COLLECTIONS_IMPORT);
+    }
+    code.push('\n');
+    code.push_str(body.as_str());
+    code
+}
+
+/// Reads an ABI JSON file and writes the generated proxy to a Rust source file.
+pub fn write_proxy_from_abi_file(abi_file_path: &str, proxy_file_path: &str) {
+    let abi_string = fs::read_to_string(abi_file_path)
+        .unwrap_or_else(|err| panic!("could not read {}: {}", abi_file_path, err));
+    let abi = deserialize_abi_from_json(abi_string.as_str())
+        .unwrap_or_else(|err| panic!("invalid ABI in {}: {}", abi_file_path, err));
+    fs::write(proxy_file_path, proxy_source(&abi))
+        .unwrap_or_else(|err| panic!("could not write {}: {}", proxy_file_path, err));
+}
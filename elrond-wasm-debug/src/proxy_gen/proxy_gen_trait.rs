@@ -0,0 +1,183 @@
+use super::{
+    proxy_gen_types::{MultiValueContext, ProxyTypes},
+    proxy_gen_util::*,
+    AbiTypeTree,
+};
+use crate::abi_json::{
+    ConstructorAbiJson, ContractAbiJson, EndpointAbiJson, EndpointMutabilityAbiJson, InputAbiJson,
+    OutputAbiJson,
+};
+use alloc::{format, string::String, vec::Vec};
+
+/// Same as rustfmt.
+const MAX_LINE_LEN: usize = 100;
+
+const EGLD: &str = "EGLD";
+
+/// Tokens read at runtime, e.g. from storage, appear in the ABI as `token_from:<method name>`.
+const TOKEN_FROM_PREFIX: &str = "token_from:";
+
+/// Payment arguments are not part of the endpoint inputs in the ABI, they are added here.
+/// Constructors can only receive EGLD.
+fn payment_snippets(payable_in_tokens: &[String], is_init: bool) -> (Option<String>, Vec<String>) {
+    if payable_in_tokens.is_empty() {
+        return (None, Vec::new());
+    }
+    if is_init || payable_in_tokens.iter().all(|token| token == EGLD) {
+        return (
+            Some(format!("#[payable(\"{}\")]", EGLD)),
+            vec!["#[payment] payment: BigUint".into()],
+        );
+    }
+    let payable_token = match payable_in_tokens {
+        [single_token] if !single_token.starts_with(TOKEN_FROM_PREFIX) => single_token.as_str(),
+        _ => "*",
+    };
+    (
+        Some(format!("#[payable(\"{}\")]", payable_token)),
+        vec![
+            "#[payment_token] payment_token: TokenIdentifier".into(),
+            "#[payment_nonce] payment_nonce: u64".into(),
+            "#[payment] payment: BigUint".into(),
+        ],
+    )
+}
+
+fn input_snippet(types: &ProxyTypes, input: &InputAbiJson) -> String {
+    let tree = AbiTypeTree::parse(input.type_name.as_str());
+    let var_args_attr = match tree.name.as_str() {
+        "variadic" | "optional" | "multi" => "#[var_args] ",
+        _ => "",
+    };
+    format!(
+        "{}{}: {}",
+        var_args_attr,
+        rust_ident(input.arg_name.as_str()),
+        types.rust_multi_type(&tree, MultiValueContext::Input)
+    )
+}
+
+fn method_sig(method_name: &str, args: &[String], output: Option<String>) -> String {
+    let output_snippet = output
+        .map(|output| format!(" -> {}", output))
+        .unwrap_or_default();
+    let single_line = format!(
+        "    fn {}(&self{}){};\n",
+        method_name,
+        args.iter()
+            .map(|arg| format!(", {}", arg))
+            .collect::<String>(),
+        output_snippet
+    );
+    if single_line.len() <= MAX_LINE_LEN + 1 {
+        return single_line;
+    }
+
+    let mut sig = format!("    fn {}(\n        &self,\n", method_name);
+    for arg in args {
+        sig.push_str(&format!("        {},\n", arg));
+    }
+    sig.push_str(&format!("    ){};\n", output_snippet));
+    sig
+}
+
+fn method_code(
+    types: &ProxyTypes,
+    docs: &[String],
+    endpoint_attr: String,
+    method_name: &str,
+    payable_in_tokens: &[String],
+    inputs: &[InputAbiJson],
+    outputs: &[OutputAbiJson],
+) -> String {
+    let is_init = endpoint_attr == "#[init]" || endpoint_attr == "#[upgrade]";
+    let (payable_attr, mut args) = payment_snippets(payable_in_tokens, is_init);
+    args.extend(inputs.iter().map(|input| input_snippet(types, input)));
+    let output_trees: Vec<AbiTypeTree> = outputs
+        .iter()
+        .map(|output| AbiTypeTree::parse(output.type_name.as_str()))
+        .collect();
+
+    let mut code = String::new();
+    push_docs(&mut code, docs, "    ");
+    code.push_str(&format!("    {}\n", endpoint_attr));
+    if let Some(payable_attr) = payable_attr {
+        code.push_str(&format!("    {}\n", payable_attr));
+    }
+    code.push_str(&method_sig(
+        method_name,
+        args.as_slice(),
+        types.rust_multi_result_type(output_trees.as_slice()),
+    ));
+    code
+}
+
+fn constructor_code(types: &ProxyTypes, constructor: &ConstructorAbiJson) -> String {
+    method_code(
+        types,
+        constructor.docs.as_slice(),
+        "#[init]".into(),
+        "init",
+        constructor.payable_in_tokens.as_slice(),
+        constructor.inputs.as_slice(),
+        constructor.outputs.as_slice(),
+    )
+}
+
+fn upgrade_constructor_code(
+    types: &ProxyTypes,
+    upgrade_constructor: &ConstructorAbiJson,
+) -> String {
+    method_code(
+        types,
+        upgrade_constructor.docs.as_slice(),
+        "#[upgrade]".into(),
+        "upgrade",
+        upgrade_constructor.payable_in_tokens.as_slice(),
+        upgrade_constructor.inputs.as_slice(),
+        upgrade_constructor.outputs.as_slice(),
+    )
+}
+
+fn endpoint_code(types: &ProxyTypes, endpoint: &EndpointAbiJson) -> String {
+    let method_name = rust_ident(snake_case(endpoint.name.as_str()).as_str());
+    let annotation = match endpoint.mutability {
+        EndpointMutabilityAbiJson::Mutable => "endpoint",
+        EndpointMutabilityAbiJson::Readonly | EndpointMutabilityAbiJson::Pure => "view",
+    };
+    let endpoint_attr = if method_name == endpoint.name {
+        format!("#[{}]", annotation)
+    } else {
+        format!("#[{}({})]", annotation, endpoint.name)
+    };
+    method_code(
+        types,
+        endpoint.docs.as_slice(),
+        endpoint_attr,
+        method_name.as_str(),
+        endpoint.payable_in_tokens.as_slice(),
+        endpoint.inputs.as_slice(),
+        endpoint.outputs.as_slice(),
+    )
+}
+
+pub fn proxy_trait_code(types: &ProxyTypes, abi: &ContractAbiJson) -> String {
+    let mut methods = Vec::new();
+    if let Some(constructor) = &abi.constructor {
+        methods.push(constructor_code(types, constructor));
+    }
+    if let Some(upgrade_constructor) = &abi.upgrade_constructor {
+        methods.push(upgrade_constructor_code(types, upgrade_constructor));
+    }
+    for endpoint in &abi.endpoints {
+        methods.push(endpoint_code(types, endpoint));
+    }
+
+    let mut code = String::new();
+    push_docs(&mut code, abi.docs.as_slice(), "");
+    code.push_str("#[elrond_wasm::proxy]\n");
+    code.push_str(&format!("pub trait {} {{\n", abi.name));
+    code.push_str(methods.join("\n").as_str());
+    code.push_str("}\n");
+    code
+}
@@ -0,0 +1,401 @@
+use super::{proxy_gen_util::*, AbiTypeTree};
+use crate::abi_json::{StructFieldDescriptionJson, TypeDescriptionJson};
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+use std::collections::{BTreeMap, BTreeSet};
+
+/// Types that already exist as such in the framework, with the same name as in the ABI.
+const FRAMEWORK_TYPES: &[&str] = &[
+    "()",
+    "bool",
+    "u8",
+    "u16",
+    "u32",
+    "u64",
+    "u128",
+    "usize",
+    "i8",
+    "i16",
+    "i32",
+    "i64",
+    "i128",
+    "isize",
+    "H256",
+    "CodeMetadata",
+    "EsdtLocalRole",
+    "EsdtTokenType",
+    "OperationCompletionStatus",
+];
+
+/// Framework types that are generic over the API.
+const FRAMEWORK_MANAGED_TYPES: &[&str] = &["EsdtTokenPayment", "EsdtTokenData"];
+
+/// Managed types that show up under a different name in the ABI.
+fn managed_type_name(abi_name: &str) -> Option<&'static str> {
+    match abi_name {
+        "BigUint" => Some("BigUint"),
+        "BigInt" => Some("BigInt"),
+        "bytes" | "utf-8 string" => Some("ManagedBuffer"),
+        "Address" => Some("ManagedAddress"),
+        "TokenIdentifier" => Some("TokenIdentifier"),
+        "EllipticCurve" => Some("EllipticCurve"),
+        _ => None,
+    }
+}
+
+pub fn is_framework_type(abi_name: &str) -> bool {
+    FRAMEWORK_TYPES.contains(&abi_name)
+        || FRAMEWORK_MANAGED_TYPES.contains(&abi_name)
+        || managed_type_name(abi_name).is_some()
+}
+
+/// Where a Rust type is written.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum ApiContext {
+    /// In the proxy trait, where the contract macros add the API to the managed types.
+    ProxyTrait,
+
+    /// In a generated type definition, generic over `M`.
+    TypeDefinition,
+}
+
+impl ApiContext {
+    fn api_type(self) -> &'static str {
+        match self {
+            ApiContext::ProxyTrait => "Self::Api",
+            ApiContext::TypeDefinition => "M",
+        }
+    }
+}
+
+/// Multi-values only appear at the top level of endpoint inputs and outputs.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum MultiValueContext {
+    Input,
+    Output,
+}
+
+/// Translates ABI types to Rust types, using the type descriptions from the ABI.
+pub struct ProxyTypes<'a> {
+    descriptions: &'a BTreeMap<String, TypeDescriptionJson>,
+
+    /// Custom types that contain managed types, and which therefore get a generic API.
+    managed: BTreeSet<String>,
+}
+
+impl<'a> ProxyTypes<'a> {
+    pub fn new(descriptions: &'a BTreeMap<String, TypeDescriptionJson>) -> Self {
+        let mut proxy_types = ProxyTypes {
+            descriptions,
+            managed: BTreeSet::new(),
+        };
+        // managed fields propagate to the types containing them, until nothing changes
+        loop {
+            let newly_managed: Vec<String> = proxy_types
+                .custom_type_names()
+                .filter(|name| {
+                    !proxy_types.managed.contains(*name) && proxy_types.contains_managed(name)
+                })
+                .map(|name| name.to_string())
+                .collect();
+            if newly_managed.is_empty() {
+                break;
+            }
+            proxy_types.managed.extend(newly_managed);
+        }
+        proxy_types
+    }
+
+    /// The types from the ABI that need a definition in the generated code.
+    pub fn custom_type_names(&self) -> impl Iterator<Item = &'a str> {
+        self.descriptions
+            .keys()
+            .map(|name| name.as_str())
+            .filter(|name| !is_framework_type(name))
+    }
+
+    fn is_custom(&self, abi_name: &str) -> bool {
+        !is_framework_type(abi_name) && self.descriptions.contains_key(abi_name)
+    }
+
+    /// All type names that appear in the fields of a type, including type arguments.
+    fn field_type_names(&self, abi_name: &str) -> Vec<String> {
+        let mut names = Vec::new();
+        if let Some(description) = self.descriptions.get(abi_name) {
+            let fields = description
+                .fields
+                .iter()
+                .chain(description.variants.iter().flat_map(|v| v.fields.iter()));
+            for field in fields {
+                let tree = AbiTypeTree::parse(field.field_type.as_str());
+                let mut tree_names = Vec::new();
+                tree.collect_names(&mut tree_names);
+                names.extend(tree_names.into_iter().map(|name| name.to_string()));
+            }
+        }
+        names
+    }
+
+    fn contains_managed(&self, abi_name: &str) -> bool {
+        self.field_type_names(abi_name).iter().any(|name| {
+            managed_type_name(name).is_some()
+                || FRAMEWORK_MANAGED_TYPES.contains(&name.as_str())
+                || self.managed.contains(name)
+        })
+    }
+
+    fn is_managed(&self, abi_name: &str) -> bool {
+        self.managed.contains(abi_name)
+    }
+
+    /// True if the definition of `from` contains `to`, directly or not.
+    fn reaches(&self, from: &str, to: &str) -> bool {
+        let mut visited = BTreeSet::new();
+        let mut stack = vec![from.to_string()];
+        while let Some(current) = stack.pop() {
+            if current == to {
+                return true;
+            }
+            if self.is_custom(current.as_str()) && visited.insert(current.clone()) {
+                stack.extend(self.field_type_names(current.as_str()));
+            }
+        }
+        false
+    }
+
+    /// The Rust type for an ABI type.
+    /// When in the definition of a type, references back to it get boxed.
+    pub fn rust_type(
+        &self,
+        tree: &AbiTypeTree,
+        api_context: ApiContext,
+        enclosing_type: Option<&str>,
+    ) -> String {
+        self.rust_type_rec(tree, api_context, enclosing_type, false)
+    }
+
+    fn rust_type_rec(
+        &self,
+        tree: &AbiTypeTree,
+        api_context: ApiContext,
+        enclosing_type: Option<&str>,
+        indirect: bool,
+    ) -> String {
+        let arg = |index: usize, indirect: bool| {
+            self.rust_type_rec(&tree.args[index], api_context, enclosing_type, indirect)
+        };
+        let name = tree.name.as_str();
+        match (name, tree.args.len()) {
+            ("List", 1) => format!("Vec<{}>", arg(0, true)),
+            ("Option", 1) => format!("Option<{}>", arg(0, indirect)),
+            ("BTreeSet", 1) => format!("BTreeSet<{}>", arg(0, true)),
+            ("BTreeMap", 2) => format!("BTreeMap<{}, {}>", arg(0, true), arg(1, true)),
+            ("tuple", 1) => format!("({},)", arg(0, indirect)),
+            ("tuple", len) => {
+                let items: Vec<String> = (0..len).map(|index| arg(index, indirect)).collect();
+                format!("({})", items.join(", "))
+            },
+            (_, 1) if array_len(name).is_some() => {
+                format!("[{}; {}]", arg(0, indirect), array_len(name).unwrap())
+            },
+            (_, 0) => self.rust_leaf_type(name, api_context, enclosing_type, indirect),
+            _ => panic!("unsupported ABI type `{}`", name),
+        }
+    }
+
+    fn rust_leaf_type(
+        &self,
+        name: &str,
+        api_context: ApiContext,
+        enclosing_type: Option<&str>,
+        indirect: bool,
+    ) -> String {
+        if FRAMEWORK_TYPES.contains(&name) {
+            return name.to_string();
+        }
+        if let Some(managed_name) = managed_type_name(name) {
+            return match api_context {
+                ApiContext::ProxyTrait => managed_name.to_string(),
+                ApiContext::TypeDefinition => format!("{}<M>", managed_name),
+            };
+        }
+        if FRAMEWORK_MANAGED_TYPES.contains(&name) {
+            return format!("{}<{}>", name, api_context.api_type());
+        }
+        assert!(self.is_custom(name), "unsupported ABI type `{}`", name);
+
+        let rust_name = if self.is_managed(name) {
+            format!("{}<{}>", name, api_context.api_type())
+        } else {
+            name.to_string()
+        };
+        match enclosing_type {
+            Some(enclosing) if !indirect && self.reaches(name, enclosing) => {
+                format!("Box<{}>", rust_name)
+            },
+            _ => rust_name,
+        }
+    }
+
+    /// The Rust type for endpoint inputs and outputs, which can also be multi-values.
+    pub fn rust_multi_type(&self, tree: &AbiTypeTree, multi_context: MultiValueContext) -> String {
+        let multi_arg = |index: usize| self.rust_multi_type(&tree.args[index], multi_context);
+        match (tree.name.as_str(), multi_context) {
+            ("variadic", MultiValueContext::Input) => format!("VarArgs<{}>", multi_arg(0)),
+            ("variadic", MultiValueContext::Output) => format!("MultiResultVec<{}>", multi_arg(0)),
+            ("optional", MultiValueContext::Input) => format!("OptionalArg<{}>", multi_arg(0)),
+            ("optional", MultiValueContext::Output) => format!("OptionalResult<{}>", multi_arg(0)),
+            ("multi", _) => {
+                let items: Vec<String> = (0..tree.args.len()).map(multi_arg).collect();
+                format!(
+                    "{}{}<{}>",
+                    multi_type_prefix(multi_context),
+                    items.len(),
+                    items.join(", ")
+                )
+            },
+            _ => self.rust_type(tree, ApiContext::ProxyTrait, None),
+        }
+    }
+
+    /// Several endpoint outputs are returned together as a multi-result.
+    pub fn rust_multi_result_type(&self, output_trees: &[AbiTypeTree]) -> Option<String> {
+        match output_trees {
+            [] => None,
+            [single] => Some(self.rust_multi_type(single, MultiValueContext::Output)),
+            _ => {
+                let items: Vec<String> = output_trees
+                    .iter()
+                    .map(|tree| self.rust_multi_type(tree, MultiValueContext::Output))
+                    .collect();
+                Some(format!(
+                    "{}{}<{}>",
+                    multi_type_prefix(MultiValueContext::Output),
+                    items.len(),
+                    items.join(", ")
+                ))
+            },
+        }
+    }
+
+    pub fn type_definition(&self, name: &str) -> String {
+        let description = &self.descriptions[name];
+        let generics = if self.is_managed(name) {
+            "<M: ManagedTypeApi>"
+        } else {
+            ""
+        };
+
+        let mut code = String::new();
+        push_docs(&mut code, description.docs.as_slice(), "");
+        code.push_str("#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, TypeAbi)]\n");
+        match description.content_type.as_str() {
+            "struct" => {
+                code.push_str(&format!("pub struct {}{}", name, generics));
+                code.push_str(&self.fields_definition(name, &description.fields, "", "pub "));
+                if !is_named(&description.fields) {
+                    code.push(';');
+                }
+                code.push('\n');
+            },
+            "enum" => {
+                code.push_str(&format!("pub enum {}{} {{\n", name, generics));
+                let mut implicit_discriminant = 0;
+                for variant in &description.variants {
+                    push_docs(&mut code, variant.docs.as_slice(), "    ");
+                    if variant.discriminant != implicit_discriminant {
+                        code.push_str(&format!(
+                            "    #[codec(discriminant = {})]\n",
+                            variant.discriminant
+                        ));
+                    }
+                    implicit_discriminant = variant.discriminant + 1;
+                    code.push_str(&format!("    {}", variant.name));
+                    code.push_str(&self.fields_definition(name, &variant.fields, "    ", ""));
+                    code.push_str(",\n");
+                }
+                code.push_str("}\n");
+            },
+            other => panic!(
+                "unsupported content type `{}` for ABI type `{}`",
+                other, name
+            ),
+        }
+        code
+    }
+
+    fn fields_definition(
+        &self,
+        enclosing_type: &str,
+        fields: &[StructFieldDescriptionJson],
+        indent: &str,
+        visibility: &str,
+    ) -> String {
+        let field_type = |field: &StructFieldDescriptionJson| {
+            self.rust_type(
+                &AbiTypeTree::parse(field.field_type.as_str()),
+                ApiContext::TypeDefinition,
+                Some(enclosing_type),
+            )
+        };
+        if fields.is_empty() {
+            String::new()
+        } else if is_named(fields) {
+            let mut code = String::from(" {\n");
+            for field in fields {
+                push_docs(&mut code, field.docs.as_slice(), &format!("{}    ", indent));
+                if is_optional(field) {
+                    code.push_str(&format!("{}    #[codec(optional)]\n", indent));
+                }
+                code.push_str(&format!(
+                    "{}    {}{}: {},\n",
+                    indent,
+                    visibility,
+                    rust_ident(field.name.as_str()),
+                    field_type(field)
+                ));
+            }
+            code.push_str(indent);
+            code.push('}');
+            code
+        } else {
+            let items: Vec<String> = fields
+                .iter()
+                .map(|field| {
+                    let optional_attr = if is_optional(field) {
+                        "#[codec(optional)] "
+                    } else {
+                        ""
+                    };
+                    format!("{}{}{}", optional_attr, visibility, field_type(field))
+                })
+                .collect();
+            format!("({})", items.join(", "))
+        }
+    }
+}
+
+/// Fields named `0`, `1`, ... come from tuple structs and variants.
+fn is_named(fields: &[StructFieldDescriptionJson]) -> bool {
+    fields
+        .iter()
+        .any(|field| field.name.parse::<usize>().is_err())
+}
+
+fn is_optional(field: &StructFieldDescriptionJson) -> bool {
+    field.optional == Some(true)
+}
+
+fn array_len(abi_name: &str) -> Option<usize> {
+    abi_name.strip_prefix("array")?.parse().ok()
+}
+
+fn multi_type_prefix(multi_context: MultiValueContext) -> &'static str {
+    match multi_context {
+        MultiValueContext::Input => "MultiArg",
+        MultiValueContext::Output => "MultiResult",
+    }
+}
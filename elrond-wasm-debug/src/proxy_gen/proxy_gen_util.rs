@@ -0,0 +1,53 @@
+use alloc::{format, string::String};
+
+const RUST_KEYWORDS: &[&str] = &[
+    "as", "async", "await", "break", "const", "continue", "dyn", "else", "enum", "extern", "false",
+    "fn", "for", "if", "impl", "in", "let", "loop", "match", "mod", "move", "mut", "pub", "ref",
+    "return", "static", "struct", "trait", "true", "type", "unsafe", "use", "where", "while",
+    "abstract", "become", "box", "do", "final", "macro", "override", "priv", "try", "typeof",
+    "unsized", "virtual", "yield",
+];
+
+/// Keywords that cannot be used as raw identifiers either.
+const PATH_KEYWORDS: &[&str] = &["crate", "self", "Self", "super"];
+
+/// Names from the ABI that clash with Rust keywords become raw identifiers,
+/// or get a trailing underscore, for the keywords that cannot be raw identifiers.
+pub fn rust_ident(name: &str) -> String {
+    if PATH_KEYWORDS.contains(&name) {
+        format!("{}_", name)
+    } else if RUST_KEYWORDS.contains(&name) {
+        format!("r#{}", name)
+    } else {
+        name.into()
+    }
+}
+
+/// Endpoint names are usually camel case, e.g. `getSum` becomes `get_sum`.
+pub fn snake_case(name: &str) -> String {
+    let mut result = String::new();
+    let mut previous_lowercase = false;
+    for c in name.chars() {
+        if c.is_ascii_uppercase() {
+            if previous_lowercase {
+                result.push('_');
+            }
+            result.push(c.to_ascii_lowercase());
+            previous_lowercase = false;
+        } else {
+            result.push(c);
+            previous_lowercase = c.is_ascii_lowercase() || c.is_ascii_digit();
+        }
+    }
+    result
+}
+
+pub fn push_docs(code: &mut String, docs: &[String], indent: &str) {
+    for line in docs {
+        if line.is_empty() {
+            code.push_str(&format!("{}///\n", indent));
+        } else {
+            code.push_str(&format!("{}/// {}\n", indent, line));
+        }
+    }
+}
@@ -1,5 +1,5 @@
 use crate::{
-    tx_execution::default_execution,
+    tx_execution::{default_execution_with, execute_upgrade_tx_context},
     tx_mock::{BlockchainUpdate, TxCache, TxInput, TxResult},
 };
 
@@ -41,5 +41,5 @@ pub fn execute_upgrade_contract(
         tx_hash: tx_input.tx_hash,
     };
 
-    default_execution(exec_input, tx_cache)
+    default_execution_with(exec_input, tx_cache, execute_upgrade_tx_context)
 }
@@ -1,9 +1,11 @@
 use std::{collections::HashMap, rc::Rc};
 
+use elrond_wasm::types::Address;
+
 use crate::{
     tx_mock::{
-        async_call_tx_input, async_callback_tx_input, merge_results, TxCache, TxContextRef,
-        TxInput, TxResult, TxResultCalls,
+        async_call_tx_input, async_callback_tx_input, is_esdt_return_transfer, merge_results,
+        AsyncCallTxData, TxCache, TxContextRef, TxInput, TxResult, TxResultCalls,
     },
     world_mock::{AccountData, AccountEsdt, BlockchainMock},
 };
@@ -44,18 +46,38 @@ pub fn sc_call_with_async_and_callback(
     increase_nonce: bool,
 ) -> TxResult {
     let contract_address = tx_input.to.clone();
-    let mut tx_result = sc_call(tx_input, state, increase_nonce);
+    let tx_result = sc_call(tx_input, state, increase_nonce);
+    execute_result_calls(contract_address, tx_result, state)
+}
+
+/// Executes the async call and the transfer-execute calls produced by a contract call.
+fn execute_result_calls(
+    contract_address: Address,
+    mut tx_result: TxResult,
+    state: &mut Rc<BlockchainMock>,
+) -> TxResult {
     let result_calls = std::mem::replace(&mut tx_result.result_calls, TxResultCalls::empty());
     if tx_result.result_status == 0 {
         if let Some(async_data) = result_calls.async_call {
             if state.accounts.contains_key(&async_data.to) {
                 let async_input = async_call_tx_input(&async_data);
 
-                let async_result = sc_call_with_async_and_callback(async_input, state, false);
+                let mut async_result = sc_call(async_input, state, false);
+                let esdt_return = take_esdt_return_transfer(&async_data, &mut async_result);
+                let async_result = execute_result_calls(async_data.to.clone(), async_result, state);
 
                 tx_result = merge_results(tx_result, async_result.clone());
 
-                let callback_input = async_callback_tx_input(&async_data, &async_result);
+                if async_result.result_status != 0 {
+                    // the failed call did not take the EGLD, but the callback returns it from the callee
+                    let tx_cache = TxCache::new(state.clone());
+                    tx_cache.subtract_egld_balance(&async_data.from, &async_data.call_value);
+                    tx_cache.increase_egld_balance(&async_data.to, &async_data.call_value);
+                    state.commit_tx_cache(tx_cache);
+                }
+
+                let callback_input =
+                    async_callback_tx_input(&async_data, &async_result, esdt_return.as_ref());
                 let callback_result = sc_call(callback_input, state, false);
                 assert!(
                     tx_result.result_calls.async_call.is_none(),
@@ -90,3 +112,17 @@ pub fn sc_call_with_async_and_callback(
 
     tx_result
 }
+
+fn take_esdt_return_transfer(
+    async_data: &AsyncCallTxData,
+    async_result: &mut TxResult,
+) -> Option<AsyncCallTxData> {
+    match &async_result.result_calls.async_call {
+        Some(nested)
+            if async_result.result_status == 0 && is_esdt_return_transfer(async_data, nested) =>
+        {
+            async_result.result_calls.async_call.take()
+        },
+        _ => None,
+    }
+}
@@ -11,12 +11,28 @@ use crate::{
 /// The endpoint name is taken from the tx context.
 /// Catches and wraps any panics thrown in the contract.
 pub fn execute_tx_context(tx_context_ref: TxContextRef) -> TxResult {
-    let func_name = tx_context_ref.tx_input_box.func_name.as_slice();
+    let func_name = tx_context_ref.tx_input_box.func_name.clone();
+    let contract_instance = new_contract_instance(tx_context_ref);
+    execute_contract_instance_endpoint(contract_instance, func_name.as_slice())
+}
+
+/// Runs the endpoint the VM calls on the new contract code when upgrading it.
+/// That is the constructor, as on the current VM,
+/// except for contracts with an `#[upgrade]` method, which only compile for VM versions that call it instead.
+pub fn execute_upgrade_tx_context(tx_context_ref: TxContextRef) -> TxResult {
+    let contract_instance = new_contract_instance(tx_context_ref);
+    let endpoint_name: &[u8] = if contract_instance.has_upgrade_method() {
+        b"upgrade"
+    } else {
+        b"init"
+    };
+    execute_contract_instance_endpoint(contract_instance, endpoint_name)
+}
+
+fn new_contract_instance(tx_context_ref: TxContextRef) -> Box<dyn CallableContract<DebugApi>> {
     let contract_identifier = get_contract_identifier(&tx_context_ref);
     let contract_map = &tx_context_ref.blockchain_ref().contract_map;
-    let contract_instance =
-        contract_map.new_contract_instance(contract_identifier.as_slice(), tx_context_ref.clone());
-    execute_contract_instance_endpoint(contract_instance, func_name)
+    contract_map.new_contract_instance(contract_identifier.as_slice(), tx_context_ref.clone())
 }
 
 fn get_contract_identifier(tx_context: &TxContext) -> Vec<u8> {
@@ -8,6 +8,18 @@ use crate::{
 use super::execute_tx_context;
 
 pub fn default_execution(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
+    default_execution_with(tx_input, tx_cache, execute_tx_context)
+}
+
+/// Same as `default_execution`, but the contract code is run by `execute_contract`.
+pub fn default_execution_with<F>(
+    tx_input: TxInput,
+    tx_cache: TxCache,
+    execute_contract: F,
+) -> (TxResult, BlockchainUpdate)
+where
+    F: FnOnce(TxContextRef) -> TxResult,
+{
     let tx_context = TxContextRef::new(tx_input, tx_cache);
 
     tx_context.tx_cache.subtract_egld_balance(
@@ -36,7 +48,7 @@ pub fn default_execution(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, Blo
         // direct EGLD transfer
         TxResult::empty()
     } else {
-        execute_tx_context(tx_context.clone())
+        execute_contract(tx_context.clone())
     };
 
     let blockchain_updates = tx_context.into_blockchain_updates();
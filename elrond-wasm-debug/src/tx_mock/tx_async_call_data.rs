@@ -1,5 +1,6 @@
 use crate::tx_mock::{TxInput, TxResult};
 use elrond_wasm::{
+    api::{ESDT_MULTI_TRANSFER_FUNC_NAME, ESDT_NFT_TRANSFER_FUNC_NAME, ESDT_TRANSFER_FUNC_NAME},
     elrond_codec::*,
     types::{Address, H256},
 };
@@ -32,7 +33,32 @@ pub fn async_call_tx_input(async_data: &AsyncCallTxData) -> TxInput {
     }
 }
 
-pub fn async_callback_tx_input(async_data: &AsyncCallTxData, async_result: &TxResult) -> TxInput {
+/// An async call made by the callee back to the original caller, that only transfers ESDT,
+/// is not executed on its own. The tokens arrive together with the callback instead, like in Arwen.
+pub fn is_esdt_return_transfer(async_data: &AsyncCallTxData, nested: &AsyncCallTxData) -> bool {
+    let caller = async_data.from.as_bytes();
+    let args = &nested.arguments;
+    match nested.endpoint_name.as_slice() {
+        ESDT_TRANSFER_FUNC_NAME => nested.to == async_data.from && args.len() == 2,
+        ESDT_NFT_TRANSFER_FUNC_NAME => args.len() == 4 && args[3].as_slice() == caller,
+        ESDT_MULTI_TRANSFER_FUNC_NAME => {
+            args.len() >= 2
+                && args[0].as_slice() == caller
+                && usize::top_decode(args[1].as_slice())
+                    .map(|num_payments| args.len() == 2 + num_payments * 3)
+                    .unwrap_or(false)
+        },
+        _ => false,
+    }
+}
+
+/// If the callee returned ESDT to the caller, the callback is called via the same ESDT builtin function,
+/// so it receives the tokens as call value.
+pub fn async_callback_tx_input(
+    async_data: &AsyncCallTxData,
+    async_result: &TxResult,
+    esdt_return: Option<&AsyncCallTxData>,
+) -> TxInput {
     let mut args: Vec<Vec<u8>> = Vec::new();
     let serialized_bytes = top_encode_to_vec_u8(&async_result.result_status).unwrap();
     args.push(serialized_bytes);
@@ -41,10 +67,35 @@ pub fn async_callback_tx_input(async_data: &AsyncCallTxData, async_result: &TxRe
     } else {
         args.push(async_result.result_message.clone().into_bytes());
     }
+
+    if let Some(esdt_return) = esdt_return {
+        let mut builtin_args = esdt_return.arguments.clone();
+        builtin_args.push(b"callBack".to_vec());
+        builtin_args.append(&mut args);
+        return TxInput {
+            from: esdt_return.from.clone(),
+            to: esdt_return.to.clone(),
+            egld_value: 0u32.into(),
+            esdt_values: Vec::new(),
+            func_name: esdt_return.endpoint_name.clone(),
+            args: builtin_args,
+            gas_limit: 1000,
+            gas_price: 0,
+            tx_hash: async_data.tx_hash.clone(),
+        };
+    }
+
+    // the EGLD sent with a failed async call is returned together with the callback
+    let egld_value = if async_result.result_status == 0 {
+        0u32.into()
+    } else {
+        async_data.call_value.clone()
+    };
+
     TxInput {
         from: async_data.to.clone(),
         to: async_data.from.clone(),
-        egld_value: 0u32.into(),
+        egld_value,
         esdt_values: Vec::new(),
         func_name: b"callBack".to_vec(),
         args,
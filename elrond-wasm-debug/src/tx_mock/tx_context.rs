@@ -1,6 +1,6 @@
 use crate::world_mock::{AccountData, AccountEsdt, BlockchainMock};
 use alloc::vec::Vec;
-use core::cell::RefCell;
+use core::cell::{Cell, RefCell};
 use elrond_wasm::types::Address;
 use num_bigint::BigUint;
 use num_traits::Zero;
@@ -18,6 +18,8 @@ pub struct TxContext {
     pub tx_cache: Rc<TxCache>,
     pub managed_types: RefCell<TxManagedTypes>,
     pub tx_result_cell: RefCell<TxResult>,
+    pub readonly: Cell<bool>,
+    pub held_reentrancy_lock: Cell<Option<&'static [u8]>>,
 }
 
 impl TxContext {
@@ -27,6 +29,8 @@ impl TxContext {
             tx_cache: Rc::new(tx_cache),
             managed_types: RefCell::new(TxManagedTypes::new()),
             tx_result_cell: RefCell::new(TxResult::empty()),
+            readonly: Cell::new(false),
+            held_reentrancy_lock: Cell::new(None),
         }
     }
 
@@ -58,6 +62,8 @@ impl TxContext {
             tx_cache: Rc::new(tx_cache),
             managed_types: RefCell::new(TxManagedTypes::new()),
             tx_result_cell: RefCell::new(TxResult::empty()),
+            readonly: Cell::new(false),
+            held_reentrancy_lock: Cell::new(None),
         }
     }
 
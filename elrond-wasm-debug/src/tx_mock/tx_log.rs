@@ -1,6 +1,11 @@
 use alloc::vec::Vec;
-use elrond_wasm::types::Address;
-use mandos::model::Checkable;
+use elrond_wasm::{
+    elrond_codec::{DecodeError, TopDecode},
+    types::Address,
+};
+use mandos::model::{BytesValue, Checkable};
+
+use crate::verbose_hex;
 
 #[derive(Clone, Debug)]
 pub struct TxLog {
@@ -11,6 +16,47 @@ pub struct TxLog {
 }
 
 impl TxLog {
+    /// Decodes one of the topics. Topic 0 is the event identifier.
+    pub fn decode_topic<T: TopDecode>(&self, index: usize) -> Result<T, DecodeError> {
+        match self.topics.get(index) {
+            Some(topic) => T::top_decode(topic.as_slice()),
+            None => Err(DecodeError::INPUT_TOO_SHORT),
+        }
+    }
+
+    /// Decodes the log data.
+    /// Events with several data fields can be decoded as a tuple of all of them, in order.
+    pub fn decode_data<T: TopDecode>(&self) -> Result<T, DecodeError> {
+        T::top_decode(self.data.as_slice())
+    }
+
+    /// Splits the data into fields as long as the expected ones
+    /// and describes the first field that does not match, if any.
+    pub fn data_fields_mismatch(&self, expected_fields: &[BytesValue]) -> Option<String> {
+        let mut remaining = self.data.as_slice();
+        for (index, expected_field) in expected_fields.iter().enumerate() {
+            let field_len = expected_field.value.len().min(remaining.len());
+            let (actual_field, rest) = remaining.split_at(field_len);
+            if actual_field != expected_field.value.as_slice() {
+                return Some(format!(
+                    "data field {} does not match. Want: {}. Have: {}",
+                    index,
+                    expected_field,
+                    verbose_hex(actual_field)
+                ));
+            }
+            remaining = rest;
+        }
+        if !remaining.is_empty() {
+            return Some(format!(
+                "data has {} more bytes than the expected fields: {}",
+                remaining.len(),
+                verbose_hex(remaining)
+            ));
+        }
+        None
+    }
+
     pub fn mandos_check(&self, check_log: &mandos::model::CheckLog) -> bool {
         if self.address.to_vec() == check_log.address.value
             && check_log.endpoint.check(self.endpoint.as_slice())
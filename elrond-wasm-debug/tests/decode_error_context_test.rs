@@ -0,0 +1,34 @@
+#![cfg(feature = "decode-error-context")]
+
+use elrond_wasm::{load_dyn_arg, ArgId, CallDataArgLoader, HexCallDataDeserializer, Vec};
+use elrond_wasm_debug::{tx_mock::TxPanic, DebugApi};
+
+elrond_wasm::derive_imports!();
+
+// to test, run the following command in elrond-wasm-debug folder:
+// cargo test --features decode-error-context --test decode_error_context_test
+
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, PartialEq, Debug)]
+struct Item {
+    id: u32,
+    tags: Vec<u16>,
+}
+
+#[test]
+fn test_arg_decode_error_context() {
+    let api = DebugApi::dummy();
+    // the second tag is missing a byte
+    let input: &[u8] = b"func@0000000100000002000100";
+    let de = HexCallDataDeserializer::new(input);
+    let mut cd_loader = CallDataArgLoader::new(de, api);
+
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        let _: Item = load_dyn_arg(&mut cd_loader, ArgId::from(&b"item"[..]));
+    }));
+    let tx_panic = result.unwrap_err().downcast::<TxPanic>().unwrap();
+    assert_eq!(tx_panic.status, 4);
+    assert_eq!(
+        String::from_utf8(tx_panic.message).unwrap(),
+        "argument decode error (item): input too short (at tags[1])"
+    );
+}
@@ -1,6 +1,9 @@
 use elrond_wasm::{
+    elrond_codec::DecodeError,
     load_dyn_arg,
-    types::{AsyncCallResult, BigUint, ManagedFrom, MultiArg2, OptionalArg, VarArgs},
+    types::{
+        AsyncCallResult, BigUint, CallbackResult, ManagedFrom, MultiArg2, OptionalArg, VarArgs,
+    },
     ArgId, CallDataArgLoader, DynArgInput, HexCallDataDeserializer,
 };
 use elrond_wasm_debug::DebugApi;
@@ -158,3 +161,99 @@ fn test_async_call_result_err() {
         },
     }
 }
+
+#[test]
+fn test_callback_result_ok() {
+    let api = DebugApi::dummy();
+    let input: &[u8] = b"func@@1111@2222";
+    let de = HexCallDataDeserializer::new(input);
+    let mut cd_loader = CallDataArgLoader::new(de, api);
+    let cr: CallbackResult<DebugApi, MultiArg2<i32, i32>> =
+        load_dyn_arg(&mut cd_loader, ArgId::empty());
+    match cr {
+        CallbackResult::Ok(tuple_arg) => {
+            let tuple = tuple_arg.into_tuple();
+            assert_eq!(tuple.0, 0x1111i32);
+            assert_eq!(tuple.1, 0x2222i32);
+        },
+        _ => {
+            panic!("CallbackResult::Ok expected");
+        },
+    }
+}
+
+#[test]
+fn test_callback_result_callee_error() {
+    let api = DebugApi::dummy();
+    let input: &[u8] = b"func@04@1111";
+    let de = HexCallDataDeserializer::new(input);
+    let mut cd_loader = CallDataArgLoader::new(de, api);
+    let cr: CallbackResult<DebugApi, MultiArg2<i32, i32>> =
+        load_dyn_arg(&mut cd_loader, ArgId::empty());
+    match cr {
+        CallbackResult::CalleeError(async_call_error) => {
+            assert_eq!(async_call_error.err_code, 4);
+            assert_eq!(
+                async_call_error.err_msg.to_boxed_bytes().as_slice(),
+                &[0x11u8, 0x11u8][..]
+            );
+        },
+        _ => {
+            panic!("CallbackResult::CalleeError expected");
+        },
+    }
+}
+
+#[test]
+fn test_callback_result_too_few_results() {
+    let api = DebugApi::dummy();
+    let input: &[u8] = b"func@@1111";
+    let de = HexCallDataDeserializer::new(input);
+    let mut cd_loader = CallDataArgLoader::new(de, api);
+    let cr: CallbackResult<DebugApi, MultiArg2<i32, i32>> =
+        load_dyn_arg(&mut cd_loader, ArgId::empty());
+    match cr {
+        CallbackResult::DecodeError { raw_results, error } => {
+            assert_eq!(raw_results.len(), 1);
+            assert_eq!(
+                error.message_bytes(),
+                DecodeError::INPUT_TOO_SHORT.message_bytes()
+            );
+        },
+        _ => {
+            panic!("CallbackResult::DecodeError expected");
+        },
+    }
+}
+
+#[test]
+fn test_callback_result_too_many_results() {
+    let api = DebugApi::dummy();
+    let input: &[u8] = b"func@@1111@2222@3333";
+    let de = HexCallDataDeserializer::new(input);
+    let mut cd_loader = CallDataArgLoader::new(de, api);
+    let cr: CallbackResult<DebugApi, MultiArg2<i32, i32>> =
+        load_dyn_arg(&mut cd_loader, ArgId::empty());
+    match cr {
+        CallbackResult::DecodeError { raw_results, error } => {
+            assert_eq!(raw_results.len(), 3);
+            assert_eq!(
+                error.message_bytes(),
+                DecodeError::INPUT_TOO_LONG.message_bytes()
+            );
+        },
+        _ => {
+            panic!("CallbackResult::DecodeError expected");
+        },
+    }
+}
+
+#[test]
+fn test_callback_result_bad_value() {
+    let api = DebugApi::dummy();
+    let input: &[u8] = b"func@@0102030405";
+    let de = HexCallDataDeserializer::new(input);
+    let mut cd_loader = CallDataArgLoader::new(de, api);
+    let cr: CallbackResult<DebugApi, u32> = load_dyn_arg(&mut cd_loader, ArgId::empty());
+    assert!(matches!(cr, CallbackResult::DecodeError { .. }));
+}
@@ -0,0 +1,175 @@
+use elrond_wasm::{
+    api::ManagedTypeApi,
+    types::{BigUint, ManagedBuffer, ManagedFrom, ManagedVec, ManagedVecItem, TokenIdentifier},
+};
+use elrond_wasm_debug::{check_managed_top_encode_decode, DebugApi};
+
+elrond_wasm::derive_imports!();
+
+#[derive(
+    ManagedVecItem, NestedEncode, NestedDecode, TopEncode, TopDecode, PartialEq, Clone, Debug,
+)]
+pub enum OrderStatus {
+    Open,
+    #[codec(discriminant = 5)]
+    Filled,
+}
+
+#[derive(
+    ManagedVecItem, NestedEncode, NestedDecode, TopEncode, TopDecode, PartialEq, Clone, Debug,
+)]
+pub struct Point {
+    pub x: i32,
+    pub y: u16,
+    pub visible: bool,
+}
+
+#[derive(ManagedVecItem)]
+pub struct Dimensions {
+    pub width: u16,
+    pub height: u16,
+}
+
+#[derive(ManagedVecItem, NestedEncode, NestedDecode, TopEncode, TopDecode, Clone, Debug)]
+pub struct Order<M: ManagedTypeApi> {
+    pub id: u64,
+    pub token: TokenIdentifier<M>,
+    pub amount: BigUint<M>,
+    pub status: OrderStatus,
+    pub position: Point,
+}
+
+// derived, it would require `M: PartialEq`
+impl<M: ManagedTypeApi> PartialEq for Order<M> {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+            && self.token == other.token
+            && self.amount == other.amount
+            && self.status == other.status
+            && self.position == other.position
+    }
+}
+
+#[derive(ManagedVecItem, NestedEncode, NestedDecode, TopEncode, TopDecode, Debug)]
+pub struct OrderBook<M: ManagedTypeApi>(ManagedBuffer<M>, ManagedVec<M, Order<M>>);
+
+fn order(api: DebugApi, id: u64, status: OrderStatus) -> Order<DebugApi> {
+    Order {
+        id,
+        token: TokenIdentifier::from_esdt_bytes(api.clone(), &b"TOKEN-123456"[..]),
+        amount: BigUint::managed_from(api, id * 1000),
+        status,
+        position: Point {
+            x: -(id as i32),
+            y: 7,
+            visible: true,
+        },
+    }
+}
+
+// checked at compile time
+const _: () = assert!(!<Dimensions as ManagedVecItem<DebugApi>>::NEEDS_RESERIALIZATION);
+// because of the bool field
+const _: () = assert!(<Point as ManagedVecItem<DebugApi>>::NEEDS_RESERIALIZATION);
+const _: () = assert!(<OrderStatus as ManagedVecItem<DebugApi>>::NEEDS_RESERIALIZATION);
+const _: () = assert!(<Order<DebugApi> as ManagedVecItem<DebugApi>>::NEEDS_RESERIALIZATION);
+
+#[test]
+fn test_payload_size() {
+    assert_eq!(<OrderStatus as ManagedVecItem<DebugApi>>::PAYLOAD_SIZE, 1);
+    assert_eq!(<Point as ManagedVecItem<DebugApi>>::PAYLOAD_SIZE, 7);
+    assert_eq!(
+        <Order<DebugApi> as ManagedVecItem<DebugApi>>::PAYLOAD_SIZE,
+        8 + 4 + 4 + 1 + 7
+    );
+    assert_eq!(
+        <OrderBook<DebugApi> as ManagedVecItem<DebugApi>>::PAYLOAD_SIZE,
+        8
+    );
+}
+
+#[test]
+fn test_managed_vec_of_structs() {
+    let api = DebugApi::dummy();
+
+    let mut managed_vec = ManagedVec::new(api.clone());
+    for id in 1..=5 {
+        managed_vec.push(order(api.clone(), id, OrderStatus::Open));
+    }
+    managed_vec.push(order(api.clone(), 6, OrderStatus::Filled));
+    assert_eq!(managed_vec.len(), 6);
+
+    assert_eq!(
+        managed_vec.get(2),
+        Some(order(api.clone(), 3, OrderStatus::Open))
+    );
+    assert_eq!(
+        managed_vec.get(5),
+        Some(order(api.clone(), 6, OrderStatus::Filled))
+    );
+    assert_eq!(managed_vec.get(6), None);
+
+    let ids: Vec<u64> = managed_vec.iter().rev().map(|order| order.id).collect();
+    assert_eq!(ids, vec![6, 5, 4, 3, 2, 1]);
+    let amounts: Vec<u64> = managed_vec
+        .iter()
+        .map(|order| order.amount.to_u64().unwrap())
+        .collect();
+    assert_eq!(amounts, vec![1000, 2000, 3000, 4000, 5000, 6000]);
+}
+
+#[test]
+fn test_managed_vec_of_structs_serialization() {
+    let api = DebugApi::dummy();
+
+    let mut managed_vec = ManagedVec::new(api.clone());
+    managed_vec.push(order(api.clone(), 1, OrderStatus::Filled));
+
+    #[rustfmt::skip]
+    let expected: &[u8] = &[
+        /* id */ 0, 0, 0, 0, 0, 0, 0, 1,
+        /* token */ 0, 0, 0, 12, b'T', b'O', b'K', b'E', b'N', b'-', b'1', b'2', b'3', b'4', b'5', b'6',
+        /* amount */ 0, 0, 0, 2, 0x03, 0xe8,
+        /* status */ 5,
+        /* position */ 0xff, 0xff, 0xff, 0xff, 0, 7, 1,
+    ];
+    check_managed_top_encode_decode(api.clone(), managed_vec.clone(), expected);
+
+    let book = OrderBook(
+        ManagedBuffer::new_from_bytes(api.clone(), b"book"),
+        managed_vec,
+    );
+    let mut books = ManagedVec::new(api.clone());
+    books.push(book);
+    let decoded = books.get(0).unwrap();
+    assert_eq!(
+        decoded.0,
+        ManagedBuffer::new_from_bytes(api.clone(), b"book")
+    );
+    assert_eq!(decoded.1.get(0), Some(order(api, 1, OrderStatus::Filled)));
+}
+
+#[test]
+fn test_managed_vec_of_plain_structs() {
+    let api = DebugApi::dummy();
+
+    let mut managed_vec = ManagedVec::new(api.clone());
+    managed_vec.push(Point {
+        x: 1,
+        y: 2,
+        visible: false,
+    });
+    managed_vec.push(Point {
+        x: -1,
+        y: 3,
+        visible: true,
+    });
+
+    // the payload is the same as the encoding, the buffer is used as-is
+    #[rustfmt::skip]
+    let expected: &[u8] = &[
+        0, 0, 0, 1, 0, 2, 0,
+        0xff, 0xff, 0xff, 0xff, 0, 3, 1,
+    ];
+    check_managed_top_encode_decode(api, managed_vec, expected);
+}
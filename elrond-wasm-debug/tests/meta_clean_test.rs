@@ -0,0 +1,88 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use elrond_wasm::abi::*;
+use elrond_wasm_debug::meta::{clean, generated_file_names, MetaArgs, OutputContractConfig};
+
+fn contract_abi() -> ContractAbi {
+    ContractAbi {
+        build_info: BuildInfoAbi {
+            contract_crate: ContractCrateBuildAbi {
+                name: "sample",
+                version: "0.0.0",
+            },
+            framework: FrameworkBuildAbi::create(),
+        },
+        docs: &[],
+        name: "Sample",
+        constructor: None,
+        upgrade_constructor: None,
+        endpoints: Vec::new(),
+        storage: Vec::new(),
+        events: Vec::new(),
+        type_descriptions: TypeDescriptionContainerImpl::new(),
+    }
+}
+
+fn output_dir(test_name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!(
+        "meta_clean_test_{}_{}",
+        test_name,
+        std::process::id()
+    ));
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+fn clean_args(output_dir: &Path) -> MetaArgs {
+    MetaArgs {
+        output_dir: output_dir.to_str().unwrap().to_string(),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_generated_file_names() {
+    let config = OutputContractConfig::default_config(&contract_abi());
+    assert_eq!(
+        generated_file_names(&config),
+        vec![
+            "sample.abi.json",
+            "sample.wasm",
+            "sample.wat",
+            "sample.snippets.sh",
+            "mandos_rs_test.rs",
+            "mandos_go_test.rs",
+        ]
+    );
+}
+
+#[test]
+fn test_clean_keeps_other_files() {
+    let config = OutputContractConfig::default_config(&contract_abi());
+    let dir = output_dir("keeps_other_files");
+    fs::write(dir.join("sample.abi.json"), "{}").unwrap();
+    fs::write(dir.join("sample.wasm"), "").unwrap();
+    fs::write(dir.join("notes.txt"), "not generated").unwrap();
+
+    clean(&config, &clean_args(&dir));
+
+    assert!(!dir.join("sample.abi.json").exists());
+    assert!(!dir.join("sample.wasm").exists());
+    assert!(dir.join("notes.txt").exists());
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn test_clean_removes_empty_output_dir() {
+    let config = OutputContractConfig::default_config(&contract_abi());
+    let dir = output_dir("removes_empty_output_dir");
+    fs::write(dir.join("sample.abi.json"), "{}").unwrap();
+    fs::write(dir.join("mandos_rs_test.rs"), "").unwrap();
+
+    clean(&config, &clean_args(&dir));
+
+    assert!(!dir.exists());
+}
@@ -0,0 +1,94 @@
+use elrond_wasm_debug::meta::{
+    mandos_go_test_source, mandos_rs_test_source, scenario_names, MetaArgs, MetaCommand,
+};
+
+fn parse(args: &[&str]) -> Result<MetaArgs, String> {
+    let args: Vec<String> = args.iter().map(|arg| arg.to_string()).collect();
+    MetaArgs::parse(args.as_slice())
+}
+
+#[test]
+fn test_no_args_means_abi() {
+    assert_eq!(parse(&[]), Ok(MetaArgs::default()));
+    assert_eq!(MetaArgs::default().command, MetaCommand::Abi);
+    assert_eq!(MetaArgs::default().output_dir, "../output");
+}
+
+#[test]
+fn test_build_with_options() {
+    let args = parse(&[
+        "build",
+        "--output-dir",
+        "out",
+        "--target-dir=/tmp/target",
+        "--wasm-opt",
+        "--wat",
+    ])
+    .unwrap();
+    assert_eq!(
+        args,
+        MetaArgs {
+            command: MetaCommand::Build,
+            output_dir: "out".to_string(),
+            target_dir: Some("/tmp/target".to_string()),
+            mandos_dir: "../mandos".to_string(),
+            wasm_opt: true,
+            wat: true,
+        }
+    );
+}
+
+#[test]
+fn test_options_before_command() {
+    let args = parse(&["--mandos-dir", "scenarios", "test-gen"]).unwrap();
+    assert_eq!(args.command, MetaCommand::TestGen);
+    assert_eq!(args.mandos_dir, "scenarios");
+}
+
+#[test]
+fn test_invalid_args() {
+    assert_eq!(
+        parse(&["deploy"]),
+        Err("unknown argument: deploy".to_string())
+    );
+    assert_eq!(
+        parse(&["abi", "clean"]),
+        Err("unexpected command: clean".to_string())
+    );
+    assert_eq!(
+        parse(&["build", "--output-dir"]),
+        Err("missing value for --output-dir".to_string())
+    );
+}
+
+#[test]
+fn test_gen_adder() {
+    let names = scenario_names("../contracts/examples/adder/mandos");
+    assert_eq!(names, vec!["adder".to_string()]);
+
+    assert_eq!(
+        mandos_rs_test_source(names.as_slice()),
+        r#"#[test]
+fn adder_rs() {
+    elrond_wasm_debug::mandos_rs("mandos/adder.scen.json", contract_map());
+}
+"#
+    );
+    assert_eq!(
+        mandos_go_test_source(names.as_slice()),
+        r#"#[test]
+fn adder_go() {
+    elrond_wasm_debug::mandos_go("mandos/adder.scen.json");
+}
+"#
+    );
+}
+
+#[test]
+fn test_gen_names() {
+    let names = vec!["multi-transfer".to_string(), "Simple.Call".to_string()];
+    let source = mandos_go_test_source(names.as_slice());
+    assert!(source.contains("fn multi_transfer_go() {"));
+    assert!(source.contains("fn simple_call_go() {"));
+    assert!(source.contains("\"mandos/Simple.Call.scen.json\""));
+}
@@ -0,0 +1,279 @@
+use elrond_wasm::abi::*;
+use elrond_wasm_debug::meta::validate_abi;
+
+fn endpoint(
+    name: &'static str,
+    rust_method_name: &'static str,
+    module_name: &'static str,
+) -> EndpointAbi {
+    EndpointAbi {
+        docs: &[],
+        name,
+        rust_method_name,
+        module_name,
+        only_owner: false,
+        only_role: None,
+        labels: &[],
+        mutability: EndpointMutabilityAbi::Mutable,
+        payable_in_tokens: &[],
+        inputs: Vec::new(),
+        outputs: Vec::new(),
+    }
+}
+
+fn event(
+    identifier: &'static str,
+    rust_method_name: &'static str,
+    module_name: &'static str,
+) -> EventAbi {
+    EventAbi {
+        docs: &[],
+        identifier,
+        rust_method_name,
+        module_name,
+        inputs: Vec::new(),
+    }
+}
+
+fn storage(
+    key: &'static str,
+    mapper: Option<&'static str>,
+    rust_method_name: &'static str,
+    module_name: &'static str,
+) -> StorageAbi {
+    StorageAbi {
+        docs: &[],
+        key,
+        rust_method_name,
+        module_name,
+        mapper,
+        key_args: Vec::new(),
+        value_types: vec!["BigUint".into()],
+    }
+}
+
+fn with_key_arg(mut storage: StorageAbi, arg_name: &'static str, type_name: &str) -> StorageAbi {
+    storage.key_args.push(InputAbi {
+        arg_name,
+        type_name: type_name.into(),
+        multi_arg: false,
+    });
+    storage
+}
+
+fn contract_abi() -> ContractAbi {
+    ContractAbi {
+        build_info: BuildInfoAbi {
+            contract_crate: ContractCrateBuildAbi {
+                name: "sample",
+                version: "0.0.0",
+            },
+            framework: FrameworkBuildAbi::create(),
+        },
+        docs: &[],
+        name: "Sample",
+        constructor: Some(endpoint("init", "init", "Sample")),
+        upgrade_constructor: None,
+        endpoints: vec![endpoint("getSum", "sum", "Sample")],
+        storage: vec![storage("sum", Some("SingleValueMapper"), "sum", "Sample")],
+        events: vec![event("sum_changed", "sum_changed_event", "Sample")],
+        type_descriptions: TypeDescriptionContainerImpl::new(),
+    }
+}
+
+#[test]
+fn test_valid() {
+    assert_eq!(validate_abi(&contract_abi()), Ok(()));
+}
+
+#[test]
+fn test_missing_constructor() {
+    let mut abi = contract_abi();
+    abi.constructor = None;
+    assert!(validate_abi(&abi).is_err());
+}
+
+#[test]
+fn test_duplicate_endpoint_name() {
+    let mut abi = contract_abi();
+    abi.endpoints
+        .push(endpoint("getSum", "get_sum", "SumModule"));
+    assert_eq!(
+        validate_abi(&abi),
+        Err("Duplicate endpoint name `getSum` (method `sum` in `Sample` and method `get_sum` in `SumModule`)".to_string())
+    );
+}
+
+#[test]
+fn test_callback_endpoint_name() {
+    let mut abi = contract_abi();
+    abi.endpoints
+        .push(endpoint("callBack", "call_back", "CallbackModule"));
+    assert_eq!(
+        validate_abi(&abi),
+        Err(
+            "Endpoint name `callBack` is reserved (method `call_back` in `CallbackModule`)"
+                .to_string()
+        )
+    );
+}
+
+#[test]
+fn test_upgrade_endpoint_name() {
+    let mut abi = contract_abi();
+    abi.endpoints
+        .push(endpoint("upgrade", "upgrade_endpoint", "UpgradeModule"));
+    assert_eq!(validate_abi(&abi), Ok(()));
+
+    abi.upgrade_constructor = Some(endpoint("upgrade", "upgrade", "Sample"));
+    assert_eq!(
+        validate_abi(&abi),
+        Err(
+            "Endpoint name `upgrade` is reserved (method `upgrade_endpoint` in `UpgradeModule`)"
+                .to_string()
+        )
+    );
+}
+
+#[test]
+fn test_duplicate_event_identifier() {
+    let mut abi = contract_abi();
+    abi.events
+        .push(event("sum_changed", "sum_event", "SumModule"));
+    assert_eq!(
+        validate_abi(&abi),
+        Err("Duplicate event identifier `sum_changed` (method `sum_changed_event` in `Sample` and method `sum_event` in `SumModule`)".to_string())
+    );
+}
+
+#[test]
+fn test_getter_and_setter_share_key() {
+    let mut abi = contract_abi();
+    abi.storage
+        .push(storage("sum", None, "get_sum", "SumGetModule"));
+    abi.storage
+        .push(storage("sum", None, "set_sum", "SumSetModule"));
+    assert_eq!(validate_abi(&abi), Ok(()));
+}
+
+#[test]
+fn test_getter_with_different_type() {
+    let mut abi = contract_abi();
+    let mut getter = storage("sum", None, "get_sum", "SumModule");
+    getter.value_types = vec!["u64".into()];
+    abi.storage.push(getter);
+    assert_eq!(
+        validate_abi(&abi),
+        Err("Storage key `sum` declared more than once (method `sum` in `Sample` and method `get_sum` in `SumModule`)".to_string())
+    );
+}
+
+#[test]
+fn test_mappers_share_key() {
+    let mut abi = contract_abi();
+    abi.storage.push(storage(
+        "sum",
+        Some("SingleValueMapper"),
+        "sum_mapper",
+        "SumModule",
+    ));
+    assert_eq!(
+        validate_abi(&abi),
+        Err("Storage key `sum` declared more than once (method `sum` in `Sample` and method `sum_mapper` in `SumModule`)".to_string())
+    );
+}
+
+#[test]
+fn test_mapper_key_overlap() {
+    let mut abi = contract_abi();
+    abi.storage
+        .push(storage("list", Some("VecMapper"), "list", "Sample"));
+    abi.storage
+        .push(storage("list.len", None, "list_len", "ListModule"));
+    assert_eq!(
+        validate_abi(&abi),
+        Err("Storage key `list.len` (method `list_len` in `ListModule`) overlaps with the keys of the VecMapper with base key `list` (method `list` in `Sample`)".to_string())
+    );
+}
+
+#[test]
+fn test_user_mapper_key_overlap() {
+    let mut abi = contract_abi();
+    abi.storage
+        .push(storage("user", Some("UserMapper"), "users", "UsersModule"));
+    abi.storage
+        .push(storage("userStatus", None, "user_status", "Sample"));
+    assert_eq!(validate_abi(&abi), Ok(()));
+
+    abi.storage
+        .push(storage("user_count", None, "user_count", "Sample"));
+    assert!(validate_abi(&abi).is_err());
+}
+
+#[test]
+fn test_key_args_prefix_overlap() {
+    let mut abi = contract_abi();
+    abi.storage.push(with_key_arg(
+        storage("reward", None, "reward", "Sample"),
+        "token",
+        "TokenIdentifier",
+    ));
+    abi.storage
+        .push(storage("supply", None, "supply", "Sample"));
+    assert_eq!(validate_abi(&abi), Ok(()));
+
+    abi.storage
+        .push(storage("rewardLimit", None, "reward_limit", "LimitModule"));
+    assert_eq!(
+        validate_abi(&abi),
+        Err("Storage key `rewardLimit` (method `reward_limit` in `LimitModule`) overlaps with the keys built from base key `reward` and key arguments (method `reward` in `Sample`)".to_string())
+    );
+}
+
+#[test]
+fn test_fixed_width_key_args_prefix() {
+    let mut abi = contract_abi();
+    abi.storage.push(with_key_arg(
+        storage("kitty", None, "kitty", "Sample"),
+        "kitty_id",
+        "u32",
+    ));
+    abi.storage.push(storage(
+        "kittyAuctionContractAddress",
+        None,
+        "kitty_auction_contract_address",
+        "Sample",
+    ));
+    assert_eq!(validate_abi(&abi), Ok(()));
+
+    abi.storage
+        .push(storage("kittyName", None, "kitty_name", "NameModule"));
+    assert_eq!(
+        validate_abi(&abi),
+        Err("Storage key `kittyName` (method `kitty_name` in `NameModule`) overlaps with the keys built from base key `kitty` and key arguments (method `kitty` in `Sample`)".to_string())
+    );
+}
+
+#[test]
+fn test_keyed_mapper_suffix_after_args() {
+    let mut abi = contract_abi();
+    abi.storage.push(with_key_arg(
+        storage("list", Some("VecMapper"), "list", "Sample"),
+        "owner",
+        "Address",
+    ));
+    abi.storage
+        .push(storage("list.len", None, "list_len", "ListModule"));
+    assert_eq!(validate_abi(&abi), Ok(()));
+
+    abi.storage.push(storage(
+        "listItemsOfTheFirstThirtyTwoOwnersByIndex",
+        None,
+        "list_of_first_owners_items",
+        "ListModule",
+    ));
+    assert_eq!(
+        validate_abi(&abi),
+        Err("Storage key `listItemsOfTheFirstThirtyTwoOwnersByIndex` (method `list_of_first_owners_items` in `ListModule`) overlaps with the keys built from base key `list` and key arguments (method `list` in `Sample`)".to_string())
+    );
+}
@@ -0,0 +1,123 @@
+//! The ABI check of `#[elrond_wasm::proxy(abi = "...")]` names the proxy types from their syntax,
+//! mirroring the `TypeAbi::type_name` implementations of the framework types.
+//! The proxy below only compiles if those names match the ABI file,
+//! the test checks that the ABI file matches the actual `TypeAbi::type_name`.
+//! Types are declared as endpoint arguments, except the ones that can only be endpoint results.
+//! `Result` and `AsyncCallResult` are neither, so they cannot appear in a proxy.
+
+extern crate alloc;
+
+macro_rules! type_name_endpoints {
+    (
+        inputs { $($input_endpoint:ident: $input_ty:ty,)* }
+        outputs { $($output_endpoint:ident: $output_ty:ty,)* }
+    ) => {
+        #[allow(dead_code)]
+        mod checked_proxy {
+            elrond_wasm::imports!();
+
+            use alloc::collections::{BTreeMap, BTreeSet};
+
+            #[elrond_wasm::proxy(abi = "tests/proxy_abi_type_names.abi.json")]
+            pub trait TypeNames {
+                $(
+                    #[endpoint]
+                    fn $input_endpoint(&self, value: $input_ty);
+                )*
+                $(
+                    #[endpoint]
+                    fn $output_endpoint(&self) -> $output_ty;
+                )*
+            }
+        }
+
+        /// The same types, with the managed types bound to the debug API.
+        mod debug_types {
+            use alloc::collections::{BTreeMap, BTreeSet};
+            use elrond_wasm::{
+                abi::TypeAbi,
+                types::{
+                    Address, BoxedBytes, MultiArg2, MultiArgVec, MultiResult2,
+                    MultiResultVec, OptionalArg, OptionalResult, Queue, SCResult, VarArgs,
+                },
+            };
+            use elrond_wasm_debug::DebugApi;
+
+            type ManagedBuffer = elrond_wasm::types::ManagedBuffer<DebugApi>;
+            type ManagedAddress = elrond_wasm::types::ManagedAddress<DebugApi>;
+            type ManagedByteArray<const N: usize> = elrond_wasm::types::ManagedByteArray<DebugApi, N>;
+            type ManagedVec<T> = elrond_wasm::types::ManagedVec<DebugApi, T>;
+            type ManagedVarArgs<T> = elrond_wasm::types::ManagedMultiResultVec<DebugApi, T>;
+            type ManagedMultiResultVec<T> = elrond_wasm::types::ManagedMultiResultVec<DebugApi, T>;
+            type ManagedAsyncCallResult<T> = elrond_wasm::types::ManagedAsyncCallResult<DebugApi, T>;
+
+            pub fn type_names() -> Vec<(&'static str, String)> {
+                vec![
+                    $((stringify!($input_endpoint), <$input_ty as TypeAbi>::type_name()),)*
+                    $((stringify!($output_endpoint), <$output_ty as TypeAbi>::type_name()),)*
+                ]
+            }
+        }
+    };
+}
+
+type_name_endpoints! {
+    inputs {
+        type_usize: usize,
+        type_isize: isize,
+        type_str: &'static str,
+        type_string: String,
+        type_box_str: Box<str>,
+        type_managed_buffer: ManagedBuffer,
+        type_boxed_bytes: BoxedBytes,
+        type_managed_address: ManagedAddress,
+        type_address: Address,
+        type_managed_byte_array: ManagedByteArray<32>,
+        type_box: Box<u32>,
+        type_vec: Vec<u32>,
+        type_vec_u8: Vec<u8>,
+        type_managed_vec: ManagedVec<u32>,
+        type_managed_vec_u8: ManagedVec<u8>,
+        type_boxed_slice: Box<[u8]>,
+        type_array: [u8; 4],
+        type_tuple: (u32, bool),
+        type_option: Option<u32>,
+        type_btree_set: BTreeSet<u32>,
+        type_btree_map: BTreeMap<u32, bool>,
+        type_queue: Queue<u32>,
+        type_var_args: VarArgs<u32>,
+        type_multi_arg_vec: MultiArgVec<u32>,
+        type_multi_result_vec: MultiResultVec<u32>,
+        type_managed_var_args: ManagedVarArgs<u32>,
+        type_managed_multi_result_vec: ManagedMultiResultVec<u32>,
+        type_optional_arg: OptionalArg<u32>,
+        type_optional_result: OptionalResult<u32>,
+        type_managed_async_call_result: ManagedAsyncCallResult<u32>,
+        type_multi_arg: MultiArg2<u32, bool>,
+        type_multi_result: MultiResult2<u32, bool>,
+    }
+    outputs {
+        type_sc_result: SCResult<u32>,
+    }
+}
+
+#[test]
+fn proxy_abi_type_names_test() {
+    let abi_json: serde_json::Value =
+        serde_json::from_str(include_str!("proxy_abi_type_names.abi.json")).unwrap();
+    let abi_endpoints = abi_json["endpoints"].as_array().unwrap();
+    let type_names = debug_types::type_names();
+    assert_eq!(abi_endpoints.len(), type_names.len());
+    for (endpoint_name, type_name) in type_names {
+        let abi_endpoint = abi_endpoints
+            .iter()
+            .find(|endpoint| endpoint["name"] == endpoint_name)
+            .unwrap();
+        let abi_type = if abi_endpoint["inputs"][0].is_null() {
+            &abi_endpoint["outputs"][0]["type"]
+        } else {
+            &abi_endpoint["inputs"][0]["type"]
+        };
+        assert_eq!(abi_type, &type_name, "endpoint `{}`", endpoint_name);
+    }
+}
@@ -0,0 +1,149 @@
+use elrond_wasm_debug::{
+    abi_json::deserialize_abi_from_json,
+    proxy_gen::{proxy_source, AbiTypeTree},
+};
+
+#[test]
+fn abi_type_tree_parse_test() {
+    let tree = AbiTypeTree::parse("variadic<multi<utf-8 string,List<array3<u8>>>>");
+    assert_eq!(tree.name, "variadic");
+    let multi = &tree.args[0];
+    assert_eq!(multi.name, "multi");
+    assert_eq!(multi.args.len(), 2);
+    assert_eq!(multi.args[0], AbiTypeTree::parse("utf-8 string"));
+    assert_eq!(multi.args[1].name, "List");
+    assert_eq!(multi.args[1].args[0].name, "array3");
+    assert_eq!(multi.args[1].args[0].args[0].name, "u8");
+}
+
+#[test]
+#[should_panic(expected = "unclosed `<` in ABI type `Option<u32`")]
+fn abi_type_tree_unclosed_test() {
+    let _ = AbiTypeTree::parse("Option<u32");
+}
+
+const SAMPLE_ABI: &str = r#"{
+    "buildInfo": {
+        "rustc": {
+            "version": "",
+            "commitHash": "",
+            "commitDate": "",
+            "channel": "",
+            "short": ""
+        },
+        "contractCrate": { "name": "sample", "version": "0.0.0" },
+        "framework": { "name": "elrond-wasm", "version": "0.0.0" }
+    },
+    "name": "Sample",
+    "endpoints": [
+        {
+            "name": "getTypeInfo",
+            "mutability": "readonly",
+            "inputs": [ { "name": "type", "type": "Info" }, { "name": "self", "type": "u32" } ],
+            "outputs": [ { "type": "Option<Info>" } ]
+        }
+    ],
+    "types": {
+        "Info": {
+            "type": "struct",
+            "fields": [
+                { "name": "ref", "type": "BigUint" },
+                { "name": "crate", "type": "u32" },
+                { "name": "children", "type": "List<Info>" }
+            ]
+        }
+    }
+}"#;
+
+#[test]
+fn proxy_names_test() {
+    let abi = deserialize_abi_from_json(SAMPLE_ABI).unwrap();
+    let proxy = proxy_source(&abi);
+    assert!(proxy.contains(
+        "    #[view(getTypeInfo)]\n    fn get_type_info(&self, r#type: Info<Self::Api>, self_: u32) -> Option<Info<Self::Api>>;\n"
+    ));
+    assert!(proxy.contains("pub struct Info<M: ManagedTypeApi> {\n"));
+    assert!(proxy.contains("    pub r#ref: BigUint<M>,\n"));
+    assert!(proxy.contains("    pub crate_: u32,\n"));
+    // no need to box, lists are already indirect
+    assert!(proxy.contains("    pub children: Vec<Info<M>>,\n"));
+}
+
+const UPGRADE_ABI: &str = r#"{
+    "buildInfo": {
+        "rustc": {
+            "version": "",
+            "commitHash": "",
+            "commitDate": "",
+            "channel": "",
+            "short": ""
+        },
+        "contractCrate": { "name": "sample", "version": "0.0.0" },
+        "framework": { "name": "elrond-wasm", "version": "0.0.0" }
+    },
+    "name": "Sample",
+    "constructor": {
+        "inputs": [ { "name": "initial_value", "type": "u64" } ],
+        "outputs": []
+    },
+    "upgradeConstructor": {
+        "payableInTokens": [ "EGLD" ],
+        "inputs": [ { "name": "new_value", "type": "u64" } ],
+        "outputs": []
+    },
+    "endpoints": [],
+    "types": {}
+}"#;
+
+#[test]
+fn proxy_upgrade_test() {
+    let abi = deserialize_abi_from_json(UPGRADE_ABI).unwrap();
+    let proxy = proxy_source(&abi);
+    assert!(proxy.contains("    #[init]\n    fn init(&self, initial_value: u64);\n"));
+    assert!(proxy.contains(
+        "    #[upgrade]\n    #[payable(\"EGLD\")]\n    fn upgrade(&self, #[payment] payment: BigUint, new_value: u64);\n"
+    ));
+}
+
+const TOKEN_LIST_ABI: &str = r#"{
+    "buildInfo": {
+        "rustc": {
+            "version": "",
+            "commitHash": "",
+            "commitDate": "",
+            "channel": "",
+            "short": ""
+        },
+        "contractCrate": { "name": "sample", "version": "0.0.0" },
+        "framework": { "name": "elrond-wasm", "version": "0.0.0" }
+    },
+    "name": "Sample",
+    "endpoints": [
+        {
+            "name": "deposit",
+            "mutability": "mutable",
+            "payableInTokens": [ "EGLD", "TOK-123456" ],
+            "inputs": [],
+            "outputs": []
+        },
+        {
+            "name": "depositAccepted",
+            "mutability": "mutable",
+            "payableInTokens": [ "token_from:accepted_token" ],
+            "inputs": [],
+            "outputs": []
+        }
+    ],
+    "types": {}
+}"#;
+
+#[test]
+fn proxy_token_list_test() {
+    let abi = deserialize_abi_from_json(TOKEN_LIST_ABI).unwrap();
+    let proxy = proxy_source(&abi);
+    // token lists and tokens read at runtime fall back to any token in the proxy
+    assert!(proxy.contains("    #[endpoint]\n    #[payable(\"*\")]\n    fn deposit(\n"));
+    assert!(proxy.contains(
+        "    #[endpoint(depositAccepted)]\n    #[payable(\"*\")]\n    fn deposit_accepted(\n"
+    ));
+}
@@ -85,11 +85,15 @@ mod module_1 {
         type Api = elrond_wasm::api::uncallable::UncallableApi;
 
         fn abi() -> elrond_wasm::abi::ContractAbi {
-            let mut contract_abi = elrond_wasm :: abi :: ContractAbi { build_info : elrond_wasm :: abi :: BuildInfoAbi { contract_crate : elrond_wasm :: abi :: ContractCrateBuildAbi { name : "adder" , version : "0.0.0" , } , framework : elrond_wasm :: abi :: FrameworkBuildAbi :: create () , } , docs : & ["One of the simplest smart contracts possible," , "it holds a single variable in storage, which anyone can increment."] , name : "Adder" , constructor : None , endpoints : Vec :: new () , type_descriptions : < elrond_wasm :: abi :: TypeDescriptionContainerImpl as elrond_wasm :: abi :: TypeDescriptionContainer > :: new () , } ;
+            let mut contract_abi = elrond_wasm :: abi :: ContractAbi { build_info : elrond_wasm :: abi :: BuildInfoAbi { contract_crate : elrond_wasm :: abi :: ContractCrateBuildAbi { name : "adder" , version : "0.0.0" , } , framework : elrond_wasm :: abi :: FrameworkBuildAbi :: create () , } , docs : & ["One of the simplest smart contracts possible," , "it holds a single variable in storage, which anyone can increment."] , name : "Adder" , constructor : None , upgrade_constructor : None , endpoints : Vec :: new () , storage : Vec :: new () , events : Vec :: new () , type_descriptions : < elrond_wasm :: abi :: TypeDescriptionContainerImpl as elrond_wasm :: abi :: TypeDescriptionContainer > :: new () , } ;
             let mut endpoint_abi = elrond_wasm::abi::EndpointAbi {
                 docs: &[],
                 name: "version",
+                rust_method_name: "version",
+                module_name: contract_abi.name,
                 only_owner: false,
+                only_role: None,
+                labels: &[],
                 mutability: EndpointMutabilityAbi::Mutable,
                 payable_in_tokens: &[],
                 inputs: Vec::new(),
@@ -329,11 +333,15 @@ mod sample_adder {
         type Api = elrond_wasm::api::uncallable::UncallableApi;
 
         fn abi() -> elrond_wasm::abi::ContractAbi {
-            let mut contract_abi = elrond_wasm :: abi :: ContractAbi { build_info : elrond_wasm :: abi :: BuildInfoAbi { contract_crate : elrond_wasm :: abi :: ContractCrateBuildAbi { name : "adder" , version : "0.0.0" , } , framework : elrond_wasm :: abi :: FrameworkBuildAbi :: create () , } , docs : & ["One of the simplest smart contracts possible," , "it holds a single variable in storage, which anyone can increment."] , name : "Adder" , constructor : None , endpoints : Vec :: new () , type_descriptions : < elrond_wasm :: abi :: TypeDescriptionContainerImpl as elrond_wasm :: abi :: TypeDescriptionContainer > :: new () , } ;
+            let mut contract_abi = elrond_wasm :: abi :: ContractAbi { build_info : elrond_wasm :: abi :: BuildInfoAbi { contract_crate : elrond_wasm :: abi :: ContractCrateBuildAbi { name : "adder" , version : "0.0.0" , } , framework : elrond_wasm :: abi :: FrameworkBuildAbi :: create () , } , docs : & ["One of the simplest smart contracts possible," , "it holds a single variable in storage, which anyone can increment."] , name : "Adder" , constructor : None , upgrade_constructor : None , endpoints : Vec :: new () , storage : Vec :: new () , events : Vec :: new () , type_descriptions : < elrond_wasm :: abi :: TypeDescriptionContainerImpl as elrond_wasm :: abi :: TypeDescriptionContainer > :: new () , } ;
             let mut endpoint_abi = elrond_wasm::abi::EndpointAbi {
                 docs: &[],
                 name: "getSum",
+                rust_method_name: "get_sum",
+                module_name: contract_abi.name,
                 only_owner: false,
+                only_role: None,
+                labels: &[],
                 mutability: EndpointMutabilityAbi::Readonly,
                 payable_in_tokens: &[],
                 inputs: Vec::new(),
@@ -345,7 +353,11 @@ mod sample_adder {
             let mut endpoint_abi = elrond_wasm::abi::EndpointAbi {
                 docs: &[],
                 name: "init",
+                rust_method_name: "init",
+                module_name: contract_abi.name,
                 only_owner: false,
+                only_role: None,
+                labels: &[],
                 mutability: EndpointMutabilityAbi::Pure,
                 payable_in_tokens: &[],
                 inputs: Vec::new(),
@@ -357,7 +369,11 @@ mod sample_adder {
             let mut endpoint_abi = elrond_wasm::abi::EndpointAbi {
                 docs: &["Add desired amount to the storage variable."],
                 name: "add",
+                rust_method_name: "add",
+                module_name: contract_abi.name,
                 only_owner: false,
+                only_role: None,
+                labels: &[],
                 mutability: EndpointMutabilityAbi::Mutable,
                 payable_in_tokens: &[],
                 inputs: Vec::new(),
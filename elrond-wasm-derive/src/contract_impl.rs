@@ -4,7 +4,8 @@ use crate::{
         auto_impl::generate_auto_impls, auto_impl_proxy::generate_all_proxy_trait_imports,
         callback_gen::*, callback_proxies_gen::*, contract_gen::*,
         endpoints_mod_gen::generate_endpoints_mod,
-        function_selector::generate_function_selector_body, proxy_gen, supertrait_gen,
+        function_selector::generate_function_selector_body,
+        migration_gen::generate_run_storage_migrations, proxy_gen, supertrait_gen,
     },
     model::ContractTrait,
 };
@@ -20,8 +21,10 @@ pub fn contract_implementation(
     let trait_name_ident = contract.trait_name.clone();
     let method_impls = extract_method_impls(contract);
     let call_methods = generate_call_methods(contract);
+    let upgrade_vm_check = generate_upgrade_vm_check(contract);
     let auto_impl_defs = generate_auto_impl_defs(contract);
     let auto_impls = generate_auto_impls(contract);
+    let run_storage_migrations = generate_run_storage_migrations(contract, is_contract_main);
     let endpoints_mod = generate_endpoints_mod(contract, is_contract_main);
     let function_selector_body = generate_function_selector_body(contract);
     let (callback_selector_body, callback_body) = generate_callback_selector_and_main(contract);
@@ -31,6 +34,8 @@ pub fn contract_implementation(
     // this definition is common to release and debug mode
     let supertraits_main = supertrait_gen::main_supertrait_decl(contract.supertraits.as_slice());
     let main_definition = quote! {
+        #upgrade_vm_check
+
         #(#proxy_trait_imports)*
 
         #(#module_original_attributes)*
@@ -44,6 +49,8 @@ pub fn contract_implementation(
 
             #(#auto_impl_defs)*
 
+            #run_storage_migrations
+
             #callbacks_def
         }
     };
@@ -102,7 +109,7 @@ pub fn contract_implementation(
     let impl_all_auto_impl = supertrait_gen::impl_all_auto_impl(contract.supertraits.as_slice());
     let impl_all_endpoint_wrappers =
         supertrait_gen::impl_all_endpoint_wrappers(contract.supertraits.as_slice());
-    let impl_callable_contract = snippets::impl_callable_contract();
+    let impl_callable_contract = snippets::impl_callable_contract(has_upgrade_method(contract));
     let new_contract_object_fn = snippets::new_contract_object_fn();
 
     let contract_obj_code = quote! {
@@ -1,13 +1,19 @@
 use super::util::*;
-use crate::model::{ContractTrait, EndpointMutabilityMetadata, Method, PublicRole};
+use crate::model::{
+    AutoImpl, ContractTrait, EndpointMutabilityMetadata, Method, MethodArgument, MethodImpl,
+    PublicRole,
+};
 
 fn generate_endpoint_snippet(
     m: &Method,
     endpoint_name: &str,
     only_owner: bool,
+    only_role: Option<&str>,
+    labels: &[String],
     mutability: EndpointMutabilityMetadata,
 ) -> proc_macro2::TokenStream {
     let endpoint_docs = &m.docs;
+    let rust_method_name = m.name.to_string();
     let payable_in_tokens = m.payable_metadata().abi_strings();
 
     let input_snippets: Vec<proc_macro2::TokenStream> = m
@@ -42,12 +48,20 @@ fn generate_endpoint_snippet(
         },
     };
     let mutability_tokens = mutability.to_tokens();
+    let only_role_tokens = match only_role {
+        Some(role) => quote! { Some(#role) },
+        None => quote! { None },
+    };
 
     quote! {
         let mut endpoint_abi = elrond_wasm::abi::EndpointAbi{
             docs: &[ #(#endpoint_docs),* ],
             name: #endpoint_name,
+            rust_method_name: #rust_method_name,
+            module_name: contract_abi.name,
             only_owner: #only_owner,
+            only_role: #only_role_tokens,
+            labels: &[ #(#labels),* ],
             mutability: #mutability_tokens,
             payable_in_tokens: &[ #(#payable_in_tokens),* ],
             inputs: Vec::new(),
@@ -58,6 +72,137 @@ fn generate_endpoint_snippet(
     }
 }
 
+fn generate_storage_key_arg_snippets(key_args: &[MethodArgument]) -> Vec<proc_macro2::TokenStream> {
+    key_args
+        .iter()
+        .map(|arg| {
+            let mut arg_type = arg.ty.clone();
+            clear_all_type_lifetimes(&mut arg_type);
+            let arg_name = &arg.pat;
+            let arg_name_str = quote! { #arg_name }.to_string();
+            quote! {
+                storage_abi.add_key_arg::<#arg_type>(#arg_name_str);
+                contract_abi.add_type_descriptions::<#arg_type>();
+            }
+        })
+        .collect()
+}
+
+fn method_return_type(m: &Method) -> syn::Type {
+    match &m.return_type {
+        syn::ReturnType::Default => panic!("storage getters and mappers should return some value"),
+        syn::ReturnType::Type(_, ty) => {
+            let mut res_type = *ty.clone();
+            clear_all_type_lifetimes(&mut res_type);
+            res_type
+        },
+    }
+}
+
+/// Storage getters, setters and mappers annotated with `abi = true` describe the storage layout.
+/// Their mapper and key argument types need to implement `StorageMapperAbi` and `TypeAbi`,
+/// which is why describing the storage is opt-in.
+/// `#[storage_is_empty]` and `#[storage_clear]` carry no type information, so they are left out.
+fn generate_storage_snippet(m: &Method) -> Option<proc_macro2::TokenStream> {
+    let (identifier, key_args, value_snippet) = match &m.implementation {
+        MethodImpl::Generated(AutoImpl::StorageGetter {
+            identifier,
+            abi: true,
+        }) => {
+            let value_type = method_return_type(m);
+            let value_snippet = quote! {
+                storage_abi.set_value_type::<#value_type>();
+                contract_abi.add_type_descriptions::<#value_type>();
+            };
+            (identifier, m.method_args.as_slice(), value_snippet)
+        },
+        MethodImpl::Generated(AutoImpl::StorageSetter {
+            identifier,
+            abi: true,
+        }) => {
+            let (value_arg, key_args) = m
+                .method_args
+                .split_last()
+                .expect("setter must have at least one argument, for the value");
+            let mut value_type = value_arg.ty.clone();
+            clear_all_type_lifetimes(&mut value_type);
+            let value_snippet = quote! {
+                storage_abi.set_value_type::<#value_type>();
+                contract_abi.add_type_descriptions::<#value_type>();
+            };
+            (identifier, key_args, value_snippet)
+        },
+        MethodImpl::Generated(AutoImpl::StorageMapper {
+            identifier,
+            abi: true,
+        }) => {
+            let mapper_type = method_return_type(m);
+            let value_snippet = quote! {
+                storage_abi.set_mapper::<#mapper_type>();
+                contract_abi.add_storage_mapper_type_descriptions::<#mapper_type>();
+            };
+            (identifier, m.method_args.as_slice(), value_snippet)
+        },
+        _ => return None,
+    };
+
+    let storage_docs = &m.docs;
+    let rust_method_name = m.name.to_string();
+    let key_arg_snippets = generate_storage_key_arg_snippets(key_args);
+    Some(quote! {
+        let mut storage_abi = elrond_wasm::abi::StorageAbi{
+            docs: &[ #(#storage_docs),* ],
+            key: #identifier,
+            rust_method_name: #rust_method_name,
+            module_name: contract_abi.name,
+            mapper: None,
+            key_args: Vec::new(),
+            value_types: Vec::new(),
+        };
+        #(#key_arg_snippets)*
+        #value_snippet
+        contract_abi.add_storage(storage_abi);
+    })
+}
+
+/// Only events declared with `#[event]` are described, legacy events are left out.
+fn generate_event_snippet(m: &Method) -> Option<proc_macro2::TokenStream> {
+    let identifier = match &m.implementation {
+        MethodImpl::Generated(AutoImpl::Event { identifier }) => identifier,
+        _ => return None,
+    };
+
+    let input_snippets: Vec<proc_macro2::TokenStream> = m
+        .method_args
+        .iter()
+        .map(|arg| {
+            let mut arg_type = arg.ty.clone();
+            clear_all_type_lifetimes(&mut arg_type);
+            let arg_name = &arg.pat;
+            let arg_name_str = quote! { #arg_name }.to_string();
+            let indexed = arg.metadata.event_topic;
+            quote! {
+                event_abi.add_input::<#arg_type>(#arg_name_str, #indexed);
+                contract_abi.add_type_descriptions::<#arg_type>();
+            }
+        })
+        .collect();
+
+    let event_docs = &m.docs;
+    let rust_method_name = m.name.to_string();
+    Some(quote! {
+        let mut event_abi = elrond_wasm::abi::EventAbi{
+            docs: &[ #(#event_docs),* ],
+            identifier: #identifier,
+            rust_method_name: #rust_method_name,
+            module_name: contract_abi.name,
+            inputs: Vec::new(),
+        };
+        #(#input_snippets)*
+        contract_abi.events.push(event_abi);
+    })
+}
+
 fn generate_abi_method_body(
     contract: &ContractTrait,
     is_contract_main: bool,
@@ -71,18 +216,35 @@ fn generate_abi_method_body(
                     m,
                     "init",
                     false,
+                    None,
+                    &[],
                     EndpointMutabilityMetadata::Mutable,
                 );
                 Some(quote! {
                     #endpoint_def
                     contract_abi.constructor = Some(endpoint_abi);
                 })
+            } else if let PublicRole::Upgrade(_) = &m.public_role {
+                let endpoint_def = generate_endpoint_snippet(
+                    m,
+                    "upgrade",
+                    false,
+                    None,
+                    &[],
+                    EndpointMutabilityMetadata::Mutable,
+                );
+                Some(quote! {
+                    #endpoint_def
+                    contract_abi.upgrade_constructor = Some(endpoint_abi);
+                })
             } else if let PublicRole::Endpoint(endpoint_metadata) = &m.public_role {
                 let endpoint_name_str = endpoint_metadata.public_name.to_string();
                 let endpoint_def = generate_endpoint_snippet(
                     m,
                     &endpoint_name_str,
                     endpoint_metadata.only_owner,
+                    endpoint_metadata.only_role.as_deref(),
+                    endpoint_metadata.labels.as_slice(),
                     endpoint_metadata.mutability.clone(),
                 );
                 Some(quote! {
@@ -95,6 +257,18 @@ fn generate_abi_method_body(
         })
         .collect();
 
+    let storage_snippets: Vec<proc_macro2::TokenStream> = contract
+        .methods
+        .iter()
+        .filter_map(generate_storage_snippet)
+        .collect();
+
+    let event_snippets: Vec<proc_macro2::TokenStream> = contract
+        .methods
+        .iter()
+        .filter_map(generate_event_snippet)
+        .collect();
+
     let supertrait_snippets: Vec<proc_macro2::TokenStream> = if is_contract_main {
         contract
 			.supertraits
@@ -124,10 +298,15 @@ fn generate_abi_method_body(
             docs: &[ #(#contract_docs),* ],
             name: #contract_name,
             constructor: None,
+            upgrade_constructor: None,
             endpoints: Vec::new(),
+            storage: Vec::new(),
+            events: Vec::new(),
             type_descriptions: <elrond_wasm::abi::TypeDescriptionContainerImpl as elrond_wasm::abi::TypeDescriptionContainer>::new(),
         };
         #(#endpoint_snippets)*
+        #(#storage_snippets)*
+        #(#event_snippets)*
         #(#supertrait_snippets)*
         contract_abi
     }
@@ -46,6 +46,46 @@ pub fn generate_load_single_arg(
     }
 }
 
+/// Arguments whose type carries a lifetime, e.g. `MessageView<'a>`, borrow from their raw bytes.
+pub fn is_borrowed_arg(arg: &MethodArgument) -> bool {
+    if let syn::Type::Path(type_path) = &arg.ty {
+        if let Some(last_segment) = type_path.path.segments.last() {
+            if let syn::PathArguments::AngleBracketed(generic_args) = &last_segment.arguments {
+                return generic_args
+                    .args
+                    .iter()
+                    .any(|generic_arg| matches!(generic_arg, syn::GenericArgument::Lifetime(_)));
+            }
+        }
+    }
+    false
+}
+
+fn borrowed_arg_bytes_ident(pat: &syn::Pat) -> syn::Ident {
+    if let syn::Pat::Ident(pat_ident) = pat {
+        syn::Ident::new(
+            &format!("___{}_bytes___", pat_ident.ident),
+            pat_ident.ident.span(),
+        )
+    } else {
+        panic!("Borrowed arguments must be named by an identifier")
+    }
+}
+
+/// The raw bytes are loaded once into a local, the argument is then decoded as a view into them.
+pub fn generate_load_single_borrowed_arg(
+    arg: &MethodArgument,
+    arg_index_expr: &proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    let pat = &arg.pat;
+    let arg_name_expr = arg_id_literal(pat);
+    let bytes_ident = borrowed_arg_bytes_ident(pat);
+    quote! {
+        let #bytes_ident = elrond_wasm::load_single_arg::<Self::Api, Box<[u8]>>(self.raw_vm_api(), #arg_index_expr, #arg_name_expr);
+        let #pat = elrond_wasm::decode_borrowed_arg(self.raw_vm_api(), &#bytes_ident, #arg_name_expr);
+    }
+}
+
 pub fn generate_load_dyn_arg(
     arg: &MethodArgument,
     loader_expr: &proc_macro2::TokenStream,
@@ -53,6 +93,13 @@ pub fn generate_load_dyn_arg(
     let pat = &arg.pat;
     let arg_ty = &arg.ty;
     let arg_name_expr = arg_id_literal(pat);
+    if is_borrowed_arg(arg) {
+        let bytes_ident = borrowed_arg_bytes_ident(pat);
+        return quote! {
+            let #bytes_ident: Box<[u8]> = elrond_wasm::load_dyn_arg(#loader_expr, #arg_name_expr);
+            let #pat = elrond_wasm::decode_borrowed_arg(self.raw_vm_api(), &#bytes_ident, #arg_name_expr);
+        };
+    }
     match &arg.ty {
         syn::Type::Reference(type_reference) => {
             assert!(
@@ -33,9 +33,9 @@ fn generate_auto_impl(m: &Method, auto_impl: &AutoImpl) -> proc_macro2::TokenStr
             generate_legacy_event_impl(m, identifier.as_slice())
         },
         AutoImpl::Event { identifier } => generate_event_impl(m, identifier),
-        AutoImpl::StorageGetter { identifier } => generate_getter_impl(m, identifier),
-        AutoImpl::StorageSetter { identifier } => generate_setter_impl(m, identifier),
-        AutoImpl::StorageMapper { identifier } => generate_mapper_impl(m, identifier),
+        AutoImpl::StorageGetter { identifier, .. } => generate_getter_impl(m, identifier),
+        AutoImpl::StorageSetter { identifier, .. } => generate_setter_impl(m, identifier),
+        AutoImpl::StorageMapper { identifier, .. } => generate_mapper_impl(m, identifier),
         AutoImpl::StorageIsEmpty { identifier } => generate_is_empty_impl(m, identifier),
         AutoImpl::StorageClear { identifier } => generate_clear_impl(m, identifier),
         AutoImpl::ProxyGetter => generate_proxy_getter_impl(m),
@@ -2,15 +2,13 @@ use super::{method_gen, util::*};
 use crate::model::{Method, MethodArgument};
 
 pub fn generate_event_impl(m: &Method, event_identifier: &str) -> proc_macro2::TokenStream {
-    let mut data_arg: Option<&MethodArgument> = None;
+    let mut data_args = Vec::<&MethodArgument>::new();
     let mut topic_args = Vec::<&MethodArgument>::new();
     for arg in &m.method_args {
         if arg.metadata.event_topic {
             topic_args.push(arg);
-        } else if data_arg.is_none() {
-            data_arg = Some(arg);
         } else {
-            panic!("only 1 data argument allowed in event log");
+            data_args.push(arg);
         }
     }
 
@@ -23,16 +21,7 @@ pub fn generate_event_impl(m: &Method, event_identifier: &str) -> proc_macro2::T
             }
         })
         .collect();
-    let data_buffer_snippet = if let Some(data_arg) = data_arg {
-        let data_pat = &data_arg.pat;
-        quote! {
-            let ___data_buffer___ = elrond_wasm::log_util::serialize_log_data(self.raw_vm_api(), #data_pat);
-        }
-    } else {
-        quote! {
-            let ___data_buffer___ = elrond_wasm::types::ManagedBuffer::new(self.raw_vm_api());
-        }
-    };
+    let data_buffer_snippet = generate_data_buffer_snippet(data_args.as_slice());
 
     let msig = method_gen::generate_sig_with_attributes(m);
     let event_identifier_literal = byte_slice_literal(event_identifier.as_bytes());
@@ -49,6 +38,35 @@ pub fn generate_event_impl(m: &Method, event_identifier: &str) -> proc_macro2::T
     }
 }
 
+/// A single data argument is top-encoded, as it always was.
+/// Several data arguments are nested-encoded one after the other,
+/// which is the same as top-encoding them as a tuple.
+fn generate_data_buffer_snippet(data_args: &[&MethodArgument]) -> proc_macro2::TokenStream {
+    match data_args {
+        [] => quote! {
+            let ___data_buffer___ = elrond_wasm::types::ManagedBuffer::new(self.raw_vm_api());
+        },
+        [data_arg] => {
+            let data_pat = &data_arg.pat;
+            quote! {
+                let ___data_buffer___ = elrond_wasm::log_util::serialize_log_data(self.raw_vm_api(), #data_pat);
+            }
+        },
+        _ => {
+            let data_field_snippets = data_args.iter().map(|arg| {
+                let data_pat = &arg.pat;
+                quote! {
+                    elrond_wasm::log_util::serialize_log_data_field(&mut ___data_buffer___, #data_pat);
+                }
+            });
+            quote! {
+                let mut ___data_buffer___ = elrond_wasm::types::ManagedBuffer::new(self.raw_vm_api());
+                #(#data_field_snippets)*
+            }
+        },
+    }
+}
+
 /// Still only used in legacy event logs.
 fn generate_topic_conversion_code(
     topic_index: usize,
@@ -6,7 +6,7 @@ use super::{
     payable_gen::*,
     util::*,
 };
-use crate::model::{ContractTrait, Method, PublicRole, Supertrait};
+use crate::model::{ContractTrait, Method, MethodArgument, PublicRole, Supertrait};
 
 /// Callback name max length is checked during derive,
 /// so as not to burden the contract at runtime.
@@ -81,6 +81,7 @@ fn match_arms(methods: &[Method]) -> Vec<proc_macro2::TokenStream> {
             if let PublicRole::Callback(callback) = &m.public_role {
                 let payable_snippet = generate_payable_snippet(m);
                 let mut has_call_result = false;
+                let mut has_callback_context = false;
                 let arg_init_snippets: Vec<proc_macro2::TokenStream> = m
                     .method_args
                     .iter()
@@ -93,6 +94,10 @@ fn match_arms(methods: &[Method]) -> Vec<proc_macro2::TokenStream> {
                             // Should be an AsyncCallResult argument that wraps what comes from the async call.
                             // But in principle, one can express it it any way.
                             generate_load_dyn_arg(arg, &quote! { &mut ___call_result_loader___ })
+                        } else if arg.metadata.callback_context {
+                            has_call_result = true;
+                            has_callback_context = true;
+                            generate_load_callback_context(arg)
                         } else {
                             // callback args, loaded from storage via the tx hash
                             generate_load_dyn_arg(arg, &quote! { &mut ___cb_arg_loader___ })
@@ -117,10 +122,19 @@ fn match_arms(methods: &[Method]) -> Vec<proc_macro2::TokenStream> {
                     quote! {}
                 };
                 let body_with_result = generate_body_with_result(&m.return_type, &call);
+                // the closure is consumed by the arg loader, so the payments are extracted first
+                let original_payments_snippet = if has_callback_context {
+                    quote! {
+                        let ___original_payments___ = ___cb_closure___.original_payments();
+                    }
+                } else {
+                    quote! {}
+                };
 
                 let match_arm = quote! {
                     else if ___cb_closure_matcher___.name_matches(#callback_name_literal) {
                         #payable_snippet
+                        #original_payments_snippet
                         let mut ___cb_arg_loader___ = ___cb_closure___.into_arg_loader();
                         #(#arg_init_snippets)*
                         ___cb_arg_loader___.assert_no_more_args();
@@ -137,6 +151,21 @@ fn match_arms(methods: &[Method]) -> Vec<proc_macro2::TokenStream> {
         .collect()
 }
 
+/// The call result is decoded without crashing the callback,
+/// the payments come from the closure and from the callback call value.
+fn generate_load_callback_context(arg: &MethodArgument) -> proc_macro2::TokenStream {
+    let pat = &arg.pat;
+    let arg_ty = &arg.ty;
+    let arg_name_expr = arg_id_literal(pat);
+    quote! {
+        let #pat: #arg_ty = elrond_wasm::types::CallbackContext::new(
+            ___original_payments___,
+            elrond_wasm::api::CallValueApi::get_all_esdt_transfers(&self.raw_vm_api()),
+            elrond_wasm::load_dyn_arg(&mut ___call_result_loader___, #arg_name_expr),
+        );
+    }
+}
+
 pub fn module_calls(supertraits: &[Supertrait]) -> Vec<proc_macro2::TokenStream> {
     supertraits
 		.iter()
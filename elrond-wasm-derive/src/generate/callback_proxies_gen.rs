@@ -1,12 +1,12 @@
 use super::{snippets, util::*};
 use crate::model::{ArgPaymentMetadata, ContractTrait, Method, MethodArgument, PublicRole};
 
-/// Excludes the `#[call_result]` and the payment args.
+/// Excludes the `#[call_result]`, `#[callback_context]` and the payment args.
 pub fn cb_proxy_arg_declarations(method_args: &[MethodArgument]) -> Vec<proc_macro2::TokenStream> {
     method_args
         .iter()
         .filter_map(|arg| {
-            if arg.metadata.payment.is_payment_arg() || arg.metadata.callback_call_result {
+            if arg.metadata.payment.is_payment_arg() || arg.is_callback_result_arg() {
                 None
             } else {
                 let pat = &arg.pat;
@@ -30,7 +30,11 @@ pub fn generate_callback_proxies_object(methods: &[Method]) -> proc_macro2::Toke
                     .iter()
                     .map(|arg| {
                         if let ArgPaymentMetadata::NotPayment = arg.metadata.payment {
-                            if arg.metadata.callback_call_result {
+                            if arg.metadata.callback_context {
+                                quote! {
+                                    ___callback_call___.request_original_payments();
+                                }
+                            } else if arg.is_callback_result_arg() {
                                 quote! {}
                             } else {
                                 let pat = &arg.pat;
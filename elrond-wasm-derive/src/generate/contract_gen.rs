@@ -1,5 +1,14 @@
-use super::{method_call_gen::generate_call_method, method_gen};
-use crate::model::{ContractTrait, MethodImpl, PublicRole};
+use super::{
+    method_call_gen::{
+        generate_body_with_result, generate_call_method, generate_call_method_body,
+        generate_call_to_method_expr,
+    },
+    method_gen,
+    migration_gen::{generate_load_upgrade_args, has_migrations, latest_storage_version},
+    payable_gen::generate_payable_snippet,
+    util::generate_call_method_name,
+};
+use crate::model::{ContractTrait, Method, MethodImpl, PublicRole};
 
 pub fn extract_method_impls(contract_trait: &ContractTrait) -> Vec<proc_macro2::TokenStream> {
     contract_trait
@@ -19,12 +28,79 @@ pub fn extract_method_impls(contract_trait: &ContractTrait) -> Vec<proc_macro2::
         .collect()
 }
 
+/// A fresh deploy starts directly at the latest storage version, no migrations are run.
+fn generate_call_init_with_storage_version(
+    m: &Method,
+    contract_trait: &ContractTrait,
+) -> proc_macro2::TokenStream {
+    let call_method_ident = generate_call_method_name(&m.name);
+    let call_method_body = generate_call_method_body(m);
+    let latest_version = latest_storage_version(contract_trait);
+    quote! {
+        #[inline]
+        fn #call_method_ident (&self) {
+            elrond_wasm::storage::migration::set_storage_version(self.raw_vm_api(), #latest_version);
+            #call_method_body
+        }
+    }
+}
+
+/// Pending storage migrations run before the `#[upgrade]` method, so it never sees a partially migrated storage.
+/// The arguments are checked first. If the migrations do not all complete, they are saved
+/// and the `run_storage_migrations` call that completes them also calls the `#[upgrade]` method.
+fn generate_call_upgrade_with_migrations(m: &Method) -> proc_macro2::TokenStream {
+    let call_method_ident = generate_call_method_name(&m.name);
+    let payable_snippet = generate_payable_snippet(m);
+    let load_args = generate_load_upgrade_args(m, &quote! { ___upgrade_args___.clone() });
+    let call = generate_call_to_method_expr(m);
+    let body_with_result = generate_body_with_result(&m.return_type, &call);
+    quote! {
+        #[inline]
+        fn #call_method_ident (&self) {
+            #payable_snippet
+            elrond_wasm::storage::migration::clear_pending_upgrade(self.raw_vm_api());
+            let ___upgrade_args___ = elrond_wasm::storage::migration::get_raw_call_arguments(self.raw_vm_api());
+            #load_args
+            if !self.run_storage_migrations().is_completed() {
+                elrond_wasm::storage::migration::set_pending_upgrade(self.raw_vm_api(), &___upgrade_args___);
+                return;
+            }
+            #body_with_result
+        }
+    }
+}
+
+pub fn has_upgrade_method(contract_trait: &ContractTrait) -> bool {
+    contract_trait
+        .methods
+        .iter()
+        .any(|m| matches!(m.public_role, PublicRole::Upgrade(_)))
+}
+
+/// Contracts with an `#[upgrade]` method only compile for VM versions that call it on upgrade.
+pub fn generate_upgrade_vm_check(contract_trait: &ContractTrait) -> proc_macro2::TokenStream {
+    if has_upgrade_method(contract_trait) {
+        quote! {
+            elrond_wasm::require_vm_upgrade_endpoint!();
+        }
+    } else {
+        quote! {}
+    }
+}
+
 pub fn generate_call_methods(contract_trait: &ContractTrait) -> Vec<proc_macro2::TokenStream> {
+    let with_migrations = has_migrations(contract_trait);
     contract_trait
         .methods
         .iter()
         .filter_map(|m| match &m.public_role {
-            PublicRole::Init(_init_metadata) => Some(generate_call_method(m)),
+            PublicRole::Init(_) if with_migrations => {
+                Some(generate_call_init_with_storage_version(m, contract_trait))
+            },
+            PublicRole::Upgrade(_) if with_migrations => {
+                Some(generate_call_upgrade_with_migrations(m))
+            },
+            PublicRole::Init(_) | PublicRole::Upgrade(_) => Some(generate_call_method(m)),
             PublicRole::Endpoint(_endpoint_metadata) => Some(generate_call_method(m)),
             _ => None,
         })
@@ -20,6 +20,7 @@ pub fn generate_function_selector_body(contract: &ContractTrait) -> proc_macro2:
         .iter()
         .filter_map(|m| match &m.public_role {
             PublicRole::Init(_) => Some(function_selector_match_arm(m, "init")),
+            PublicRole::Upgrade(_) => Some(function_selector_match_arm(m, "upgrade")),
             PublicRole::Endpoint(endpoint_metadata) => Some(function_selector_match_arm(
                 m,
                 endpoint_metadata.public_name.to_string().as_str(),
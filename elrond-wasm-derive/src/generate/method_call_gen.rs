@@ -1,5 +1,6 @@
 use super::{
-    arg_regular::*, method_gen::generate_arg_call_name, only_owner_gen::*, payable_gen::*, util::*,
+    arg_regular::*, method_gen::generate_arg_call_name, non_reentrant_gen::*, only_owner_gen::*,
+    payable_gen::*, readonly_gen::*, util::*,
 };
 use crate::model::Method;
 
@@ -34,6 +35,7 @@ pub fn generate_call_method_body(m: &Method) -> proc_macro2::TokenStream {
 pub fn generate_call_method_body_fixed_args(m: &Method) -> proc_macro2::TokenStream {
     let payable_snippet = generate_payable_snippet(m);
     let only_owner_snippet = generate_only_owner_snippet(m);
+    let only_role_snippet = generate_only_role_snippet(m);
 
     let mut arg_index = -1i32;
     let arg_init_snippets: Vec<proc_macro2::TokenStream> = m
@@ -47,6 +49,9 @@ pub fn generate_call_method_body_fixed_args(m: &Method) -> proc_macro2::TokenStr
 
             if arg.is_endpoint_arg() {
                 arg_index += 1;
+                if is_borrowed_arg(arg) {
+                    return generate_load_single_borrowed_arg(arg, &quote! { #arg_index });
+                }
                 let pat = &arg.pat;
                 let arg_get = generate_load_single_arg(arg, &quote! { #arg_index });
                 quote! {
@@ -58,15 +63,18 @@ pub fn generate_call_method_body_fixed_args(m: &Method) -> proc_macro2::TokenStr
         })
         .collect();
 
+    let readonly_snippet = generate_readonly_snippet(m);
     let call = generate_call_to_method_expr(m);
-    let body_with_result = generate_body_with_result(&m.return_type, &call);
+    let body_with_result = generate_guarded_body_with_result(m, &call);
     let nr_args = arg_index + 1;
 
     quote! {
         #payable_snippet
         #only_owner_snippet
+        #only_role_snippet
         elrond_wasm::api::EndpointArgumentApi::check_num_arguments(&self.raw_vm_api(), #nr_args);
         #(#arg_init_snippets)*
+        #readonly_snippet
         #body_with_result
     }
 }
@@ -74,6 +82,7 @@ pub fn generate_call_method_body_fixed_args(m: &Method) -> proc_macro2::TokenStr
 fn generate_call_method_body_variable_nr_args(m: &Method) -> proc_macro2::TokenStream {
     let payable_snippet = generate_payable_snippet(m);
     let only_owner_snippet = generate_only_owner_snippet(m);
+    let only_role_snippet = generate_only_role_snippet(m);
 
     let arg_init_snippets: Vec<proc_macro2::TokenStream> = m
         .method_args
@@ -87,20 +96,25 @@ fn generate_call_method_body_variable_nr_args(m: &Method) -> proc_macro2::TokenS
         })
         .collect();
 
+    let readonly_snippet = generate_readonly_snippet(m);
     let call = generate_call_to_method_expr(m);
-    let body_with_result = generate_body_with_result(&m.return_type, &call);
+    let body_with_result = generate_guarded_body_with_result(m, &call);
 
     quote! {
         #payable_snippet
 
         #only_owner_snippet
 
+        #only_role_snippet
+
         let mut ___arg_loader = EndpointDynArgLoader::new(self.raw_vm_api());
 
         #(#arg_init_snippets)*
 
         ___arg_loader.assert_no_more_args();
 
+        #readonly_snippet
+
         #body_with_result
     }
 }
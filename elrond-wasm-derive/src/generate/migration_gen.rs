@@ -0,0 +1,112 @@
+use super::{arg_regular::generate_load_dyn_arg, method_call_gen::generate_call_to_method_expr};
+use crate::model::{ContractTrait, Method, PublicRole};
+
+pub fn has_migrations(contract: &ContractTrait) -> bool {
+    contract.methods.iter().any(|m| m.migration.is_some())
+}
+
+/// The storage version reached after running all migrations, also the one of freshly deployed contracts.
+pub fn latest_storage_version(contract: &ContractTrait) -> u32 {
+    contract
+        .methods
+        .iter()
+        .filter_map(|m| m.migration.as_ref())
+        .map(|migration| migration.to)
+        .max()
+        .expect("no migrations declared")
+}
+
+fn generate_migration_match_arm(m: &Method) -> proc_macro2::TokenStream {
+    let migration = m.migration.as_ref().unwrap();
+    let from = migration.from;
+    let to = migration.to;
+    let fn_ident = &m.name;
+    // migrations that return nothing always complete in one go
+    let status_expr = match &m.return_type {
+        syn::ReturnType::Default => quote! {
+            {
+                self.#fn_ident();
+                elrond_wasm::storage::migration::MigrationStatus::Completed
+            }
+        },
+        syn::ReturnType::Type(..) => quote! {
+            self.#fn_ident()
+        },
+    };
+    quote! {
+        #from => {
+            let ___status___: elrond_wasm::storage::migration::MigrationStatus = #status_expr;
+            if !___status___.is_completed() {
+                return ___status___;
+            }
+            elrond_wasm::storage::migration::set_storage_version(self.raw_vm_api(), #to);
+        },
+    }
+}
+
+/// Decodes the `#[upgrade]` method arguments from a list of raw arguments.
+pub fn generate_load_upgrade_args(
+    m: &Method,
+    raw_args_expr: &proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    let arg_init_snippets: Vec<proc_macro2::TokenStream> = m
+        .method_args
+        .iter()
+        .filter(|arg| arg.is_endpoint_arg())
+        .map(|arg| generate_load_dyn_arg(arg, &quote! { &mut ___upgrade_arg_loader___ }))
+        .collect();
+    quote! {
+        let mut ___upgrade_arg_loader___ = elrond_wasm::io::ManagedResultArgLoader::new(#raw_args_expr);
+        #(#arg_init_snippets)*
+        ___upgrade_arg_loader___.assert_no_more_args();
+    }
+}
+
+/// Generates the `run_storage_migrations` method, which runs all pending migrations in order.
+/// It is called automatically before the `#[upgrade]` method and can also be called explicitly,
+/// to resume migrations that did not complete.
+/// The call that completes them also calls the `#[upgrade]` method postponed by the upgrade, if any.
+pub fn generate_run_storage_migrations(
+    contract: &ContractTrait,
+    is_contract_main: bool,
+) -> proc_macro2::TokenStream {
+    if !has_migrations(contract) {
+        return quote! {};
+    }
+    assert!(
+        is_contract_main,
+        "migrations can only be declared in the main contract trait, not in modules"
+    );
+
+    let match_arms: Vec<proc_macro2::TokenStream> = contract
+        .methods
+        .iter()
+        .filter(|m| m.migration.is_some())
+        .map(generate_migration_match_arm)
+        .collect();
+
+    let upgrade_method = contract
+        .methods
+        .iter()
+        .find(|m| matches!(m.public_role, PublicRole::Upgrade(_)))
+        .expect("contracts with migrations need an `#[upgrade]` method");
+    let load_upgrade_args =
+        generate_load_upgrade_args(upgrade_method, &quote! { ___upgrade_args___ });
+    let upgrade_call = generate_call_to_method_expr(upgrade_method);
+
+    quote! {
+        fn run_storage_migrations(&self) -> elrond_wasm::storage::migration::MigrationStatus {
+            loop {
+                match elrond_wasm::storage::migration::get_storage_version(self.raw_vm_api()) {
+                    #(#match_arms)*
+                    _ => break,
+                }
+            }
+            if let Some(___upgrade_args___) = elrond_wasm::storage::migration::take_pending_upgrade(self.raw_vm_api()) {
+                #load_upgrade_args
+                #upgrade_call;
+            }
+            elrond_wasm::storage::migration::MigrationStatus::Completed
+        }
+    }
+}
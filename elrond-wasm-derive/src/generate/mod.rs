@@ -11,9 +11,12 @@ pub mod endpoints_mod_gen;
 pub mod function_selector;
 pub mod method_call_gen;
 pub mod method_gen;
+pub mod migration_gen;
+pub mod non_reentrant_gen;
 pub mod only_owner_gen;
 pub mod payable_gen;
 pub mod proxy_gen;
+pub mod readonly_gen;
 pub mod snippets;
 pub mod supertrait_gen;
 pub mod util;
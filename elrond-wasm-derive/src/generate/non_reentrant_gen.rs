@@ -0,0 +1,26 @@
+use super::{method_call_gen::generate_body_with_result, util::*};
+use crate::model::{Method, PublicRole};
+
+/// Wraps the method call of `#[non_reentrant]` endpoints between acquiring and releasing the lock.
+/// The lock is released before the result is finished,
+/// because finishing an `AsyncCall` result ends the execution.
+pub fn generate_guarded_body_with_result(
+    m: &Method,
+    call: &proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    if let PublicRole::Endpoint(endpoint_metadata) = &m.public_role {
+        if let Some(group) = &endpoint_metadata.non_reentrant {
+            let group_literal = byte_str_slice_literal(group.as_bytes());
+            let guarded_call = quote! {
+                {
+                    elrond_wasm::storage::reentrancy_guard::acquire_reentrancy_lock(self.raw_vm_api(), #group_literal);
+                    let ___result___ = #call;
+                    elrond_wasm::storage::reentrancy_guard::release_reentrancy_lock(self.raw_vm_api(), #group_literal);
+                    ___result___
+                }
+            };
+            return generate_body_with_result(&m.return_type, &guarded_call);
+        }
+    }
+    generate_body_with_result(&m.return_type, call)
+}
@@ -1,3 +1,4 @@
+use super::util::*;
 use crate::model::{Method, PublicRole};
 
 pub fn generate_only_owner_snippet(m: &Method) -> proc_macro2::TokenStream {
@@ -10,3 +11,17 @@ pub fn generate_only_owner_snippet(m: &Method) -> proc_macro2::TokenStream {
     }
     return quote! {};
 }
+
+/// Relies on the `require_caller_has_role` method,
+/// provided by the `elrond-wasm-module-access-control` module.
+pub fn generate_only_role_snippet(m: &Method) -> proc_macro2::TokenStream {
+    if let PublicRole::Endpoint(endpoint_metadata) = &m.public_role {
+        if let Some(role) = &endpoint_metadata.only_role {
+            let role_literal = byte_str_literal(role.as_bytes());
+            return quote! {
+                self.require_caller_has_role(&#role_literal[..]);
+            };
+        }
+    }
+    quote! {}
+}
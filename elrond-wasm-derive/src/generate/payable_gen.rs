@@ -1,14 +1,37 @@
 use super::util::*;
-use crate::model::{Method, MethodArgument, MethodPayableMetadata};
+use crate::model::{
+    Method, MethodArgument, MethodPayableMetadata, PayableToken, PayableTokenType,
+    PaymentConstraints,
+};
 
 pub fn generate_payable_snippet(m: &Method) -> proc_macro2::TokenStream {
+    let payable_metadata = m.payable_metadata();
+    let payment_constraints = m.payment_constraints();
+    let payment_multi = multi_getter_init(&m.payment_multi_arg());
+
+    if matches!(payable_metadata, MethodPayableMetadata::TokenList(_))
+        || !payment_constraints.is_empty()
+    {
+        let payment_checks = payment_checks_snippet(&payable_metadata, &payment_constraints);
+        let payment_single = payable_single_snippet_for_metadata(
+            MethodPayableMetadata::AnyToken,
+            &m.payment_token_arg(),
+            &m.payment_amount_arg(),
+            &m.payment_nonce_arg(),
+        );
+        return quote! {
+            #payment_checks
+            #payment_single
+            #payment_multi
+        };
+    }
+
     let payment_single = payable_single_snippet_for_metadata(
-        m.payable_metadata(),
+        payable_metadata,
         &m.payment_token_arg(),
         &m.payment_amount_arg(),
         &m.payment_nonce_arg(),
     );
-    let payment_multi = multi_getter_init(&m.payment_multi_arg());
 
     quote! {
         #payment_single
@@ -16,6 +39,135 @@ pub fn generate_payable_snippet(m: &Method) -> proc_macro2::TokenStream {
     }
 }
 
+fn signal_error_snippet(err_msg_name: &str) -> proc_macro2::TokenStream {
+    let err_msg_ident = syn::Ident::new(err_msg_name, proc_macro2::Span::call_site());
+    quote! {
+        elrond_wasm::api::ErrorApi::signal_error(&self.raw_vm_api(), elrond_wasm::err_msg::#err_msg_ident);
+    }
+}
+
+/// Checks all payments, including each one in a multi-transfer,
+/// against the accepted tokens and the payment constraints.
+/// The payment arguments are then loaded as for `#[payable("*")]`.
+fn payment_checks_snippet(
+    mpm: &MethodPayableMetadata,
+    payment_constraints: &PaymentConstraints,
+) -> proc_macro2::TokenStream {
+    let accepted_tokens = match mpm {
+        MethodPayableMetadata::Egld => vec![PayableToken::Egld],
+        MethodPayableMetadata::SingleEsdtToken(token_identifier) => {
+            vec![PayableToken::Esdt(token_identifier.clone())]
+        },
+        MethodPayableMetadata::TokenList(tokens) => tokens.clone(),
+        MethodPayableMetadata::NotPayable | MethodPayableMetadata::AnyToken => Vec::new(),
+    };
+    let min_payments = payment_constraints.min_payments.or_else(|| {
+        if accepted_tokens.is_empty() && payment_constraints.token_type.is_none() {
+            None
+        } else {
+            Some(1)
+        }
+    });
+    // explicitly allowing no payments needs no check
+    let min_payments = min_payments.filter(|min_payments| *min_payments > 0);
+    let min_check = if let Some(min_payments) = min_payments {
+        let signal_error = signal_error_snippet("TOO_FEW_PAYMENTS");
+        quote! {
+            if ___payments___.len() < #min_payments {
+                #signal_error
+            }
+        }
+    } else {
+        quote! {}
+    };
+    let max_check = if let Some(max_payments) = payment_constraints.max_payments {
+        let signal_error = signal_error_snippet("TOO_MANY_PAYMENTS");
+        quote! {
+            if ___payments___.len() > #max_payments {
+                #signal_error
+            }
+        }
+    } else {
+        quote! {}
+    };
+
+    let token_from_inits: Vec<proc_macro2::TokenStream> = accepted_tokens
+        .iter()
+        .enumerate()
+        .filter_map(|(i, token)| match token {
+            PayableToken::FromMethod(method_name) => {
+                let var_name = token_from_var_name(i);
+                Some(quote! {
+                    let #var_name = self.#method_name();
+                })
+            },
+            _ => None,
+        })
+        .collect();
+    let token_check = if accepted_tokens.is_empty() {
+        quote! {}
+    } else {
+        let token_conditions: Vec<proc_macro2::TokenStream> = accepted_tokens
+            .iter()
+            .enumerate()
+            .map(|(i, token)| match token {
+                PayableToken::Egld => quote! { ___payment___.token_identifier.is_egld() },
+                PayableToken::Esdt(token_identifier) => {
+                    let token_literal = byte_str_slice_literal(token_identifier.as_bytes());
+                    quote! { ___payment___.token_identifier.as_managed_buffer() == #token_literal }
+                },
+                PayableToken::FromMethod(_) => {
+                    let var_name = token_from_var_name(i);
+                    quote! { ___payment___.token_identifier == #var_name }
+                },
+            })
+            .collect();
+        let signal_error = signal_error_snippet("BAD_TOKEN_PROVIDED");
+        quote! {
+            if !(#(#token_conditions)||*) {
+                #signal_error
+            }
+        }
+    };
+    let token_type_check = match payment_constraints.token_type {
+        Some(PayableTokenType::Fungible) => {
+            let signal_error = signal_error_snippet("BAD_TOKEN_TYPE_PROVIDED");
+            quote! {
+                if ___payment___.token_nonce != 0 {
+                    #signal_error
+                }
+            }
+        },
+        Some(PayableTokenType::NonFungible) => {
+            let signal_error = signal_error_snippet("BAD_TOKEN_TYPE_PROVIDED");
+            quote! {
+                if ___payment___.token_nonce == 0 {
+                    #signal_error
+                }
+            }
+        },
+        None => quote! {},
+    };
+
+    quote! {
+        let ___payments___ = elrond_wasm::api::CallValueApi::get_all_payments(&self.raw_vm_api());
+        #min_check
+        #max_check
+        #(#token_from_inits)*
+        for ___payment___ in ___payments___.iter() {
+            #token_check
+            #token_type_check
+        }
+    }
+}
+
+fn token_from_var_name(index: usize) -> syn::Ident {
+    syn::Ident::new(
+        format!("___accepted_token_{}___", index).as_str(),
+        proc_macro2::Span::call_site(),
+    )
+}
+
 fn payable_single_snippet_for_metadata(
     mpm: MethodPayableMetadata,
     payment_token_arg: &Option<MethodArgument>,
@@ -63,7 +215,8 @@ fn payable_single_snippet_for_metadata(
                 #nonce_init
             }
         },
-        MethodPayableMetadata::AnyToken => {
+        // the tokens of a list are checked in `payment_checks_snippet`
+        MethodPayableMetadata::AnyToken | MethodPayableMetadata::TokenList(_) => {
             let nonce_init = nonce_getter_init(payment_nonce_arg);
             if payment_amount_arg.is_none() && payment_token_arg.is_none() {
                 nonce_init
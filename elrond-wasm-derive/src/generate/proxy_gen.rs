@@ -216,12 +216,77 @@ pub fn generate_proxy_deploy(init_method: &Method) -> proc_macro2::TokenStream {
     sig
 }
 
+pub fn generate_proxy_upgrade_sig(method: &Method) -> proc_macro2::TokenStream {
+    let method_name = &method.name;
+    let generics = &method.generics;
+    let generics_where = &method.generics.where_clause;
+    let arg_decl = arg_declarations(&method.method_args);
+    let result = quote! {
+        fn #method_name #generics (
+            self,
+            #(#arg_decl),*
+        ) -> elrond_wasm::types::ContractUpgrade<Self::Api>
+        #generics_where
+    };
+    result
+}
+
+pub fn generate_proxy_upgrade(upgrade_method: &Method) -> proc_macro2::TokenStream {
+    let msig = generate_proxy_upgrade_sig(upgrade_method);
+
+    let mut payment_count = 0;
+
+    let arg_push_snippets: Vec<proc_macro2::TokenStream> = upgrade_method
+        .method_args
+        .iter()
+        .map(|arg| match &arg.metadata.payment {
+            ArgPaymentMetadata::NotPayment => {
+                let pat = &arg.pat;
+                quote! {
+                    ___contract_upgrade___.push_endpoint_arg(#pat);
+                }
+            },
+            ArgPaymentMetadata::PaymentAmount => {
+                payment_count += 1;
+                let pat = &arg.pat;
+                quote! {
+                    ___contract_upgrade___ = ___contract_upgrade___.with_egld_transfer(#pat);
+                }
+            },
+            _ => panic!("Only EGLD payments allowed in #[upgrade]"),
+        })
+        .collect();
+
+    assert!(
+        payment_count <= 1,
+        "No more than one payment argument allowed in call proxy"
+    );
+
+    let sig = quote! {
+        #[allow(clippy::too_many_arguments)]
+        #[allow(clippy::type_complexity)]
+        #msig {
+            let (___api___, ___address___) =
+                self.into_fields();
+            let mut ___contract_upgrade___ = elrond_wasm::types::new_contract_upgrade(
+                ___api___.clone(),
+                ___address___,
+            );
+            #(#arg_push_snippets)*
+            ___contract_upgrade___
+        }
+    };
+
+    sig
+}
+
 pub fn generate_method_impl(contract_trait: &ContractTrait) -> Vec<proc_macro2::TokenStream> {
     contract_trait
         .methods
         .iter()
         .filter_map(|m| match &m.public_role {
             PublicRole::Init(_) => Some(generate_proxy_deploy(m)),
+            PublicRole::Upgrade(_) => Some(generate_proxy_upgrade(m)),
             PublicRole::Endpoint(endpoint_metadata) => Some(generate_proxy_endpoint(
                 m,
                 endpoint_metadata.public_name.to_string(),
@@ -0,0 +1,13 @@
+use crate::model::Method;
+
+/// Views switch the API to read-only mode right before the method call,
+/// so that any attempt to change the blockchain state fails.
+pub fn generate_readonly_snippet(m: &Method) -> proc_macro2::TokenStream {
+    if m.is_view() {
+        quote! {
+            elrond_wasm::api::ReadonlyApi::set_readonly(&self.raw_vm_api(), true);
+        }
+    } else {
+        quote! {}
+    }
+}
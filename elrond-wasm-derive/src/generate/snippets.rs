@@ -52,7 +52,7 @@ pub fn impl_auto_impl() -> proc_macro2::TokenStream {
     }
 }
 
-pub fn impl_callable_contract() -> proc_macro2::TokenStream {
+pub fn impl_callable_contract(has_upgrade_method: bool) -> proc_macro2::TokenStream {
     quote! {
         impl<A> elrond_wasm::contract_base::CallableContract<A> for ContractObj<A>
         where
@@ -61,6 +61,9 @@ pub fn impl_callable_contract() -> proc_macro2::TokenStream {
             fn call(&self, fn_name: &[u8]) -> bool {
                 EndpointWrappers::call(self, fn_name)
             }
+            fn has_upgrade_method(&self) -> bool {
+                #has_upgrade_method
+            }
             fn into_api(self: Box<Self>) -> A {
                 self.api
             }
@@ -1,9 +1,9 @@
 use crate::{
     generate::{contract_gen::*, proxy_gen, supertrait_gen},
     model::ContractTrait,
-    parse::parse_contract_trait,
+    parse::{parse_contract_trait, parse_proxy_args},
     preprocessing::trait_preprocessing,
-    validate::validate_contract,
+    validate::{proxy_abi_full_path, validate_contract, validate_proxy_abi},
 };
 
 pub fn process_proxy(
@@ -14,12 +14,26 @@ pub fn process_proxy(
     let args_input = parse_macro_input!(args as syn::AttributeArgs);
     let proc_input = parse_macro_input!(new_input as syn::ItemTrait);
 
-    let contract = parse_contract_trait(args_input, &proc_input);
+    let proxy_args = parse_proxy_args(args_input);
+    let contract = parse_contract_trait(Vec::new(), &proc_input);
     validate_contract(&contract);
 
+    // recompiles the proxy whenever the ABI file changes
+    let abi_dependency = if let Some(abi_path) = &proxy_args.abi_path {
+        let abi_full_path = proxy_abi_full_path(abi_path);
+        validate_proxy_abi(&contract, abi_full_path.as_str());
+        quote! {
+            const _: &[u8] = include_bytes!(#abi_full_path);
+        }
+    } else {
+        quote! {}
+    };
+
     let proxy_impl = proxy_implementation(&contract, true);
 
     proc_macro::TokenStream::from(quote! {
+      #abi_dependency
+
       #proxy_impl
     })
 }
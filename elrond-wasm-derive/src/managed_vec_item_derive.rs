@@ -0,0 +1,152 @@
+use super::parse::attributes::{has_codec_attribute, CodecAbiAttributes};
+use proc_macro::TokenStream;
+use quote::quote;
+
+/// The API type parameter is the first type parameter of the struct, by convention `M`.
+/// Types without type parameters get an implementation for any API.
+fn api_type_and_generics(ast: &syn::DeriveInput) -> (syn::Ident, syn::Generics) {
+    if let Some(type_param) = ast.generics.type_params().next() {
+        return (type_param.ident.clone(), ast.generics.clone());
+    }
+    let api_ident: syn::Ident = parse_quote!(M);
+    let mut generics = ast.generics.clone();
+    generics
+        .params
+        .push(parse_quote!(#api_ident: elrond_wasm::api::ManagedTypeApi));
+    (api_ident, generics)
+}
+
+fn field_idents(fields: &syn::Fields) -> Vec<proc_macro2::TokenStream> {
+    fields
+        .iter()
+        .enumerate()
+        .map(|(index, field)| {
+            if let Some(ident) = &field.ident {
+                quote! { #ident }
+            } else {
+                let index = syn::Index::from(index);
+                quote! { #index }
+            }
+        })
+        .collect()
+}
+
+fn struct_derive(
+    ast: &syn::DeriveInput,
+    fields: &syn::Fields,
+    api_ident: &syn::Ident,
+) -> proc_macro2::TokenStream {
+    let field_types: Vec<&syn::Type> = fields.iter().map(|field| &field.ty).collect();
+    let field_idents = field_idents(fields);
+
+    // the payload is the same as the nested encoding only if all the fields are,
+    // and if the codec derives do not change the encoding of the fields
+    let codec_attributes = has_codec_attribute(ast.attrs.as_slice())
+        || fields
+            .iter()
+            .any(|field| has_codec_attribute(field.attrs.as_slice()));
+
+    quote! {
+        const PAYLOAD_SIZE: usize = elrond_wasm::types::managed_vec_item_derive_payload_size(
+            0 #(+ <#field_types as elrond_wasm::types::ManagedVecItem<#api_ident>>::PAYLOAD_SIZE)*
+        );
+        const NEEDS_RESERIALIZATION: bool = #codec_attributes
+            #(|| <#field_types as elrond_wasm::types::ManagedVecItem<#api_ident>>::NEEDS_RESERIALIZATION)*;
+
+        #[allow(unused_variables, unused_mut)]
+        fn from_byte_reader<Reader: FnMut(&mut [u8])>(api: #api_ident, mut reader: Reader) -> Self {
+            let mut payload = [0u8; elrond_wasm::types::MANAGED_VEC_ITEM_DERIVE_MAX_PAYLOAD_SIZE];
+            let payload_slice = &mut payload[..<Self as elrond_wasm::types::ManagedVecItem<#api_ident>>::PAYLOAD_SIZE];
+            reader(payload_slice);
+            let mut index = 0;
+            Self {
+                #(
+                    #field_idents: elrond_wasm::types::managed_vec_item_read_from_payload_index(
+                        api.clone(),
+                        payload_slice,
+                        &mut index,
+                    ),
+                )*
+            }
+        }
+
+        #[allow(unused_variables, unused_mut)]
+        fn to_byte_writer<R, Writer: FnMut(&[u8]) -> R>(&self, mut writer: Writer) -> R {
+            let mut payload = [0u8; elrond_wasm::types::MANAGED_VEC_ITEM_DERIVE_MAX_PAYLOAD_SIZE];
+            let payload_slice = &mut payload[..<Self as elrond_wasm::types::ManagedVecItem<#api_ident>>::PAYLOAD_SIZE];
+            let mut index = 0;
+            #(
+                elrond_wasm::types::managed_vec_item_save_to_payload_index::<#api_ident, _>(
+                    &self.#field_idents,
+                    payload_slice,
+                    &mut index,
+                );
+            )*
+            writer(payload_slice)
+        }
+    }
+}
+
+/// Fieldless enums are stored as their discriminant, on 1 byte.
+fn enum_derive(data_enum: &syn::DataEnum, api_ident: &syn::Ident) -> proc_macro2::TokenStream {
+    let mut next_discriminant = 0usize;
+    let mut discriminants = Vec::new();
+    let mut variant_idents = Vec::new();
+    for variant in data_enum.variants.iter() {
+        assert!(
+            matches!(variant.fields, syn::Fields::Unit),
+            "ManagedVecItem can only be derived for enums without fields"
+        );
+        let discriminant = CodecAbiAttributes::parse(variant.attrs.as_slice())
+            .discriminant
+            .unwrap_or(next_discriminant);
+        assert!(
+            discriminant <= u8::MAX as usize,
+            "enum discriminants must fit in a u8"
+        );
+        next_discriminant = discriminant + 1;
+        discriminants.push(discriminant as u8);
+        variant_idents.push(&variant.ident);
+    }
+
+    quote! {
+        const PAYLOAD_SIZE: usize = 1;
+
+        // the payload can come directly from the contract input, in which case it is not validated,
+        // reserializing ensures that all payloads hold valid discriminants
+        const NEEDS_RESERIALIZATION: bool = true;
+
+        fn from_byte_reader<Reader: FnMut(&mut [u8])>(api: #api_ident, reader: Reader) -> Self {
+            match <u8 as elrond_wasm::types::ManagedVecItem<#api_ident>>::from_byte_reader(api, reader) {
+                #( #discriminants => Self::#variant_idents, )*
+                _ => unreachable!(),
+            }
+        }
+
+        fn to_byte_writer<R, Writer: FnMut(&[u8]) -> R>(&self, writer: Writer) -> R {
+            let discriminant: u8 = match self {
+                #( Self::#variant_idents => #discriminants, )*
+            };
+            <u8 as elrond_wasm::types::ManagedVecItem<#api_ident>>::to_byte_writer(&discriminant, writer)
+        }
+    }
+}
+
+pub fn managed_vec_item_derive(ast: &syn::DeriveInput) -> TokenStream {
+    let (api_ident, generics) = api_type_and_generics(ast);
+    let items_impl = match &ast.data {
+        syn::Data::Struct(data_struct) => struct_derive(ast, &data_struct.fields, &api_ident),
+        syn::Data::Enum(data_enum) => enum_derive(data_enum, &api_ident),
+        syn::Data::Union(_) => panic!("Union not supported!"),
+    };
+
+    let name = &ast.ident;
+    let (impl_generics, _, where_clause) = generics.split_for_impl();
+    let (_, ty_generics, _) = ast.generics.split_for_impl();
+    let managed_vec_item_impl = quote! {
+        impl #impl_generics elrond_wasm::types::ManagedVecItem<#api_ident> for #name #ty_generics #where_clause {
+            #items_impl
+        }
+    };
+    managed_vec_item_impl.into()
+}
@@ -14,6 +14,7 @@ pub struct ArgMetadata {
     pub payment: ArgPaymentMetadata,
     pub var_args: bool,
     pub callback_call_result: bool,
+    pub callback_context: bool,
     pub event_topic: bool,
 }
 
@@ -23,6 +24,7 @@ impl Default for ArgMetadata {
             payment: ArgPaymentMetadata::NotPayment,
             var_args: false,
             callback_call_result: false,
+            callback_context: false,
             event_topic: false,
         }
     }
@@ -53,4 +55,9 @@ impl MethodArgument {
     pub fn is_endpoint_arg(&self) -> bool {
         matches!(self.metadata.payment, ArgPaymentMetadata::NotPayment)
     }
+
+    /// Arguments filled in from the async call result, not from the callback closure.
+    pub fn is_callback_result_arg(&self) -> bool {
+        self.metadata.callback_call_result || self.metadata.callback_context
+    }
 }
@@ -1,8 +1,9 @@
-use super::MethodPayableMetadata;
+use super::{MethodPayableMetadata, PaymentConstraints};
 
 #[derive(Clone, Debug)]
 pub struct InitMetadata {
     pub payable: MethodPayableMetadata,
+    pub payment_constraints: PaymentConstraints,
 }
 
 #[derive(Debug, Clone)]
@@ -32,7 +33,13 @@ impl EndpointMutabilityMetadata {
 pub struct EndpointMetadata {
     pub public_name: syn::Ident,
     pub payable: MethodPayableMetadata,
+    pub payment_constraints: PaymentConstraints,
     pub only_owner: bool,
+    pub only_role: Option<String>,
+    /// Lock group of `#[non_reentrant]` endpoints.
+    pub non_reentrant: Option<String>,
+    /// Used to select which endpoints go into which output contract.
+    pub labels: Vec<String>,
     pub mutability: EndpointMutabilityMetadata,
 }
 
@@ -47,6 +54,9 @@ pub enum PublicRole {
     /// The smart contract constructor. There can be only one.
     Init(InitMetadata),
 
+    /// Called instead of the constructor when the contract code gets upgraded. At most one.
+    Upgrade(InitMetadata),
+
     /// Means it gets a smart contract function generated for it
     Endpoint(EndpointMetadata),
 
@@ -3,13 +3,31 @@ use super::*;
 /// Indicates how smart contract method implementations will be auto-generated based on their annotations.
 #[derive(Clone, Debug)]
 pub enum AutoImpl {
-    LegacyEvent { identifier: Vec<u8> },
-    Event { identifier: String },
-    StorageGetter { identifier: String },
-    StorageSetter { identifier: String },
-    StorageMapper { identifier: String },
-    StorageIsEmpty { identifier: String },
-    StorageClear { identifier: String },
+    LegacyEvent {
+        identifier: Vec<u8>,
+    },
+    Event {
+        identifier: String,
+    },
+    /// `abi` is `false` if the storage is explicitly left out of the ABI.
+    StorageGetter {
+        identifier: String,
+        abi: bool,
+    },
+    StorageSetter {
+        identifier: String,
+        abi: bool,
+    },
+    StorageMapper {
+        identifier: String,
+        abi: bool,
+    },
+    StorageIsEmpty {
+        identifier: String,
+    },
+    StorageClear {
+        identifier: String,
+    },
     ProxyGetter,
 }
 #[derive(Clone, Debug)]
@@ -31,6 +49,13 @@ impl MethodImpl {
     }
 }
 
+/// Marks methods annotated with `#[migration(from = X, to = Y)]`.
+#[derive(Clone, Debug)]
+pub struct MigrationMetadata {
+    pub from: u32,
+    pub to: u32,
+}
+
 /// Models any method argument from a contract, module or callable proxy trait.
 #[derive(Clone, Debug)]
 pub struct Method {
@@ -43,6 +68,7 @@ pub struct Method {
     pub output_names: Vec<String>,
     pub return_type: syn::ReturnType,
     pub implementation: MethodImpl,
+    pub migration: Option<MigrationMetadata>,
 }
 
 impl Method {
@@ -76,7 +102,9 @@ impl Method {
 
     pub fn is_payable(&self) -> bool {
         match &self.public_role {
-            PublicRole::Init(init_metadata) => init_metadata.payable.is_payable(),
+            PublicRole::Init(init_metadata) | PublicRole::Upgrade(init_metadata) => {
+                init_metadata.payable.is_payable()
+            },
             PublicRole::Endpoint(endpoint_metadata) => endpoint_metadata.payable.is_payable(),
             PublicRole::Callback(_) | PublicRole::CallbackRaw => true,
             PublicRole::Private => false,
@@ -85,13 +113,37 @@ impl Method {
 
     pub fn payable_metadata(&self) -> MethodPayableMetadata {
         match &self.public_role {
-            PublicRole::Init(init_metadata) => init_metadata.payable.clone(),
+            PublicRole::Init(init_metadata) | PublicRole::Upgrade(init_metadata) => {
+                init_metadata.payable.clone()
+            },
             PublicRole::Endpoint(endpoint_metadata) => endpoint_metadata.payable.clone(),
             PublicRole::Callback(_) | PublicRole::CallbackRaw => MethodPayableMetadata::AnyToken,
             PublicRole::Private => MethodPayableMetadata::NotPayable,
         }
     }
 
+    pub fn payment_constraints(&self) -> PaymentConstraints {
+        match &self.public_role {
+            PublicRole::Init(init_metadata) | PublicRole::Upgrade(init_metadata) => {
+                init_metadata.payment_constraints.clone()
+            },
+            PublicRole::Endpoint(endpoint_metadata) => {
+                endpoint_metadata.payment_constraints.clone()
+            },
+            _ => PaymentConstraints::default(),
+        }
+    }
+
+    pub fn is_view(&self) -> bool {
+        matches!(
+            &self.public_role,
+            PublicRole::Endpoint(EndpointMetadata {
+                mutability: EndpointMutabilityMetadata::Readonly,
+                ..
+            })
+        )
+    }
+
     pub fn has_variable_nr_args(&self) -> bool {
         self.method_args.iter().any(|arg| arg.metadata.var_args)
     }
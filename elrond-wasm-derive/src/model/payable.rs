@@ -6,6 +6,28 @@ pub enum MethodPayableMetadata {
     Egld,
     SingleEsdtToken(String),
     AnyToken,
+    /// Any of several tokens, e.g. `#[payable("EGLD", "TOKEN-123456", token_from = "accepted_token")]`.
+    TokenList(Vec<PayableToken>),
+}
+
+/// One of the tokens accepted by a `#[payable(...)]` endpoint with several tokens.
+#[derive(Clone, Debug)]
+pub enum PayableToken {
+    Egld,
+    Esdt(String),
+    /// The token is provided at runtime by a contract method without arguments,
+    /// typically a storage getter.
+    FromMethod(syn::Ident),
+}
+
+impl PayableToken {
+    pub fn abi_string(&self) -> String {
+        match self {
+            PayableToken::Egld => "EGLD".to_string(),
+            PayableToken::Esdt(s) => s.clone(),
+            PayableToken::FromMethod(method_name) => format!("token_from:{}", method_name),
+        }
+    }
 }
 
 impl MethodPayableMetadata {
@@ -14,10 +36,13 @@ impl MethodPayableMetadata {
     }
 
     pub fn no_esdt(&self) -> bool {
-        matches!(
-            self,
-            MethodPayableMetadata::NotPayable | MethodPayableMetadata::Egld
-        )
+        match self {
+            MethodPayableMetadata::NotPayable | MethodPayableMetadata::Egld => true,
+            MethodPayableMetadata::TokenList(tokens) => tokens
+                .iter()
+                .all(|token| matches!(token, PayableToken::Egld)),
+            _ => false,
+        }
     }
 
     pub fn abi_strings(&self) -> Vec<String> {
@@ -26,6 +51,36 @@ impl MethodPayableMetadata {
             MethodPayableMetadata::Egld => vec!["EGLD".to_string()],
             MethodPayableMetadata::SingleEsdtToken(s) => vec![s.clone()],
             MethodPayableMetadata::AnyToken => vec!["*".to_string()],
+            MethodPayableMetadata::TokenList(tokens) => {
+                tokens.iter().map(PayableToken::abi_string).collect()
+            },
         }
     }
 }
+
+/// Constraint on the kind of token received, as reported by the VM.
+#[derive(Clone, Copy, Debug)]
+pub enum PayableTokenType {
+    /// EGLD or fungible ESDT, i.e. no nonce.
+    Fungible,
+    /// Any ESDT with a nonce: NFT, SFT or Meta ESDT.
+    NonFungible,
+}
+
+/// The shape of the payments accepted by a payable endpoint, besides the tokens,
+/// e.g. `#[payable("*", min_payments = 2, max_payments = 2, token_type = "NonFungible")]`.
+/// No constraints by default.
+#[derive(Clone, Debug, Default)]
+pub struct PaymentConstraints {
+    /// When missing, at least 1 payment is required if the tokens or the token type are restricted,
+    /// otherwise a call without payment would pass all the checks.
+    pub min_payments: Option<usize>,
+    pub max_payments: Option<usize>,
+    pub token_type: Option<PayableTokenType>,
+}
+
+impl PaymentConstraints {
+    pub fn is_empty(&self) -> bool {
+        self.min_payments.is_none() && self.max_payments.is_none() && self.token_type.is_none()
+    }
+}
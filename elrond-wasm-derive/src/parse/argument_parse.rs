@@ -76,6 +76,7 @@ fn process_arg_attribute(attr: &syn::Attribute, arg_metadata: &mut ArgMetadata)
         || process_payment_multi_attribute(attr, arg_metadata)
         || process_var_args_attribute(attr, arg_metadata)
         || process_callback_result_attribute(attr, arg_metadata)
+        || process_callback_context_attribute(attr, arg_metadata)
         || process_event_topic_attribute(attr, arg_metadata)
 }
 
@@ -138,6 +139,17 @@ fn process_callback_result_attribute(
     has_attr
 }
 
+fn process_callback_context_attribute(
+    attr: &syn::Attribute,
+    arg_metadata: &mut ArgMetadata,
+) -> bool {
+    let has_attr = is_callback_context_arg(attr);
+    if has_attr {
+        arg_metadata.callback_context = true;
+    }
+    has_attr
+}
+
 fn process_event_topic_attribute(attr: &syn::Attribute, arg_metadata: &mut ArgMetadata) -> bool {
     let has_attr = is_event_topic(attr);
     if has_attr {
@@ -25,6 +25,10 @@ pub fn is_callback_result_arg(attr: &syn::Attribute) -> bool {
     is_attribute_with_no_args(attr, ATTR_CALLBACK_CALL_RESULT)
 }
 
+pub fn is_callback_context_arg(attr: &syn::Attribute) -> bool {
+    is_attribute_with_no_args(attr, ATTR_CALLBACK_CONTEXT)
+}
+
 pub fn is_event_topic(attr: &syn::Attribute) -> bool {
     is_attribute_with_no_args(attr, ATTR_EVENT_INDEXED)
 }
@@ -1,5 +1,8 @@
 pub(super) static ATTR_PAYABLE: &str = "payable";
 pub(super) static ATTR_ONLY_OWNER: &str = "only_owner";
+pub(super) static ATTR_ONLY_ROLE: &str = "only_role";
+pub(super) static ATTR_NON_REENTRANT: &str = "non_reentrant";
+pub(super) static ATTR_LABEL: &str = "label";
 pub(super) static ATTR_OUTPUT_NAME: &str = "output_name";
 pub(super) static ATTR_PAYMENT: &str = "payment"; // synonymous with `payment_amount`
 pub(super) static ATTR_PAYMENT_AMOUNT: &str = "payment_amount";
@@ -8,6 +11,7 @@ pub(super) static ATTR_PAYMENT_NONCE: &str = "payment_nonce";
 pub(super) static ATTR_PAYMENT_MULTI: &str = "payment_multi";
 pub(super) static ATTR_VAR_ARGS: &str = "var_args";
 pub(super) static ATTR_INIT: &str = "init";
+pub(super) static ATTR_UPGRADE: &str = "upgrade";
 pub(super) static ATTR_VIEW: &str = "view";
 pub(super) static ATTR_LEGACY_EVENT: &str = "legacy_event";
 pub(super) static ATTR_EVENT: &str = "event";
@@ -16,9 +20,12 @@ pub(super) static ATTR_ENDPOINT: &str = "endpoint";
 pub(super) static ATTR_CALLBACK_DECL: &str = "callback";
 pub(super) static ATTR_CALLBACK_RAW_DECL: &str = "callback_raw";
 pub(super) static ATTR_CALLBACK_CALL_RESULT: &str = "call_result";
+pub(super) static ATTR_CALLBACK_CONTEXT: &str = "callback_context";
 pub(super) static ATTR_STORAGE_GET: &str = "storage_get";
 pub(super) static ATTR_STORAGE_SET: &str = "storage_set";
 pub(super) static ATTR_STORAGE_MAPPER: &str = "storage_mapper";
 pub(super) static ATTR_STORAGE_IS_EMPTY: &str = "storage_is_empty";
 pub(super) static ATTR_STORAGE_CLEAR: &str = "storage_clear";
 pub(super) static ATTR_PROXY: &str = "proxy";
+pub(super) static ATTR_MIGRATION: &str = "migration";
+pub(super) static ATTR_CODEC: &str = "codec";
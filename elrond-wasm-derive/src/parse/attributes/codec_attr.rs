@@ -0,0 +1,63 @@
+use super::attr_names::*;
+
+/// The parts of the `#[codec(...)]` struct, field and variant annotations that affect the ABI.
+/// The annotations are validated by the codec derives, unknown arguments are ignored here.
+#[derive(Default)]
+pub struct CodecAbiAttributes {
+    pub skip: bool,
+    pub optional: bool,
+    pub abi_type: Option<syn::Type>,
+    pub discriminant: Option<usize>,
+    pub version: Option<u8>,
+}
+
+impl CodecAbiAttributes {
+    pub fn parse(attrs: &[syn::Attribute]) -> CodecAbiAttributes {
+        let mut result = CodecAbiAttributes::default();
+        for attr in attrs {
+            if !attr.path.is_ident(ATTR_CODEC) {
+                continue;
+            }
+            let meta_list = match attr.parse_meta() {
+                Ok(syn::Meta::List(meta_list)) => meta_list,
+                _ => panic!("codec attribute expected in the form `#[codec(...)]`"),
+            };
+            for nested in meta_list.nested.iter() {
+                match nested {
+                    syn::NestedMeta::Meta(syn::Meta::Path(path)) if path.is_ident("skip") => {
+                        result.skip = true;
+                    },
+                    syn::NestedMeta::Meta(syn::Meta::Path(path)) if path.is_ident("optional") => {
+                        result.optional = true;
+                    },
+                    syn::NestedMeta::Meta(syn::Meta::NameValue(name_value)) => {
+                        if name_value.path.is_ident("abi_type") {
+                            if let syn::Lit::Str(lit_str) = &name_value.lit {
+                                result.abi_type = Some(
+                                    lit_str
+                                        .parse()
+                                        .expect("codec attribute `abi_type` expects a type"),
+                                );
+                            }
+                        } else if name_value.path.is_ident("discriminant") {
+                            if let syn::Lit::Int(lit_int) = &name_value.lit {
+                                result.discriminant = lit_int.base10_parse().ok();
+                            }
+                        } else if name_value.path.is_ident("version") {
+                            if let syn::Lit::Int(lit_int) = &name_value.lit {
+                                result.version = lit_int.base10_parse().ok();
+                            }
+                        }
+                    },
+                    _ => {},
+                }
+            }
+        }
+        result
+    }
+}
+
+/// True if there is at least one `#[codec(...)]` annotation, of any kind.
+pub fn has_codec_attribute(attrs: &[syn::Attribute]) -> bool {
+    attrs.iter().any(|attr| attr.path.is_ident(ATTR_CODEC))
+}
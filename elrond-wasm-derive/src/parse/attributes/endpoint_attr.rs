@@ -4,10 +4,65 @@ pub fn is_init(attr: &syn::Attribute) -> bool {
     is_attribute_with_no_args(attr, ATTR_INIT)
 }
 
+pub fn is_upgrade(attr: &syn::Attribute) -> bool {
+    is_attribute_with_no_args(attr, ATTR_UPGRADE)
+}
+
 pub fn is_only_owner(attr: &syn::Attribute) -> bool {
     is_attribute_with_no_args(attr, ATTR_ONLY_OWNER)
 }
 
+#[derive(Clone, Debug)]
+pub struct OnlyRoleAttribute {
+    pub role: String,
+}
+
+impl OnlyRoleAttribute {
+    pub fn parse(attr: &syn::Attribute) -> Option<OnlyRoleAttribute> {
+        is_attr_one_string_arg(attr, ATTR_ONLY_ROLE).map(|role| OnlyRoleAttribute { role })
+    }
+}
+
+/// `#[non_reentrant]` or `#[non_reentrant("group")]`.
+/// Endpoints without an explicit group all share the default group, named `""`.
+#[derive(Clone, Debug)]
+pub struct NonReentrantAttribute {
+    pub group: String,
+}
+
+impl NonReentrantAttribute {
+    pub fn parse(attr: &syn::Attribute) -> Option<NonReentrantAttribute> {
+        match is_attr_with_one_opt_token_tree_arg(attr, ATTR_NON_REENTRANT) {
+            None => None,
+            Some(Some(proc_macro2::TokenTree::Literal(lit))) => {
+                let str_val = lit.to_string();
+                assert!(
+                    str_val.starts_with('\"') && str_val.ends_with('\"'),
+                    "string literal expected as `#[non_reentrant]` group name"
+                );
+                Some(NonReentrantAttribute {
+                    group: str_val[1..str_val.len() - 1].to_string(),
+                })
+            },
+            Some(None) => Some(NonReentrantAttribute {
+                group: String::new(),
+            }),
+            _ => panic!("unexpected non_reentrant argument tokens"),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct LabelAttribute {
+    pub label: String,
+}
+
+impl LabelAttribute {
+    pub fn parse(attr: &syn::Attribute) -> Option<LabelAttribute> {
+        is_attr_one_string_arg(attr, ATTR_LABEL).map(|label| LabelAttribute { label })
+    }
+}
+
 pub fn is_callback_raw(attr: &syn::Attribute) -> bool {
     is_attribute_with_no_args(attr, ATTR_CALLBACK_RAW_DECL)
 }
@@ -0,0 +1,53 @@
+use super::attr_names::*;
+
+pub struct MigrationAttribute {
+    pub from: u32,
+    pub to: u32,
+}
+
+impl MigrationAttribute {
+    /// Parses `#[migration(from = 1, to = 2)]`.
+    pub fn parse(attr: &syn::Attribute) -> Option<MigrationAttribute> {
+        let first_seg = attr.path.segments.first()?;
+        if first_seg.ident != ATTR_MIGRATION {
+            return None;
+        }
+
+        let meta_list = match attr.parse_meta() {
+            Ok(syn::Meta::List(meta_list)) => meta_list,
+            _ => {
+                panic!("migration attribute expected in the form `#[migration(from = 1, to = 2)]`")
+            },
+        };
+
+        let mut from = None;
+        let mut to = None;
+        for nested in meta_list.nested.iter() {
+            match nested {
+                syn::NestedMeta::Meta(syn::Meta::NameValue(name_value)) => {
+                    let version = match &name_value.lit {
+                        syn::Lit::Int(lit_int) => lit_int
+                            .base10_parse::<u32>()
+                            .expect("migration versions must be u32 values"),
+                        _ => panic!("migration versions must be integer literals"),
+                    };
+                    if name_value.path.is_ident("from") {
+                        from = Some(version);
+                    } else if name_value.path.is_ident("to") {
+                        to = Some(version);
+                    } else {
+                        panic!("unknown migration attribute argument, only `from` and `to` are allowed");
+                    }
+                },
+                _ => panic!(
+                    "migration attribute expected in the form `#[migration(from = 1, to = 2)]`"
+                ),
+            }
+        }
+
+        Some(MigrationAttribute {
+            from: from.expect("missing `from` argument in migration attribute"),
+            to: to.expect("missing `to` argument in migration attribute"),
+        })
+    }
+}
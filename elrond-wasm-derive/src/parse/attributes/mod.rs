@@ -1,15 +1,19 @@
 mod argument_attr;
 mod attr_names;
+mod codec_attr;
 mod doc_attr;
 mod endpoint_attr;
 mod event_attr;
+mod migration_attr;
 mod payable_attr;
 mod storage_attr;
 mod util;
 
 pub use argument_attr::*;
+pub use codec_attr::{has_codec_attribute, CodecAbiAttributes};
 pub use doc_attr::{extract_doc, OutputNameAttribute};
 pub use endpoint_attr::*;
 pub use event_attr::*;
+pub use migration_attr::*;
 pub use payable_attr::*;
 pub use storage_attr::*;
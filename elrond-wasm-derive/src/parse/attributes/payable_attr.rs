@@ -1,57 +1,72 @@
 use super::attr_names::*;
 
+/// Parses `#[payable("TOKEN-1", "TOKEN-2", token_from = "method", min_payments = 1, max_payments = 2, token_type = "NonFungible")]`.
+/// All arguments are optional, but at least one token must be given, either as literal or with `token_from`.
+#[derive(Default)]
 pub struct PayableAttribute {
-    pub identifier: Option<String>,
+    pub identifiers: Vec<String>,
+    pub token_from: Vec<String>,
+    pub min_payments: Option<usize>,
+    pub max_payments: Option<usize>,
+    pub token_type: Option<String>,
 }
 
 impl PayableAttribute {
     pub fn parse(attr: &syn::Attribute) -> Option<PayableAttribute> {
-        if let Some(first_seg) = attr.path.segments.first() {
-            if first_seg.ident == ATTR_PAYABLE {
-                Some(PayableAttribute {
-                    identifier: extract_token_identifier(attr),
-                })
-            } else {
-                None
-            }
-        } else {
-            None
+        let first_seg = attr.path.segments.first()?;
+        if first_seg.ident != ATTR_PAYABLE {
+            return None;
         }
-    }
-}
 
-/// Current implementation only works with 1 token name.
-/// Might be extended in the future.
-fn extract_token_identifier(attr: &syn::Attribute) -> Option<String> {
-    let mut iter = attr.clone().tokens.into_iter();
-    let result_str = match iter.next() {
-        Some(proc_macro2::TokenTree::Group(group)) => {
-            assert!(
-                group.delimiter() == proc_macro2::Delimiter::Parenthesis,
-                "payable token name must be specified in parantheses"
-            );
-            let mut iter2 = group.stream().into_iter();
-            match iter2.next() {
-                Some(proc_macro2::TokenTree::Literal(lit)) => {
-                    let str_val = lit.to_string();
-                    assert!(
-                        str_val.starts_with('\"') && str_val.ends_with('\"'),
-                        "string literal expected as attribute argument"
-                    );
-                    let substr = &str_val[1..str_val.len() - 1];
-                    Some(substr.to_string())
+        let meta_list = match attr.parse_meta() {
+            Ok(syn::Meta::List(meta_list)) => meta_list,
+            Ok(syn::Meta::Path(_)) => return Some(PayableAttribute::default()),
+            _ => panic!("unexpected payable attribute format"),
+        };
+
+        let mut result = PayableAttribute::default();
+        for nested in meta_list.nested.iter() {
+            match nested {
+                syn::NestedMeta::Lit(syn::Lit::Str(lit_str)) => {
+                    result.identifiers.push(lit_str.value());
+                },
+                syn::NestedMeta::Meta(syn::Meta::NameValue(name_value)) => {
+                    if name_value.path.is_ident("token_from") {
+                        result
+                            .token_from
+                            .push(lit_string(&name_value.lit, "token_from"));
+                    } else if name_value.path.is_ident("min_payments") {
+                        result.min_payments = Some(lit_usize(&name_value.lit, "min_payments"));
+                    } else if name_value.path.is_ident("max_payments") {
+                        result.max_payments = Some(lit_usize(&name_value.lit, "max_payments"));
+                    } else if name_value.path.is_ident("token_type") {
+                        result.token_type = Some(lit_string(&name_value.lit, "token_type"));
+                    } else {
+                        panic!("unknown payable attribute argument, only `token_from`, `min_payments`, `max_payments` and `token_type` are allowed");
+                    }
                 },
-                _ => panic!("literal expected as event identifier"),
+                _ => panic!("string literal or named argument expected in payable attribute"),
             }
-        },
-        None => None,
-        _ => panic!("unexpected payable attribute format"),
-    };
+        }
+        Some(result)
+    }
+}
 
-    assert!(
-        iter.next().is_none(),
-        "event too many tokens in event attribute"
-    );
+fn lit_string(lit: &syn::Lit, arg_name: &str) -> String {
+    match lit {
+        syn::Lit::Str(lit_str) => lit_str.value(),
+        _ => panic!("payable attribute `{}` must be a string literal", arg_name),
+    }
+}
 
-    result_str
+fn lit_usize(lit: &syn::Lit, arg_name: &str) -> usize {
+    match lit {
+        syn::Lit::Int(lit_int) => lit_int
+            .base10_parse::<usize>()
+            .unwrap_or_else(|_| panic!("payable attribute `{}` out of range", arg_name)),
+        _ => panic!(
+            "payable attribute `{}` must be an integer literal",
+            arg_name
+        ),
+    }
 }
@@ -2,37 +2,43 @@ use super::{attr_names::*, util::*};
 
 pub struct StorageGetAttribute {
     pub identifier: String,
+
+    /// Set to `true` via `abi = true`, to describe the storage in the ABI.
+    pub abi: bool,
 }
 
 impl StorageGetAttribute {
     pub fn parse(attr: &syn::Attribute) -> Option<Self> {
-        is_attr_one_string_arg(attr, ATTR_STORAGE_GET).map(|arg_str| StorageGetAttribute {
-            identifier: arg_str,
-        })
+        is_attr_storage_key_and_abi_flag(attr, ATTR_STORAGE_GET)
+            .map(|(identifier, abi)| StorageGetAttribute { identifier, abi })
     }
 }
 
 pub struct StorageSetAttribute {
     pub identifier: String,
+
+    /// Set to `true` via `abi = true`, to describe the storage in the ABI.
+    pub abi: bool,
 }
 
 impl StorageSetAttribute {
     pub fn parse(attr: &syn::Attribute) -> Option<Self> {
-        is_attr_one_string_arg(attr, ATTR_STORAGE_SET).map(|arg_str| StorageSetAttribute {
-            identifier: arg_str,
-        })
+        is_attr_storage_key_and_abi_flag(attr, ATTR_STORAGE_SET)
+            .map(|(identifier, abi)| StorageSetAttribute { identifier, abi })
     }
 }
 
 pub struct StorageMapperAttribute {
     pub identifier: String,
+
+    /// Set to `true` via `abi = true`, to describe the storage in the ABI.
+    pub abi: bool,
 }
 
 impl StorageMapperAttribute {
     pub fn parse(attr: &syn::Attribute) -> Option<Self> {
-        is_attr_one_string_arg(attr, ATTR_STORAGE_MAPPER).map(|arg_str| StorageMapperAttribute {
-            identifier: arg_str,
-        })
+        is_attr_storage_key_and_abi_flag(attr, ATTR_STORAGE_MAPPER)
+            .map(|(identifier, abi)| StorageMapperAttribute { identifier, abi })
     }
 }
 
@@ -59,6 +59,45 @@ pub(super) fn is_attr_one_string_arg(attr: &syn::Attribute, attr_name: &str) ->
     }
 }
 
+/// Parses storage annotations of the form `#[name("key")]` or `#[name("key", abi = true)]`.
+/// The flag indicates whether the storage gets described in the ABI, it is `false` unless stated otherwise.
+pub(super) fn is_attr_storage_key_and_abi_flag(
+    attr: &syn::Attribute,
+    attr_name: &str,
+) -> Option<(String, bool)> {
+    if !attr.path.is_ident(attr_name) {
+        return None;
+    }
+
+    let nested_args = match attr.parse_meta() {
+        Ok(syn::Meta::List(meta_list)) => meta_list.nested,
+        _ => panic!("missing annotation identifier (check events and storage)"),
+    };
+    let mut nested_iter = nested_args.iter();
+    let identifier = match nested_iter.next() {
+        Some(syn::NestedMeta::Lit(syn::Lit::Str(lit_str))) => lit_str.value(),
+        _ => panic!("string literal expected as attribute argument (check events and storage)"),
+    };
+    let abi = match nested_iter.next() {
+        None => false,
+        Some(syn::NestedMeta::Meta(syn::Meta::NameValue(name_value)))
+            if name_value.path.is_ident("abi") =>
+        {
+            match &name_value.lit {
+                syn::Lit::Bool(lit_bool) => lit_bool.value,
+                _ => panic!("`abi` storage annotation argument must be `true` or `false`"),
+            }
+        },
+        Some(_) => panic!("only `abi = true` or `abi = false` allowed after the storage key"),
+    };
+    assert!(
+        nested_iter.next().is_none(),
+        "too many tokens in attribute (check events and storage)"
+    );
+
+    Some((identifier, abi))
+}
+
 fn attr_one_opt_token_tree_arg(attr: &syn::Attribute) -> Option<proc_macro2::TokenTree> {
     let mut iter = attr.clone().tokens.into_iter();
     let arg_token_tree: Option<proc_macro2::TokenTree> = match iter.next() {
@@ -56,6 +56,7 @@ pub fn process_storage_get_attribute(attr: &syn::Attribute, method: &mut Method)
             assert_no_other_auto_impl(&*method);
             method.implementation = MethodImpl::Generated(AutoImpl::StorageGetter {
                 identifier: storage_get.identifier,
+                abi: storage_get.abi,
             });
         })
         .is_some()
@@ -67,6 +68,7 @@ pub fn process_storage_set_attribute(attr: &syn::Attribute, method: &mut Method)
             assert_no_other_auto_impl(&*method);
             method.implementation = MethodImpl::Generated(AutoImpl::StorageSetter {
                 identifier: storage_set.identifier,
+                abi: storage_set.abi,
             });
         })
         .is_some()
@@ -78,6 +80,7 @@ pub fn process_storage_mapper_attribute(attr: &syn::Attribute, method: &mut Meth
             assert_no_other_auto_impl(&*method);
             method.implementation = MethodImpl::Generated(AutoImpl::StorageMapper {
                 identifier: storage_mapper.identifier,
+                abi: storage_mapper.abi,
             });
         })
         .is_some()
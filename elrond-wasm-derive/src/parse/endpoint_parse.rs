@@ -1,11 +1,12 @@
 use crate::model::{
     CallbackMetadata, EndpointMetadata, EndpointMutabilityMetadata, InitMetadata, Method,
-    PublicRole,
+    MigrationMetadata, PublicRole,
 };
 
 use super::{
     attributes::{
-        is_callback_raw, is_init, is_only_owner, CallbackAttribute, EndpointAttribute,
+        is_callback_raw, is_init, is_only_owner, is_upgrade, CallbackAttribute, EndpointAttribute,
+        LabelAttribute, MigrationAttribute, NonReentrantAttribute, OnlyRoleAttribute,
         OutputNameAttribute, ViewAttribute,
     },
     MethodAttributesPass1,
@@ -13,7 +14,7 @@ use super::{
 
 fn check_single_role(method: &Method) {
     assert!(matches!(method.public_role, PublicRole::Private),
-		"Can only annotate with one of the following arguments: `#[init]`, `#[endpoint]`, `#[view]`, `#[callback]`, `#[callback_raw]`."
+		"Can only annotate with one of the following arguments: `#[init]`, `#[upgrade]`, `#[endpoint]`, `#[view]`, `#[callback]`, `#[callback_raw]`."
 	);
 }
 
@@ -26,6 +27,24 @@ pub fn process_init_attribute(
         check_single_role(&*method);
         method.public_role = PublicRole::Init(InitMetadata {
             payable: pass_1_data.payable.clone(),
+            payment_constraints: pass_1_data.payment_constraints.clone(),
+        });
+        true
+    } else {
+        false
+    }
+}
+
+pub fn process_upgrade_attribute(
+    attr: &syn::Attribute,
+    pass_1_data: &MethodAttributesPass1,
+    method: &mut Method,
+) -> bool {
+    if is_upgrade(attr) {
+        check_single_role(&*method);
+        method.public_role = PublicRole::Upgrade(InitMetadata {
+            payable: pass_1_data.payable.clone(),
+            payment_constraints: pass_1_data.payment_constraints.clone(),
         });
         true
     } else {
@@ -44,6 +63,52 @@ pub fn process_only_owner_attribute(
     is_only_owner
 }
 
+pub fn process_only_role_attribute(
+    attr: &syn::Attribute,
+    pass_1_data: &mut MethodAttributesPass1,
+) -> bool {
+    OnlyRoleAttribute::parse(attr)
+        .map(|only_role_attr| {
+            assert!(
+                pass_1_data.only_role.is_none(),
+                "only one `#[only_role]` annotation allowed per endpoint"
+            );
+            pass_1_data.only_role = Some(only_role_attr.role);
+        })
+        .is_some()
+}
+
+pub fn process_non_reentrant_attribute(
+    attr: &syn::Attribute,
+    pass_1_data: &mut MethodAttributesPass1,
+) -> bool {
+    NonReentrantAttribute::parse(attr)
+        .map(|non_reentrant_attr| {
+            assert!(
+                pass_1_data.non_reentrant.is_none(),
+                "only one `#[non_reentrant]` annotation allowed per endpoint"
+            );
+            pass_1_data.non_reentrant = Some(non_reentrant_attr.group);
+        })
+        .is_some()
+}
+
+pub fn process_label_attribute(
+    attr: &syn::Attribute,
+    pass_1_data: &mut MethodAttributesPass1,
+) -> bool {
+    LabelAttribute::parse(attr)
+        .map(|label_attr| {
+            assert!(
+                !pass_1_data.labels.contains(&label_attr.label),
+                "duplicate label `{}`",
+                label_attr.label
+            );
+            pass_1_data.labels.push(label_attr.label);
+        })
+        .is_some()
+}
+
 pub fn process_endpoint_attribute(
     attr: &syn::Attribute,
     pass_1_data: &MethodAttributesPass1,
@@ -59,7 +124,11 @@ pub fn process_endpoint_attribute(
             method.public_role = PublicRole::Endpoint(EndpointMetadata {
                 public_name: endpoint_ident,
                 payable: pass_1_data.payable.clone(),
+                payment_constraints: pass_1_data.payment_constraints.clone(),
                 only_owner: pass_1_data.only_owner,
+                only_role: pass_1_data.only_role.clone(),
+                non_reentrant: pass_1_data.non_reentrant.clone(),
+                labels: pass_1_data.labels.clone(),
                 mutability: EndpointMutabilityMetadata::Mutable,
             });
         })
@@ -81,7 +150,11 @@ pub fn process_view_attribute(
             method.public_role = PublicRole::Endpoint(EndpointMetadata {
                 public_name: view_ident,
                 payable: pass_1_data.payable.clone(),
+                payment_constraints: pass_1_data.payment_constraints.clone(),
                 only_owner: pass_1_data.only_owner,
+                only_role: pass_1_data.only_role.clone(),
+                non_reentrant: pass_1_data.non_reentrant.clone(),
+                labels: pass_1_data.labels.clone(),
                 mutability: EndpointMutabilityMetadata::Readonly,
             });
         })
@@ -120,3 +193,18 @@ pub fn process_output_names_attribute(attr: &syn::Attribute, method: &mut Method
         })
         .is_some()
 }
+
+pub fn process_migration_attribute(attr: &syn::Attribute, method: &mut Method) -> bool {
+    MigrationAttribute::parse(attr)
+        .map(|migration_attr| {
+            assert!(
+                method.migration.is_none(),
+                "only one `#[migration]` annotation allowed per method"
+            );
+            method.migration = Some(MigrationMetadata {
+                from: migration_attr.from,
+                to: migration_attr.to,
+            });
+        })
+        .is_some()
+}
@@ -1,4 +1,4 @@
-use crate::model::{Method, MethodImpl, MethodPayableMetadata, PublicRole};
+use crate::model::{Method, MethodImpl, MethodPayableMetadata, PaymentConstraints, PublicRole};
 
 use super::{
     attributes::extract_doc,
@@ -9,13 +9,19 @@ use super::{
         process_storage_set_attribute,
     },
     extract_method_args, process_callback_attribute, process_callback_raw_attribute,
-    process_endpoint_attribute, process_init_attribute, process_only_owner_attribute,
-    process_output_names_attribute, process_payable_attribute, process_view_attribute,
+    process_endpoint_attribute, process_init_attribute, process_label_attribute,
+    process_migration_attribute, process_non_reentrant_attribute, process_only_owner_attribute,
+    process_only_role_attribute, process_output_names_attribute, process_payable_attribute,
+    process_upgrade_attribute, process_view_attribute,
 };
 pub struct MethodAttributesPass1 {
     pub method_name: String,
     pub payable: MethodPayableMetadata,
+    pub payment_constraints: PaymentConstraints,
     pub only_owner: bool,
+    pub only_role: Option<String>,
+    pub non_reentrant: Option<String>,
+    pub labels: Vec<String>,
 }
 
 pub fn process_method(m: &syn::TraitItemMethod) -> Method {
@@ -30,7 +36,11 @@ pub fn process_method(m: &syn::TraitItemMethod) -> Method {
     let mut first_pass_data = MethodAttributesPass1 {
         method_name: m.sig.ident.to_string(),
         payable: MethodPayableMetadata::NotPayable,
+        payment_constraints: PaymentConstraints::default(),
         only_owner: false,
+        only_role: None,
+        non_reentrant: None,
+        labels: Vec::new(),
     };
     let mut first_pass_unprocessed_attributes = Vec::new();
 
@@ -50,6 +60,7 @@ pub fn process_method(m: &syn::TraitItemMethod) -> Method {
         output_names: Vec::new(),
         return_type: m.sig.output.clone(),
         implementation,
+        migration: None,
     };
 
     process_attributes_second_pass(
@@ -57,10 +68,29 @@ pub fn process_method(m: &syn::TraitItemMethod) -> Method {
         &first_pass_data,
         &mut method,
     );
+    check_endpoint_only_attributes(&first_pass_data, &method);
 
     method
 }
 
+/// Access control and reentrancy locks only get generated for endpoints and views,
+/// anywhere else they would be silently ignored.
+fn check_endpoint_only_attributes(first_pass_data: &MethodAttributesPass1, method: &Method) {
+    if matches!(method.public_role, PublicRole::Endpoint(_)) {
+        return;
+    }
+    assert!(
+        first_pass_data.only_role.is_none(),
+        "`#[only_role]` is only allowed on endpoints and views (method: `{}`)",
+        method.name
+    );
+    assert!(
+        first_pass_data.non_reentrant.is_none(),
+        "`#[non_reentrant]` is only allowed on endpoints (method: `{}`)",
+        method.name
+    );
+}
+
 fn process_attributes_first_pass(
     attrs: &[syn::Attribute],
     first_pass_data: &mut MethodAttributesPass1,
@@ -80,6 +110,9 @@ fn process_attribute_first_pass(
 ) -> bool {
     process_payable_attribute(attr, first_pass_data)
         || process_only_owner_attribute(attr, first_pass_data)
+        || process_only_role_attribute(attr, first_pass_data)
+        || process_non_reentrant_attribute(attr, first_pass_data)
+        || process_label_attribute(attr, first_pass_data)
 }
 
 fn process_attributes_second_pass(
@@ -101,6 +134,7 @@ fn process_attribute_second_pass(
     method: &mut Method,
 ) -> bool {
     process_init_attribute(attr, first_pass_data, method)
+        || process_upgrade_attribute(attr, first_pass_data, method)
         || process_endpoint_attribute(attr, first_pass_data, method)
         || process_view_attribute(attr, first_pass_data, method)
         || process_callback_raw_attribute(attr, method)
@@ -114,4 +148,82 @@ fn process_attribute_second_pass(
         || process_storage_is_empty_attribute(attr, method)
         || process_storage_clear_attribute(attr, method)
         || process_output_names_attribute(attr, method)
+        || process_migration_attribute(attr, method)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::process_method;
+    use crate::model::PublicRole;
+
+    #[test]
+    fn test_only_role_on_endpoint() {
+        let method = process_method(&syn::parse_quote! {
+            #[endpoint]
+            #[only_role("minter")]
+            fn mint(&self);
+        });
+        match method.public_role {
+            PublicRole::Endpoint(endpoint_metadata) => {
+                assert_eq!(endpoint_metadata.only_role, Some("minter".to_string()))
+            },
+            _ => panic!("endpoint expected"),
+        }
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "`#[only_role]` is only allowed on endpoints and views (method: `init`)"
+    )]
+    fn test_only_role_on_init_rejected() {
+        process_method(&syn::parse_quote! {
+            #[init]
+            #[only_role("minter")]
+            fn init(&self);
+        });
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "`#[only_role]` is only allowed on endpoints and views (method: `my_callback`)"
+    )]
+    fn test_only_role_on_callback_rejected() {
+        process_method(&syn::parse_quote! {
+            #[callback]
+            #[only_role("minter")]
+            fn my_callback(&self);
+        });
+    }
+    #[test]
+    #[should_panic(expected = "`#[non_reentrant]` is only allowed on endpoints (method: `init`)")]
+    fn test_non_reentrant_on_init_rejected() {
+        process_method(&syn::parse_quote! {
+            #[init]
+            #[non_reentrant]
+            fn init(&self);
+        });
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "`#[non_reentrant]` is only allowed on endpoints (method: `my_callback`)"
+    )]
+    fn test_non_reentrant_on_callback_rejected() {
+        process_method(&syn::parse_quote! {
+            #[callback]
+            #[non_reentrant]
+            fn my_callback(&self);
+        });
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "`#[non_reentrant]` is only allowed on endpoints (method: `private_method`)"
+    )]
+    fn test_non_reentrant_on_private_method_rejected() {
+        process_method(&syn::parse_quote! {
+            #[non_reentrant("group_a")]
+            fn private_method(&self) {}
+        });
+    }
 }
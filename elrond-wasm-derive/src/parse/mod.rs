@@ -7,6 +7,7 @@ mod endpoint_parse;
 mod method_parse;
 mod parse_util;
 mod payable_parse;
+mod proxy_args_parse;
 mod split_path;
 mod supertrait_parse;
 
@@ -15,5 +16,6 @@ pub use contract_trait_parse::*;
 pub use endpoint_parse::*;
 pub use method_parse::*;
 pub use payable_parse::*;
+pub use proxy_args_parse::*;
 pub use split_path::*;
 pub use supertrait_parse::*;
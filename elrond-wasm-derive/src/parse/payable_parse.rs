@@ -1,21 +1,53 @@
 use super::{attributes::PayableAttribute, MethodAttributesPass1};
-use crate::model::MethodPayableMetadata;
+use crate::model::{MethodPayableMetadata, PayableToken, PayableTokenType, PaymentConstraints};
 
 pub fn process_payable_attribute(
     attr: &syn::Attribute,
     pass_1_data: &mut MethodAttributesPass1,
 ) -> bool {
     PayableAttribute::parse(attr).map(|payable_attr| {
-		if let Some(identifier) = payable_attr.identifier {
-			pass_1_data.payable = parse_payable_identifier(identifier.as_str());
-		} else {
+		if payable_attr.identifiers.is_empty() && payable_attr.token_from.is_empty() {
 			panic!(
 				"Endpoint `payable` attribute requires one argument. Replace with `#[payable(\"*\")]` or `#[payable(\"EGLD\")]`. Method name: {}",
 				&pass_1_data.method_name);
 		}
+		pass_1_data.payable = parse_payable_tokens(&payable_attr);
+		pass_1_data.payment_constraints = parse_payment_constraints(&payable_attr);
 	}).is_some()
 }
 
+fn parse_payable_tokens(payable_attr: &PayableAttribute) -> MethodPayableMetadata {
+    if payable_attr.token_from.is_empty() {
+        if let [identifier] = payable_attr.identifiers.as_slice() {
+            return parse_payable_identifier(identifier.as_str());
+        }
+    }
+
+    assert!(
+        !payable_attr
+            .identifiers
+            .iter()
+            .any(|identifier| identifier == "*"),
+        "`*` cannot be combined with other tokens in #[payable] attribute"
+    );
+    let mut tokens: Vec<PayableToken> = payable_attr
+        .identifiers
+        .iter()
+        .map(|identifier| match identifier.as_str() {
+            "EGLD" => PayableToken::Egld,
+            "" => panic!("empty token name not allowed in #[payable] attribute"),
+            _ => PayableToken::Esdt(identifier.clone()),
+        })
+        .collect();
+    tokens.extend(payable_attr.token_from.iter().map(|method_name| {
+        PayableToken::FromMethod(syn::Ident::new(
+            method_name.as_str(),
+            proc_macro2::Span::call_site(),
+        ))
+    }));
+    MethodPayableMetadata::TokenList(tokens)
+}
+
 fn parse_payable_identifier(identifier: &str) -> MethodPayableMetadata {
     match identifier {
         "EGLD" => MethodPayableMetadata::Egld,
@@ -24,3 +56,30 @@ fn parse_payable_identifier(identifier: &str) -> MethodPayableMetadata {
         _ => MethodPayableMetadata::SingleEsdtToken(identifier.to_string()),
     }
 }
+
+fn parse_payment_constraints(payable_attr: &PayableAttribute) -> PaymentConstraints {
+    if let (Some(min_payments), Some(max_payments)) =
+        (payable_attr.min_payments, payable_attr.max_payments)
+    {
+        assert!(
+            min_payments <= max_payments,
+            "`min_payments` cannot be greater than `max_payments` in #[payable] attribute"
+        );
+    }
+    let token_type = payable_attr
+        .token_type
+        .as_deref()
+        .map(|token_type| match token_type {
+            "Fungible" => PayableTokenType::Fungible,
+            "NonFungible" => PayableTokenType::NonFungible,
+            _ => panic!(
+                "unknown token type `{}` in #[payable] attribute, only `Fungible` and `NonFungible` are allowed",
+                token_type
+            ),
+        });
+    PaymentConstraints {
+        min_payments: payable_attr.min_payments,
+        max_payments: payable_attr.max_payments,
+        token_type,
+    }
+}
@@ -0,0 +1,28 @@
+/// Arguments of the `#[elrond_wasm::proxy(...)]` annotation.
+#[derive(Default)]
+pub struct ProxyArgs {
+    /// Path to the ABI of the called contract, relative to the crate root.
+    /// If present, the proxy methods are checked against it at compile time.
+    pub abi_path: Option<String>,
+}
+
+/// Parses `#[elrond_wasm::proxy]` and `#[elrond_wasm::proxy(abi = "path/to/callee.abi.json")]`.
+pub fn parse_proxy_args(args: syn::AttributeArgs) -> ProxyArgs {
+    let mut proxy_args = ProxyArgs::default();
+    for arg in args.iter() {
+        match arg {
+            syn::NestedMeta::Meta(syn::Meta::NameValue(name_value))
+                if name_value.path.is_ident("abi") =>
+            {
+                match &name_value.lit {
+                    syn::Lit::Str(lit_str) => proxy_args.abi_path = Some(lit_str.value()),
+                    _ => panic!("proxy ABI path must be a string literal"),
+                }
+            },
+            _ => panic!(
+                "proxy annotation expected in the form `#[elrond_wasm::proxy]` or `#[elrond_wasm::proxy(abi = \"path/to/callee.abi.json\")]`"
+            ),
+        }
+    }
+    proxy_args
+}
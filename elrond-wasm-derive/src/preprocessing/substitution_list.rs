@@ -42,6 +42,11 @@ fn add_managed_types(substitutions: &mut SubstitutionsMap) {
     add_managed_type(substitutions, &quote!(ManagedAsyncCallError));
 
     add_managed_type_with_generics(substitutions, &quote!(ManagedVec), &quote!(ManagedVec));
+    add_managed_type_with_generics(
+        substitutions,
+        &quote!(ManagedByteArray),
+        &quote!(ManagedByteArray),
+    );
     add_managed_type_with_generics(
         substitutions,
         &quote!(ManagedVarArgs),
@@ -57,6 +62,16 @@ fn add_managed_types(substitutions: &mut SubstitutionsMap) {
         &quote!(ManagedAsyncCallResult),
         &quote!(ManagedAsyncCallResult),
     );
+    add_managed_type_with_generics(
+        substitutions,
+        &quote!(CallbackContext),
+        &quote!(CallbackContext),
+    );
+    add_managed_type_with_generics(
+        substitutions,
+        &quote!(CallbackResult),
+        &quote!(CallbackResult),
+    );
 }
 
 fn add_special_methods(substitutions: &mut SubstitutionsMap) {
@@ -121,6 +136,15 @@ fn add_storage_mapper_single_generic_arg(
     substitutions.add_substitution(quote!(#mapper_name), quote!(#mapper_name<Self::Api>));
 }
 
+/// For mappers whose only generic argument besides the API has a default.
+fn add_storage_mapper_optional_generic_arg(
+    substitutions: &mut SubstitutionsMap,
+    mapper_name: &proc_macro2::TokenStream,
+) {
+    add_storage_mapper_single_generic_arg(substitutions, mapper_name);
+    add_managed_type_with_generics(substitutions, mapper_name, mapper_name);
+}
+
 fn add_storage_mapper(
     substitutions: &mut SubstitutionsMap,
     mapper_name: &proc_macro2::TokenStream,
@@ -129,12 +153,15 @@ fn add_storage_mapper(
 }
 
 fn add_storage_mappers(substitutions: &mut SubstitutionsMap) {
+    add_storage_mapper_single_generic_arg(substitutions, &quote!(FungibleTokenMapper));
+    add_storage_mapper_single_generic_arg(substitutions, &quote!(NonFungibleTokenMapper));
     add_storage_mapper_single_generic_arg(substitutions, &quote!(TokenAttributesMapper));
     add_storage_mapper_single_generic_arg(substitutions, &quote!(UserMapper));
 
     add_storage_mapper(substitutions, &quote!(LinkedListMapper));
     add_storage_mapper(substitutions, &quote!(MapMapper));
     add_storage_mapper(substitutions, &quote!(MapStorageMapper));
+    add_storage_mapper_optional_generic_arg(substitutions, &quote!(MerkleTreeMapper));
     add_storage_mapper(substitutions, &quote!(SetMapper));
     add_storage_mapper(substitutions, &quote!(SingleValueMapper));
     add_storage_mapper(substitutions, &quote!(VecMapper));
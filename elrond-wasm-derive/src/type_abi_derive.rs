@@ -1,25 +1,45 @@
-use super::parse::attributes::extract_doc;
+use super::parse::attributes::{extract_doc, CodecAbiAttributes};
 use proc_macro::TokenStream;
 use quote::quote;
 
 fn field_snippet(index: usize, field: &syn::Field) -> proc_macro2::TokenStream {
+    let codec_attributes = CodecAbiAttributes::parse(field.attrs.as_slice());
+    if codec_attributes.skip {
+        return quote! {};
+    }
     let field_docs = extract_doc(field.attrs.as_slice());
     let field_name_str = if let Some(ident) = &field.ident {
         ident.to_string()
     } else {
         index.to_string()
     };
-    let field_ty = &field.ty;
+    // fields with a custom codec can declare the type that describes their encoding
+    let field_ty = codec_attributes.abi_type.as_ref().unwrap_or(&field.ty);
+    let optional = codec_attributes.optional;
     quote! {
         field_descriptions.push(elrond_wasm::abi::StructFieldDescription {
             docs: &[ #(#field_docs),* ],
             name: #field_name_str,
             field_type: <#field_ty>::type_name(),
+            optional: #optional,
         });
         <#field_ty>::provide_type_descriptions(accumulator);
     }
 }
 
+/// Versioned structs are encoded starting with the version byte, which shows up as a first field.
+fn version_field_snippet(version: u8) -> proc_macro2::TokenStream {
+    let version_doc = format!("Encoding version, always {}.", version);
+    quote! {
+        field_descriptions.push(elrond_wasm::abi::StructFieldDescription {
+            docs: &[ #version_doc ],
+            name: "version",
+            field_type: <u8>::type_name(),
+            optional: false,
+        });
+    }
+}
+
 fn fields_snippets(fields: &syn::Fields) -> Vec<proc_macro2::TokenStream> {
     match fields {
         syn::Fields::Named(fields_named) => fields_named
@@ -42,6 +62,9 @@ pub fn type_abi_derive(ast: &syn::DeriveInput) -> TokenStream {
     let type_docs = extract_doc(ast.attrs.as_slice());
     let type_description_impl = match &ast.data {
         syn::Data::Struct(data_struct) => {
+            let version_snippet = CodecAbiAttributes::parse(ast.attrs.as_slice())
+                .version
+                .map(version_field_snippet);
             let struct_field_snippets = fields_snippets(&data_struct.fields);
             quote! {
                 fn provide_type_descriptions<TDC: elrond_wasm::abi::TypeDescriptionContainer>(accumulator: &mut TDC) {
@@ -49,6 +72,7 @@ pub fn type_abi_derive(ast: &syn::DeriveInput) -> TokenStream {
                     if !accumulator.contains_type(&type_name) {
                         accumulator.reserve_type_name(type_name.clone());
                         let mut field_descriptions = elrond_wasm::Vec::new();
+                        #version_snippet
                         #(#struct_field_snippets)*
                         accumulator.insert(
                             type_name.clone(),
@@ -66,8 +90,14 @@ pub fn type_abi_derive(ast: &syn::DeriveInput) -> TokenStream {
             let enum_variant_snippets: Vec<proc_macro2::TokenStream> = data_enum
                 .variants
                 .iter()
-                .enumerate()
-                .map(|(variant_index, variant)| {
+                .scan(0usize, |next_discriminant, variant| {
+                    let discriminant = CodecAbiAttributes::parse(variant.attrs.as_slice())
+                        .discriminant
+                        .unwrap_or(*next_discriminant);
+                    *next_discriminant = discriminant + 1;
+                    Some((discriminant, variant))
+                })
+                .map(|(discriminant, variant)| {
                     let variant_docs = extract_doc(variant.attrs.as_slice());
                     let variant_name_str = variant.ident.to_string();
                     let variant_field_snippets = fields_snippets(&variant.fields);
@@ -76,7 +106,7 @@ pub fn type_abi_derive(ast: &syn::DeriveInput) -> TokenStream {
                         #(#variant_field_snippets)*
                         variant_descriptions.push(elrond_wasm::abi::EnumVariantDescription {
                             docs: &[ #(#variant_docs),* ],
-                            discriminant: #variant_index,
+                            discriminant: #discriminant,
                             name: #variant_name_str,
                             fields: field_descriptions,
                         });
@@ -1,4 +1,8 @@
 mod reserved;
 mod validate_method;
+mod validate_proxy_abi;
+mod validate_view;
 
 pub use validate_method::*;
+pub use validate_proxy_abi::*;
+pub use validate_view::*;
@@ -1,19 +1,28 @@
-use super::reserved;
-use crate::model::{ArgPaymentMetadata, ContractTrait, Method, PublicRole};
+use super::{reserved, validate_views};
+use crate::model::{ArgPaymentMetadata, AutoImpl, ContractTrait, Method, MethodImpl, PublicRole};
 
 const INIT_ENDPOINT_NAME: &str = "init";
+const UPGRADE_ENDPOINT_NAME: &str = "upgrade";
+const CALLBACK_ENDPOINT_NAME: &str = "callBack";
 
 /// TODO: make it work with Result instead of panic
 pub fn validate_contract(contract_trait: &ContractTrait) {
     for m in &contract_trait.methods {
         validate_method(m);
     }
+    validate_migrations(contract_trait);
+    validate_upgrade(contract_trait);
+    validate_unique_endpoint_names(contract_trait);
+    validate_unique_event_identifiers(contract_trait);
+    validate_views(contract_trait);
 }
 
 pub fn validate_method(m: &Method) {
     validate_method_name(m);
     validate_payment_args(m);
     validate_callback_call_result_arg(m);
+    validate_migration_method(m);
+    validate_non_reentrant(m);
 }
 
 fn validate_method_name(m: &Method) {
@@ -23,6 +32,10 @@ fn validate_method_name(m: &Method) {
             endpoint_name_str != INIT_ENDPOINT_NAME,
             "Cannot declare endpoint with name 'init'. Use #[init] instead."
         );
+        assert!(
+            endpoint_name_str != CALLBACK_ENDPOINT_NAME,
+            "Cannot declare endpoint with name 'callBack'. Use #[callback] instead."
+        );
         assert!(!reserved::is_reserved(endpoint_name_str.as_str()), "Cannot declare endpoint with name '{}', because that name is reserved by the Arwen API.", endpoint_name_str);
     }
 }
@@ -73,10 +86,11 @@ fn validate_payment_args(m: &Method) {
 
         assert!(num_payment_token == 0, "`#[payment_token]` only allowed in payable endpoints, payable init or callbacks (method: `{}`)", m.name);
     }
-    if let PublicRole::Init(init_metadata) = &m.public_role {
+    if let PublicRole::Init(init_metadata) | PublicRole::Upgrade(init_metadata) = &m.public_role {
         assert!(
             init_metadata.payable.no_esdt(),
-            "only EGLD payments currently allowed in constructors"
+            "only EGLD payments currently allowed in constructors (method: `{}`)",
+            m.name
         );
     }
     validate_payment_args_not_reference(m);
@@ -100,16 +114,165 @@ fn validate_callback_call_result_arg(m: &Method) {
         .iter()
         .filter(|&arg| arg.metadata.callback_call_result)
         .count();
+    let num_callback_context = m
+        .method_args
+        .iter()
+        .filter(|&arg| arg.metadata.callback_context)
+        .count();
 
     if matches!(&m.public_role, PublicRole::Callback(_)) {
         assert!(
             num_call_result <= 1,
             "only one `#[call_result]` argument allowed"
         );
+        assert!(
+            num_callback_context <= 1,
+            "only one `#[callback_context]` argument allowed"
+        );
+        assert!(
+            num_call_result + num_callback_context <= 1,
+            "`#[call_result]` and `#[callback_context]` cannot be used together, the callback context already contains the result"
+        );
     } else {
         assert!(
             num_call_result <= 1,
             "`#[call_result]` argument only allowed in `#[callback]` methods"
         );
+        assert!(
+            num_callback_context == 0,
+            "`#[callback_context]` argument only allowed in `#[callback]` methods"
+        );
+    }
+}
+
+fn validate_migration_method(m: &Method) {
+    if let Some(migration) = &m.migration {
+        assert!(
+            matches!(m.public_role, PublicRole::Private),
+            "migrations cannot be endpoints or callbacks (method: `{}`)",
+            m.name
+        );
+        assert!(
+            m.method_args.is_empty(),
+            "migrations cannot have arguments (method: `{}`)",
+            m.name
+        );
+        assert!(
+            migration.from < migration.to,
+            "migrations must go from a lower to a higher storage version (method: `{}`)",
+            m.name
+        );
+    }
+}
+
+/// The reentrancy lock lives in storage, which views cannot write.
+fn validate_non_reentrant(m: &Method) {
+    if let PublicRole::Endpoint(endpoint_metadata) = &m.public_role {
+        assert!(
+            endpoint_metadata.non_reentrant.is_none() || !m.is_view(),
+            "`#[non_reentrant]` not allowed in views (method: `{}`)",
+            m.name
+        );
+    }
+}
+
+fn validate_migrations(contract_trait: &ContractTrait) {
+    let migrations: Vec<&Method> = contract_trait
+        .methods
+        .iter()
+        .filter(|m| m.migration.is_some())
+        .collect();
+    if !migrations.is_empty() {
+        assert!(
+            contract_trait
+                .methods
+                .iter()
+                .any(|m| matches!(m.public_role, PublicRole::Upgrade(_))),
+            "contracts with migrations need an `#[upgrade]` method, to tell upgrades apart from fresh deploys"
+        );
+    }
+    for (i, m1) in migrations.iter().enumerate() {
+        for m2 in &migrations[i + 1..] {
+            assert!(
+                m1.migration.as_ref().unwrap().from != m2.migration.as_ref().unwrap().from,
+                "only one migration allowed from each storage version (methods: `{}`, `{}`)",
+                m1.name,
+                m2.name
+            );
+        }
+    }
+}
+
+fn validate_upgrade(contract_trait: &ContractTrait) {
+    let upgrade_methods: Vec<&Method> = contract_trait
+        .methods
+        .iter()
+        .filter(|m| matches!(m.public_role, PublicRole::Upgrade(_)))
+        .collect();
+    if let [first, second, ..] = upgrade_methods.as_slice() {
+        panic!(
+            "only one `#[upgrade]` method allowed (methods: `{}`, `{}`)",
+            first.name, second.name
+        );
+    }
+    // the `upgrade` export is only reserved for contracts with an `#[upgrade]` method
+    if upgrade_methods.is_empty() {
+        return;
+    }
+    for m in &contract_trait.methods {
+        if let PublicRole::Endpoint(endpoint_metadata) = &m.public_role {
+            assert!(
+                endpoint_metadata.public_name != UPGRADE_ENDPOINT_NAME,
+                "Cannot declare endpoint with name 'upgrade' next to an #[upgrade] method (method: `{}`)",
+                m.name
+            );
+        }
+    }
+}
+
+/// Endpoints from different modules are only checked when building the contract ABI, in the meta crate.
+fn validate_unique_endpoint_names(contract_trait: &ContractTrait) {
+    let endpoints: Vec<(String, &Method)> = contract_trait
+        .methods
+        .iter()
+        .filter_map(|m| match &m.public_role {
+            PublicRole::Endpoint(endpoint_metadata) => {
+                Some((endpoint_metadata.public_name.to_string(), m))
+            },
+            _ => None,
+        })
+        .collect();
+    for (i, (name1, m1)) in endpoints.iter().enumerate() {
+        for (name2, m2) in &endpoints[i + 1..] {
+            assert!(
+                name1 != name2,
+                "duplicate endpoint name `{}` (methods: `{}`, `{}`)",
+                name1,
+                m1.name,
+                m2.name
+            );
+        }
+    }
+}
+
+fn validate_unique_event_identifiers(contract_trait: &ContractTrait) {
+    let events: Vec<(&String, &Method)> = contract_trait
+        .methods
+        .iter()
+        .filter_map(|m| match &m.implementation {
+            MethodImpl::Generated(AutoImpl::Event { identifier }) => Some((identifier, m)),
+            _ => None,
+        })
+        .collect();
+    for (i, (identifier1, m1)) in events.iter().enumerate() {
+        for (identifier2, m2) in &events[i + 1..] {
+            assert!(
+                identifier1 != identifier2,
+                "duplicate event identifier `{}` (methods: `{}`, `{}`)",
+                identifier1,
+                m1.name,
+                m2.name
+            );
+        }
     }
 }
@@ -0,0 +1,482 @@
+use crate::model::{ContractTrait, Method, PublicRole};
+use quote::ToTokens;
+use serde_json::Value;
+use std::{fmt::Write, path::Path};
+
+/// Types that never produce results, mirrors their `TypeAbi::output_abis` implementations.
+const NO_OUTPUT_TYPES: &[&str] = &["AsyncCall", "SendToken", "SendEgld", "SendEsdt"];
+
+/// Signature of an endpoint, as it appears in the ABI.
+#[derive(PartialEq)]
+struct AbiSignature {
+    inputs: Vec<(String, String)>,
+    outputs: Vec<String>,
+}
+
+impl AbiSignature {
+    fn display(&self, endpoint_name: &str) -> String {
+        let inputs: Vec<String> = self
+            .inputs
+            .iter()
+            .map(|(name, type_name)| format!("{}: {}", name, type_name))
+            .collect();
+        let mut result = format!("{}({})", endpoint_name, inputs.join(", "));
+        if !self.outputs.is_empty() {
+            write!(result, " -> {}", self.outputs.join(", ")).unwrap();
+        }
+        result
+    }
+}
+
+/// Resolves the path given in `#[elrond_wasm::proxy(abi = "...")]` relative to the crate being compiled.
+pub fn proxy_abi_full_path(abi_path: &str) -> String {
+    let manifest_dir =
+        std::env::var("CARGO_MANIFEST_DIR").expect("CARGO_MANIFEST_DIR not available");
+    Path::new(&manifest_dir)
+        .join(abi_path)
+        .to_string_lossy()
+        .into_owned()
+}
+
+/// Checks that the endpoints declared in a proxy match those of the callee ABI:
+/// same endpoint names, same number of arguments, same argument and result type names.
+/// Payment arguments do not show up in the ABI, so they are not checked.
+/// The proxy can declare only a part of the callee endpoints.
+pub fn validate_proxy_abi(contract_trait: &ContractTrait, abi_full_path: &str) {
+    let abi_json_str = std::fs::read_to_string(abi_full_path)
+        .unwrap_or_else(|err| panic!("could not read proxy ABI `{}`: {}", abi_full_path, err));
+    let abi_json: Value = serde_json::from_str(abi_json_str.as_str())
+        .unwrap_or_else(|err| panic!("could not parse proxy ABI `{}`: {}", abi_full_path, err));
+
+    let mismatches = proxy_abi_mismatches(contract_trait, &abi_json);
+    assert!(
+        mismatches.is_empty(),
+        "proxy `{}` does not match ABI `{}` (- ABI, + proxy):\n{}",
+        contract_trait.trait_name,
+        abi_full_path,
+        mismatches
+    );
+}
+
+/// One entry for each proxy endpoint that is missing from the ABI or has a different signature.
+fn proxy_abi_mismatches(contract_trait: &ContractTrait, abi_json: &Value) -> String {
+    let mut mismatches = String::new();
+    for m in &contract_trait.methods {
+        let (endpoint_name, abi_endpoint) = match &m.public_role {
+            PublicRole::Init(_) => ("init".to_string(), abi_json.get("constructor")),
+            PublicRole::Upgrade(_) => ("upgrade".to_string(), abi_json.get("upgradeConstructor")),
+            PublicRole::Endpoint(endpoint_metadata) => {
+                let endpoint_name = endpoint_metadata.public_name.to_string();
+                let abi_endpoint = find_abi_endpoint(abi_json, endpoint_name.as_str());
+                (endpoint_name, abi_endpoint)
+            },
+            _ => continue,
+        };
+
+        let method_desc = if m.name == endpoint_name {
+            format!("`{}`", endpoint_name)
+        } else {
+            format!("`{}` (method `{}`)", endpoint_name, m.name)
+        };
+        let found = proxy_method_signature(m);
+        let expected = match abi_endpoint {
+            Some(abi_endpoint) => abi_endpoint_signature(abi_endpoint),
+            None => {
+                writeln!(mismatches, "  {} not found in the ABI", method_desc).unwrap();
+                continue;
+            },
+        };
+        if !signatures_match(&expected, &found) {
+            writeln!(mismatches, "  {}:", method_desc).unwrap();
+            writeln!(mismatches, "    - {}", expected.display(&endpoint_name)).unwrap();
+            writeln!(mismatches, "    + {}", found.display(&endpoint_name)).unwrap();
+        }
+    }
+    mismatches
+}
+
+/// Argument names are only informative, they do not need to match.
+fn signatures_match(expected: &AbiSignature, found: &AbiSignature) -> bool {
+    expected.inputs.len() == found.inputs.len()
+        && expected
+            .inputs
+            .iter()
+            .zip(found.inputs.iter())
+            .all(|((_, expected_type), (_, found_type))| expected_type == found_type)
+        && expected.outputs == found.outputs
+}
+
+fn find_abi_endpoint<'a>(abi_json: &'a Value, endpoint_name: &str) -> Option<&'a Value> {
+    abi_json
+        .get("endpoints")?
+        .as_array()?
+        .iter()
+        .find(|endpoint| endpoint.get("name").and_then(Value::as_str) == Some(endpoint_name))
+}
+
+fn abi_endpoint_signature(abi_endpoint: &Value) -> AbiSignature {
+    let json_list = |key: &str| {
+        abi_endpoint
+            .get(key)
+            .and_then(Value::as_array)
+            .cloned()
+            .unwrap_or_default()
+    };
+    let json_str = |value: &Value, key: &str| {
+        value
+            .get(key)
+            .and_then(Value::as_str)
+            .unwrap_or_default()
+            .to_string()
+    };
+    AbiSignature {
+        inputs: json_list("inputs")
+            .iter()
+            .map(|input| (json_str(input, "name"), json_str(input, "type")))
+            .collect(),
+        outputs: json_list("outputs")
+            .iter()
+            .map(|output| json_str(output, "type"))
+            .collect(),
+    }
+}
+
+fn proxy_method_signature(m: &Method) -> AbiSignature {
+    AbiSignature {
+        inputs: m
+            .method_args
+            .iter()
+            .filter(|arg| arg.is_endpoint_arg())
+            .map(|arg| {
+                (
+                    arg.pat.to_token_stream().to_string(),
+                    abi_type_name(&arg.ty),
+                )
+            })
+            .collect(),
+        outputs: match &m.return_type {
+            syn::ReturnType::Default => Vec::new(),
+            syn::ReturnType::Type(_, ty) => abi_output_type_names(ty),
+        },
+    }
+}
+
+/// Mirrors the `TypeAbi::output_abis` implementations:
+/// the unit type and the various async call/send types produce no outputs,
+/// `SCResult` and `Result` produce the outputs of their contents,
+/// multi-results produce one output for each of their components.
+fn abi_output_type_names(ty: &syn::Type) -> Vec<String> {
+    match ty {
+        syn::Type::Tuple(tuple) if tuple.elems.is_empty() => Vec::new(),
+        syn::Type::Paren(paren) => abi_output_type_names(&paren.elem),
+        syn::Type::Group(group) => abi_output_type_names(&group.elem),
+        syn::Type::Path(type_path) => {
+            let (ident, type_args) = path_ident_and_type_args(&type_path.path);
+            if NO_OUTPUT_TYPES.contains(&ident.as_str()) {
+                Vec::new()
+            } else if matches!(ident.as_str(), "SCResult" | "Result") && !type_args.is_empty() {
+                abi_output_type_names(type_args[0])
+            } else if is_multi_type(ident.as_str()) {
+                type_args
+                    .into_iter()
+                    .flat_map(abi_output_type_names)
+                    .collect()
+            } else {
+                vec![abi_type_name(ty)]
+            }
+        },
+        _ => vec![abi_type_name(ty)],
+    }
+}
+
+/// Mirrors the `TypeAbi::type_name` implementations of the framework types.
+/// All other types are named after their type identifier, just like `#[derive(TypeAbi)]` does.
+fn abi_type_name(ty: &syn::Type) -> String {
+    match ty {
+        syn::Type::Reference(reference) => abi_type_name(&reference.elem),
+        syn::Type::Paren(paren) => abi_type_name(&paren.elem),
+        syn::Type::Group(group) => abi_type_name(&group.elem),
+        syn::Type::Slice(slice) => list_type_name(&slice.elem),
+        syn::Type::Array(array) => format!(
+            "array{}<{}>",
+            array.len.to_token_stream(),
+            abi_type_name(&array.elem)
+        ),
+        syn::Type::Tuple(tuple) if tuple.elems.is_empty() => "()".to_string(),
+        syn::Type::Tuple(tuple) => {
+            let elems: Vec<&syn::Type> = tuple.elems.iter().collect();
+            generic_type_name("tuple", &elems)
+        },
+        syn::Type::Path(type_path) => path_type_name(&type_path.path),
+        _ => ty.to_token_stream().to_string(),
+    }
+}
+
+fn path_type_name(path: &syn::Path) -> String {
+    let (ident, type_args) = path_ident_and_type_args(path);
+    match (ident.as_str(), type_args.as_slice()) {
+        ("usize", []) => "u32".to_string(),
+        ("isize", []) => "i32".to_string(),
+        ("str", []) | ("String", []) => "utf-8 string".to_string(),
+        ("ManagedBuffer", []) | ("BoxedBytes", []) => "bytes".to_string(),
+        ("ManagedAddress", []) | ("Address", []) => "Address".to_string(),
+        ("ManagedByteArray", []) => format!("array{}<u8>", path_const_arg(path)),
+        ("Box", [t]) | ("SCResult", [t]) | ("Result", [t, ..]) => abi_type_name(t),
+        ("Vec", [t]) | ("ManagedVec", [t]) => list_type_name(t),
+        ("Option", args) | ("BTreeSet", args) | ("BTreeMap", args) | ("Queue", args) => {
+            generic_type_name(ident.as_str(), args)
+        },
+        ("VarArgs", args)
+        | ("MultiArgVec", args)
+        | ("MultiResultVec", args)
+        | ("ManagedVarArgs", args)
+        | ("ManagedMultiResultVec", args) => generic_type_name("variadic", args),
+        ("OptionalArg", args) | ("OptionalResult", args) => generic_type_name("optional", args),
+        ("AsyncCallResult", args) | ("ManagedAsyncCallResult", args) => {
+            generic_type_name("AsyncCallResult", args)
+        },
+        (multi, args) if is_multi_type(multi) => generic_type_name("multi", args),
+        _ => ident,
+    }
+}
+
+fn list_type_name(item_type: &syn::Type) -> String {
+    let item_type_name = abi_type_name(item_type);
+    if item_type_name == "u8" {
+        "bytes".to_string()
+    } else {
+        format!("List<{}>", item_type_name)
+    }
+}
+
+fn generic_type_name(name: &str, type_args: &[&syn::Type]) -> String {
+    let type_arg_names: Vec<String> = type_args.iter().map(|t| abi_type_name(t)).collect();
+    format!("{}<{}>", name, type_arg_names.join(","))
+}
+
+fn is_multi_type(ident: &str) -> bool {
+    ["MultiArg", "MultiResult"].iter().any(|prefix| {
+        matches!(ident.strip_prefix(prefix), Some(arity) if arity.parse::<usize>().is_ok())
+    })
+}
+
+/// Last path segment identifier, and its type arguments,
+/// not counting the `Self::Api` argument of managed types.
+fn path_ident_and_type_args(path: &syn::Path) -> (String, Vec<&syn::Type>) {
+    let last_segment = path.segments.last().expect("empty type path");
+    let mut type_args = Vec::new();
+    if let syn::PathArguments::AngleBracketed(generic_args) = &last_segment.arguments {
+        for generic_arg in generic_args.args.iter() {
+            if let syn::GenericArgument::Type(t) = generic_arg {
+                if !is_self_api(t) {
+                    type_args.push(t);
+                }
+            }
+        }
+    }
+    (last_segment.ident.to_string(), type_args)
+}
+
+fn path_const_arg(path: &syn::Path) -> String {
+    let last_segment = path.segments.last().expect("empty type path");
+    if let syn::PathArguments::AngleBracketed(generic_args) = &last_segment.arguments {
+        for generic_arg in generic_args.args.iter() {
+            if let syn::GenericArgument::Const(expr) = generic_arg {
+                return expr.to_token_stream().to_string();
+            }
+        }
+    }
+    String::new()
+}
+
+fn is_self_api(ty: &syn::Type) -> bool {
+    if let syn::Type::Path(type_path) = ty {
+        let segments: Vec<String> = type_path
+            .path
+            .segments
+            .iter()
+            .map(|segment| segment.ident.to_string())
+            .collect();
+        segments == ["Self", "Api"]
+    } else {
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::parse_contract_trait;
+    use serde_json::json;
+
+    fn adder_abi() -> Value {
+        json!({
+            "constructor": {
+                "inputs": [{ "name": "initial_value", "type": "BigUint" }],
+                "outputs": []
+            },
+            "endpoints": [
+                {
+                    "name": "add",
+                    "inputs": [{ "name": "value", "type": "BigUint" }],
+                    "outputs": []
+                },
+                {
+                    "name": "getSum",
+                    "inputs": [],
+                    "outputs": [{ "type": "BigUint" }]
+                }
+            ]
+        })
+    }
+
+    fn signature(inputs: &[(&str, &str)], outputs: &[&str]) -> AbiSignature {
+        AbiSignature {
+            inputs: inputs
+                .iter()
+                .map(|(name, type_name)| (name.to_string(), type_name.to_string()))
+                .collect(),
+            outputs: outputs.iter().map(|output| output.to_string()).collect(),
+        }
+    }
+
+    fn type_name(ty: syn::Type) -> String {
+        abi_type_name(&ty)
+    }
+
+    #[test]
+    fn test_abi_type_name() {
+        assert_eq!(type_name(syn::parse_quote!(usize)), "u32");
+        assert_eq!(type_name(syn::parse_quote!(&str)), "utf-8 string");
+        assert_eq!(type_name(syn::parse_quote!(&[u8])), "bytes");
+        assert_eq!(
+            type_name(syn::parse_quote!(ManagedBuffer<Self::Api>)),
+            "bytes"
+        );
+        assert_eq!(
+            type_name(syn::parse_quote!(ManagedVec<Self::Api, BigUint<Self::Api>>)),
+            "List<BigUint>"
+        );
+        assert_eq!(
+            type_name(syn::parse_quote!(ManagedByteArray<Self::Api, 32>)),
+            "array32<u8>"
+        );
+        assert_eq!(
+            type_name(syn::parse_quote!(MultiArg2<u64, ManagedAddress<Self::Api>>)),
+            "multi<u64,Address>"
+        );
+        assert_eq!(
+            type_name(syn::parse_quote!(OptionalArg<Option<u32>>)),
+            "optional<Option<u32>>"
+        );
+        assert_eq!(type_name(syn::parse_quote!(MyStruct)), "MyStruct");
+    }
+
+    #[test]
+    fn test_signatures_match() {
+        let expected = signature(&[("value", "BigUint")], &["u32"]);
+        // argument names do not matter
+        assert!(signatures_match(
+            &expected,
+            &signature(&[("amount", "BigUint")], &["u32"])
+        ));
+        // wrong argument count
+        assert!(!signatures_match(
+            &expected,
+            &signature(&[("value", "BigUint"), ("extra", "u32")], &["u32"])
+        ));
+        // wrong argument type
+        assert!(!signatures_match(
+            &expected,
+            &signature(&[("value", "u64")], &["u32"])
+        ));
+        // wrong result type
+        assert!(!signatures_match(
+            &expected,
+            &signature(&[("value", "BigUint")], &["u64"])
+        ));
+    }
+
+    #[test]
+    fn test_matching_proxy() {
+        let contract_trait = parse_contract_trait(
+            Vec::new(),
+            &syn::parse_quote! {
+                pub trait Adder {
+                    #[init]
+                    fn init(&self, initial_value: BigUint);
+
+                    #[endpoint(getSum)]
+                    fn get_sum(&self) -> BigUint;
+                }
+            },
+        );
+        assert_eq!(proxy_abi_mismatches(&contract_trait, &adder_abi()), "");
+    }
+
+    #[test]
+    fn test_mismatches() {
+        let contract_trait = parse_contract_trait(
+            Vec::new(),
+            &syn::parse_quote! {
+                pub trait Adder {
+                    #[init]
+                    fn init(&self);
+
+                    #[endpoint]
+                    fn add(&self, value: u32);
+
+                    #[endpoint(subtract)]
+                    fn sub(&self, value: BigUint);
+                }
+            },
+        );
+        assert_eq!(
+            proxy_abi_mismatches(&contract_trait, &adder_abi()),
+            "  `init`:
+    - init(initial_value: BigUint)
+    + init()
+  `add`:
+    - add(value: BigUint)
+    + add(value: u32)
+  `subtract` (method `sub`) not found in the ABI
+"
+        );
+    }
+
+    #[test]
+    fn test_validate_proxy_abi_message() {
+        let abi_path = std::env::temp_dir().join(format!(
+            "validate_proxy_abi_test_{}.abi.json",
+            std::process::id()
+        ));
+        std::fs::write(&abi_path, adder_abi().to_string()).unwrap();
+        let abi_full_path = abi_path.to_string_lossy().into_owned();
+        let contract_trait = parse_contract_trait(
+            Vec::new(),
+            &syn::parse_quote! {
+                pub trait Adder {
+                    #[endpoint(getSum)]
+                    fn get_sum(&self) -> u64;
+                }
+            },
+        );
+
+        let panic_payload = std::panic::catch_unwind(|| {
+            validate_proxy_abi(&contract_trait, abi_full_path.as_str())
+        })
+        .unwrap_err();
+        std::fs::remove_file(&abi_path).unwrap();
+        assert_eq!(
+            panic_payload.downcast_ref::<String>().unwrap(),
+            &format!(
+                "proxy `Adder` does not match ABI `{}` (- ABI, + proxy):
+  `getSum` (method `get_sum`):
+    - getSum() -> BigUint
+    + getSum() -> u64
+",
+                abi_full_path
+            )
+        );
+    }
+}
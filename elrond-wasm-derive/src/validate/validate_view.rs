@@ -0,0 +1,247 @@
+use crate::model::{AutoImpl, ContractTrait, MethodImpl};
+use proc_macro2::{Delimiter, TokenStream, TokenTree};
+use quote::ToTokens;
+
+/// Storage mapper methods that write to storage.
+const MAPPER_MUTATING_METHODS: &[&str] = &[
+    "set",
+    "set_if_empty",
+    "update",
+    "clear",
+    "insert",
+    "remove",
+    "push",
+    "push_back",
+    "push_front",
+    "pop_front",
+    "pop_back",
+    "swap_remove",
+    "extend",
+    "get_or_create_user",
+    "get_or_create_users",
+];
+
+/// `SendApi` methods that send funds, call or deploy contracts, or write to storage.
+/// Readonly calls, via `execute_on_dest_context_readonly_raw`, are allowed in views.
+const SEND_MUTATING_METHODS: &[&str] = &[
+    "async_call_raw",
+    "call_local_esdt_built_in_function",
+    "change_owner_address",
+    "contract_call",
+    "deploy_contract",
+    "deploy_from_source_contract",
+    "direct",
+    "direct_egld",
+    "direct_egld_execute",
+    "direct_esdt_execute",
+    "direct_esdt_nft_execute",
+    "direct_multi_esdt_transfer_execute",
+    "direct_with_gas_limit",
+    "esdt_local_burn",
+    "esdt_local_mint",
+    "esdt_nft_create",
+    "esdt_system_sc_proxy",
+    "execute_on_dest_context_by_caller_raw",
+    "execute_on_dest_context_raw",
+    "execute_on_dest_context_raw_custom_result_range",
+    "execute_on_same_context_raw",
+    "sell_nft",
+    "storage_store_tx_hash_key",
+    "transfer_esdt_via_async_call",
+    "transfer_multiple_esdt_via_async_call",
+    "upgrade_contract",
+    "upgrade_from_source_contract",
+];
+
+/// Rejects the obvious ways in which a `#[view]` could change the blockchain state:
+/// sending funds or calling contracts via the mutating `self.send()` methods,
+/// calling storage setters, storage clears or events declared in the same trait,
+/// and calling mutating methods on storage mappers declared in the same trait.
+/// Calls to methods from other modules are not checked here, they are only caught at runtime.
+pub fn validate_views(contract_trait: &ContractTrait) {
+    let mutating_methods = method_names(contract_trait, |auto_impl| {
+        matches!(
+            auto_impl,
+            AutoImpl::StorageSetter { .. }
+                | AutoImpl::StorageClear { .. }
+                | AutoImpl::Event { .. }
+                | AutoImpl::LegacyEvent { .. }
+        )
+    });
+    let mapper_methods = method_names(contract_trait, |auto_impl| {
+        matches!(auto_impl, AutoImpl::StorageMapper { .. })
+    });
+
+    for m in contract_trait.methods.iter().filter(|m| m.is_view()) {
+        if let MethodImpl::Explicit(body) = &m.implementation {
+            check_view_body(
+                &m.name,
+                body.to_token_stream(),
+                &mutating_methods,
+                &mapper_methods,
+            );
+        }
+    }
+}
+
+fn method_names<F>(contract_trait: &ContractTrait, filter: F) -> Vec<String>
+where
+    F: Fn(&AutoImpl) -> bool,
+{
+    contract_trait
+        .methods
+        .iter()
+        .filter_map(|m| match &m.implementation {
+            MethodImpl::Generated(auto_impl) if filter(auto_impl) => Some(m.name.to_string()),
+            _ => None,
+        })
+        .collect()
+}
+
+fn check_view_body(
+    method_name: &syn::Ident,
+    body: TokenStream,
+    mutating_methods: &[String],
+    mapper_methods: &[String],
+) {
+    let tokens: Vec<TokenTree> = body.into_iter().collect();
+    for (i, tt) in tokens.iter().enumerate() {
+        if let TokenTree::Group(group) = tt {
+            check_view_body(
+                method_name,
+                group.stream(),
+                mutating_methods,
+                mapper_methods,
+            );
+        }
+
+        if let Some(called_method) = self_method_call(&tokens[i..]) {
+            if called_method == "send" {
+                if let Some(send_method) = chained_method_call(&tokens[i + 4..]) {
+                    if SEND_MUTATING_METHODS.contains(&send_method.as_str()) {
+                        panic!(
+                            "`#[view]` method `{}` cannot change the blockchain state, but it calls `self.send().{}()`",
+                            method_name, send_method
+                        );
+                    }
+                }
+            }
+            if mutating_methods.contains(&called_method) {
+                panic!(
+                    "`#[view]` method `{}` cannot change the blockchain state, but it calls `self.{}()`",
+                    method_name, called_method
+                );
+            }
+            if mapper_methods.contains(&called_method) {
+                if let Some(mapper_method) = chained_method_call(&tokens[i + 4..]) {
+                    if MAPPER_MUTATING_METHODS.contains(&mapper_method.as_str()) {
+                        panic!(
+                            "`#[view]` method `{}` cannot change the blockchain state, but it calls `self.{}().{}()`",
+                            method_name, called_method, mapper_method
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Matches `self.method(...)`, returns the method name.
+fn self_method_call(tokens: &[TokenTree]) -> Option<String> {
+    match tokens {
+        [TokenTree::Ident(self_ident), TokenTree::Punct(dot), TokenTree::Ident(method_name), TokenTree::Group(args), ..]
+            if self_ident == "self"
+                && dot.as_char() == '.'
+                && args.delimiter() == Delimiter::Parenthesis =>
+        {
+            Some(method_name.to_string())
+        },
+        _ => None,
+    }
+}
+
+/// Matches `.method(...)`, returns the method name.
+fn chained_method_call(tokens: &[TokenTree]) -> Option<String> {
+    match tokens {
+        [TokenTree::Punct(dot), TokenTree::Ident(method_name), TokenTree::Group(args), ..]
+            if dot.as_char() == '.' && args.delimiter() == Delimiter::Parenthesis =>
+        {
+            Some(method_name.to_string())
+        },
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::check_view_body;
+    use proc_macro2::{Span, TokenStream};
+    use quote::quote;
+
+    fn check(body: TokenStream) {
+        let method_name = syn::Ident::new("my_view", Span::call_site());
+        check_view_body(
+            &method_name,
+            body,
+            &["set_sum".to_string()],
+            &["sum".to_string()],
+        );
+    }
+
+    #[test]
+    fn test_getters_allowed() {
+        check(quote! {
+            let sum = self.sum().get();
+            if self.sum().is_empty() {
+                return self.get_sum();
+            }
+            sum
+        });
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "`#[view]` method `my_view` cannot change the blockchain state, but it calls `self.send().direct_egld()`"
+    )]
+    fn test_send_rejected() {
+        check(quote! {
+            self.send().direct_egld(&caller, &amount, &[]);
+        });
+    }
+
+    #[test]
+    fn test_readonly_call_allowed() {
+        check(quote! {
+            let result = self.send().execute_on_dest_context_readonly_raw(
+                self.blockchain().get_gas_left(),
+                &to,
+                &endpoint_name,
+                &args,
+            );
+            result
+        });
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "`#[view]` method `my_view` cannot change the blockchain state, but it calls `self.set_sum()`"
+    )]
+    fn test_setter_rejected() {
+        check(quote! {
+            if amount > 0 {
+                self.set_sum(&amount);
+            }
+        });
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "`#[view]` method `my_view` cannot change the blockchain state, but it calls `self.sum().set()`"
+    )]
+    fn test_mapper_set_rejected() {
+        check(quote! {
+            let sum = self.sum().get();
+            self.sum().set(&(sum + 1u32));
+        });
+    }
+}
@@ -1,6 +1,7 @@
 use super::ArwenApiImpl;
 use elrond_wasm::{
-    api::{Handle, LogApi},
+    api::{Handle, LogApi, ReadonlyApi},
+    err_msg,
     types::ArgBuffer,
 };
 
@@ -25,6 +26,7 @@ const LEGACY_TOPIC_LENGTH: usize = 32;
 /// The smart contract code doesn't have access to these methods directly.
 impl LogApi for ArwenApiImpl {
     fn write_event_log(&self, topics_buffer: &ArgBuffer, data: &[u8]) {
+        self.check_not_readonly(err_msg::READONLY_LOG);
         unsafe {
             writeEventLog(
                 topics_buffer.num_args() as i32,
@@ -37,6 +39,7 @@ impl LogApi for ArwenApiImpl {
     }
 
     fn write_legacy_log(&self, topics: &[[u8; 32]], data: &[u8]) {
+        self.check_not_readonly(err_msg::READONLY_LOG);
         let mut topics_raw = [0u8; LEGACY_TOPIC_LENGTH * 10]; // hopefully we never have more than 10 topics
         for i in 0..topics.len() {
             topics_raw[LEGACY_TOPIC_LENGTH * i..LEGACY_TOPIC_LENGTH * (i + 1)]
@@ -65,6 +68,7 @@ impl LogApi for ArwenApiImpl {
 
     #[cfg(not(feature = "unmanaged-ei"))]
     fn managed_write_log(&self, topics_handle: Handle, data_handle: Handle) {
+        self.check_not_readonly(err_msg::READONLY_LOG);
         unsafe {
             managedWriteLog(topics_handle, data_handle);
         }
@@ -6,6 +6,8 @@ mod endpoint_finish_api_node;
 mod error_api_node;
 mod log_api_node;
 mod managed_types;
+mod readonly_api_node;
+mod reentrancy_lock_api_node;
 mod storage_api_node;
 mod unsafe_buffer;
 mod vm_api_node;
@@ -0,0 +1,19 @@
+use super::ArwenApiImpl;
+use elrond_wasm::api::ReadonlyApi;
+
+/// Each contract call runs in its own wasm instance, so a static flag is enough.
+static mut READONLY: bool = false;
+
+impl ReadonlyApi for ArwenApiImpl {
+    #[inline]
+    fn set_readonly(&self, readonly: bool) {
+        unsafe {
+            READONLY = readonly;
+        }
+    }
+
+    #[inline]
+    fn is_readonly(&self) -> bool {
+        unsafe { READONLY }
+    }
+}
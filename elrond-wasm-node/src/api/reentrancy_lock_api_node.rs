@@ -0,0 +1,19 @@
+use super::ArwenApiImpl;
+use elrond_wasm::api::ReentrancyLockApi;
+
+/// Each contract call runs in its own wasm instance, so a static is enough.
+static mut HELD_REENTRANCY_LOCK: Option<&'static [u8]> = None;
+
+impl ReentrancyLockApi for ArwenApiImpl {
+    #[inline]
+    fn set_held_reentrancy_lock(&self, group: Option<&'static [u8]>) {
+        unsafe {
+            HELD_REENTRANCY_LOCK = group;
+        }
+    }
+
+    #[inline]
+    fn held_reentrancy_lock(&self) -> Option<&'static [u8]> {
+        unsafe { HELD_REENTRANCY_LOCK }
+    }
+}
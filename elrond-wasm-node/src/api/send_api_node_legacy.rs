@@ -1,7 +1,9 @@
 use crate::{api::managed_types::big_int_api_node::unsafe_buffer_load_be_pad_right, ArwenApiImpl};
 use alloc::vec::Vec;
 use elrond_wasm::{
-    api::{BlockchainApi, SendApi, StorageReadApi, StorageWriteApi},
+    api::{BlockchainApi, ReadonlyApi, SendApi, StorageReadApi, StorageWriteApi},
+    err_msg,
+    storage::reentrancy_guard::release_held_reentrancy_lock,
     types::{
         managed_vec_from_slice_of_boxed_bytes, Address, BigUint, BoxedBytes, CodeMetadata,
         EsdtTokenPayment, ManagedAddress, ManagedArgBuffer, ManagedBuffer, ManagedFrom,
@@ -178,6 +180,7 @@ impl SendApi for ArwenApiImpl {
     where
         D: ManagedInto<Self, ManagedBuffer<Self>>,
     {
+        self.check_not_readonly(err_msg::READONLY_TRANSFER);
         let to_address = to.to_address();
         let data_bytes = data.managed_into(self.clone()).to_boxed_bytes();
         unsafe {
@@ -199,6 +202,7 @@ impl SendApi for ArwenApiImpl {
         endpoint_name: &ManagedBuffer<Self>,
         arg_buffer: &ManagedArgBuffer<Self>,
     ) -> Result<(), &'static [u8]> {
+        self.check_not_readonly(err_msg::READONLY_TRANSFER);
         let to_address = to.to_address();
         let function = endpoint_name.to_boxed_bytes();
         let legacy_arg_buffer = arg_buffer.to_legacy_arg_buffer();
@@ -231,6 +235,7 @@ impl SendApi for ArwenApiImpl {
         endpoint_name: &ManagedBuffer<Self>,
         arg_buffer: &ManagedArgBuffer<Self>,
     ) -> Result<(), &'static [u8]> {
+        self.check_not_readonly(err_msg::READONLY_TRANSFER);
         let to_address = to.to_address();
         unsafe {
             let amount_bytes32_ptr = unsafe_buffer_load_be_pad_right(amount.get_raw_handle(), 32);
@@ -266,6 +271,7 @@ impl SendApi for ArwenApiImpl {
         endpoint_name: &ManagedBuffer<Self>,
         arg_buffer: &ManagedArgBuffer<Self>,
     ) -> Result<(), &'static [u8]> {
+        self.check_not_readonly(err_msg::READONLY_TRANSFER);
         let to_address = to.to_address();
         unsafe {
             let amount_bytes32_ptr = unsafe_buffer_load_be_pad_right(amount.get_raw_handle(), 32);
@@ -300,6 +306,7 @@ impl SendApi for ArwenApiImpl {
         endpoint_name: &ManagedBuffer<Self>,
         arg_buffer: &ManagedArgBuffer<Self>,
     ) -> Result<(), &'static [u8]> {
+        self.check_not_readonly(err_msg::READONLY_TRANSFER);
         unsafe {
             let nr_transfers = payments.len();
             let mut transfer_arg_lengths = Vec::with_capacity(nr_transfers * 3);
@@ -350,6 +357,8 @@ impl SendApi for ArwenApiImpl {
         endpoint_name: &ManagedBuffer<Self>,
         arg_buffer: &ManagedArgBuffer<Self>,
     ) -> ! {
+        self.check_not_readonly(err_msg::READONLY_CONTRACT_CALL);
+        release_held_reentrancy_lock(self.clone());
         unsafe {
             let to_address = to.to_address();
             let amount_bytes32_ptr = unsafe_buffer_load_be_pad_right(amount.get_raw_handle(), 32);
@@ -373,6 +382,7 @@ impl SendApi for ArwenApiImpl {
         code_metadata: CodeMetadata,
         arg_buffer: &ManagedArgBuffer<Self>,
     ) -> (ManagedAddress<Self>, ManagedVec<Self, ManagedBuffer<Self>>) {
+        self.check_not_readonly(err_msg::READONLY_CONTRACT_CALL);
         let mut new_address = Address::zero();
         unsafe {
             let num_return_data_before = getNumReturnData();
@@ -412,6 +422,7 @@ impl SendApi for ArwenApiImpl {
         code_metadata: CodeMetadata,
         arg_buffer: &ManagedArgBuffer<Self>,
     ) -> (ManagedAddress<Self>, ManagedVec<Self, ManagedBuffer<Self>>) {
+        self.check_not_readonly(err_msg::READONLY_CONTRACT_CALL);
         let mut new_address = Address::zero();
         unsafe {
             let num_return_data_before = getNumReturnData();
@@ -451,6 +462,8 @@ impl SendApi for ArwenApiImpl {
         code_metadata: CodeMetadata,
         arg_buffer: &ManagedArgBuffer<Self>,
     ) {
+        self.check_not_readonly(err_msg::READONLY_CONTRACT_CALL);
+        release_held_reentrancy_lock(self.clone());
         unsafe {
             let amount_bytes32_ptr = unsafe_buffer_load_be_pad_right(amount.get_raw_handle(), 32);
             let legacy_arg_buffer = arg_buffer.to_legacy_arg_buffer();
@@ -479,6 +492,8 @@ impl SendApi for ArwenApiImpl {
         code_metadata: CodeMetadata,
         arg_buffer: &ManagedArgBuffer<Self>,
     ) {
+        self.check_not_readonly(err_msg::READONLY_CONTRACT_CALL);
+        release_held_reentrancy_lock(self.clone());
         unsafe {
             let amount_bytes32_ptr = unsafe_buffer_load_be_pad_right(amount.get_raw_handle(), 32);
             let legacy_arg_buffer = arg_buffer.to_legacy_arg_buffer();
@@ -506,6 +521,7 @@ impl SendApi for ArwenApiImpl {
         endpoint_name: &ManagedBuffer<Self>,
         arg_buffer: &ManagedArgBuffer<Self>,
     ) -> ManagedVec<Self, ManagedBuffer<Self>> {
+        self.check_not_readonly(err_msg::READONLY_CONTRACT_CALL);
         unsafe {
             let num_return_data_before = getNumReturnData();
 
@@ -542,6 +558,7 @@ impl SendApi for ArwenApiImpl {
     where
         F: FnOnce(usize, usize) -> (usize, usize),
     {
+        self.check_not_readonly(err_msg::READONLY_CONTRACT_CALL);
         unsafe {
             let num_return_data_before = getNumReturnData();
 
@@ -580,6 +597,7 @@ impl SendApi for ArwenApiImpl {
         endpoint_name: &ManagedBuffer<Self>,
         arg_buffer: &ManagedArgBuffer<Self>,
     ) -> ManagedVec<Self, ManagedBuffer<Self>> {
+        self.check_not_readonly(err_msg::READONLY_CONTRACT_CALL);
         unsafe {
             let num_return_data_before = getNumReturnData();
 
@@ -612,6 +630,7 @@ impl SendApi for ArwenApiImpl {
         endpoint_name: &ManagedBuffer<Self>,
         arg_buffer: &ManagedArgBuffer<Self>,
     ) -> ManagedVec<Self, ManagedBuffer<Self>> {
+        self.check_not_readonly(err_msg::READONLY_CONTRACT_CALL);
         unsafe {
             let num_return_data_before = getNumReturnData();
 
@@ -684,6 +703,8 @@ impl SendApi for ArwenApiImpl {
         function_name: &ManagedBuffer<Self>,
         arg_buffer: &ManagedArgBuffer<Self>,
     ) -> ManagedVec<Self, ManagedBuffer<Self>> {
+        self.check_not_readonly(err_msg::READONLY_CONTRACT_CALL);
+
         // account-level built-in function, so the destination address is the contract itself
         let own_address = BlockchainApi::get_sc_address(self);
 
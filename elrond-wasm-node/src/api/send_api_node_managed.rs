@@ -1,6 +1,8 @@
 use crate::{error_hook, ArwenApiImpl};
 use elrond_wasm::{
-    api::{BlockchainApi, Handle, SendApi, StorageReadApi, StorageWriteApi},
+    api::{BlockchainApi, Handle, ReadonlyApi, SendApi, StorageReadApi, StorageWriteApi},
+    err_msg,
+    storage::reentrancy_guard::release_held_reentrancy_lock,
     types::{
         BigUint, CodeMetadata, EsdtTokenPayment, ManagedAddress, ManagedArgBuffer, ManagedBuffer,
         ManagedInto, ManagedType, ManagedVec, TokenIdentifier,
@@ -119,6 +121,7 @@ impl SendApi for ArwenApiImpl {
     where
         D: ManagedInto<Self, ManagedBuffer<Self>>,
     {
+        self.check_not_readonly(err_msg::READONLY_TRANSFER);
         let data_buffer = data.managed_into(self.clone());
         unsafe {
             let arguments_handle = mBufferNew();
@@ -141,6 +144,7 @@ impl SendApi for ArwenApiImpl {
         endpoint_name: &ManagedBuffer<Self>,
         arg_buffer: &ManagedArgBuffer<Self>,
     ) -> Result<(), &'static [u8]> {
+        self.check_not_readonly(err_msg::READONLY_TRANSFER);
         unsafe {
             let result = managedTransferValueExecute(
                 to.get_raw_handle(),
@@ -192,6 +196,7 @@ impl SendApi for ArwenApiImpl {
         endpoint_name: &ManagedBuffer<Self>,
         arg_buffer: &ManagedArgBuffer<Self>,
     ) -> Result<(), &'static [u8]> {
+        self.check_not_readonly(err_msg::READONLY_TRANSFER);
         unsafe {
             let result = managedMultiTransferESDTNFTExecute(
                 to.get_raw_handle(),
@@ -215,6 +220,8 @@ impl SendApi for ArwenApiImpl {
         endpoint_name: &ManagedBuffer<Self>,
         arg_buffer: &ManagedArgBuffer<Self>,
     ) -> ! {
+        self.check_not_readonly(err_msg::READONLY_CONTRACT_CALL);
+        release_held_reentrancy_lock(self.clone());
         unsafe {
             managedAsyncCall(
                 to.get_raw_handle(),
@@ -233,6 +240,7 @@ impl SendApi for ArwenApiImpl {
         code_metadata: CodeMetadata,
         arg_buffer: &ManagedArgBuffer<Self>,
     ) -> (ManagedAddress<Self>, ManagedVec<Self, ManagedBuffer<Self>>) {
+        self.check_not_readonly(err_msg::READONLY_CONTRACT_CALL);
         unsafe {
             let code_metadata_handle = code_metadata_to_buffer_handle(code_metadata);
             let new_address_handle = mBufferNew();
@@ -263,6 +271,7 @@ impl SendApi for ArwenApiImpl {
         code_metadata: CodeMetadata,
         arg_buffer: &ManagedArgBuffer<Self>,
     ) -> (ManagedAddress<Self>, ManagedVec<Self, ManagedBuffer<Self>>) {
+        self.check_not_readonly(err_msg::READONLY_CONTRACT_CALL);
         unsafe {
             let code_metadata_handle = code_metadata_to_buffer_handle(code_metadata);
             let new_address_handle = mBufferNew();
@@ -294,6 +303,8 @@ impl SendApi for ArwenApiImpl {
         code_metadata: CodeMetadata,
         arg_buffer: &ManagedArgBuffer<Self>,
     ) {
+        self.check_not_readonly(err_msg::READONLY_CONTRACT_CALL);
+        release_held_reentrancy_lock(self.clone());
         unsafe {
             let code_metadata_handle = code_metadata_to_buffer_handle(code_metadata);
             let result_handle = mBufferNew();
@@ -318,6 +329,8 @@ impl SendApi for ArwenApiImpl {
         code_metadata: CodeMetadata,
         arg_buffer: &ManagedArgBuffer<Self>,
     ) {
+        self.check_not_readonly(err_msg::READONLY_CONTRACT_CALL);
+        release_held_reentrancy_lock(self.clone());
         unsafe {
             let code_metadata_handle = code_metadata_to_buffer_handle(code_metadata);
             let unused_result_handle = mBufferNew();
@@ -344,6 +357,7 @@ impl SendApi for ArwenApiImpl {
         endpoint_name: &ManagedBuffer<Self>,
         arg_buffer: &ManagedArgBuffer<Self>,
     ) -> ManagedVec<Self, ManagedBuffer<Self>> {
+        self.check_not_readonly(err_msg::READONLY_CONTRACT_CALL);
         unsafe {
             let result_handle = mBufferNew();
 
@@ -372,6 +386,7 @@ impl SendApi for ArwenApiImpl {
     where
         F: FnOnce(usize, usize) -> (usize, usize),
     {
+        self.check_not_readonly(err_msg::READONLY_CONTRACT_CALL);
         unsafe {
             let num_return_data_before = getNumReturnData() as usize;
             let result_handle = mBufferNew();
@@ -409,6 +424,7 @@ impl SendApi for ArwenApiImpl {
         endpoint_name: &ManagedBuffer<Self>,
         arg_buffer: &ManagedArgBuffer<Self>,
     ) -> ManagedVec<Self, ManagedBuffer<Self>> {
+        self.check_not_readonly(err_msg::READONLY_CONTRACT_CALL);
         unsafe {
             let result_handle = mBufferNew();
 
@@ -433,6 +449,7 @@ impl SendApi for ArwenApiImpl {
         endpoint_name: &ManagedBuffer<Self>,
         arg_buffer: &ManagedArgBuffer<Self>,
     ) -> ManagedVec<Self, ManagedBuffer<Self>> {
+        self.check_not_readonly(err_msg::READONLY_CONTRACT_CALL);
         unsafe {
             let result_handle = mBufferNew();
 
@@ -490,6 +507,8 @@ impl SendApi for ArwenApiImpl {
         function_name: &ManagedBuffer<Self>,
         arg_buffer: &ManagedArgBuffer<Self>,
     ) -> ManagedVec<Self, ManagedBuffer<Self>> {
+        self.check_not_readonly(err_msg::READONLY_CONTRACT_CALL);
+
         // account-level built-in function, so the destination address is the contract itself
         let own_address = BlockchainApi::get_sc_address(self);
 
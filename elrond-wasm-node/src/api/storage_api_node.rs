@@ -1,7 +1,8 @@
 use super::ArwenApiImpl;
 use alloc::vec::Vec;
 use elrond_wasm::{
-    api::{Handle, StorageReadApi, StorageWriteApi},
+    api::{Handle, ReadonlyApi, StorageReadApi, StorageWriteApi},
+    err_msg,
     types::BoxedBytes,
 };
 
@@ -98,6 +99,7 @@ impl StorageReadApi for ArwenApiImpl {
 
 impl StorageWriteApi for ArwenApiImpl {
     fn storage_store_slice_u8(&self, key: &[u8], value: &[u8]) {
+        self.check_not_readonly(err_msg::READONLY_STORAGE_WRITE);
         unsafe {
             storageStore(
                 key.as_ref().as_ptr(),
@@ -110,18 +112,21 @@ impl StorageWriteApi for ArwenApiImpl {
 
     #[inline]
     fn storage_store_big_uint_raw(&self, key: &[u8], handle: i32) {
+        self.check_not_readonly(err_msg::READONLY_STORAGE_WRITE);
         unsafe {
             bigIntStorageStoreUnsigned(key.as_ref().as_ptr(), key.len() as i32, handle);
         }
     }
 
     fn storage_store_managed_buffer_raw(&self, key_handle: Handle, value_handle: Handle) {
+        self.check_not_readonly(err_msg::READONLY_STORAGE_WRITE);
         unsafe {
             mBufferStorageStore(key_handle, value_handle);
         }
     }
 
     fn storage_store_managed_buffer_clear(&self, key_handle: Handle) {
+        self.check_not_readonly(err_msg::READONLY_STORAGE_WRITE);
         unsafe {
             let value_handle = mBufferNew();
             mBufferStorageStore(key_handle, value_handle);
@@ -130,6 +135,7 @@ impl StorageWriteApi for ArwenApiImpl {
 
     #[inline]
     fn storage_store_u64(&self, key: &[u8], value: u64) {
+        self.check_not_readonly(err_msg::READONLY_STORAGE_WRITE);
         unsafe {
             smallIntStorageStoreUnsigned(key.as_ref().as_ptr(), key.len() as i32, value as i64);
         }
@@ -137,6 +143,7 @@ impl StorageWriteApi for ArwenApiImpl {
 
     #[inline]
     fn storage_store_i64(&self, key: &[u8], value: i64) {
+        self.check_not_readonly(err_msg::READONLY_STORAGE_WRITE);
         unsafe {
             smallIntStorageStoreSigned(key.as_ref().as_ptr(), key.len() as i32, value);
         }
@@ -90,6 +90,50 @@ impl<'de> ErdDeserializer<'de> {
         self.input = &[];
         bytes
     }
+
+    /// Nested byte slices are prefixed by their length, top level ones are the entire input.
+    fn next_byte_slice(&mut self) -> Result<&'de [u8]> {
+        if self.top_level {
+            Ok(self.flush())
+        } else {
+            let size_bytes = self.next_bytes(USIZE_SIZE)?;
+            let size = bytes_to_number(size_bytes, false) as usize;
+            self.next_bytes(size)
+        }
+    }
+
+    fn next_str(&mut self) -> Result<&'de str> {
+        let bytes = self.next_byte_slice()?;
+        core::str::from_utf8(bytes).map_err(|_| SDError::Utf8DecodeError)
+    }
+
+    /// Same rules as for the other numbers, but 128-bit arithmetic is kept separate.
+    fn next_number_128(&mut self, signed: bool) -> Result<u128> {
+        let bytes = if self.top_level {
+            self.flush()
+        } else {
+            self.next_bytes(16)?
+        };
+        if bytes.len() > 16 {
+            return Err(SDError::InputTooLong);
+        }
+        let negative = signed && !bytes.is_empty() && bytes[0] >> 7 == 1;
+        let mut result = if negative { u128::MAX } else { 0u128 };
+        for byte in bytes.iter() {
+            result <<= 8;
+            result |= *byte as u128;
+        }
+        Ok(result)
+    }
+
+    /// Enum variants are encoded as a single byte.
+    /// Top level, the first variant can also be encoded as no bytes at all.
+    fn next_variant_index(&mut self) -> Result<u32> {
+        if self.top_level && self.input.is_empty() {
+            return Ok(0);
+        }
+        Ok(self.next_byte()? as u32)
+    }
 }
 
 macro_rules! impl_nums {
@@ -156,6 +200,20 @@ impl<'de> serde::Deserializer<'de> for &mut ErdDeserializer<'de> {
     impl_nums!(i32, deserialize_i32, visit_i32, 4, true);
     impl_nums!(i64, deserialize_i64, visit_i64, 8, true);
 
+    fn deserialize_i128<V>(self, visitor: V) -> Result<V::Value>
+    where
+        V: serde::de::Visitor<'de>,
+    {
+        visitor.visit_i128(self.next_number_128(true)? as i128)
+    }
+
+    fn deserialize_u128<V>(self, visitor: V) -> Result<V::Value>
+    where
+        V: serde::de::Visitor<'de>,
+    {
+        visitor.visit_u128(self.next_number_128(false)?)
+    }
+
     #[inline]
     fn deserialize_f32<V>(self, _visitor: V) -> Result<V::Value>
     where
@@ -179,46 +237,44 @@ impl<'de> serde::Deserializer<'de> for &mut ErdDeserializer<'de> {
         visitor.visit_unit()
     }
 
-    fn deserialize_char<V>(self, _: V) -> Result<V::Value>
+    // chars are encoded as their code point
+    fn deserialize_char<V>(self, visitor: V) -> Result<V::Value>
     where
         V: serde::de::Visitor<'de>,
     {
-        Err(SDError::UnsupportedOperation)
+        let code_point: u32 = serde::de::Deserialize::deserialize(&mut *self)?;
+        match core::char::from_u32(code_point) {
+            Some(c) => visitor.visit_char(c),
+            None => Err(SDError::InvalidValue),
+        }
     }
 
-    fn deserialize_str<V>(self, _: V) -> Result<V::Value>
+    fn deserialize_str<V>(self, visitor: V) -> Result<V::Value>
     where
         V: serde::de::Visitor<'de>,
     {
-        Err(SDError::UnsupportedOperation)
+        visitor.visit_borrowed_str(self.next_str()?)
     }
 
-    fn deserialize_string<V>(self, _: V) -> Result<V::Value>
+    fn deserialize_string<V>(self, visitor: V) -> Result<V::Value>
     where
         V: serde::de::Visitor<'de>,
     {
-        Err(SDError::UnsupportedOperation)
+        self.deserialize_str(visitor)
     }
 
     fn deserialize_bytes<V>(self, visitor: V) -> Result<V::Value>
     where
         V: serde::de::Visitor<'de>,
     {
-        let bytes = if self.top_level {
-            self.flush()
-        } else {
-            let size_bytes = self.next_bytes(USIZE_SIZE)?;
-            let size = bytes_to_number(size_bytes, false) as usize;
-            self.next_bytes(size)?
-        };
-        visitor.visit_borrowed_bytes(bytes)
+        visitor.visit_borrowed_bytes(self.next_byte_slice()?)
     }
 
-    fn deserialize_byte_buf<V>(self, _visitor: V) -> Result<V::Value>
+    fn deserialize_byte_buf<V>(self, visitor: V) -> Result<V::Value>
     where
         V: serde::de::Visitor<'de>,
     {
-        Err(SDError::UnsupportedOperation)
+        self.deserialize_bytes(visitor)
     }
 
     fn deserialize_enum<V>(
@@ -249,6 +305,10 @@ impl<'de> serde::Deserializer<'de> for &mut ErdDeserializer<'de> {
     where
         V: serde::de::Visitor<'de>,
     {
+        if self.top_level && self.input.is_empty() {
+            // top level None is encoded as no bytes at all
+            return visitor.visit_none();
+        }
         self.top_level = false;
         let value = self.next_byte()?;
         match value {
@@ -277,11 +337,24 @@ impl<'de> serde::Deserializer<'de> for &mut ErdDeserializer<'de> {
         })
     }
 
-    fn deserialize_map<V>(self, _visitor: V) -> Result<V::Value>
+    // maps are encoded as sequences of key-value pairs
+    fn deserialize_map<V>(self, visitor: V) -> Result<V::Value>
     where
         V: serde::de::Visitor<'de>,
     {
-        Err(SDError::NotImplemented)
+        let remaining_items_hint = if self.top_level {
+            None
+        } else {
+            let size_bytes = self.next_bytes(USIZE_SIZE)?;
+            let size = bytes_to_number(size_bytes, false) as usize;
+            Some(size)
+        };
+
+        self.top_level = false;
+        visitor.visit_map(Access {
+            deserializer: self,
+            remaining_items_hint,
+        })
     }
 
     fn deserialize_struct<V>(
@@ -307,6 +380,7 @@ impl<'de> serde::Deserializer<'de> for &mut ErdDeserializer<'de> {
     where
         V: serde::de::Visitor<'de>,
     {
+        self.top_level = false;
         visitor.visit_newtype_struct(self)
     }
 
@@ -348,8 +422,8 @@ impl<'de, 'a> serde::de::EnumAccess<'de> for &'a mut ErdDeserializer<'de> {
     fn variant_seed<V>(self, seed: V) -> Result<(V::Value, Self::Variant)>
         where V: serde::de::DeserializeSeed<'de>,
     {
+        let idx = self.next_variant_index()?;
         self.top_level = false;
-        let idx: u32 = serde::de::Deserialize::deserialize(&mut *self)?;
         let val: V::Value = seed.deserialize(idx.into_deserializer())?;
         Ok((val, self))
     }
@@ -408,14 +482,37 @@ impl<'a, 'de> serde::de::SeqAccess<'de> for Access<'a, 'de> {
                 }
             },
             None => {
-                match seed.deserialize(&mut *self.deserializer) {
-                    Ok(value) => Ok(Some(value)),
-                    Err(SDError::InputTooShort) => Ok(None),
-                    Err(other_err) => Err(other_err),
+                // top level, we know we ran out of items when the input runs out
+                if self.deserializer.input.is_empty() {
+                    Ok(None)
+                } else {
+                    let value = seed.deserialize(&mut *self.deserializer)?;
+                    Ok(Some(value))
                 }
             }
         }
-        
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        self.remaining_items_hint
+    }
+}
+
+impl<'a, 'de> serde::de::MapAccess<'de> for Access<'a, 'de> {
+    type Error = SDError;
+
+    fn next_key_seed<K>(&mut self, seed: K) -> Result<Option<K::Value>>
+    where
+        K: serde::de::DeserializeSeed<'de>,
+    {
+        serde::de::SeqAccess::next_element_seed(self, seed)
+    }
+
+    fn next_value_seed<V>(&mut self, seed: V) -> Result<V::Value>
+    where
+        V: serde::de::DeserializeSeed<'de>,
+    {
+        seed.deserialize(&mut *self.deserializer)
     }
 
     fn size_hint(&self) -> Option<usize> {
@@ -489,19 +586,19 @@ mod tests {
         }
 
         let u = E::Unit;
-        let expected: &[u8] = &[/*variant index*/ 0, 0, 0, 0];
+        let expected: &[u8] = &[/*variant index, compacted*/];
         deser_ok(u, expected);
 
         let n = E::Newtype(1);
-        let expected: &[u8] = &[/*variant index*/ 0, 0, 0, 1, /*data*/ 0, 0, 0, 1];
+        let expected: &[u8] = &[/*variant index*/ 1, /*data*/ 0, 0, 0, 1];
         deser_ok(n, expected);
 
         let t = E::Tuple(1, 2);
-        let expected: &[u8] = &[/*variant index*/ 0, 0, 0, 2, /*(*/ 0, 0, 0, 1, /*,*/ 0, 0, 0, 2 /*)*/];
+        let expected: &[u8] = &[/*variant index*/ 2, /*(*/ 0, 0, 0, 1, /*,*/ 0, 0, 0, 2 /*)*/];
         deser_ok(t, expected);
 
         let s = E::Struct { a: 1 };
-        let expected: &[u8] = &[/*variant index*/ 0, 0, 0, 3, /*data*/ 0, 0, 0, 1];
+        let expected: &[u8] = &[/*variant index*/ 3, /*data*/ 0, 0, 0, 1];
         deser_ok(s, expected);
     }
 }
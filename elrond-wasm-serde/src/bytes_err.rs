@@ -17,6 +17,7 @@ pub enum SDError {
     InputTooShort,
     InputTooLong,
     InvalidValue,
+    Utf8DecodeError,
 
     /// A custom error message from Serde.
     Custom(ErrorBuffer),
@@ -37,6 +38,7 @@ impl SDError {
             SDError::InputTooShort => b"input too short",
             SDError::InputTooLong => b"input too long",
             SDError::InvalidValue => b"invalid value",
+            SDError::Utf8DecodeError => b"utf-8 decode error",
             SDError::Custom(ref ebuf) => ebuf.err_msg_slice(),
         }
     }
@@ -42,42 +42,60 @@ impl ErdSerializer {
 
     /// Adds number to output buffer.
     /// No generics here, because we want the executable binary as small as possible.
-    fn push_number(&mut self, x: u64, size_in_bits: usize, signed: bool, mut compact: bool) {
-        let negative = 
-            compact && // only relevant when compact flag
-            signed &&  // only possible when signed flag
-            x >> (size_in_bits - 1) & 1 == 1; // compute by checking first bit
-        
-        let irrelevant_byte = if negative { 0xffu8 } else { 0x00u8 };
-        let mut bit_offset = size_in_bits as isize - 8;
-        while bit_offset >= 0 {
-            // going byte by byte from most to least significant
-            let byte = (x >> (bit_offset as usize) & 0xffu64) as u8;
-            
-            if compact {
-                // compact means ignoring irrelvant leading bytes
-                // that is 000... for positives and fff... for negatives
-                if byte != irrelevant_byte {
-                    self.output.push(byte);
-                    compact = false;
-                }
-            } else {
-                self.output.push(byte);
-            }
-            
-            bit_offset -= 8;
-        }
+    fn push_number(&mut self, x: u64, size_in_bits: usize, signed: bool, compact: bool) {
+        let bytes_be = x.to_be_bytes();
+        let bytes_be = &bytes_be[8 - size_in_bits / 8..];
+        let offset = if compact { compact_offset(bytes_be, signed) } else { 0 };
+        self.output.extend_from_slice(&bytes_be[offset..]);
+    }
+
+    /// Separate from `push_number`, so 128-bit arithmetic is only compiled in when needed.
+    fn push_number_128(&mut self, x: u128, signed: bool, compact: bool) {
+        let bytes_be = x.to_be_bytes();
+        let offset = if compact { compact_offset(&bytes_be[..], signed) } else { 0 };
+        self.output.extend_from_slice(&bytes_be[offset..]);
     }
 
     #[inline]
-    fn push_entity_size(&mut self, size: u32) {
+    fn push_entity_size(&mut self, size: usize) -> Result<()> {
+        if size > u32::MAX as usize {
+            return Err(SDError::UnsupportedOperation);
+        }
         self.push_number(size as u64, 32, false, false);
+        Ok(())
     }
 
+    /// Enum variants are encoded as a single byte, same as in elrond-codec.
     #[inline]
-    fn push_variant_index(&mut self, variant_index: u32) {
-        self.push_number(variant_index as u64, 32, false, false);
+    fn push_variant_index(&mut self, variant_index: u32, compact: bool) -> Result<()> {
+        if variant_index > u8::MAX as u32 {
+            return Err(SDError::UnsupportedOperation);
+        }
+        self.push_number(variant_index as u64, 8, false, compact);
+        Ok(())
+    }
+}
+
+/// Index of the first relevant byte of a big endian number.
+/// Compact means ignoring irrelevant leading bytes, that is 000... for positives and fff... for negatives,
+/// but keeping one of them if needed to preserve the sign of signed numbers.
+/// Zero is encoded as no bytes at all.
+fn compact_offset(bytes_be: &[u8], signed: bool) -> usize {
+    let negative = signed && bytes_be[0] >> 7 == 1;
+    let irrelevant_byte = if negative { 0xffu8 } else { 0x00u8 };
+    let mut offset = 0usize;
+    while offset < bytes_be.len() && bytes_be[offset] == irrelevant_byte {
+        offset += 1;
+    }
+    let sign_lost = if offset == bytes_be.len() {
+        negative // -1 is a special case
+    } else {
+        signed && bytes_be[offset] >> 7 != negative as u8
+    };
+    if sign_lost {
+        offset -= 1;
     }
+    offset
 }
 
 impl<'a> ser::Serializer for &'a mut ErdSerializer {
@@ -153,6 +171,16 @@ impl<'a> ser::Serializer for &'a mut ErdSerializer {
         Ok(())
     }
 
+    fn serialize_i128(self, v: i128) -> Result<()> {
+        self.push_number_128(v as u128, true, self.top_level);
+        Ok(())
+    }
+
+    fn serialize_u128(self, v: u128) -> Result<()> {
+        self.push_number_128(v, false, self.top_level);
+        Ok(())
+    }
+
     fn serialize_f32(self, _: f32) -> Result<()> {
         Err(SDError::UnsupportedOperation)
     }
@@ -161,17 +189,14 @@ impl<'a> ser::Serializer for &'a mut ErdSerializer {
         Err(SDError::UnsupportedOperation)
     }
 
-    // Serialize a char as a single-character string. Other formats may
-    // represent this differently.
-    fn serialize_char(self, _v: char) -> Result<()> {
-        Err(SDError::NotImplemented)
+    // A char is serialized as its unicode code point, same as a u32.
+    fn serialize_char(self, v: char) -> Result<()> {
+        self.serialize_u32(v as u32)
     }
 
-    // This only works for strings that don't require escape sequences but you
-    // get the idea. For example it would emit invalid JSON if the input string
-    // contains a '"' character.
-    fn serialize_str(self, _v: &str) -> Result<()> {
-        Err(SDError::NotImplemented)
+    // Strings are serialized as their UTF-8 bytes, same as elrond-codec does it.
+    fn serialize_str(self, v: &str) -> Result<()> {
+        self.serialize_bytes(v.as_bytes())
     }
 
     // Serialize a byte array as an array of bytes. Could also use a base64
@@ -181,14 +206,18 @@ impl<'a> ser::Serializer for &'a mut ErdSerializer {
         if !self.top_level {
             // only save bytes length when bytes are embedded in another structure
             // when they are the top level, the number of bytes is "encoded" in the length of the output
-            self.push_entity_size(v.len() as u32);
+            self.push_entity_size(v.len())?;
         }
         self.output.extend_from_slice(v);
         Ok(())
     }
 
     fn serialize_none(self) -> Result<()> {
-        self.push_byte(0u8); // one byte of 0 indicates that nothing comes after
+        if !self.top_level {
+            // one byte of 0 indicates that nothing comes after
+            // top level None is encoded as no bytes at all
+            self.push_byte(0u8);
+        }
         Ok(())
     }
 
@@ -223,12 +252,12 @@ impl<'a> ser::Serializer for &'a mut ErdSerializer {
         variant_index: u32,
         _variant: &'static str,
     ) -> Result<()> {
-        self.push_variant_index(variant_index);
-        Ok(())
+        // top level unit variants are compacted like any number, so the first variant is encoded as no bytes
+        self.push_variant_index(variant_index, self.top_level)
     }
 
-    // As is done here, serializers are encouraged to treat newtype structs as
-    // insignificant wrappers around the data they contain.
+    // Newtype structs are structs like any other,
+    // so their contents is never top level, same as in elrond-codec.
     fn serialize_newtype_struct<T>(
         self,
         _name: &'static str,
@@ -237,6 +266,7 @@ impl<'a> ser::Serializer for &'a mut ErdSerializer {
     where
         T: ?Sized + Serialize,
     {
+        self.top_level = false;
         value.serialize(self)
     }
 
@@ -256,7 +286,7 @@ impl<'a> ser::Serializer for &'a mut ErdSerializer {
         T: ?Sized + Serialize,
     {
         self.top_level = false;
-        self.push_variant_index(variant_index);
+        self.push_variant_index(variant_index, false)?;
         value.serialize(&mut *self)?;
         Ok(())
     }
@@ -276,7 +306,7 @@ impl<'a> ser::Serializer for &'a mut ErdSerializer {
             Some(l) => {
                 if !self.top_level {
                     // again, if it is top level, we can infer the size from the size of the serialized bytes
-                    self.push_entity_size(l as u32);
+                    self.push_entity_size(l)?;
                 }
                 self.top_level = false;
                 Ok(self)
@@ -294,13 +324,13 @@ impl<'a> ser::Serializer for &'a mut ErdSerializer {
         Ok(self)
     }
 
-    // Tuple structs look just like sequences in JSON.
+    // Tuple structs are just like tuples, their length is known, so it is not serialized.
     fn serialize_tuple_struct(
         self,
         _name: &'static str,
         len: usize,
     ) -> Result<Self::SerializeTupleStruct> {
-        self.serialize_seq(Some(len))
+        self.serialize_tuple(len)
     }
 
     // Tuple variants are represented in JSON as `{ NAME: [DATA...] }`. Again
@@ -313,15 +343,14 @@ impl<'a> ser::Serializer for &'a mut ErdSerializer {
         _len: usize,
     ) -> Result<Self::SerializeTupleVariant> {
         self.top_level = false;
-        self.push_variant_index(variant_index);
+        self.push_variant_index(variant_index, false)?;
         Ok(self)
     }
 
-    // Maps are represented in JSON as `{ K: V, K: V, ... }`.
-    fn serialize_map(self, _len: Option<usize>) -> Result<Self::SerializeMap> {
-        // self.output += "{";
-        // Ok(self)
-        Err(SDError::NotImplemented)
+    // Maps are sequences of key-value pairs, same as `BTreeMap` in elrond-codec.
+    // Only maps with a deterministic iteration order, like `BTreeMap`, produce a deterministic output.
+    fn serialize_map(self, len: Option<usize>) -> Result<Self::SerializeMap> {
+        self.serialize_seq(len)
     }
 
     // Structs look just like maps in JSON. In particular, JSON requires that we
@@ -348,7 +377,7 @@ impl<'a> ser::Serializer for &'a mut ErdSerializer {
         _len: usize,
     ) -> Result<Self::SerializeStructVariant> {
         self.top_level = false;
-        self.push_variant_index(variant_index);
+        self.push_variant_index(variant_index, false)?;
         Ok(self)
     }
 }
@@ -443,22 +472,13 @@ impl<'a> ser::SerializeTupleVariant for &'a mut ErdSerializer {
 // same time so `SerializeMap` implementations are required to support
 // `serialize_key` and `serialize_value` individually.
 //
-// There is a third optional method on the `SerializeMap` trait. The
-// `serialize_entry` method allows serializers to optimize for the case where
-// key and value are both available simultaneously. In JSON it doesn't make a
-// difference so the default behavior for `serialize_entry` is fine.
+// Each entry is the nested key, immediately followed by the nested value,
+// so the default behavior for `serialize_entry` is fine.
 impl<'a> ser::SerializeMap for &'a mut ErdSerializer {
     type Ok = ();
     type Error = SDError;
 
-    // The Serde data model allows map keys to be any serializable type. JSON
-    // only allows string keys so the implementation below will produce invalid
-    // JSON if the key serializes as something other than a string.
-    //
-    // A real JSON serializer would need to validate that map keys are strings.
-    // This can be done by using a different Serializer to serialize the key
-    // (instead of `&mut **self`) and having that other serializer only
-    // implement `serialize_str` and return an error on any other data type.
+    // Any serializable type can be a key.
     fn serialize_key<T>(&mut self, key: &T) -> Result<()>
     where
         T: ?Sized + Serialize,
@@ -466,9 +486,6 @@ impl<'a> ser::SerializeMap for &'a mut ErdSerializer {
         key.serialize(&mut **self)
     }
 
-    // It doesn't make a difference whether the colon is printed at the end of
-    // `serialize_key` or at the beginning of `serialize_value`. In this case
-    // the code is a bit simpler having it here.
     fn serialize_value<T>(&mut self, value: &T) -> Result<()>
     where
         T: ?Sized + Serialize,
@@ -624,19 +641,19 @@ mod tests {
         }
 
         let u = E::Unit;
-        let expected: &[u8] = &[/*variant index*/ 0, 0, 0, 0];
+        let expected: &[u8] = &[/*variant index, compacted*/];
         ser_ok(u, expected);
 
         let n = E::Newtype(1);
-        let expected: &[u8] = &[/*variant index*/ 0, 0, 0, 1, /*data*/ 0, 0, 0, 1];
+        let expected: &[u8] = &[/*variant index*/ 1, /*data*/ 0, 0, 0, 1];
         ser_ok(n, expected);
 
         let t = E::Tuple(1, 2);
-        let expected: &[u8] = &[/*variant index*/ 0, 0, 0, 2, /*(*/ 0, 0, 0, 1, /*,*/ 0, 0, 0, 2 /*)*/];
+        let expected: &[u8] = &[/*variant index*/ 2, /*(*/ 0, 0, 0, 1, /*,*/ 0, 0, 0, 2 /*)*/];
         ser_ok(t, expected);
 
         let s = E::Struct { a: 1 };
-        let expected: &[u8] = &[/*variant index*/ 0, 0, 0, 3, /*data*/ 0, 0, 0, 1];
+        let expected: &[u8] = &[/*variant index*/ 3, /*data*/ 0, 0, 0, 1];
         ser_ok(s, expected);
     }
 }
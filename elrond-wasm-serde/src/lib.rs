@@ -13,7 +13,7 @@ pub use bytes_ser::{to_bytes, u64_to_bytes};
 #[cfg(test)]
 pub mod tests {
     use super::*;
-    use alloc::vec::Vec;
+    use alloc::{collections::BTreeMap, string::String, vec::Vec};
     use core::fmt::Debug;
     use serde::{Deserialize, Serialize};
 
@@ -96,7 +96,73 @@ pub mod tests {
         check_top_encode_decode(some_v, expected);
 
         let none_v: Option<Vec<i32>> = None;
-        check_top_encode_decode(none_v, &[0]);
+        check_top_encode_decode(none_v, &[]);
+    }
+
+    #[test]
+    fn test_top_compacted_signed_numbers() {
+        // the sign must be preserved
+        check_top_encode_decode(128i16, &[0, 128]);
+        check_top_encode_decode(-129i16, &[255, 127]);
+        check_top_encode_decode(-1i64, &[255]);
+        check_top_encode_decode(i64::MIN, &[128, 0, 0, 0, 0, 0, 0, 0]);
+    }
+
+    #[test]
+    fn test_128() {
+        check_top_encode_decode(0u128, &[]);
+        check_top_encode_decode(5u128, &[5]);
+        check_top_encode_decode(-1i128, &[255]);
+        check_top_encode_decode(128i128, &[0, 128]);
+        check_top_encode_decode(u128::MAX, &[255; 16]);
+        check_top_encode_decode(Some(5u128), &[1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 5]);
+    }
+
+    #[test]
+    fn test_str() {
+        check_top_encode_decode(String::from("abc"), b"abc");
+        check_top_encode_decode(
+            (String::from("abc"), String::new()),
+            &[0, 0, 0, 3, b'a', b'b', b'c', 0, 0, 0, 0],
+        );
+        let borrowed: &str = from_bytes(b"abc").unwrap();
+        assert_eq!(borrowed, "abc");
+        assert!(matches!(
+            from_bytes::<String>(&[0xff]),
+            Err(SDError::Utf8DecodeError)
+        ));
+    }
+
+    #[test]
+    fn test_char() {
+        check_top_encode_decode('a', &[0x61]);
+        check_top_encode_decode(('a', '\0'), &[0, 0, 0, 0x61, 0, 0, 0, 0]);
+        assert!(matches!(
+            from_bytes::<char>(&[0xd8, 0x00]),
+            Err(SDError::InvalidValue)
+        ));
+    }
+
+    #[test]
+    fn test_map() {
+        let mut map = BTreeMap::<u8, String>::new();
+        map.insert(2, String::from("b"));
+        map.insert(1, String::from("a"));
+        check_top_encode_decode(map.clone(), &[1, 0, 0, 0, 1, b'a', 2, 0, 0, 0, 1, b'b']);
+        check_top_encode_decode(
+            Some(map),
+            &[1, 0, 0, 0, 2, 1, 0, 0, 0, 1, b'a', 2, 0, 0, 0, 1, b'b'],
+        );
+        check_top_encode_decode(BTreeMap::<u8, String>::new(), &[]);
+    }
+
+    #[test]
+    fn test_top_seq_incomplete_item() {
+        // the last item is incomplete, which is an error, not the end of the sequence
+        assert!(matches!(
+            from_bytes::<Vec<u16>>(&[0, 1, 0]),
+            Err(SDError::InputTooShort)
+        ));
     }
 
     #[test]
@@ -0,0 +1,205 @@
+use elrond_codec::{
+    elrond_codec_derive::{NestedDecode, NestedEncode, TopDecode, TopEncode},
+    test_util::{
+        dep_encode_to_vec_or_panic, top_decode_from_byte_slice_or_panic,
+        top_encode_to_vec_u8_or_panic,
+    },
+    NestedDecode, NestedEncode, TopDecode, TopEncode,
+};
+use elrond_wasm_serde::{from_bytes, to_bytes};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt::Debug,
+};
+
+/// Wraps a value, to compare the nested encodings too.
+/// The codec derives do not add bounds on type parameters, so they are explicit.
+#[derive(
+    Serialize, Deserialize, TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Debug,
+)]
+struct Nested<T: NestedEncode + NestedDecode> {
+    value: T,
+    after: u8,
+}
+
+/// Checks that serde and elrond-codec produce the same bytes,
+/// both at top level and nested, and that each side decodes what the other encoded.
+fn check_same_as_codec<T>(value: T)
+where
+    T: Serialize + DeserializeOwned + TopEncode + TopDecode + NestedEncode + NestedDecode,
+    T: PartialEq + Debug,
+{
+    let codec_bytes = top_encode_to_vec_u8_or_panic(&value);
+    let serde_bytes = to_bytes(&value).unwrap();
+    assert_eq!(serde_bytes, codec_bytes, "top encoding of {:?}", value);
+    assert_eq!(from_bytes::<T>(&codec_bytes).unwrap(), value);
+    assert_eq!(top_decode_from_byte_slice_or_panic::<T>(&serde_bytes), value);
+
+    let nested = Nested { value, after: 0xab };
+    let codec_bytes = dep_encode_to_vec_or_panic(&nested);
+    let serde_bytes = to_bytes(&nested).unwrap();
+    assert_eq!(serde_bytes, codec_bytes, "nested encoding of {:?}", nested);
+    assert_eq!(from_bytes::<Nested<T>>(&codec_bytes).unwrap(), nested);
+}
+
+#[derive(
+    Serialize, Deserialize, TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Debug,
+)]
+struct Offer {
+    id: u32,
+    price: i64,
+    seller: Vec<u8>,
+    note: String,
+    deadline: Option<u64>,
+    accepted: bool,
+}
+
+#[derive(
+    Serialize, Deserialize, TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Debug,
+)]
+struct Pair(i16, u16);
+
+#[derive(
+    Serialize, Deserialize, TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Debug,
+)]
+struct Amount(u64);
+
+#[derive(
+    Serialize, Deserialize, TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Debug,
+)]
+enum Status {
+    Pending,
+    Open,
+    Closed,
+}
+
+#[derive(
+    Serialize, Deserialize, TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Debug,
+)]
+enum Action {
+    Nothing,
+    Deposit(Amount),
+    Swap(u32, Vec<u8>),
+    Transfer { to: [u8; 4], amount: u128 },
+}
+
+#[test]
+fn numbers_test() {
+    check_same_as_codec(0u8);
+    check_same_as_codec(255u8);
+    check_same_as_codec(0x1234u16);
+    check_same_as_codec(0u32);
+    check_same_as_codec(u32::MAX);
+    check_same_as_codec(0x100u64);
+    check_same_as_codec(u64::MAX);
+
+    for v in [0i64, 1, -1, 127, 128, -128, -129, 255, -256, i64::MIN, i64::MAX] {
+        check_same_as_codec(v);
+        check_same_as_codec(v as i32);
+        check_same_as_codec(v as i16);
+        check_same_as_codec(v as i8);
+    }
+}
+
+#[test]
+fn numbers_128_test() {
+    check_same_as_codec(0u128);
+    check_same_as_codec(0x1_0000_0000_0000_0000u128);
+    check_same_as_codec(u128::MAX);
+    for v in [0i128, -1, 128, -129, i128::MIN, i128::MAX] {
+        check_same_as_codec(v);
+    }
+}
+
+#[test]
+fn bool_test() {
+    check_same_as_codec(true);
+    check_same_as_codec(false);
+}
+
+#[test]
+fn bytes_and_strings_test() {
+    check_same_as_codec(Vec::<u8>::new());
+    check_same_as_codec(b"abc".to_vec());
+    check_same_as_codec(String::new());
+    check_same_as_codec(String::from("elrond"));
+    check_same_as_codec(vec![String::from("a"), String::new()]);
+}
+
+#[test]
+fn option_test() {
+    check_same_as_codec(Option::<u32>::None);
+    check_same_as_codec(Some(0u32));
+    check_same_as_codec(Some(String::from("x")));
+    check_same_as_codec(Some(Some(5u8)));
+}
+
+#[test]
+fn collections_test() {
+    check_same_as_codec(vec![1u16, 2, 3]);
+    check_same_as_codec(vec![Some(1i32), None]);
+    check_same_as_codec([5u8; 4]);
+    check_same_as_codec([-1i64, 1]);
+    check_same_as_codec((1u8, -2i32, String::from("t")));
+
+    check_same_as_codec(BTreeSet::<u32>::new());
+    check_same_as_codec([3u32, 1, 2].iter().cloned().collect::<BTreeSet<_>>());
+
+    let mut map = BTreeMap::new();
+    map.insert(2u8, String::from("two"));
+    map.insert(1u8, String::from("one"));
+    check_same_as_codec(map);
+}
+
+#[test]
+fn structs_test() {
+    check_same_as_codec(Offer {
+        id: 1,
+        price: -129,
+        seller: b"seller".to_vec(),
+        note: String::from("note"),
+        deadline: Some(5),
+        accepted: true,
+    });
+    check_same_as_codec(Offer {
+        id: 0,
+        price: 0,
+        seller: Vec::new(),
+        note: String::new(),
+        deadline: None,
+        accepted: false,
+    });
+    check_same_as_codec(Pair(-1, 0x100));
+    check_same_as_codec(Amount(0));
+    check_same_as_codec(Amount(1_000_000));
+}
+
+#[test]
+fn enums_test() {
+    check_same_as_codec(Status::Pending);
+    check_same_as_codec(Status::Open);
+    check_same_as_codec(Status::Closed);
+    check_same_as_codec(vec![Status::Closed, Status::Pending]);
+
+    check_same_as_codec(Action::Nothing);
+    check_same_as_codec(Action::Deposit(Amount(7)));
+    check_same_as_codec(Action::Swap(3, b"xyz".to_vec()));
+    check_same_as_codec(Action::Transfer {
+        to: [1, 2, 3, 4],
+        amount: 1 << 100,
+    });
+}
+
+#[test]
+fn char_as_u32_test() {
+    // codec has no char support, chars are encoded like u32
+    assert_eq!(
+        to_bytes(&'a').unwrap(),
+        top_encode_to_vec_u8_or_panic(&('a' as u32))
+    );
+    assert_eq!(
+        to_bytes(&('€', 'a')).unwrap(),
+        top_encode_to_vec_u8_or_panic(&('€' as u32, 'a' as u32))
+    );
+}
@@ -1,25 +1,43 @@
 use super::*;
 use alloc::vec::Vec;
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct ContractAbi {
     pub build_info: BuildInfoAbi,
     pub docs: &'static [&'static str],
     pub name: &'static str,
     pub constructor: Option<EndpointAbi>,
+    /// Called when the contract code gets upgraded. If missing, the constructor gets called instead.
+    pub upgrade_constructor: Option<EndpointAbi>,
     pub endpoints: Vec<EndpointAbi>,
+    pub storage: Vec<StorageAbi>,
+    pub events: Vec<EventAbi>,
     pub type_descriptions: TypeDescriptionContainerImpl,
 }
 
 impl ContractAbi {
     pub fn coalesce(&mut self, other: Self) {
         self.endpoints.extend_from_slice(other.endpoints.as_slice());
+        for storage_abi in other.storage {
+            self.add_storage(storage_abi);
+        }
+        self.events.extend_from_slice(other.events.as_slice());
         self.type_descriptions.insert_all(&other.type_descriptions);
     }
 
+    /// The same key can be declared by several methods (e.g. a getter and a setter).
+    /// All declarations are kept, so that conflicting ones can be detected.
+    pub fn add_storage(&mut self, storage_abi: StorageAbi) {
+        self.storage.push(storage_abi);
+    }
+
     /// A type can provide more than 1 type descripions.
     /// For instance, a struct can also provide the descriptions of its fields.
     pub fn add_type_descriptions<T: TypeAbi>(&mut self) {
         T::provide_type_descriptions(&mut self.type_descriptions);
     }
+
+    pub fn add_storage_mapper_type_descriptions<M: StorageMapperAbi>(&mut self) {
+        M::provide_value_type_descriptions(&mut self.type_descriptions);
+    }
 }
@@ -26,7 +26,13 @@ pub enum EndpointMutabilityAbi {
 pub struct EndpointAbi {
     pub docs: &'static [&'static str],
     pub name: &'static str,
+    /// The Rust method behind the endpoint, only used for reporting errors.
+    pub rust_method_name: &'static str,
+    /// The contract or module trait that declares the endpoint, only used for reporting errors.
+    pub module_name: &'static str,
     pub only_owner: bool,
+    pub only_role: Option<&'static str>,
+    pub labels: &'static [&'static str],
     pub mutability: EndpointMutabilityAbi,
     pub payable_in_tokens: &'static [&'static str],
     pub inputs: Vec<InputAbi>,
@@ -0,0 +1,41 @@
+use super::*;
+use alloc::vec::Vec;
+
+/// An event argument, either a topic or the data of the log.
+#[derive(Clone, Debug)]
+pub struct EventInputAbi {
+    pub arg_name: &'static str,
+    pub type_name: TypeName,
+
+    /// Indexed arguments become topics, in order, after the event identifier.
+    pub indexed: bool,
+}
+
+/// Describes an event log, as declared by the `#[event]` annotation.
+/// Legacy events are not described.
+#[derive(Clone, Debug)]
+pub struct EventAbi {
+    pub docs: &'static [&'static str],
+
+    /// The first topic of the log.
+    pub identifier: &'static str,
+
+    /// The Rust method that emits the event, only used for reporting errors.
+    pub rust_method_name: &'static str,
+
+    /// The contract or module trait that declares the event, only used for reporting errors.
+    pub module_name: &'static str,
+
+    /// All arguments, in the order of the method declaration.
+    pub inputs: Vec<EventInputAbi>,
+}
+
+impl EventAbi {
+    pub fn add_input<T: TypeAbi>(&mut self, arg_name: &'static str, indexed: bool) {
+        self.inputs.push(EventInputAbi {
+            arg_name,
+            type_name: T::type_name(),
+            indexed,
+        });
+    }
+}
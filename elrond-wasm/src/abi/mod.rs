@@ -1,6 +1,8 @@
 mod build_info_abi;
 mod contract_abi;
 mod endpoint_abi;
+mod event_abi;
+mod storage_abi;
 mod type_abi;
 mod type_description;
 mod type_description_container;
@@ -8,6 +10,8 @@ mod type_description_container;
 pub use build_info_abi::*;
 pub use contract_abi::*;
 pub use endpoint_abi::*;
+pub use event_abi::*;
+pub use storage_abi::*;
 pub use type_abi::*;
 pub use type_description::*;
 pub use type_description_container::*;
@@ -0,0 +1,61 @@
+use super::*;
+use alloc::vec::Vec;
+
+/// Describes a storage entry (or a group of entries, in the case of mappers),
+/// as declared by the `#[storage_get]`, `#[storage_set]` and `#[storage_mapper]` annotations.
+#[derive(Clone, Debug)]
+pub struct StorageAbi {
+    pub docs: &'static [&'static str],
+
+    /// The base key, as given in the annotation.
+    pub key: &'static str,
+
+    /// The Rust method that accesses the storage, only used for reporting errors.
+    pub rust_method_name: &'static str,
+
+    /// The contract or module trait that declares the storage, only used for reporting errors.
+    pub module_name: &'static str,
+
+    /// The storage mapper used to manage the key. `None` for plain getters and setters.
+    pub mapper: Option<&'static str>,
+
+    /// Arguments that get appended to the base key, in order.
+    pub key_args: Vec<InputAbi>,
+
+    /// The types of the stored values.
+    /// A plain getter or setter has exactly one value type,
+    /// mappers list their type arguments (e.g. the key and value types of a `MapMapper`).
+    pub value_types: Vec<TypeName>,
+}
+
+impl StorageAbi {
+    pub fn add_key_arg<T: TypeAbi>(&mut self, arg_name: &'static str) {
+        self.key_args.push(InputAbi {
+            arg_name,
+            type_name: T::type_name(),
+            multi_arg: T::is_multi_arg_or_result(),
+        });
+    }
+
+    pub fn set_value_type<T: TypeAbi>(&mut self) {
+        self.value_types = alloc::vec![T::type_name()];
+    }
+
+    pub fn set_mapper<M: StorageMapperAbi>(&mut self) {
+        self.mapper = Some(M::mapper_name());
+        self.value_types = M::value_types();
+    }
+}
+
+/// Provides the storage layout of a storage mapper to the ABI.
+/// Needs to be implemented by all mappers used with `#[storage_mapper]`.
+pub trait StorageMapperAbi {
+    /// The name of the mapper type, without type arguments, e.g. `"VecMapper"`.
+    fn mapper_name() -> &'static str;
+
+    /// The types of the values managed by the mapper, in the order of its type arguments.
+    fn value_types() -> Vec<TypeName>;
+
+    /// Same as `TypeAbi::provide_type_descriptions`, for all the value types.
+    fn provide_value_type_descriptions<TDC: TypeDescriptionContainer>(accumulator: &mut TDC);
+}
@@ -1,6 +1,7 @@
 use super::*;
 use alloc::{
     boxed::Box,
+    collections::{BTreeMap, BTreeSet},
     string::{String, ToString},
     vec::Vec,
 };
@@ -143,12 +144,14 @@ type_abi_name_only!(u16, "u16");
 type_abi_name_only!(u32, "u32");
 type_abi_name_only!(usize, "u32");
 type_abi_name_only!(u64, "u64");
+type_abi_name_only!(u128, "u128");
 
 type_abi_name_only!(i8, "i8");
 type_abi_name_only!(i16, "i16");
 type_abi_name_only!(i32, "i32");
 type_abi_name_only!(isize, "i32");
 type_abi_name_only!(i64, "i64");
+type_abi_name_only!(i128, "i128");
 
 type_abi_name_only!(core::num::NonZeroUsize, "NonZeroUsize");
 type_abi_name_only!(bool, "bool");
@@ -166,6 +169,35 @@ impl<T: TypeAbi> TypeAbi for Option<T> {
     }
 }
 
+impl<T: TypeAbi> TypeAbi for BTreeSet<T> {
+    fn type_name() -> String {
+        let mut repr = String::from("BTreeSet<");
+        repr.push_str(T::type_name().as_str());
+        repr.push('>');
+        repr
+    }
+
+    fn provide_type_descriptions<TDC: TypeDescriptionContainer>(accumulator: &mut TDC) {
+        T::provide_type_descriptions(accumulator);
+    }
+}
+
+impl<K: TypeAbi, V: TypeAbi> TypeAbi for BTreeMap<K, V> {
+    fn type_name() -> String {
+        let mut repr = String::from("BTreeMap<");
+        repr.push_str(K::type_name().as_str());
+        repr.push(',');
+        repr.push_str(V::type_name().as_str());
+        repr.push('>');
+        repr
+    }
+
+    fn provide_type_descriptions<TDC: TypeDescriptionContainer>(accumulator: &mut TDC) {
+        K::provide_type_descriptions(accumulator);
+        V::provide_type_descriptions(accumulator);
+    }
+}
+
 impl<T: TypeAbi, E> TypeAbi for Result<T, E> {
     fn type_name() -> String {
         T::type_name()
@@ -45,4 +45,7 @@ pub struct StructFieldDescription {
     pub docs: &'static [&'static str],
     pub name: &'static str,
     pub field_type: String,
+
+    /// Optional fields can be missing from the end of the encoded data.
+    pub optional: bool,
 }
@@ -93,4 +93,22 @@ pub trait CallValueApi: ManagedTypeApi + ErrorApi + Sized {
 
         transfers
     }
+
+    /// Same as `get_all_esdt_transfers`, but an EGLD transfer is also returned, as a single payment.
+    /// Especially used in the auto-generated call value processing, when checking all payments.
+    fn get_all_payments(&self) -> ManagedVec<Self, EsdtTokenPayment<Self>> {
+        let egld_value = self.egld_value();
+        if egld_value == 0 {
+            return self.get_all_esdt_transfers();
+        }
+
+        let mut payments = ManagedVec::new(self.clone());
+        payments.push(EsdtTokenPayment::<Self> {
+            token_type: EsdtTokenType::Fungible,
+            token_identifier: TokenIdentifier::egld(self.clone()),
+            token_nonce: 0,
+            amount: egld_value,
+        });
+        payments
+    }
 }
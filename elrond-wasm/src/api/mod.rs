@@ -7,6 +7,8 @@ mod endpoint_finish_api;
 mod error_api;
 mod log_api;
 mod managed_types;
+mod readonly_api;
+mod reentrancy_lock_api;
 mod send_api;
 mod storage_api;
 pub mod uncallable;
@@ -21,6 +23,8 @@ pub use endpoint_finish_api::EndpointFinishApi;
 pub use error_api::ErrorApi;
 pub use log_api::LogApi;
 pub use managed_types::*;
+pub use readonly_api::ReadonlyApi;
+pub use reentrancy_lock_api::ReentrancyLockApi;
 pub use send_api::SendApi;
 pub use storage_api::*;
 pub use vm_api::VMApi;
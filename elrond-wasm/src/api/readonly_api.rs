@@ -0,0 +1,18 @@
+use super::ErrorApi;
+
+/// Tracks whether the current call is allowed to change the blockchain state.
+/// The endpoint wrappers generated for `#[view]` methods switch to read-only mode before calling the method.
+/// In read-only mode, storage writes, transfers, logs and contract calls signal an error.
+pub trait ReadonlyApi: ErrorApi {
+    fn set_readonly(&self, readonly: bool);
+
+    fn is_readonly(&self) -> bool;
+
+    /// Signals the given error if the API is in read-only mode.
+    #[inline]
+    fn check_not_readonly(&self, err_msg: &'static [u8]) {
+        if self.is_readonly() {
+            self.signal_error(err_msg);
+        }
+    }
+}
@@ -0,0 +1,8 @@
+/// Remembers the `#[non_reentrant]` lock group held by the current call, if any.
+/// Async calls and contract upgrades end the execution before the generated endpoint code
+/// gets to release the lock, so they release it themselves, using this information.
+pub trait ReentrancyLockApi {
+    fn set_held_reentrancy_lock(&self, group: Option<&'static [u8]>);
+
+    fn held_reentrancy_lock(&self) -> Option<&'static [u8]>;
+}
@@ -11,6 +11,8 @@ mod error_api_uncallable;
 mod log_api_uncallable;
 mod managed_buffer_api_uncallable;
 mod managed_type_api_uncallable;
+mod readonly_api_uncallable;
+mod reentrancy_lock_api_uncallable;
 mod send_api_uncallable;
 mod storage_api_uncallable;
 mod vm_api_uncallable;
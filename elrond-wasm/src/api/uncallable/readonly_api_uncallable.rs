@@ -0,0 +1,11 @@
+use crate::api::ReadonlyApi;
+
+impl ReadonlyApi for super::UncallableApi {
+    fn set_readonly(&self, _readonly: bool) {
+        unreachable!()
+    }
+
+    fn is_readonly(&self) -> bool {
+        unreachable!()
+    }
+}
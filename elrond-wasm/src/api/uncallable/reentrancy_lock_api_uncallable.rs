@@ -0,0 +1,11 @@
+use crate::api::ReentrancyLockApi;
+
+impl ReentrancyLockApi for super::UncallableApi {
+    fn set_held_reentrancy_lock(&self, _group: Option<&'static [u8]>) {
+        unreachable!()
+    }
+
+    fn held_reentrancy_lock(&self) -> Option<&'static [u8]> {
+        unreachable!()
+    }
+}
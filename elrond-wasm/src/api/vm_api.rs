@@ -1,6 +1,7 @@
 use super::{
     BlockchainApi, CallValueApi, CryptoApi, EndpointArgumentApi, EndpointFinishApi, ErrorApi,
-    LogApi, ManagedTypeApi, SendApi, StorageReadApi, StorageWriteApi,
+    LogApi, ManagedTypeApi, ReadonlyApi, ReentrancyLockApi, SendApi, StorageReadApi,
+    StorageWriteApi,
 };
 
 pub trait VMApi:
@@ -12,6 +13,8 @@ pub trait VMApi:
     + ErrorApi
     + LogApi
     + ManagedTypeApi
+    + ReadonlyApi
+    + ReentrancyLockApi
     + SendApi
     + StorageReadApi
     + StorageWriteApi
@@ -4,5 +4,10 @@ use alloc::boxed::Box;
 pub trait CallableContract<A> {
     fn call(&self, fn_name: &[u8]) -> bool;
 
+    /// Contracts with an `#[upgrade]` method get it called on upgrade, instead of the constructor.
+    fn has_upgrade_method(&self) -> bool {
+        false
+    }
+
     fn into_api(self: Box<Self>) -> A;
 }
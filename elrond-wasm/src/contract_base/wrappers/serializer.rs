@@ -3,6 +3,7 @@ use elrond_codec::{DecodeError, EncodeError, TopDecode, TopEncode};
 use crate::{
     api::{ErrorApi, ManagedTypeApi},
     err_msg,
+    io::append_decode_error_message,
     types::{AsManagedRef, BoxedBytes, ManagedBuffer, ManagedBytesTopDecodeInput, ManagedType},
 };
 
@@ -62,6 +63,6 @@ where
 {
     let mut message_buffer =
         ManagedBuffer::new_from_bytes(api.clone(), err_msg::SERIALIZER_DECODE_ERROR);
-    message_buffer.append_bytes(decode_err.message_bytes());
+    append_decode_error_message(&mut message_buffer, &decode_err);
     api.signal_error_from_buffer(message_buffer.get_raw_handle())
 }
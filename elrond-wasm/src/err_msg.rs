@@ -2,6 +2,9 @@ pub const NON_PAYABLE_FUNC_EGLD: &[u8] = b"function does not accept EGLD payment
 pub const NON_PAYABLE_FUNC_ESDT: &[u8] = b"function does not accept ESDT payment";
 pub const BAD_TOKEN_PROVIDED: &[u8] = b"bad call value token provided";
 pub const TOO_MANY_ESDT_TRANSFERS: &[u8] = b"too many ESDT transfers";
+pub const TOO_FEW_PAYMENTS: &[u8] = b"too few payments";
+pub const TOO_MANY_PAYMENTS: &[u8] = b"too many payments";
+pub const BAD_TOKEN_TYPE_PROVIDED: &[u8] = b"bad call value token type provided";
 
 pub const ARG_WRONG_NUMBER: &[u8] = b"wrong number of arguments";
 pub const ARG_ASYNC_WRONG_NUMBER: &[u8] = b"wrong number of arguments provided to async call";
@@ -15,6 +18,8 @@ pub const ARG_BAD_LENGTH: &[u8] = b"argument has wrong length";
 pub const ARG_BAD_LENGTH_32: &[u8] = b"argument has wrong length: 32 bytes expected";
 pub const ARG_DECODE_ERROR_1: &[u8] = b"argument decode error (";
 pub const ARG_DECODE_ERROR_2: &[u8] = b"): ";
+pub const DECODE_ERROR_CONTEXT_1: &[u8] = b" (at ";
+pub const DECODE_ERROR_CONTEXT_2: &[u8] = b")";
 pub const STORAGE_VALUE_OUT_OF_RANGE: &[u8] = b"storage value out of range";
 pub const STORAGE_DECODE_ERROR: &[u8] = b"storage decode error: ";
 pub const STORAGE_ENCODE_ERROR: &[u8] = b"storage encode error: ";
@@ -39,6 +44,13 @@ pub const DESERIALIZATION_ODD_DIGITS: &[u8] =
 pub const DESERIALIZATION_ARG_OUT_OF_RANGE: &[u8] =
     b"call data deserialization error: argument out of range";
 
+pub const READONLY_STORAGE_WRITE: &[u8] = b"storage writes not allowed in readonly mode";
+pub const READONLY_TRANSFER: &[u8] = b"transfers not allowed in readonly mode";
+pub const READONLY_LOG: &[u8] = b"logs not allowed in readonly mode";
+pub const READONLY_CONTRACT_CALL: &[u8] = b"contract calls not allowed in readonly mode";
+
+pub const REENTRANT_CALL: &[u8] = b"reentrant call";
+
 pub const CALLBACK_BAD_FUNC: &[u8] = b"no callback function with that name exists in contract";
 
 pub const STORAGE_NOT_I64: &[u8] = b"storage not i64";
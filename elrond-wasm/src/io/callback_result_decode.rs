@@ -0,0 +1,29 @@
+use crate::DynArgInput;
+use elrond_codec::*;
+
+/// Types that can be decoded from the results of an async call, in a callback.
+///
+/// Unlike `DynArg`, decoding does not crash the callback on failure,
+/// the error is returned instead, so the callback can still handle it (e.g. refund the caller).
+pub trait CallbackResultDecode: Sized {
+    fn decode_callback_result<I: DynArgInput>(loader: &mut I) -> Result<Self, DecodeError>;
+}
+
+/// All top-deserializable types can be callback results.
+impl<T> CallbackResultDecode for T
+where
+    T: TopEncode + TopDecode,
+{
+    fn decode_callback_result<I: DynArgInput>(loader: &mut I) -> Result<Self, DecodeError> {
+        if let TypeInfo::Unit = <T as TopDecode>::TYPE_INFO {
+            // unit type returns without loading anything
+            let cast_unit: T = unsafe { core::mem::transmute_copy(&()) };
+            return Ok(cast_unit);
+        }
+
+        if !loader.has_next() {
+            return Err(DecodeError::INPUT_TOO_SHORT);
+        }
+        T::top_decode(loader.next_arg_input())
+    }
+}
@@ -20,12 +20,23 @@ where
 #[inline(always)]
 fn load_single_arg_exit<AA>(ctx: (AA, ArgId), de_err: DecodeError) -> !
 where
-    AA: ManagedTypeApi + EndpointArgumentApi + ErrorApi,
+    AA: ManagedTypeApi + ErrorApi,
 {
     let (api, arg_id) = ctx;
     signal_arg_de_error(api, arg_id, de_err)
 }
 
+/// Decodes an argument that borrows from its raw bytes,
+/// which the generated endpoint code loads beforehand and keeps alive for the duration of the call.
+#[inline]
+pub fn decode_borrowed_arg<'a, AA, T>(api: AA, arg_bytes: &'a [u8], arg_id: ArgId) -> T
+where
+    T: NestedDecodeBorrowed<'a>,
+    AA: ManagedTypeApi + ErrorApi,
+{
+    dep_decode_borrowed_from_byte_slice_or_exit(arg_bytes, (api, arg_id), load_single_arg_exit)
+}
+
 /// It's easier to generate code from macros using this function, instead of the DynArg method.
 #[inline]
 pub fn load_dyn_arg<I, T>(loader: &mut I, arg_id: ArgId) -> T
@@ -1,5 +1,6 @@
 mod arg_de_input;
 mod arg_id;
+mod callback_result_decode;
 mod contract_call_arg;
 mod dyn_arg;
 mod dyn_arg_input;
@@ -14,6 +15,7 @@ mod signal_error;
 
 pub use arg_de_input::*;
 pub use arg_id::ArgId;
+pub use callback_result_decode::*;
 pub use contract_call_arg::*;
 pub use dyn_arg::*;
 pub use dyn_arg_input::*;
@@ -14,6 +14,25 @@ where
         ManagedBuffer::new_from_bytes(api.clone(), err_msg::ARG_DECODE_ERROR_1);
     message_buffer.append_bytes(arg_id.as_bytes());
     message_buffer.append_bytes(err_msg::ARG_DECODE_ERROR_2);
-    message_buffer.append_bytes(decode_err.message_bytes());
+    append_decode_error_message(&mut message_buffer, &decode_err);
     api.signal_error_from_buffer(message_buffer.get_raw_handle())
 }
+
+/// Appends the decode error message,
+/// followed by the path to the value that failed to decode, if the `decode-error-context` feature is enabled.
+pub fn append_decode_error_message<M: ManagedTypeApi>(
+    message_buffer: &mut ManagedBuffer<M>,
+    decode_err: &DecodeError,
+) {
+    message_buffer.append_bytes(decode_err.message_bytes());
+
+    #[cfg(feature = "decode-error-context")]
+    {
+        let context_path = decode_err.context_path();
+        if !context_path.is_empty() {
+            message_buffer.append_bytes(err_msg::DECODE_ERROR_CONTEXT_1);
+            message_buffer.append_bytes(context_path.as_slice());
+            message_buffer.append_bytes(err_msg::DECODE_ERROR_CONTEXT_2);
+        }
+    }
+}
@@ -1,6 +1,7 @@
 #![no_std]
 #![feature(never_type)]
 #![feature(try_trait_v2)]
+#![feature(try_trait_v2_residual)]
 #![feature(control_flow_enum)]
 #![allow(clippy::type_complexity)]
 #![allow(deprecated)]
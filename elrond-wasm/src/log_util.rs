@@ -1,4 +1,4 @@
-use elrond_codec::{EncodeError, TopEncode};
+use elrond_codec::{EncodeError, NestedEncode, TopEncode};
 
 use crate::{
     api::{ErrorApi, LogApi, ManagedTypeApi},
@@ -53,6 +53,18 @@ where
     data_buffer
 }
 
+/// Used for events with several data fields.
+/// The fields are nested-encoded one after the other,
+/// so the result is the same as the top-level encoding of a tuple containing all of them.
+pub fn serialize_log_data_field<A, T>(data_buffer: &mut ManagedBuffer<A>, field: T)
+where
+    A: ErrorApi + ManagedTypeApi + 'static,
+    T: NestedEncode,
+{
+    let api = data_buffer.type_manager();
+    field.dep_encode_or_exit(data_buffer, api, serialize_log_data_exit);
+}
+
 #[inline(always)]
 fn serialize_log_data_exit<A>(api: A, encode_err: EncodeError) -> !
 where
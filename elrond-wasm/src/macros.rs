@@ -33,16 +33,16 @@ macro_rules! imports {
     };
 }
 
-/// Imports required for deriving serialization and TypeAbi.
+/// Imports required for deriving serialization, TypeAbi and ManagedVecItem.
 #[macro_export]
 macro_rules! derive_imports {
     () => {
         use elrond_wasm::{
-            derive::TypeAbi,
+            derive::{ManagedVecItem, TypeAbi},
             elrond_codec,
             elrond_codec::elrond_codec_derive::{
-                NestedDecode, NestedEncode, TopDecode, TopDecodeOrDefault, TopEncode,
-                TopEncodeOrDefault,
+                NestedDecode, NestedDecodeBorrowed, NestedEncode, TopDecode, TopDecodeOrDefault,
+                TopEncode, TopEncodeOrDefault,
             },
         };
     };
@@ -134,3 +134,21 @@ macro_rules! non_zero_usize {
         }
     };
 }
+
+/// Generated for contracts with an `#[upgrade]` method,
+/// which only gets called by VM versions that call the `upgrade` endpoint on upgrade.
+#[cfg(feature = "vm-upgrade-endpoint")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! require_vm_upgrade_endpoint {
+    () => {};
+}
+
+#[cfg(not(feature = "vm-upgrade-endpoint"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! require_vm_upgrade_endpoint {
+    () => {
+        compile_error!("`#[upgrade]` methods require the `vm-upgrade-endpoint` feature of elrond-wasm, the VM calls `init` on upgrade otherwise");
+    };
+}
@@ -0,0 +1,237 @@
+use super::{
+    token_mapper::{
+        default_issue_callback_closure, require_all_same_token, require_same_token,
+        TOKEN_ID_ALREADY_SET_ERR_MSG, TOKEN_ID_NOT_SET_ERR_MSG,
+    },
+    StorageMapper,
+};
+use crate::{
+    abi::{StorageMapperAbi, TypeAbi, TypeDescriptionContainer, TypeName},
+    api::{EndpointFinishApi, ErrorApi, ManagedTypeApi, StorageReadApi, StorageWriteApi, VMApi},
+    contract_base::{BlockchainWrapper, CallValueWrapper, SendWrapper},
+    esdt::{ESDTSystemSmartContractProxy, FungibleTokenProperties},
+    io::EndpointResult,
+    storage::{storage_get, storage_get_len, storage_set, StorageKey},
+    types::{
+        AsyncCall, BigUint, EsdtLocalRole, EsdtTokenPayment, EsdtTokenType, ManagedAddress,
+        ManagedBuffer, TokenIdentifier,
+    },
+};
+use alloc::vec::Vec;
+
+const FUNGIBLE_TOKEN_ROLES: [EsdtLocalRole; 2] = [EsdtLocalRole::Mint, EsdtLocalRole::Burn];
+
+/// Manages a fungible ESDT issued by the contract.
+///
+/// Only the token identifier is kept in storage.
+/// Issuing is done via an async call to the ESDT system SC,
+/// the token identifier gets saved automatically by the default issue callback
+/// (see `DEFAULT_ISSUE_CALLBACK_NAME`).
+pub struct FungibleTokenMapper<SA>
+where
+    SA: StorageReadApi + StorageWriteApi + ManagedTypeApi + ErrorApi + Clone + 'static,
+{
+    api: SA,
+    key: StorageKey<SA>,
+}
+
+impl<SA> StorageMapper<SA> for FungibleTokenMapper<SA>
+where
+    SA: StorageReadApi + StorageWriteApi + ManagedTypeApi + ErrorApi + Clone + 'static,
+{
+    fn new(api: SA, base_key: StorageKey<SA>) -> Self {
+        FungibleTokenMapper { api, key: base_key }
+    }
+}
+
+impl<SA> FungibleTokenMapper<SA>
+where
+    SA: VMApi + 'static,
+{
+    /// Returns whether the token was issued (or set) yet.
+    pub fn is_empty(&self) -> bool {
+        storage_get_len(self.api.clone(), &self.key) == 0
+    }
+
+    /// Retrieves the token identifier. Signals an error if the token was not issued yet.
+    pub fn get_token_id(&self) -> TokenIdentifier<SA> {
+        if self.is_empty() {
+            self.api.signal_error(TOKEN_ID_NOT_SET_ERR_MSG);
+        }
+        storage_get(self.api.clone(), &self.key)
+    }
+
+    /// Saves a token identifier obtained by other means than issuing it via the mapper.
+    /// Signals an error if a token identifier was already set.
+    pub fn set_token_id(&self, token_id: &TokenIdentifier<SA>) {
+        self.require_not_issued();
+        storage_set(self.api.clone(), &self.key, token_id);
+    }
+
+    /// Produces an async call to the ESDT system SC, which issues a new fungible token.
+    /// The token identifier is saved automatically by the default issue callback.
+    pub fn issue(
+        &self,
+        issue_cost: BigUint<SA>,
+        token_display_name: &ManagedBuffer<SA>,
+        token_ticker: &ManagedBuffer<SA>,
+        initial_supply: &BigUint<SA>,
+        properties: FungibleTokenProperties,
+    ) -> AsyncCall<SA> {
+        self.require_not_issued();
+
+        ESDTSystemSmartContractProxy::new_proxy_obj(self.api.clone())
+            .issue_fungible(
+                issue_cost,
+                token_display_name,
+                token_ticker,
+                initial_supply,
+                properties,
+            )
+            .async_call()
+            .with_callback(default_issue_callback_closure(self.api.clone(), &self.key))
+    }
+
+    /// Produces an async call to the ESDT system SC,
+    /// which issues a new fungible token and gives the contract all the local roles.
+    /// The token identifier is saved automatically by the default issue callback.
+    pub fn issue_and_set_all_roles(
+        &self,
+        issue_cost: BigUint<SA>,
+        token_display_name: &ManagedBuffer<SA>,
+        token_ticker: &ManagedBuffer<SA>,
+        num_decimals: usize,
+    ) -> AsyncCall<SA> {
+        self.require_not_issued();
+
+        ESDTSystemSmartContractProxy::new_proxy_obj(self.api.clone())
+            .issue_and_set_all_roles(
+                issue_cost,
+                token_display_name,
+                token_ticker,
+                EsdtTokenType::Fungible,
+                num_decimals,
+            )
+            .async_call()
+            .with_callback(default_issue_callback_closure(self.api.clone(), &self.key))
+    }
+
+    /// Produces an async call to the ESDT system SC,
+    /// which gives the contract the mint and burn roles for the managed token.
+    pub fn set_local_roles(&self) -> AsyncCall<SA> {
+        let sc_address = BlockchainWrapper::new(self.api.clone()).get_sc_address();
+        self.set_local_roles_for_address(&sc_address)
+    }
+
+    /// Produces an async call to the ESDT system SC,
+    /// which gives an address the mint and burn roles for the managed token.
+    pub fn set_local_roles_for_address(&self, address: &ManagedAddress<SA>) -> AsyncCall<SA> {
+        let token_id = self.get_token_id();
+        ESDTSystemSmartContractProxy::new_proxy_obj(self.api.clone())
+            .set_special_roles(address, &token_id, FUNGIBLE_TOKEN_ROLES.iter().cloned())
+            .async_call()
+    }
+
+    /// Mints new tokens in the contract account.
+    /// The contract needs to have the mint role for the token.
+    pub fn mint(&self, amount: &BigUint<SA>) -> EsdtTokenPayment<SA> {
+        let token_id = self.get_token_id();
+        SendWrapper::new(self.api.clone()).esdt_local_mint(&token_id, 0, amount);
+        EsdtTokenPayment::from(token_id, 0, amount.clone())
+    }
+
+    /// Mints new tokens and sends them to the given address.
+    /// The contract needs to have the mint role for the token.
+    pub fn mint_and_send(
+        &self,
+        to: &ManagedAddress<SA>,
+        amount: &BigUint<SA>,
+    ) -> EsdtTokenPayment<SA> {
+        let payment = self.mint(amount);
+        SendWrapper::new(self.api.clone()).direct(
+            to,
+            &payment.token_identifier,
+            0,
+            &payment.amount,
+            &[],
+        );
+        payment
+    }
+
+    /// Burns tokens from the contract account.
+    /// The contract needs to have the burn role for the token.
+    pub fn burn(&self, amount: &BigUint<SA>) {
+        let token_id = self.get_token_id();
+        SendWrapper::new(self.api.clone()).esdt_local_burn(&token_id, 0, amount);
+    }
+
+    /// The amount of tokens held by the contract.
+    pub fn get_balance(&self) -> BigUint<SA> {
+        let token_id = self.get_token_id();
+        BlockchainWrapper::new(self.api.clone()).get_sc_balance(&token_id, 0)
+    }
+
+    /// Signals an error if the current call payment is not made with the managed token.
+    pub fn require_same_token(&self) {
+        let token_id = self.get_token_id();
+        let payment_token = CallValueWrapper::new(self.api.clone()).token();
+        require_same_token(&self.api, &token_id, &payment_token);
+    }
+
+    /// Signals an error if any of the current call payments is not made with the managed token.
+    pub fn require_all_same_token(&self) {
+        let token_id = self.get_token_id();
+        let payments = CallValueWrapper::new(self.api.clone()).all_esdt_transfers();
+        require_all_same_token(&self.api, &token_id, &payments);
+    }
+
+    fn require_not_issued(&self) {
+        if !self.is_empty() {
+            self.api.signal_error(TOKEN_ID_ALREADY_SET_ERR_MSG);
+        }
+    }
+}
+
+impl<SA> EndpointResult for FungibleTokenMapper<SA>
+where
+    SA: VMApi + 'static,
+{
+    type DecodeAs = TokenIdentifier<SA>;
+
+    fn finish<FA>(&self, api: FA)
+    where
+        FA: ManagedTypeApi + EndpointFinishApi + Clone + 'static,
+    {
+        storage_get::<SA, TokenIdentifier<SA>>(self.api.clone(), &self.key).finish(api);
+    }
+}
+
+impl<SA> TypeAbi for FungibleTokenMapper<SA>
+where
+    SA: VMApi + 'static,
+{
+    fn type_name() -> TypeName {
+        TokenIdentifier::<SA>::type_name()
+    }
+
+    fn provide_type_descriptions<TDC: TypeDescriptionContainer>(accumulator: &mut TDC) {
+        TokenIdentifier::<SA>::provide_type_descriptions(accumulator)
+    }
+}
+
+impl<SA> StorageMapperAbi for FungibleTokenMapper<SA>
+where
+    SA: VMApi + 'static,
+{
+    fn mapper_name() -> &'static str {
+        "FungibleTokenMapper"
+    }
+
+    fn value_types() -> Vec<TypeName> {
+        alloc::vec![TokenIdentifier::<SA>::type_name()]
+    }
+
+    fn provide_value_type_descriptions<TDC: TypeDescriptionContainer>(accumulator: &mut TDC) {
+        TokenIdentifier::<SA>::provide_type_descriptions(accumulator);
+    }
+}
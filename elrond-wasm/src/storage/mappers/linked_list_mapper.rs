@@ -1,6 +1,6 @@
 use super::{StorageClearable, StorageMapper};
 use crate::{
-    abi::{TypeAbi, TypeDescriptionContainer, TypeName},
+    abi::{StorageMapperAbi, TypeAbi, TypeDescriptionContainer, TypeName},
     api::{EndpointFinishApi, ErrorApi, ManagedTypeApi, StorageReadApi, StorageWriteApi},
     io::EndpointResult,
     storage::{storage_get, storage_set, StorageKey},
@@ -565,3 +565,21 @@ where
         true
     }
 }
+
+impl<SA, T> StorageMapperAbi for LinkedListMapper<SA, T>
+where
+    SA: StorageReadApi + StorageWriteApi + ManagedTypeApi + ErrorApi + Clone + 'static,
+    T: TopEncode + TopDecode + NestedEncode + NestedDecode + Clone + TypeAbi,
+{
+    fn mapper_name() -> &'static str {
+        "LinkedListMapper"
+    }
+
+    fn value_types() -> Vec<TypeName> {
+        alloc::vec![T::type_name()]
+    }
+
+    fn provide_value_type_descriptions<TDC: TypeDescriptionContainer>(accumulator: &mut TDC) {
+        T::provide_type_descriptions(accumulator);
+    }
+}
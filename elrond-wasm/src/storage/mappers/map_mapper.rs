@@ -1,8 +1,10 @@
 use super::{set_mapper, SetMapper, StorageClearable, StorageMapper};
 use crate::{
+    abi::{StorageMapperAbi, TypeAbi, TypeDescriptionContainer, TypeName},
     api::{ErrorApi, ManagedTypeApi, StorageReadApi, StorageWriteApi},
     storage::{storage_clear, storage_get, storage_set, StorageKey},
 };
+use alloc::vec::Vec;
 use core::marker::PhantomData;
 use elrond_codec::{NestedDecode, NestedEncode, TopDecode, TopEncode};
 
@@ -437,3 +439,23 @@ where
         self.map.remove(&self.key).unwrap()
     }
 }
+
+impl<SA, K, V> StorageMapperAbi for MapMapper<SA, K, V>
+where
+    SA: StorageReadApi + StorageWriteApi + ManagedTypeApi + ErrorApi + Clone + 'static,
+    K: TopEncode + TopDecode + NestedEncode + NestedDecode + TypeAbi + 'static,
+    V: TopEncode + TopDecode + TypeAbi + 'static,
+{
+    fn mapper_name() -> &'static str {
+        "MapMapper"
+    }
+
+    fn value_types() -> Vec<TypeName> {
+        alloc::vec![K::type_name(), V::type_name()]
+    }
+
+    fn provide_value_type_descriptions<TDC: TypeDescriptionContainer>(accumulator: &mut TDC) {
+        K::provide_type_descriptions(accumulator);
+        V::provide_type_descriptions(accumulator);
+    }
+}
@@ -1,8 +1,10 @@
 use super::{set_mapper, SetMapper, StorageClearable, StorageMapper};
 use crate::{
+    abi::{StorageMapperAbi, TypeAbi, TypeDescriptionContainer, TypeName},
     api::{ErrorApi, ManagedTypeApi, StorageReadApi, StorageWriteApi},
     storage::{self, StorageKey},
 };
+use alloc::{string::String, vec::Vec};
 use core::marker::PhantomData;
 use elrond_codec::{NestedDecode, NestedEncode, TopDecode, TopEncode};
 
@@ -385,3 +387,28 @@ where
         self.map.remove(&self.key);
     }
 }
+
+impl<SA, K, V> StorageMapperAbi for MapStorageMapper<SA, K, V>
+where
+    SA: StorageReadApi + StorageWriteApi + ManagedTypeApi + ErrorApi + Clone + 'static,
+    K: TopEncode + TopDecode + NestedEncode + NestedDecode + TypeAbi + 'static,
+    V: StorageMapper<SA> + StorageClearable + StorageMapperAbi,
+{
+    fn mapper_name() -> &'static str {
+        "MapStorageMapper"
+    }
+
+    fn value_types() -> Vec<TypeName> {
+        // the nested mapper is described by its name and type arguments, e.g. `SetMapper<u32>`
+        let mut nested_mapper = String::from(V::mapper_name());
+        nested_mapper.push('<');
+        nested_mapper.push_str(V::value_types().join(",").as_str());
+        nested_mapper.push('>');
+        alloc::vec![K::type_name(), nested_mapper]
+    }
+
+    fn provide_value_type_descriptions<TDC: TypeDescriptionContainer>(accumulator: &mut TDC) {
+        K::provide_type_descriptions(accumulator);
+        V::provide_value_type_descriptions(accumulator);
+    }
+}
@@ -0,0 +1,362 @@
+use super::StorageMapper;
+use crate::{
+    abi::{StorageMapperAbi, TypeAbi, TypeDescriptionContainer, TypeName},
+    api::{
+        CryptoApi, EndpointFinishApi, ErrorApi, ManagedTypeApi, StorageReadApi, StorageWriteApi,
+    },
+    io::EndpointResult,
+    storage::{storage_get, storage_set, StorageKey},
+    types::{ManagedByteArray, ManagedVec, H256},
+};
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+
+const COUNT_SUFFIX: &[u8] = b".count";
+const LEAF_SUFFIX: &[u8] = b".leaf";
+const BRANCH_SUFFIX: &[u8] = b".branch";
+
+const LEAF_INDEX_OUT_OF_RANGE_ERR_MSG: &[u8] = b"leaf index out of range";
+
+pub const MERKLE_HASH_LENGTH: usize = 32;
+
+/// Convenience alias for the node/leaf type of a Merkle tree.
+pub type MerkleHashBytes<M> = ManagedByteArray<M, MERKLE_HASH_LENGTH>;
+
+/// The hash function used to combine 2 nodes of a Merkle tree.
+/// Implemented by the `Keccak256MerkleHash` and `Sha256MerkleHash` markers.
+pub trait MerkleHash: 'static {
+    fn hash<A: CryptoApi>(api: &A, data: &[u8]) -> H256;
+}
+
+/// Merkle tree nodes are combined using `CryptoApi::keccak256`.
+pub struct Keccak256MerkleHash;
+
+impl MerkleHash for Keccak256MerkleHash {
+    #[inline]
+    fn hash<A: CryptoApi>(api: &A, data: &[u8]) -> H256 {
+        api.keccak256(data)
+    }
+}
+
+/// Merkle tree nodes are combined using `CryptoApi::sha256`.
+pub struct Sha256MerkleHash;
+
+impl MerkleHash for Sha256MerkleHash {
+    #[inline]
+    fn hash<A: CryptoApi>(api: &A, data: &[u8]) -> H256 {
+        api.sha256(data)
+    }
+}
+
+/// Prepended to a leaf before hashing it into a leaf node.
+pub const MERKLE_LEAF_PREFIX: u8 = 0x00;
+
+/// Prepended to the concatenation of 2 nodes before hashing them into a branch node.
+/// Differs from the leaf prefix, so that a branch node can never pass as a leaf.
+pub const MERKLE_BRANCH_PREFIX: u8 = 0x01;
+
+/// Hashes a leaf into the corresponding node on the lowest level of the tree.
+pub fn merkle_hash_leaf<A, H>(api: &A, leaf: &[u8; MERKLE_HASH_LENGTH]) -> [u8; MERKLE_HASH_LENGTH]
+where
+    A: CryptoApi,
+    H: MerkleHash,
+{
+    let mut prefixed = [0u8; 1 + MERKLE_HASH_LENGTH];
+    prefixed[0] = MERKLE_LEAF_PREFIX;
+    prefixed[1..].copy_from_slice(&leaf[..]);
+    let mut result = [0u8; MERKLE_HASH_LENGTH];
+    result.copy_from_slice(H::hash(api, &prefixed[..]).as_bytes());
+    result
+}
+
+/// Hashes the concatenation of 2 nodes.
+pub fn merkle_hash_pair<A, H>(
+    api: &A,
+    left: &[u8; MERKLE_HASH_LENGTH],
+    right: &[u8; MERKLE_HASH_LENGTH],
+) -> [u8; MERKLE_HASH_LENGTH]
+where
+    A: CryptoApi,
+    H: MerkleHash,
+{
+    let mut concatenated = [0u8; 1 + 2 * MERKLE_HASH_LENGTH];
+    concatenated[0] = MERKLE_BRANCH_PREFIX;
+    concatenated[1..1 + MERKLE_HASH_LENGTH].copy_from_slice(&left[..]);
+    concatenated[1 + MERKLE_HASH_LENGTH..].copy_from_slice(&right[..]);
+    let mut result = [0u8; MERKLE_HASH_LENGTH];
+    result.copy_from_slice(H::hash(api, &concatenated[..]).as_bytes());
+    result
+}
+
+/// The depth of the tree, i.e. the length of any inclusion proof,
+/// for a given number of leaves.
+/// It is the smallest `d` for which `2^d >= leaf_count`.
+pub fn merkle_tree_depth(leaf_count: u64) -> usize {
+    let mut depth = 0;
+    while depth < 64 && (1u64 << depth) < leaf_count {
+        depth += 1;
+    }
+    depth
+}
+
+/// Manages an append-only Merkle tree of 32-byte leaf hashes.
+///
+/// The tree is always the smallest complete binary tree that fits all the leaves,
+/// with the empty positions to the right filled with zero hashes
+/// (i.e. 32 zero bytes for a missing leaf node, the hash of 2 zero hashes for a missing subtree, and so on).
+/// Leaf nodes are the hashes of the leaves prefixed by `0x00`,
+/// branch nodes are the hashes of their 2 children prefixed by `0x01`.
+/// The root of a tree with a single leaf is its leaf node,
+/// the root of an empty tree is the zero hash.
+///
+/// Leaf indexes start from 0.
+/// Bit `i` of the leaf index indicates whether the node on level `i` of its path is a right child,
+/// so the sibling from position `i` of the proof is concatenated to its left.
+///
+/// Besides the leaves, only one node per level is kept in storage (the rightmost complete left subtree),
+/// so appending a leaf and computing the root both take a number of hashes proportional to the depth.
+pub struct MerkleTreeMapper<SA, H = Keccak256MerkleHash>
+where
+    SA: StorageReadApi + StorageWriteApi + ManagedTypeApi + ErrorApi + Clone + 'static,
+    H: MerkleHash,
+{
+    api: SA,
+    base_key: StorageKey<SA>,
+    _phantom: PhantomData<H>,
+}
+
+impl<SA, H> StorageMapper<SA> for MerkleTreeMapper<SA, H>
+where
+    SA: StorageReadApi + StorageWriteApi + ManagedTypeApi + ErrorApi + Clone + 'static,
+    H: MerkleHash,
+{
+    fn new(api: SA, base_key: StorageKey<SA>) -> Self {
+        MerkleTreeMapper {
+            api,
+            base_key,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<SA, H> MerkleTreeMapper<SA, H>
+where
+    SA: StorageReadApi + StorageWriteApi + ManagedTypeApi + ErrorApi + CryptoApi + Clone + 'static,
+    H: MerkleHash,
+{
+    fn count_key(&self) -> StorageKey<SA> {
+        let mut count_key = self.base_key.clone();
+        count_key.append_bytes(COUNT_SUFFIX);
+        count_key
+    }
+
+    fn leaf_key(&self, index: u64) -> StorageKey<SA> {
+        let mut leaf_key = self.base_key.clone();
+        leaf_key.append_bytes(LEAF_SUFFIX);
+        leaf_key.append_item(&index);
+        leaf_key
+    }
+
+    fn branch_key(&self, level: usize) -> StorageKey<SA> {
+        let mut branch_key = self.base_key.clone();
+        branch_key.append_bytes(BRANCH_SUFFIX);
+        branch_key.append_item(&level);
+        branch_key
+    }
+
+    fn get_branch(&self, level: usize) -> [u8; MERKLE_HASH_LENGTH] {
+        let branch: MerkleHashBytes<SA> = storage_get(self.api.clone(), &self.branch_key(level));
+        branch.to_byte_array()
+    }
+
+    fn set_branch(&self, level: usize, node: &[u8; MERKLE_HASH_LENGTH]) {
+        let branch = MerkleHashBytes::new_from_bytes(self.api.clone(), node);
+        storage_set(self.api.clone(), &self.branch_key(level), &branch);
+    }
+
+    fn hash_pair(
+        &self,
+        left: &[u8; MERKLE_HASH_LENGTH],
+        right: &[u8; MERKLE_HASH_LENGTH],
+    ) -> [u8; MERKLE_HASH_LENGTH] {
+        merkle_hash_pair::<SA, H>(&self.api, left, right)
+    }
+
+    fn hash_leaf(&self, leaf: &MerkleHashBytes<SA>) -> [u8; MERKLE_HASH_LENGTH] {
+        merkle_hash_leaf::<SA, H>(&self.api, &leaf.to_byte_array())
+    }
+
+    /// Number of leaves in the tree.
+    pub fn leaf_count(&self) -> u64 {
+        storage_get(self.api.clone(), &self.count_key())
+    }
+
+    /// True if no leaves were added yet.
+    pub fn is_empty(&self) -> bool {
+        self.leaf_count() == 0
+    }
+
+    /// The current depth of the tree, which is also the length of any valid proof.
+    pub fn depth(&self) -> usize {
+        merkle_tree_depth(self.leaf_count())
+    }
+
+    /// Retrieves a leaf by index. Signals an error if the index is out of range.
+    pub fn get_leaf(&self, index: u64) -> MerkleHashBytes<SA> {
+        if index >= self.leaf_count() {
+            self.api.signal_error(LEAF_INDEX_OUT_OF_RANGE_ERR_MSG);
+        }
+        storage_get(self.api.clone(), &self.leaf_key(index))
+    }
+
+    /// Appends a leaf to the tree.
+    /// Returns the index of the new leaf.
+    pub fn push_leaf(&self, leaf: &MerkleHashBytes<SA>) -> u64 {
+        let index = self.leaf_count();
+        storage_set(self.api.clone(), &self.leaf_key(index), leaf);
+        let new_count = index + 1;
+        storage_set(self.api.clone(), &self.count_key(), &new_count);
+
+        // the new leaf completes all subtrees corresponding to the trailing 1 bits of the new count,
+        // the resulting subtree root becomes the branch node on the first level with a 0 bit
+        let mut node = self.hash_leaf(leaf);
+        let mut level = 0;
+        let mut size = new_count;
+        while size & 1 == 0 {
+            node = self.hash_pair(&self.get_branch(level), &node);
+            size >>= 1;
+            level += 1;
+        }
+        self.set_branch(level, &node);
+
+        index
+    }
+
+    /// Computes the current root of the tree.
+    pub fn get_root(&self) -> MerkleHashBytes<SA> {
+        let leaf_count = self.leaf_count();
+        let depth = merkle_tree_depth(leaf_count);
+        let root = if leaf_count == 1u64 << depth {
+            // complete tree, the root was already saved on the last push
+            self.get_branch(depth)
+        } else {
+            let mut node = [0u8; MERKLE_HASH_LENGTH];
+            let mut zero_subtree = [0u8; MERKLE_HASH_LENGTH];
+            let mut size = leaf_count;
+            for level in 0..depth {
+                node = if size & 1 == 1 {
+                    self.hash_pair(&self.get_branch(level), &node)
+                } else {
+                    self.hash_pair(&node, &zero_subtree)
+                };
+                zero_subtree = self.hash_pair(&zero_subtree, &zero_subtree);
+                size >>= 1;
+            }
+            node
+        };
+        MerkleHashBytes::new_from_bytes(self.api.clone(), &root)
+    }
+
+    /// Computes the root obtained by combining a leaf with the siblings on its path.
+    pub fn compute_root_from_proof(
+        &self,
+        leaf: &MerkleHashBytes<SA>,
+        leaf_index: u64,
+        proof: &ManagedVec<SA, MerkleHashBytes<SA>>,
+    ) -> MerkleHashBytes<SA> {
+        let mut node = self.hash_leaf(leaf);
+        let mut path = leaf_index;
+        for sibling in proof.iter() {
+            let sibling_bytes = sibling.to_byte_array();
+            node = if path & 1 == 1 {
+                self.hash_pair(&sibling_bytes, &node)
+            } else {
+                self.hash_pair(&node, &sibling_bytes)
+            };
+            path >>= 1;
+        }
+        MerkleHashBytes::new_from_bytes(self.api.clone(), &node)
+    }
+
+    /// Checks that the leaf is part of the current tree, at the given index.
+    pub fn verify_proof(
+        &self,
+        leaf: &MerkleHashBytes<SA>,
+        leaf_index: u64,
+        proof: &ManagedVec<SA, MerkleHashBytes<SA>>,
+    ) -> bool {
+        let leaf_count = self.leaf_count();
+        if leaf_index >= leaf_count || proof.len() != merkle_tree_depth(leaf_count) {
+            return false;
+        }
+        self.compute_root_from_proof(leaf, leaf_index, proof) == self.get_root()
+    }
+
+    /// Checks a proof against a root that was obtained by other means,
+    /// e.g. one computed off-chain and saved by the owner.
+    /// Uses the same hash function and tree layout as the mapper.
+    ///
+    /// The depth of the tree the root belongs to also needs to be known,
+    /// proofs of any other length are rejected.
+    pub fn verify_proof_against_root(
+        &self,
+        root: &MerkleHashBytes<SA>,
+        tree_depth: usize,
+        leaf: &MerkleHashBytes<SA>,
+        leaf_index: u64,
+        proof: &ManagedVec<SA, MerkleHashBytes<SA>>,
+    ) -> bool {
+        if proof.len() != tree_depth || (tree_depth < 64 && leaf_index >> tree_depth != 0) {
+            return false;
+        }
+        &self.compute_root_from_proof(leaf, leaf_index, proof) == root
+    }
+}
+
+/// Behaves like a `MerkleHashBytes` when returned from an endpoint, the current root is returned.
+impl<SA, H> EndpointResult for MerkleTreeMapper<SA, H>
+where
+    SA: StorageReadApi + StorageWriteApi + ManagedTypeApi + ErrorApi + CryptoApi + Clone + 'static,
+    H: MerkleHash,
+{
+    type DecodeAs = MerkleHashBytes<SA>;
+
+    fn finish<FA>(&self, api: FA)
+    where
+        FA: ManagedTypeApi + EndpointFinishApi + Clone + 'static,
+    {
+        self.get_root().finish(api);
+    }
+}
+
+impl<SA, H> TypeAbi for MerkleTreeMapper<SA, H>
+where
+    SA: StorageReadApi + StorageWriteApi + ManagedTypeApi + ErrorApi + Clone + 'static,
+    H: MerkleHash,
+{
+    fn type_name() -> TypeName {
+        MerkleHashBytes::<SA>::type_name()
+    }
+
+    fn provide_type_descriptions<TDC: TypeDescriptionContainer>(accumulator: &mut TDC) {
+        MerkleHashBytes::<SA>::provide_type_descriptions(accumulator)
+    }
+}
+
+impl<SA, H> StorageMapperAbi for MerkleTreeMapper<SA, H>
+where
+    SA: StorageReadApi + StorageWriteApi + ManagedTypeApi + ErrorApi + Clone + 'static,
+    H: MerkleHash,
+{
+    fn mapper_name() -> &'static str {
+        "MerkleTreeMapper"
+    }
+
+    fn value_types() -> Vec<TypeName> {
+        alloc::vec![MerkleHashBytes::<SA>::type_name()]
+    }
+
+    fn provide_value_type_descriptions<TDC: TypeDescriptionContainer>(accumulator: &mut TDC) {
+        MerkleHashBytes::<SA>::provide_type_descriptions(accumulator);
+    }
+}
@@ -1,21 +1,32 @@
+mod fungible_token_mapper;
 mod linked_list_mapper;
 mod map_mapper;
 mod map_storage_mapper;
 mod mapper;
+mod merkle_tree_mapper;
+mod non_fungible_token_mapper;
 mod queue_mapper;
 mod set_mapper;
 mod single_value_mapper;
 mod token_attributes_mapper;
+mod token_mapper;
 mod user_mapper;
 mod vec_mapper;
 
+pub use fungible_token_mapper::FungibleTokenMapper;
 pub use linked_list_mapper::{LinkedListMapper, LinkedListNode};
 pub use map_mapper::MapMapper;
 pub use map_storage_mapper::MapStorageMapper;
 pub use mapper::{StorageClearable, StorageMapper};
+pub use merkle_tree_mapper::{
+    merkle_hash_leaf, merkle_hash_pair, merkle_tree_depth, Keccak256MerkleHash, MerkleHash,
+    MerkleHashBytes, MerkleTreeMapper, Sha256MerkleHash, MERKLE_HASH_LENGTH,
+};
+pub use non_fungible_token_mapper::NonFungibleTokenMapper;
 pub use queue_mapper::QueueMapper;
 pub use set_mapper::SetMapper;
 pub use single_value_mapper::SingleValueMapper;
 pub use token_attributes_mapper::TokenAttributesMapper;
+pub use token_mapper::DEFAULT_ISSUE_CALLBACK_NAME;
 pub use user_mapper::UserMapper;
 pub use vec_mapper::VecMapper;
@@ -0,0 +1,352 @@
+use super::{
+    token_mapper::{
+        default_issue_callback_closure, require_all_same_token, require_same_token,
+        TOKEN_ID_ALREADY_SET_ERR_MSG, TOKEN_ID_NOT_SET_ERR_MSG,
+    },
+    StorageMapper,
+};
+use crate::{
+    abi::{StorageMapperAbi, TypeAbi, TypeDescriptionContainer, TypeName},
+    api::{EndpointFinishApi, ErrorApi, ManagedTypeApi, StorageReadApi, StorageWriteApi, VMApi},
+    contract_base::{BlockchainWrapper, CallValueWrapper, SendWrapper},
+    esdt::{
+        ESDTSystemSmartContractProxy, MetaTokenProperties, NonFungibleTokenProperties,
+        SemiFungibleTokenProperties,
+    },
+    io::EndpointResult,
+    storage::{storage_get, storage_get_len, storage_set, StorageKey},
+    types::{
+        AsyncCall, BigUint, ContractCall, EsdtLocalRole, EsdtTokenData, EsdtTokenPayment,
+        EsdtTokenType, ManagedAddress, ManagedBuffer, ManagedVec, TokenIdentifier,
+    },
+};
+use alloc::vec::Vec;
+use elrond_codec::{TopDecode, TopEncode};
+
+/// Manages a non-fungible, semi-fungible or meta ESDT issued by the contract.
+///
+/// Only the token identifier is kept in storage.
+/// Issuing is done via an async call to the ESDT system SC,
+/// the token identifier gets saved automatically by the default issue callback
+/// (see `DEFAULT_ISSUE_CALLBACK_NAME`).
+pub struct NonFungibleTokenMapper<SA>
+where
+    SA: StorageReadApi + StorageWriteApi + ManagedTypeApi + ErrorApi + Clone + 'static,
+{
+    api: SA,
+    key: StorageKey<SA>,
+}
+
+impl<SA> StorageMapper<SA> for NonFungibleTokenMapper<SA>
+where
+    SA: StorageReadApi + StorageWriteApi + ManagedTypeApi + ErrorApi + Clone + 'static,
+{
+    fn new(api: SA, base_key: StorageKey<SA>) -> Self {
+        NonFungibleTokenMapper { api, key: base_key }
+    }
+}
+
+impl<SA> NonFungibleTokenMapper<SA>
+where
+    SA: VMApi + 'static,
+{
+    /// Returns whether the token was issued (or set) yet.
+    pub fn is_empty(&self) -> bool {
+        storage_get_len(self.api.clone(), &self.key) == 0
+    }
+
+    /// Retrieves the token identifier. Signals an error if the token was not issued yet.
+    pub fn get_token_id(&self) -> TokenIdentifier<SA> {
+        if self.is_empty() {
+            self.api.signal_error(TOKEN_ID_NOT_SET_ERR_MSG);
+        }
+        storage_get(self.api.clone(), &self.key)
+    }
+
+    /// Saves a token identifier obtained by other means than issuing it via the mapper.
+    /// Signals an error if a token identifier was already set.
+    pub fn set_token_id(&self, token_id: &TokenIdentifier<SA>) {
+        self.require_not_issued();
+        storage_set(self.api.clone(), &self.key, token_id);
+    }
+
+    /// Produces an async call to the ESDT system SC, which issues a new non-fungible token.
+    /// The token identifier is saved automatically by the default issue callback.
+    pub fn issue_non_fungible(
+        &self,
+        issue_cost: BigUint<SA>,
+        token_display_name: &ManagedBuffer<SA>,
+        token_ticker: &ManagedBuffer<SA>,
+        properties: NonFungibleTokenProperties,
+    ) -> AsyncCall<SA> {
+        self.require_not_issued();
+        let contract_call = self.system_sc_proxy().issue_non_fungible(
+            issue_cost,
+            token_display_name,
+            token_ticker,
+            properties,
+        );
+        self.async_call_with_default_callback(contract_call)
+    }
+
+    /// Produces an async call to the ESDT system SC, which issues a new semi-fungible token.
+    /// The token identifier is saved automatically by the default issue callback.
+    pub fn issue_semi_fungible(
+        &self,
+        issue_cost: BigUint<SA>,
+        token_display_name: &ManagedBuffer<SA>,
+        token_ticker: &ManagedBuffer<SA>,
+        properties: SemiFungibleTokenProperties,
+    ) -> AsyncCall<SA> {
+        self.require_not_issued();
+        let contract_call = self.system_sc_proxy().issue_semi_fungible(
+            issue_cost,
+            token_display_name,
+            token_ticker,
+            properties,
+        );
+        self.async_call_with_default_callback(contract_call)
+    }
+
+    /// Produces an async call to the ESDT system SC, which registers a new meta ESDT.
+    /// The token identifier is saved automatically by the default issue callback.
+    pub fn register_meta_esdt(
+        &self,
+        issue_cost: BigUint<SA>,
+        token_display_name: &ManagedBuffer<SA>,
+        token_ticker: &ManagedBuffer<SA>,
+        properties: MetaTokenProperties,
+    ) -> AsyncCall<SA> {
+        self.require_not_issued();
+        let contract_call = self.system_sc_proxy().register_meta_esdt(
+            issue_cost,
+            token_display_name,
+            token_ticker,
+            properties,
+        );
+        self.async_call_with_default_callback(contract_call)
+    }
+
+    /// Produces an async call to the ESDT system SC,
+    /// which issues a new token of the given type and gives the contract all the local roles.
+    /// The token identifier is saved automatically by the default issue callback.
+    pub fn issue_and_set_all_roles(
+        &self,
+        token_type: EsdtTokenType,
+        issue_cost: BigUint<SA>,
+        token_display_name: &ManagedBuffer<SA>,
+        token_ticker: &ManagedBuffer<SA>,
+        num_decimals: usize,
+    ) -> AsyncCall<SA> {
+        self.require_not_issued();
+        let contract_call = self.system_sc_proxy().issue_and_set_all_roles(
+            issue_cost,
+            token_display_name,
+            token_ticker,
+            token_type,
+            num_decimals,
+        );
+        self.async_call_with_default_callback(contract_call)
+    }
+
+    /// Produces an async call to the ESDT system SC,
+    /// which gives the contract the given roles for the managed token.
+    pub fn set_local_roles(&self, roles: &[EsdtLocalRole]) -> AsyncCall<SA> {
+        let sc_address = BlockchainWrapper::new(self.api.clone()).get_sc_address();
+        self.set_local_roles_for_address(&sc_address, roles)
+    }
+
+    /// Produces an async call to the ESDT system SC,
+    /// which gives an address the given roles for the managed token.
+    pub fn set_local_roles_for_address(
+        &self,
+        address: &ManagedAddress<SA>,
+        roles: &[EsdtLocalRole],
+    ) -> AsyncCall<SA> {
+        let token_id = self.get_token_id();
+        self.system_sc_proxy()
+            .set_special_roles(address, &token_id, roles.iter().cloned())
+            .async_call()
+    }
+
+    /// Creates a new token nonce in the contract account, with the given attributes.
+    /// The contract needs to have the NFT create role for the token.
+    pub fn nft_create<T: TopEncode>(
+        &self,
+        amount: &BigUint<SA>,
+        attributes: &T,
+    ) -> EsdtTokenPayment<SA> {
+        let empty_name = ManagedBuffer::new(self.api.clone());
+        self.nft_create_named(amount, &empty_name, attributes)
+    }
+
+    /// Same as `nft_create`, but also sets the display name of the new token nonce.
+    pub fn nft_create_named<T: TopEncode>(
+        &self,
+        amount: &BigUint<SA>,
+        name: &ManagedBuffer<SA>,
+        attributes: &T,
+    ) -> EsdtTokenPayment<SA> {
+        let token_id = self.get_token_id();
+        let token_nonce = SendWrapper::new(self.api.clone()).esdt_nft_create(
+            &token_id,
+            amount,
+            name,
+            &BigUint::zero(self.api.clone()),
+            &ManagedBuffer::new(self.api.clone()),
+            attributes,
+            &ManagedVec::new(self.api.clone()),
+        );
+        EsdtTokenPayment::from(token_id, token_nonce, amount.clone())
+    }
+
+    /// Creates a new token nonce and sends it to the given address.
+    /// The contract needs to have the NFT create role for the token.
+    pub fn nft_create_and_send<T: TopEncode>(
+        &self,
+        to: &ManagedAddress<SA>,
+        amount: &BigUint<SA>,
+        attributes: &T,
+    ) -> EsdtTokenPayment<SA> {
+        let payment = self.nft_create(amount, attributes);
+        self.send_payment(to, &payment);
+        payment
+    }
+
+    /// Mints more tokens of an existing nonce in the contract account.
+    /// The contract needs to have the NFT add quantity role for the token.
+    pub fn nft_add_quantity(&self, token_nonce: u64, amount: &BigUint<SA>) -> EsdtTokenPayment<SA> {
+        let token_id = self.get_token_id();
+        SendWrapper::new(self.api.clone()).esdt_local_mint(&token_id, token_nonce, amount);
+        EsdtTokenPayment::from(token_id, token_nonce, amount.clone())
+    }
+
+    /// Mints more tokens of an existing nonce and sends them to the given address.
+    /// The contract needs to have the NFT add quantity role for the token.
+    pub fn nft_add_quantity_and_send(
+        &self,
+        to: &ManagedAddress<SA>,
+        token_nonce: u64,
+        amount: &BigUint<SA>,
+    ) -> EsdtTokenPayment<SA> {
+        let payment = self.nft_add_quantity(token_nonce, amount);
+        self.send_payment(to, &payment);
+        payment
+    }
+
+    /// Burns tokens of the given nonce from the contract account.
+    /// The contract needs to have the NFT burn role for the token.
+    pub fn nft_burn(&self, token_nonce: u64, amount: &BigUint<SA>) {
+        let token_id = self.get_token_id();
+        SendWrapper::new(self.api.clone()).esdt_local_burn(&token_id, token_nonce, amount);
+    }
+
+    /// Retrieves all the token data of a nonce held by the contract.
+    pub fn get_all_token_data(&self, token_nonce: u64) -> EsdtTokenData<SA> {
+        let token_id = self.get_token_id();
+        let blockchain = BlockchainWrapper::new(self.api.clone());
+        blockchain.get_esdt_token_data(&blockchain.get_sc_address(), &token_id, token_nonce)
+    }
+
+    /// Retrieves and decodes the attributes of a nonce held by the contract.
+    /// Signals an error if the attributes cannot be decoded to the given type.
+    pub fn get_token_attributes<T: TopDecode>(&self, token_nonce: u64) -> T {
+        match self.get_all_token_data(token_nonce).decode_attributes() {
+            Ok(attributes) => attributes,
+            Err(err) => self.api.signal_error(err.message_bytes()),
+        }
+    }
+
+    /// The amount of tokens of the given nonce held by the contract.
+    pub fn get_balance(&self, token_nonce: u64) -> BigUint<SA> {
+        let token_id = self.get_token_id();
+        BlockchainWrapper::new(self.api.clone()).get_sc_balance(&token_id, token_nonce)
+    }
+
+    /// Signals an error if the current call payment is not made with the managed token.
+    pub fn require_same_token(&self) {
+        let token_id = self.get_token_id();
+        let payment_token = CallValueWrapper::new(self.api.clone()).token();
+        require_same_token(&self.api, &token_id, &payment_token);
+    }
+
+    /// Signals an error if any of the current call payments is not made with the managed token.
+    pub fn require_all_same_token(&self) {
+        let token_id = self.get_token_id();
+        let payments = CallValueWrapper::new(self.api.clone()).all_esdt_transfers();
+        require_all_same_token(&self.api, &token_id, &payments);
+    }
+
+    fn require_not_issued(&self) {
+        if !self.is_empty() {
+            self.api.signal_error(TOKEN_ID_ALREADY_SET_ERR_MSG);
+        }
+    }
+
+    fn system_sc_proxy(&self) -> ESDTSystemSmartContractProxy<SA> {
+        ESDTSystemSmartContractProxy::new_proxy_obj(self.api.clone())
+    }
+
+    fn async_call_with_default_callback(
+        &self,
+        contract_call: ContractCall<SA, ()>,
+    ) -> AsyncCall<SA> {
+        contract_call
+            .async_call()
+            .with_callback(default_issue_callback_closure(self.api.clone(), &self.key))
+    }
+
+    fn send_payment(&self, to: &ManagedAddress<SA>, payment: &EsdtTokenPayment<SA>) {
+        SendWrapper::new(self.api.clone()).direct(
+            to,
+            &payment.token_identifier,
+            payment.token_nonce,
+            &payment.amount,
+            &[],
+        );
+    }
+}
+
+impl<SA> EndpointResult for NonFungibleTokenMapper<SA>
+where
+    SA: VMApi + 'static,
+{
+    type DecodeAs = TokenIdentifier<SA>;
+
+    fn finish<FA>(&self, api: FA)
+    where
+        FA: ManagedTypeApi + EndpointFinishApi + Clone + 'static,
+    {
+        storage_get::<SA, TokenIdentifier<SA>>(self.api.clone(), &self.key).finish(api);
+    }
+}
+
+impl<SA> TypeAbi for NonFungibleTokenMapper<SA>
+where
+    SA: VMApi + 'static,
+{
+    fn type_name() -> TypeName {
+        TokenIdentifier::<SA>::type_name()
+    }
+
+    fn provide_type_descriptions<TDC: TypeDescriptionContainer>(accumulator: &mut TDC) {
+        TokenIdentifier::<SA>::provide_type_descriptions(accumulator)
+    }
+}
+
+impl<SA> StorageMapperAbi for NonFungibleTokenMapper<SA>
+where
+    SA: VMApi + 'static,
+{
+    fn mapper_name() -> &'static str {
+        "NonFungibleTokenMapper"
+    }
+
+    fn value_types() -> Vec<TypeName> {
+        alloc::vec![TokenIdentifier::<SA>::type_name()]
+    }
+
+    fn provide_value_type_descriptions<TDC: TypeDescriptionContainer>(accumulator: &mut TDC) {
+        TokenIdentifier::<SA>::provide_type_descriptions(accumulator);
+    }
+}
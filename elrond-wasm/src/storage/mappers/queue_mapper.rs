@@ -1,6 +1,6 @@
 use super::{StorageClearable, StorageMapper};
 use crate::{
-    abi::{TypeAbi, TypeDescriptionContainer, TypeName},
+    abi::{StorageMapperAbi, TypeAbi, TypeDescriptionContainer, TypeName},
     api::{EndpointFinishApi, ErrorApi, ManagedTypeApi, StorageReadApi, StorageWriteApi},
     io::EndpointResult,
     storage::{storage_get, storage_set, StorageKey},
@@ -486,3 +486,21 @@ where
         true
     }
 }
+
+impl<SA, T> StorageMapperAbi for QueueMapper<SA, T>
+where
+    SA: StorageReadApi + StorageWriteApi + ManagedTypeApi + ErrorApi + Clone + 'static,
+    T: TopEncode + TopDecode + TypeAbi,
+{
+    fn mapper_name() -> &'static str {
+        "QueueMapper"
+    }
+
+    fn value_types() -> Vec<TypeName> {
+        alloc::vec![T::type_name()]
+    }
+
+    fn provide_value_type_descriptions<TDC: TypeDescriptionContainer>(accumulator: &mut TDC) {
+        T::provide_type_descriptions(accumulator);
+    }
+}
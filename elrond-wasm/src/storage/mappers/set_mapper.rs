@@ -1,7 +1,7 @@
 pub use super::queue_mapper::Iter;
 use super::{QueueMapper, StorageClearable, StorageMapper};
 use crate::{
-    abi::{TypeAbi, TypeDescriptionContainer, TypeName},
+    abi::{StorageMapperAbi, TypeAbi, TypeDescriptionContainer, TypeName},
     api::{EndpointFinishApi, ErrorApi, ManagedTypeApi, StorageReadApi, StorageWriteApi},
     io::EndpointResult,
     storage::{storage_get, storage_set, StorageKey},
@@ -173,3 +173,21 @@ where
         true
     }
 }
+
+impl<SA, T> StorageMapperAbi for SetMapper<SA, T>
+where
+    SA: StorageReadApi + StorageWriteApi + ManagedTypeApi + ErrorApi + Clone + 'static,
+    T: TopEncode + TopDecode + NestedEncode + NestedDecode + TypeAbi,
+{
+    fn mapper_name() -> &'static str {
+        "SetMapper"
+    }
+
+    fn value_types() -> Vec<TypeName> {
+        alloc::vec![T::type_name()]
+    }
+
+    fn provide_value_type_descriptions<TDC: TypeDescriptionContainer>(accumulator: &mut TDC) {
+        T::provide_type_descriptions(accumulator);
+    }
+}
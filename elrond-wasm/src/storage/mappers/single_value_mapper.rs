@@ -1,10 +1,11 @@
 use super::StorageMapper;
 use crate::{
-    abi::{TypeAbi, TypeDescriptionContainer, TypeName},
+    abi::{StorageMapperAbi, TypeAbi, TypeDescriptionContainer, TypeName},
     api::{EndpointFinishApi, ErrorApi, ManagedTypeApi, StorageReadApi, StorageWriteApi},
     io::EndpointResult,
     storage::{storage_clear, storage_get, storage_get_len, storage_set, StorageKey},
 };
+use alloc::vec::Vec;
 use core::marker::PhantomData;
 use elrond_codec::{TopDecode, TopEncode};
 
@@ -109,3 +110,21 @@ where
         T::provide_type_descriptions(accumulator)
     }
 }
+
+impl<SA, T> StorageMapperAbi for SingleValueMapper<SA, T>
+where
+    SA: StorageReadApi + StorageWriteApi + ManagedTypeApi + ErrorApi + Clone + 'static,
+    T: TopEncode + TopDecode + TypeAbi,
+{
+    fn mapper_name() -> &'static str {
+        "SingleValueMapper"
+    }
+
+    fn value_types() -> Vec<TypeName> {
+        alloc::vec![T::type_name()]
+    }
+
+    fn provide_value_type_descriptions<TDC: TypeDescriptionContainer>(accumulator: &mut TDC) {
+        T::provide_type_descriptions(accumulator);
+    }
+}
@@ -2,10 +2,12 @@ use elrond_codec::{NestedDecode, NestedEncode, TopDecode, TopEncode};
 
 use super::StorageMapper;
 use crate::{
+    abi::{StorageMapperAbi, TypeDescriptionContainer, TypeName},
     api::{ErrorApi, ManagedTypeApi, StorageReadApi, StorageWriteApi},
     storage::{storage_clear, storage_get, storage_get_len, storage_set, StorageKey},
     types::TokenIdentifier,
 };
+use alloc::vec::Vec;
 
 const MAPPING_SUFFIX: &[u8] = b".mapping";
 const COUNTER_SUFFIX: &[u8] = b".counter";
@@ -353,3 +355,18 @@ where
         );
     }
 }
+
+impl<SA> StorageMapperAbi for TokenAttributesMapper<SA>
+where
+    SA: StorageReadApi + StorageWriteApi + ManagedTypeApi + ErrorApi + Clone + 'static,
+{
+    fn mapper_name() -> &'static str {
+        "TokenAttributesMapper"
+    }
+
+    fn value_types() -> Vec<TypeName> {
+        Vec::new()
+    }
+
+    fn provide_value_type_descriptions<TDC: TypeDescriptionContainer>(_accumulator: &mut TDC) {}
+}
@@ -0,0 +1,60 @@
+use crate::{
+    api::{BlockchainApi, ErrorApi, ManagedTypeApi},
+    storage::StorageKey,
+    types::{CallbackClosure, EsdtTokenPayment, ManagedBuffer, ManagedVec, TokenIdentifier},
+};
+
+/// Name of the callback that the token mappers register for issue calls.
+/// The callback itself is provided by the
+/// `elrond_wasm_module_esdt::token_mapper_callbacks::TokenMapperCallbacksModule` module,
+/// which needs to be added to every contract that issues tokens via a token mapper.
+pub const DEFAULT_ISSUE_CALLBACK_NAME: &[u8] = b"default_issue_cb";
+
+pub(super) const TOKEN_ID_ALREADY_SET_ERR_MSG: &[u8] = b"Token already issued";
+pub(super) const TOKEN_ID_NOT_SET_ERR_MSG: &[u8] = b"Token must be issued first";
+pub(super) const INVALID_PAYMENT_TOKEN_ERR_MSG: &[u8] = b"Invalid payment token";
+
+/// Builds the closure of the default issue callback.
+/// It saves the original caller, to return the issue cost in case of failure,
+/// and the storage key of the mapper, where the new token identifier will be stored.
+pub(super) fn default_issue_callback_closure<SA>(
+    api: SA,
+    storage_key: &StorageKey<SA>,
+) -> CallbackClosure<SA>
+where
+    SA: BlockchainApi + ManagedTypeApi + ErrorApi + 'static,
+{
+    let mut callback_closure = CallbackClosure::new(ManagedBuffer::new_from_bytes(
+        api.clone(),
+        DEFAULT_ISSUE_CALLBACK_NAME,
+    ));
+    callback_closure.push_endpoint_arg(api.get_caller());
+    callback_closure.push_endpoint_arg(&storage_key.buffer);
+    callback_closure
+}
+
+/// Signals an error if the payment was not made with the expected token.
+pub(super) fn require_same_token<SA>(
+    api: &SA,
+    expected_token_id: &TokenIdentifier<SA>,
+    actual_token_id: &TokenIdentifier<SA>,
+) where
+    SA: ManagedTypeApi + ErrorApi,
+{
+    if expected_token_id != actual_token_id {
+        api.signal_error(INVALID_PAYMENT_TOKEN_ERR_MSG);
+    }
+}
+
+/// Signals an error if any of the payments was not made with the expected token.
+pub(super) fn require_all_same_token<SA>(
+    api: &SA,
+    expected_token_id: &TokenIdentifier<SA>,
+    payments: &ManagedVec<SA, EsdtTokenPayment<SA>>,
+) where
+    SA: ManagedTypeApi + ErrorApi,
+{
+    for payment in payments.iter() {
+        require_same_token(api, expected_token_id, &payment.token_identifier);
+    }
+}
@@ -1,11 +1,12 @@
 use super::StorageMapper;
 use crate::{
-    abi::{TypeAbi, TypeName},
+    abi::{StorageMapperAbi, TypeAbi, TypeDescriptionContainer, TypeName},
     api::{EndpointFinishApi, ErrorApi, ManagedTypeApi, StorageReadApi, StorageWriteApi},
     io::EndpointResult,
     storage::{storage_get, storage_get_len, storage_set, StorageKey},
     types::{ManagedAddress, ManagedVec, MultiResultVec},
 };
+use alloc::vec::Vec;
 
 const ADDRESS_TO_ID_SUFFIX: &[u8] = b"_address_to_id";
 const ID_TO_ADDRESS_SUFFIX: &[u8] = b"_id_to_address";
@@ -199,3 +200,20 @@ where
         true
     }
 }
+
+impl<SA> StorageMapperAbi for UserMapper<SA>
+where
+    SA: StorageReadApi + StorageWriteApi + ManagedTypeApi + ErrorApi + Clone + 'static,
+{
+    fn mapper_name() -> &'static str {
+        "UserMapper"
+    }
+
+    fn value_types() -> Vec<TypeName> {
+        alloc::vec![ManagedAddress::<SA>::type_name()]
+    }
+
+    fn provide_value_type_descriptions<TDC: TypeDescriptionContainer>(accumulator: &mut TDC) {
+        ManagedAddress::<SA>::provide_type_descriptions(accumulator);
+    }
+}
@@ -0,0 +1,134 @@
+//! Support for versioned storage.
+//!
+//! Contracts declare migrations with `#[migration(from = X, to = Y)]`,
+//! together with an `#[upgrade]` method.
+//! All pending migrations are run automatically, in order, before the `#[upgrade]` method.
+//! Freshly deployed contracts start directly at the latest storage version, without running any migration.
+//!
+//! A migration that does not fit in a single transaction can return `MigrationStatus::InProgress`.
+//! The storage version then stays unchanged and the remaining migrations are postponed,
+//! until the contract calls the generated `run_storage_migrations` method again
+//! (typically from an owner-only endpoint).
+//! The `#[upgrade]` method is postponed as well: its arguments are checked and saved on upgrade,
+//! it gets called with them by the `run_storage_migrations` call that completes the migrations.
+
+use crate as elrond_wasm; // needed by the TypeAbi generated code
+use crate::{
+    api::{
+        BlockchainApi, EndpointArgumentApi, ErrorApi, ManagedTypeApi, StorageReadApi,
+        StorageWriteApi,
+    },
+    derive::TypeAbi,
+    storage::{storage_clear, storage_get, storage_get_len, storage_set, StorageKey},
+    types::{ManagedBuffer, ManagedType, ManagedVec},
+};
+use elrond_codec::elrond_codec_derive::{NestedDecode, NestedEncode, TopDecode, TopEncode};
+
+/// Storage key of the current storage version.
+pub const STORAGE_VERSION_KEY: &[u8] = b"~storageVersion";
+
+/// Storage key of the arguments of an upgrade whose `#[upgrade]` method waits for the migrations to complete.
+pub const PENDING_UPGRADE_KEY: &[u8] = b"~pendingUpgrade";
+
+/// The storage version of contracts deployed before they declared any migration.
+pub const INITIAL_STORAGE_VERSION: u32 = 1;
+
+/// Result of a migration, or of a migration batch.
+#[derive(
+    TopDecode, TopEncode, NestedDecode, NestedEncode, TypeAbi, Clone, Copy, PartialEq, Debug,
+)]
+pub enum MigrationStatus {
+    Completed,
+    InProgress,
+}
+
+impl MigrationStatus {
+    pub fn is_completed(&self) -> bool {
+        *self == MigrationStatus::Completed
+    }
+}
+
+/// Retrieves the storage version. Contracts that never saved a storage version are at `INITIAL_STORAGE_VERSION`.
+pub fn get_storage_version<A>(api: A) -> u32
+where
+    A: StorageReadApi + ManagedTypeApi + ErrorApi + Clone + 'static,
+{
+    let key = StorageKey::new(api.clone(), STORAGE_VERSION_KEY);
+    if storage_get_len(api.clone(), &key) == 0 {
+        INITIAL_STORAGE_VERSION
+    } else {
+        storage_get(api, &key)
+    }
+}
+
+/// Called by the generated code on deploy, and after each completed migration.
+pub fn set_storage_version<A>(api: A, version: u32)
+where
+    A: StorageWriteApi + ManagedTypeApi + ErrorApi + Clone + 'static,
+{
+    let key = StorageKey::new(api.clone(), STORAGE_VERSION_KEY);
+    storage_set(api, &key, &version);
+}
+
+/// Retrieves all arguments of the current call, undecoded.
+pub fn get_raw_call_arguments<A>(api: A) -> ManagedVec<A, ManagedBuffer<A>>
+where
+    A: EndpointArgumentApi + ManagedTypeApi + ErrorApi + Clone + 'static,
+{
+    let mut arguments = ManagedVec::new(api.clone());
+    for arg_index in 0..api.get_num_arguments() {
+        let handle = api.get_argument_managed_buffer_raw(arg_index);
+        arguments.push(ManagedBuffer::from_raw_handle(api.clone(), handle));
+    }
+    arguments
+}
+
+/// Called by the generated code when an upgrade leaves migrations pending,
+/// the `#[upgrade]` method gets called with these arguments once they complete.
+pub fn set_pending_upgrade<A>(api: A, arguments: &ManagedVec<A, ManagedBuffer<A>>)
+where
+    A: StorageWriteApi + ManagedTypeApi + ErrorApi + Clone + 'static,
+{
+    let key = StorageKey::new(api.clone(), PENDING_UPGRADE_KEY);
+    storage_set(api, &key, &Some(arguments));
+}
+
+/// Called by the generated code at the start of each upgrade,
+/// only the `#[upgrade]` method of the latest upgrade is ever called.
+pub fn clear_pending_upgrade<A>(api: A)
+where
+    A: StorageWriteApi + ManagedTypeApi + ErrorApi + Clone + 'static,
+{
+    let key = StorageKey::new(api.clone(), PENDING_UPGRADE_KEY);
+    storage_clear(api, &key);
+}
+
+/// Retrieves and clears the arguments of the pending `#[upgrade]` method call, if any.
+pub fn take_pending_upgrade<A>(api: A) -> Option<ManagedVec<A, ManagedBuffer<A>>>
+where
+    A: StorageReadApi + StorageWriteApi + ManagedTypeApi + ErrorApi + Clone + 'static,
+{
+    let key = StorageKey::new(api.clone(), PENDING_UPGRADE_KEY);
+    let pending_upgrade = storage_get(api.clone(), &key);
+    storage_clear(api, &key);
+    pending_upgrade
+}
+
+/// Helps splitting large migrations into batches.
+///
+/// Calls `step` repeatedly, until it returns `MigrationStatus::Completed`,
+/// or until the gas left drops below `min_gas_left`.
+/// Each step should process a small amount of data
+/// and save its progress in storage, so that the next batch can pick up from there.
+pub fn run_while_gas_left<A, F>(api: &A, min_gas_left: u64, mut step: F) -> MigrationStatus
+where
+    A: BlockchainApi,
+    F: FnMut() -> MigrationStatus,
+{
+    while api.get_gas_left() >= min_gas_left {
+        if step().is_completed() {
+            return MigrationStatus::Completed;
+        }
+    }
+    MigrationStatus::InProgress
+}
@@ -1,5 +1,7 @@
 pub mod mappers;
+pub mod migration;
 pub mod protected_keys;
+pub mod reentrancy_guard;
 mod storage_get;
 mod storage_key;
 mod storage_set;
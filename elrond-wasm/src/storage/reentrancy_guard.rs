@@ -0,0 +1,74 @@
+//! Support for `#[non_reentrant]` endpoints.
+//!
+//! The generated endpoint code acquires a lock in storage before calling the endpoint method
+//! and releases it right after, before the results are returned.
+//! Any call back into the contract that reaches an endpoint guarded by the same lock in the meantime,
+//! e.g. via `execute_on_dest_context` or `transfer_execute`, fails.
+//!
+//! Endpoints annotated with `#[non_reentrant]` all share the same lock.
+//! Endpoints annotated with `#[non_reentrant("group")]` share a lock per group.
+//!
+//! If the endpoint fails, the lock is released together with all other storage changes
+//! of the failed transaction.
+//!
+//! Async calls and contract upgrades end the execution before the endpoint method returns,
+//! so they release the lock held by the current call themselves.
+//! The lock is therefore not held while waiting for the async call result.
+
+use crate::{
+    api::{ErrorApi, ManagedTypeApi, ReentrancyLockApi, StorageReadApi, StorageWriteApi},
+    err_msg,
+    storage::{storage_clear, storage_get_len, storage_set, StorageKey},
+};
+
+/// Base storage key of the reentrancy locks. The lock group name gets appended to it.
+pub const REENTRANCY_LOCK_BASE_KEY: &[u8] = b"~reentrancyLock";
+
+fn reentrancy_lock_key<A>(api: A, group: &[u8]) -> StorageKey<A>
+where
+    A: ManagedTypeApi + ErrorApi + 'static,
+{
+    let mut key = StorageKey::new(api, REENTRANCY_LOCK_BASE_KEY);
+    key.append_bytes(group);
+    key
+}
+
+/// Called by the generated endpoint code, before calling a `#[non_reentrant]` endpoint method.
+/// Crashes if the lock is already taken.
+pub fn acquire_reentrancy_lock<A>(api: A, group: &'static [u8])
+where
+    A: StorageReadApi
+        + StorageWriteApi
+        + ManagedTypeApi
+        + ErrorApi
+        + ReentrancyLockApi
+        + Clone
+        + 'static,
+{
+    let key = reentrancy_lock_key(api.clone(), group);
+    if storage_get_len(api.clone(), &key) > 0 {
+        api.signal_error(err_msg::REENTRANT_CALL);
+    }
+    storage_set(api.clone(), &key, &true);
+    api.set_held_reentrancy_lock(Some(group));
+}
+
+/// Called by the generated endpoint code, after a `#[non_reentrant]` endpoint method returns.
+pub fn release_reentrancy_lock<A>(api: A, group: &'static [u8])
+where
+    A: StorageWriteApi + ManagedTypeApi + ErrorApi + ReentrancyLockApi + Clone + 'static,
+{
+    let key = reentrancy_lock_key(api.clone(), group);
+    storage_clear(api.clone(), &key);
+    api.set_held_reentrancy_lock(None);
+}
+
+/// Called by the send API right before an async call or a contract upgrade ends the execution.
+pub fn release_held_reentrancy_lock<A>(api: A)
+where
+    A: StorageWriteApi + ManagedTypeApi + ErrorApi + ReentrancyLockApi + Clone + 'static,
+{
+    if let Some(group) = api.held_reentrancy_lock() {
+        release_reentrancy_lock(api, group);
+    }
+}
@@ -1,6 +1,7 @@
 use crate::{
     api::{ErrorApi, ManagedTypeApi, StorageReadApi},
     err_msg,
+    io::append_decode_error_message,
     types::{BigInt, BigUint, ManagedBuffer, ManagedBufferNestedDecodeInput, ManagedType},
 };
 use alloc::boxed::Box;
@@ -118,6 +119,6 @@ where
 {
     let mut message_buffer =
         ManagedBuffer::new_from_bytes(api.clone(), err_msg::STORAGE_DECODE_ERROR);
-    message_buffer.append_bytes(de_err.message_bytes());
+    append_decode_error_message(&mut message_buffer, &de_err);
     api.signal_error_from_buffer(message_buffer.get_raw_handle())
 }
@@ -2,7 +2,10 @@ use crate::{
     abi::{OutputAbi, TypeAbi, TypeDescriptionContainer},
     api::{ManagedTypeApi, SendApi, StorageWriteApi},
     io::EndpointResult,
-    types::{BigUint, CallbackClosure, ManagedAddress, ManagedArgBuffer, ManagedBuffer},
+    types::{
+        BigUint, CallbackClosure, EsdtTokenPayment, ManagedAddress, ManagedArgBuffer,
+        ManagedBuffer, ManagedVec,
+    },
 };
 use alloc::{string::String, vec::Vec};
 
@@ -16,6 +19,7 @@ where
     pub(crate) egld_payment: BigUint<SA>,
     pub(crate) endpoint_name: ManagedBuffer<SA>,
     pub(crate) arg_buffer: ManagedArgBuffer<SA>,
+    pub(crate) original_payments: ManagedVec<SA, EsdtTokenPayment<SA>>,
     pub(crate) callback_call: Option<CallbackClosure<SA>>,
 }
 
@@ -23,7 +27,8 @@ impl<SA> AsyncCall<SA>
 where
     SA: SendApi + 'static,
 {
-    pub fn with_callback(self, callback_call: CallbackClosure<SA>) -> Self {
+    pub fn with_callback(self, mut callback_call: CallbackClosure<SA>) -> Self {
+        callback_call.set_original_payments(self.original_payments.clone());
         AsyncCall {
             callback_call: Some(callback_call),
             ..self
@@ -3,19 +3,64 @@ use crate::{
     contract_base::ManagedSerializer,
     storage::StorageKey,
     storage_clear, storage_get, storage_set,
-    types::{ManagedBuffer, ManagedType},
+    types::{EsdtTokenPayment, ManagedBuffer, ManagedType, ManagedVec},
     ContractCallArg, ManagedResultArgLoader,
 };
-use elrond_codec::elrond_codec_derive::{TopDecode, TopEncode};
+use elrond_codec::{
+    elrond_codec_derive::TopDecode, EncodeError, NestedEncode, TopEncode, TopEncodeOutput,
+};
 
 use super::ManagedArgBuffer;
 
 pub const CALLBACK_CLOSURE_STORAGE_BASE_KEY: &[u8] = b"CB_CLOSURE";
 
-#[derive(TopEncode, TopDecode)]
+#[derive(TopDecode)]
 pub struct CallbackClosure<M: ManagedTypeApi> {
     callback_name: ManagedBuffer<M>,
     closure_args: ManagedArgBuffer<M>,
+
+    /// Only callbacks with a `#[callback_context]` argument need the payments of the async call,
+    /// their callback proxies request them. Not saved with the closure.
+    #[codec(skip)]
+    original_payments_requested: bool,
+
+    /// Payments of the async call, made available to the callback.
+    /// Optional, so that closures saved by older contract versions can still be decoded.
+    #[codec(optional)]
+    original_payments: Option<ManagedVec<M, EsdtTokenPayment<M>>>,
+}
+
+/// The original payments are only encoded when present,
+/// so closures without them are saved exactly like before they were introduced.
+impl<M: ManagedTypeApi> TopEncode for CallbackClosure<M> {
+    fn top_encode<O: TopEncodeOutput>(&self, output: O) -> Result<(), EncodeError> {
+        let mut buffer = output.start_nested_encode();
+        self.callback_name.dep_encode(&mut buffer)?;
+        self.closure_args.dep_encode(&mut buffer)?;
+        if self.original_payments.is_some() {
+            self.original_payments.dep_encode(&mut buffer)?;
+        }
+        output.finalize_nested_encode(buffer);
+        Ok(())
+    }
+
+    fn top_encode_or_exit<O: TopEncodeOutput, ExitCtx: Clone>(
+        &self,
+        output: O,
+        c: ExitCtx,
+        exit: fn(ExitCtx, EncodeError) -> !,
+    ) {
+        let mut buffer = output.start_nested_encode();
+        self.callback_name
+            .dep_encode_or_exit(&mut buffer, c.clone(), exit);
+        self.closure_args
+            .dep_encode_or_exit(&mut buffer, c.clone(), exit);
+        if self.original_payments.is_some() {
+            self.original_payments
+                .dep_encode_or_exit(&mut buffer, c, exit);
+        }
+        output.finalize_nested_encode(buffer);
+    }
 }
 
 /// Syntactical sugar to help macros to generate code easier.
@@ -35,6 +80,8 @@ impl<M: ManagedTypeApi> CallbackClosure<M> {
         CallbackClosure {
             callback_name,
             closure_args: arg_buffer,
+            original_payments_requested: false,
+            original_payments: None,
         }
     }
 
@@ -44,6 +91,8 @@ impl<M: ManagedTypeApi> CallbackClosure<M> {
         CallbackClosure {
             callback_name: ManagedBuffer::new(api.clone()),
             closure_args: ManagedArgBuffer::new_empty(api),
+            original_payments_requested: false,
+            original_payments: None,
         }
     }
 
@@ -51,6 +100,24 @@ impl<M: ManagedTypeApi> CallbackClosure<M> {
         endpoint_arg.push_dyn_arg(&mut self.closure_args);
     }
 
+    pub fn request_original_payments(&mut self) {
+        self.original_payments_requested = true;
+    }
+
+    /// Only saved when requested and when there are any, to keep the closure small otherwise.
+    pub(crate) fn set_original_payments(&mut self, payments: ManagedVec<M, EsdtTokenPayment<M>>) {
+        if self.original_payments_requested && !payments.is_empty() {
+            self.original_payments = Some(payments);
+        }
+    }
+
+    pub fn original_payments(&self) -> ManagedVec<M, EsdtTokenPayment<M>> {
+        match &self.original_payments {
+            Some(payments) => payments.clone(),
+            None => ManagedVec::new(self.callback_name.type_manager()),
+        }
+    }
+
     pub fn save_to_storage<A: BlockchainApi + StorageWriteApi>(&self, api: A) {
         let storage_key = cb_closure_storage_key(api.clone());
         storage_set(api, &storage_key, self);
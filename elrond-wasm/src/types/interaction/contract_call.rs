@@ -4,8 +4,8 @@ use crate::{
         ESDT_TRANSFER_FUNC_NAME,
     },
     types::{
-        AsyncCall, BigUint, EsdtTokenPayment, ManagedAddress, ManagedArgBuffer, ManagedBuffer,
-        ManagedVec, TokenIdentifier,
+        AsyncCall, BigUint, EsdtTokenPayment, EsdtTokenType, ManagedAddress, ManagedArgBuffer,
+        ManagedBuffer, ManagedVec, TokenIdentifier,
     },
     ArgId, ContractCallArg, DynArg, ManagedResultArgLoader,
 };
@@ -262,7 +262,25 @@ where
         }
     }
 
+    /// All payments as `EsdtTokenPayment`, EGLD included.
+    fn all_payments(&self) -> ManagedVec<SA, EsdtTokenPayment<SA>> {
+        if self.egld_payment == 0 {
+            return self.payments.clone();
+        }
+
+        let mut payments = self.no_payments();
+        payments.push(EsdtTokenPayment {
+            token_type: EsdtTokenType::Fungible,
+            token_identifier: TokenIdentifier::egld(self.api.clone()),
+            token_nonce: 0,
+            amount: self.egld_payment.clone(),
+        });
+        payments
+    }
+
     pub fn async_call(mut self) -> AsyncCall<SA> {
+        // saved before the conversion, which moves the ESDT payments into the arguments
+        let original_payments = self.all_payments();
         self = self.convert_to_esdt_transfer_call();
         AsyncCall {
             api: self.api,
@@ -270,6 +288,7 @@ where
             egld_payment: self.egld_payment,
             endpoint_name: self.endpoint_name,
             arg_buffer: self.arg_buffer,
+            original_payments,
             callback_call: None,
         }
     }
@@ -0,0 +1,109 @@
+use crate::{
+    api::SendApi,
+    types::{BigUint, CodeMetadata, ManagedAddress, ManagedBuffer},
+    ContractCallArg,
+};
+
+use super::ManagedArgBuffer;
+
+/// Using max u64 to represent maximum possible gas,
+/// so that the value zero is not reserved and can be specified explicitly.
+/// Leaving the gas limit unspecified will replace it with `api.get_gas_left()`.
+const UNSPECIFIED_GAS_LIMIT: u64 = u64::MAX;
+
+/// Upgrades an existing contract, running its `#[upgrade]` method with the pushed arguments.
+/// Generated by the proxies for methods annotated with `#[upgrade]`.
+#[must_use]
+pub struct ContractUpgrade<SA>
+where
+    SA: SendApi + 'static,
+{
+    api: SA,
+    to: ManagedAddress<SA>,
+    egld_payment: BigUint<SA>,
+    explicit_gas_limit: u64,
+    code_metadata: CodeMetadata,
+    arg_buffer: ManagedArgBuffer<SA>,
+}
+
+/// Syntactical sugar to help macros to generate code easier.
+/// Unlike calling `ContractUpgrade::<SA>::new`, here types can be inferred from the context.
+pub fn new_contract_upgrade<SA>(api: SA, to: ManagedAddress<SA>) -> ContractUpgrade<SA>
+where
+    SA: SendApi + 'static,
+{
+    ContractUpgrade::<SA>::new(api, to)
+}
+
+impl<SA> ContractUpgrade<SA>
+where
+    SA: SendApi + 'static,
+{
+    pub fn new(api: SA, to: ManagedAddress<SA>) -> Self {
+        let zero = BigUint::zero(api.clone());
+        let arg_buffer = ManagedArgBuffer::new_empty(api.clone());
+        ContractUpgrade {
+            api,
+            to,
+            egld_payment: zero,
+            explicit_gas_limit: UNSPECIFIED_GAS_LIMIT,
+            code_metadata: CodeMetadata::DEFAULT,
+            arg_buffer,
+        }
+    }
+
+    pub fn with_egld_transfer(mut self, payment_amount: BigUint<SA>) -> Self {
+        self.egld_payment = payment_amount;
+        self
+    }
+
+    pub fn with_gas_limit(mut self, gas_limit: u64) -> Self {
+        self.explicit_gas_limit = gas_limit;
+        self
+    }
+
+    /// The code metadata of the upgraded contract, `CodeMetadata::DEFAULT` if not specified.
+    /// Flags can be combined, e.g. `CodeMetadata::UPGRADEABLE | CodeMetadata::PAYABLE`.
+    pub fn with_code_metadata(mut self, code_metadata: CodeMetadata) -> Self {
+        self.code_metadata = code_metadata;
+        self
+    }
+
+    pub fn push_endpoint_arg<D: ContractCallArg>(&mut self, endpoint_arg: D) {
+        endpoint_arg.push_dyn_arg(&mut self.arg_buffer);
+    }
+
+    fn resolve_gas_limit(&self) -> u64 {
+        if self.explicit_gas_limit == UNSPECIFIED_GAS_LIMIT {
+            self.api.get_gas_left()
+        } else {
+            self.explicit_gas_limit
+        }
+    }
+
+    /// Replaces the code of the contract with the given code.
+    /// Just like an async call, it ends the execution of the current transaction.
+    pub fn upgrade_contract(self, code: &ManagedBuffer<SA>) {
+        self.api.upgrade_contract(
+            &self.to,
+            self.resolve_gas_limit(),
+            &self.egld_payment,
+            code,
+            self.code_metadata,
+            &self.arg_buffer,
+        );
+    }
+
+    /// Replaces the code of the contract with the code of the contract at `source_address`.
+    /// Just like an async call, it ends the execution of the current transaction.
+    pub fn upgrade_from_source(self, source_address: &ManagedAddress<SA>) {
+        self.api.upgrade_from_source_contract(
+            &self.to,
+            self.resolve_gas_limit(),
+            &self.egld_payment,
+            source_address,
+            self.code_metadata,
+            &self.arg_buffer,
+        )
+    }
+}
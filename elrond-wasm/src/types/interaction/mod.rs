@@ -6,6 +6,7 @@ mod callback_closure_unmanaged_args;
 mod callback_selector_result;
 mod contract_call;
 mod contract_deploy;
+mod contract_upgrade;
 mod send_egld;
 mod send_esdt;
 mod send_token;
@@ -18,6 +19,7 @@ pub use callback_closure_unmanaged_args::CallbackClosureUnmanagedArgs;
 pub use callback_selector_result::CallbackSelectorResult;
 pub use contract_call::{new_contract_call, ContractCall};
 pub use contract_deploy::{new_contract_deploy, ContractDeploy};
+pub use contract_upgrade::{new_contract_upgrade, ContractUpgrade};
 pub use send_egld::SendEgld;
 pub use send_esdt::SendEsdt;
 pub use send_token::SendToken;
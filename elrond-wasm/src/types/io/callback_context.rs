@@ -0,0 +1,110 @@
+use crate::{
+    api::{ErrorApi, ManagedTypeApi},
+    io::{ArgId, CallbackResultDecode, DynArg, DynArgInput},
+    types::{EsdtTokenPayment, ManagedAsyncCallError, ManagedBuffer, ManagedVec},
+    ManagedResultArgLoader,
+};
+use elrond_codec::DecodeError;
+
+/// Everything a callback can find out about the async call that triggered it.
+///
+/// Declared in a `#[callback]` method as an argument annotated with `#[callback_context]`.
+///
+/// The gas used by the async call is not included, since the VM does not pass it to the callback.
+pub struct CallbackContext<M, T>
+where
+    M: ManagedTypeApi,
+{
+    /// The EGLD or ESDT payments sent with the original async call.
+    pub original_payments: ManagedVec<M, EsdtTokenPayment<M>>,
+
+    /// The ESDT transfers sent back by the callee, received together with the callback.
+    pub returned_payments: ManagedVec<M, EsdtTokenPayment<M>>,
+
+    pub result: CallbackResult<M, T>,
+}
+
+/// Outcome of the async call, as seen by the callback.
+pub enum CallbackResult<M, T>
+where
+    M: ManagedTypeApi,
+{
+    /// The call succeeded and its results were decoded.
+    Ok(T),
+
+    /// The callee failed, with the given error code and message.
+    CalleeError(ManagedAsyncCallError<M>),
+
+    /// The call succeeded, but its results could not be decoded as `T`.
+    /// The raw results are kept, so the callback can still inspect them.
+    DecodeError {
+        raw_results: ManagedVec<M, ManagedBuffer<M>>,
+        error: DecodeError,
+    },
+}
+
+impl<M, T> CallbackContext<M, T>
+where
+    M: ManagedTypeApi,
+{
+    pub fn new(
+        original_payments: ManagedVec<M, EsdtTokenPayment<M>>,
+        returned_payments: ManagedVec<M, EsdtTokenPayment<M>>,
+        result: CallbackResult<M, T>,
+    ) -> Self {
+        CallbackContext {
+            original_payments,
+            returned_payments,
+            result,
+        }
+    }
+}
+
+impl<M, T> CallbackResult<M, T>
+where
+    M: ManagedTypeApi,
+{
+    #[inline]
+    pub fn is_ok(&self) -> bool {
+        matches!(self, CallbackResult::Ok(_))
+    }
+
+    #[inline]
+    pub fn is_err(&self) -> bool {
+        !self.is_ok()
+    }
+}
+
+/// Never crashes because of the async call results, a decode failure is returned as `CallbackResult::DecodeError`.
+impl<M, T> DynArg for CallbackResult<M, T>
+where
+    M: ManagedTypeApi + ErrorApi,
+    T: CallbackResultDecode,
+{
+    fn dyn_load<I: DynArgInput>(loader: &mut I, arg_id: ArgId) -> Self {
+        let err_code = u32::dyn_load(loader, arg_id);
+        if err_code != 0 {
+            let err_msg = if loader.has_next() {
+                ManagedBuffer::dyn_load(loader, arg_id)
+            } else {
+                ManagedBuffer::new(loader.vm_api_cast())
+            };
+            return CallbackResult::CalleeError(ManagedAsyncCallError { err_code, err_msg });
+        }
+
+        let mut raw_results = ManagedVec::new(loader.vm_api_cast());
+        while loader.has_next() {
+            raw_results.push(ManagedBuffer::dyn_load(loader, arg_id));
+        }
+
+        let mut results_loader = ManagedResultArgLoader::new(raw_results.clone());
+        match T::decode_callback_result(&mut results_loader) {
+            Ok(_) if results_loader.has_next() => CallbackResult::DecodeError {
+                raw_results,
+                error: DecodeError::INPUT_TOO_LONG,
+            },
+            Ok(decoded) => CallbackResult::Ok(decoded),
+            Err(error) => CallbackResult::DecodeError { raw_results, error },
+        }
+    }
+}
@@ -1,10 +1,11 @@
 use crate::{
     abi::{OutputAbi, TypeAbi, TypeDescriptionContainer},
     api::{EndpointFinishApi, ManagedTypeApi},
-    io::{ArgId, ContractCallArg, DynArg, DynArgInput, DynArgOutput},
+    io::{ArgId, CallbackResultDecode, ContractCallArg, DynArg, DynArgInput, DynArgOutput},
     EndpointResult,
 };
 use alloc::{string::String, vec::Vec};
+use elrond_codec::DecodeError;
 
 macro_rules! multi_arg_impls {
     ($(($marg_struct:ident $mres_struct:ident $($n:tt $name:ident)+) )+) => {
@@ -30,6 +31,22 @@ macro_rules! multi_arg_impls {
                 }
             }
 
+            impl<$($name),+ > CallbackResultDecode for $marg_struct<$($name,)+>
+            where
+                $($name: CallbackResultDecode,)+
+            {
+                fn decode_callback_result<I>(loader: &mut I) -> Result<Self, DecodeError>
+                where
+                    I: DynArgInput,
+                {
+                    Ok($marg_struct((
+                        $(
+                            $name::decode_callback_result(loader)?
+                        ),+
+                    )))
+                }
+            }
+
             impl<$($name),+> EndpointResult for $marg_struct<$($name,)+>
             where
                 $($name: EndpointResult,)+
@@ -1,11 +1,12 @@
 use crate::{
     abi::{TypeAbi, TypeDescriptionContainer},
     api::{EndpointFinishApi, ManagedTypeApi},
-    io::{ArgId, ContractCallArg, DynArg, DynArgInput},
+    io::{ArgId, CallbackResultDecode, ContractCallArg, DynArg, DynArgInput},
     DynArgOutput, EndpointResult,
 };
 use alloc::{string::String, vec::Vec};
 use core::iter::FromIterator;
+use elrond_codec::DecodeError;
 
 /// Structure that allows taking a variable number of arguments
 /// or returning a variable number of results in a smart contract endpoint.
@@ -93,6 +94,19 @@ where
     }
 }
 
+impl<T> CallbackResultDecode for MultiArgVec<T>
+where
+    T: CallbackResultDecode,
+{
+    fn decode_callback_result<I: DynArgInput>(loader: &mut I) -> Result<Self, DecodeError> {
+        let mut result_vec: Vec<T> = Vec::new();
+        while loader.has_next() {
+            result_vec.push(T::decode_callback_result(loader)?);
+        }
+        Ok(MultiArgVec(result_vec))
+    }
+}
+
 impl<T> EndpointResult for MultiArgVec<T>
 where
     T: EndpointResult,
@@ -1,10 +1,11 @@
 use crate::{
     abi::{TypeAbi, TypeDescriptionContainer},
     api::{EndpointFinishApi, ManagedTypeApi},
-    io::{ArgId, ContractCallArg, DynArg, DynArgInput, DynArgOutput},
+    io::{ArgId, CallbackResultDecode, ContractCallArg, DynArg, DynArgInput, DynArgOutput},
     EndpointResult,
 };
 use alloc::string::String;
+use elrond_codec::DecodeError;
 
 /// A smart contract argument or result that can be missing.
 ///
@@ -56,6 +57,19 @@ where
     }
 }
 
+impl<T> CallbackResultDecode for OptionalArg<T>
+where
+    T: CallbackResultDecode,
+{
+    fn decode_callback_result<I: DynArgInput>(loader: &mut I) -> Result<Self, DecodeError> {
+        if loader.has_next() {
+            Ok(OptionalArg::Some(T::decode_callback_result(loader)?))
+        } else {
+            Ok(OptionalArg::None)
+        }
+    }
+}
+
 impl<T> EndpointResult for OptionalArg<T>
 where
     T: EndpointResult,
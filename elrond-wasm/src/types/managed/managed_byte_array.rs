@@ -80,6 +80,13 @@ where
     pub fn as_managed_buffer(&self) -> &ManagedBuffer<M> {
         &self.buffer
     }
+
+    /// Copies the contents to a new stack-allocated byte array.
+    pub fn to_byte_array(&self) -> [u8; N] {
+        let mut result = [0u8; N];
+        let _ = self.buffer.load_slice(0, &mut result[..]);
+        result
+    }
 }
 
 impl<M, const N: usize> PartialEq for ManagedByteArray<M, N>
@@ -4,7 +4,8 @@ use crate::{
 };
 
 use super::{
-    BigInt, BigUint, EllipticCurve, ManagedAddress, ManagedBuffer, ManagedType, ManagedVec,
+    BigInt, BigUint, EllipticCurve, ManagedAddress, ManagedBuffer, ManagedByteArray, ManagedType,
+    ManagedVec,
 };
 
 /// Types that implement this trait can be items inside a `ManagedVec`.
@@ -47,8 +48,14 @@ macro_rules! impl_int {
     };
 }
 
+impl_int! {u8, 1}
+impl_int! {u16, 2}
 impl_int! {u32, 4}
+impl_int! {u64, 8}
+impl_int! {i8, 1}
+impl_int! {i16, 2}
 impl_int! {i32, 4}
+impl_int! {i64, 8}
 
 impl<M: ManagedTypeApi> ManagedVecItem<M> for usize {
     const PAYLOAD_SIZE: usize = 4;
@@ -66,6 +73,20 @@ impl<M: ManagedTypeApi> ManagedVecItem<M> for usize {
     }
 }
 
+impl<M: ManagedTypeApi> ManagedVecItem<M> for bool {
+    const PAYLOAD_SIZE: usize = 1;
+    // any non-zero payload byte reads as `true`, but only 1 is a valid encoding
+    const NEEDS_RESERIALIZATION: bool = true;
+
+    fn from_byte_reader<Reader: FnMut(&mut [u8])>(api: M, reader: Reader) -> Self {
+        <u8 as ManagedVecItem<M>>::from_byte_reader(api, reader) > 0
+    }
+
+    fn to_byte_writer<R, Writer: FnMut(&[u8]) -> R>(&self, writer: Writer) -> R {
+        <u8 as ManagedVecItem<M>>::to_byte_writer(&(*self as u8), writer)
+    }
+}
+
 macro_rules! impl_managed_type {
     ($ty:ident) => {
         impl<M: ManagedTypeApi> ManagedVecItem<M> for $ty<M> {
@@ -91,6 +112,23 @@ impl_managed_type! {EllipticCurve}
 impl_managed_type! {ManagedAddress}
 impl_managed_type! {TokenIdentifier}
 
+impl<M, const N: usize> ManagedVecItem<M> for ManagedByteArray<M, N>
+where
+    M: ManagedTypeApi,
+{
+    const PAYLOAD_SIZE: usize = 4;
+    const NEEDS_RESERIALIZATION: bool = true;
+
+    fn from_byte_reader<Reader: FnMut(&mut [u8])>(api: M, reader: Reader) -> Self {
+        let handle = Handle::from_byte_reader(api.clone(), reader);
+        Self::from_raw_handle(api, handle)
+    }
+
+    fn to_byte_writer<R, Writer: FnMut(&[u8]) -> R>(&self, writer: Writer) -> R {
+        <Handle as ManagedVecItem<M>>::to_byte_writer(&self.get_raw_handle(), writer)
+    }
+}
+
 impl<M, T> ManagedVecItem<M> for ManagedVec<M, T>
 where
     M: ManagedTypeApi,
@@ -108,3 +146,53 @@ where
         <Handle as ManagedVecItem<M>>::to_byte_writer(&self.get_raw_handle(), writer)
     }
 }
+
+/// Derived `ManagedVecItem` implementations assemble the payload of all their fields
+/// in a buffer on the stack, of this size.
+/// Their payload cannot be larger, i.e. at most 32 handles.
+pub const MANAGED_VEC_ITEM_DERIVE_MAX_PAYLOAD_SIZE: usize = 128;
+
+/// Used by the `ManagedVecItem` derive to compute the payload size.
+/// Stops compilation if the payload does not fit in the buffer.
+pub const fn managed_vec_item_derive_payload_size(payload_size: usize) -> usize {
+    assert!(
+        payload_size <= MANAGED_VEC_ITEM_DERIVE_MAX_PAYLOAD_SIZE,
+        "ManagedVecItem payload too large"
+    );
+    payload_size
+}
+
+/// Used by the `ManagedVecItem` derive: reads a field from the struct payload,
+/// starting at `index`, then moves the index past the field.
+pub fn managed_vec_item_read_from_payload_index<M, T>(
+    api: M,
+    payload_slice: &[u8],
+    index: &mut usize,
+) -> T
+where
+    M: ManagedTypeApi,
+    T: ManagedVecItem<M>,
+{
+    T::from_byte_reader(api, |bytes| {
+        let next_index = *index + T::PAYLOAD_SIZE;
+        bytes.copy_from_slice(&payload_slice[*index..next_index]);
+        *index = next_index;
+    })
+}
+
+/// Used by the `ManagedVecItem` derive: writes a field in the struct payload,
+/// starting at `index`, then moves the index past the field.
+pub fn managed_vec_item_save_to_payload_index<M, T>(
+    item: &T,
+    payload_slice: &mut [u8],
+    index: &mut usize,
+) where
+    M: ManagedTypeApi,
+    T: ManagedVecItem<M>,
+{
+    item.to_byte_writer(|bytes| {
+        let next_index = *index + T::PAYLOAD_SIZE;
+        payload_slice[*index..next_index].copy_from_slice(bytes);
+        *index = next_index;
+    })
+}
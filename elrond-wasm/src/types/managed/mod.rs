@@ -36,5 +36,9 @@ pub use managed_type_trait::ManagedType;
 pub use managed_vec::{
     managed_vec_from_slice_of_boxed_bytes, managed_vec_of_buffers_to_arg_buffer, ManagedVec,
 };
-pub use managed_vec_item::ManagedVecItem;
+pub use managed_vec_item::{
+    managed_vec_item_derive_payload_size, managed_vec_item_read_from_payload_index,
+    managed_vec_item_save_to_payload_index, ManagedVecItem,
+    MANAGED_VEC_ITEM_DERIVE_MAX_PAYLOAD_SIZE,
+};
 pub use managed_vec_iter::ManagedVecIterator;
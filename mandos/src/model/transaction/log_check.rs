@@ -1,7 +1,7 @@
 use crate::{
     interpret_trait::{InterpretableFrom, InterpreterContext},
     model::{BytesValue, CheckValue},
-    serde_raw::CheckLogRaw,
+    serde_raw::{CheckBytesValueRaw, CheckLogRaw, ValueSubTree},
 };
 
 #[derive(Debug)]
@@ -10,10 +10,24 @@ pub struct CheckLog {
     pub endpoint: CheckValue<BytesValue>,
     pub topics: Vec<CheckValue<BytesValue>>,
     pub data: CheckValue<BytesValue>,
+
+    /// Set when the data is given as a list, one item per data field.
+    /// Events with several data fields nest-encode them one after the other,
+    /// so each item is the nested encoding of a field and `data` is their concatenation.
+    pub data_fields: Option<Vec<BytesValue>>,
 }
 
 impl InterpretableFrom<CheckLogRaw> for CheckLog {
     fn interpret_from(from: CheckLogRaw, context: &InterpreterContext) -> Self {
+        let data_fields = match &from.data {
+            CheckBytesValueRaw::Equal(ValueSubTree::List(items)) => Some(
+                items
+                    .iter()
+                    .map(|item| BytesValue::interpret_from(item.clone(), context))
+                    .collect(),
+            ),
+            _ => None,
+        };
         CheckLog {
             address: BytesValue::interpret_from(from.address, context),
             endpoint: CheckValue::<BytesValue>::interpret_from(from.endpoint, context),
@@ -23,6 +37,7 @@ impl InterpretableFrom<CheckLogRaw> for CheckLog {
                 .map(|t| CheckValue::<BytesValue>::interpret_from(t, context))
                 .collect(),
             data: CheckValue::<BytesValue>::interpret_from(from.data, context),
+            data_fields,
         }
     }
 }
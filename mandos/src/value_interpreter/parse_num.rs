@@ -3,6 +3,10 @@ use num_bigint::{BigInt, BigUint, Sign};
 use num_traits::identities::Zero;
 
 pub fn try_parse_fixed_width(s: &str) -> Option<Vec<u8>> {
+    if let Some(stripped) = s.strip_prefix(U128_PREFIX) {
+        return Some(parse_fixed_width_unsigned(stripped, 16));
+    }
+
     if let Some(stripped) = s.strip_prefix(U64_PREFIX) {
         return Some(parse_fixed_width_unsigned(stripped, 8));
     }
@@ -19,6 +23,10 @@ pub fn try_parse_fixed_width(s: &str) -> Option<Vec<u8>> {
         return Some(parse_fixed_width_unsigned(stripped, 1));
     }
 
+    if let Some(stripped) = s.strip_prefix(I128_PREFIX) {
+        return Some(parse_fixed_width_signed(stripped, 16));
+    }
+
     if let Some(stripped) = s.strip_prefix(I64_PREFIX) {
         return Some(parse_fixed_width_signed(stripped, 8));
     }
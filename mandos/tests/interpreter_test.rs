@@ -186,6 +186,19 @@ fn test_unsigned_fixed_width() {
         vec![0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x05],
         interpret_string("u64:0b101", context)
     );
+    assert_eq!(vec![0x00; 16], interpret_string("u128:0", context));
+    assert_eq!(
+        [
+            &[0x00; 8][..],
+            &[0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00][..]
+        ]
+        .concat(),
+        interpret_string("u128:72057594037927936", context)
+    );
+    assert_eq!(
+        vec![0xff; 16],
+        interpret_string("u128:340282366920938463463374607431768211455", context)
+    );
 }
 
 #[test]
@@ -218,6 +231,19 @@ fn test_signed_fixed_width() {
         interpret_string("i64:-256", context)
     );
     assert_eq!(vec![0xfb], interpret_string("i8:-0b101", context));
+    assert_eq!(vec![0x00; 16], interpret_string("i128:0", context));
+    assert_eq!(vec![0xff; 16], interpret_string("i128:-1", context));
+    assert_eq!(
+        [&[0x00; 15][..], &[0x05][..]].concat(),
+        interpret_string("i128:+5", context)
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_unsigned_fixed_width_128_panic() {
+    let context = &InterpreterContext::default();
+    interpret_string("u128:340282366920938463463374607431768211456", context);
 }
 
 #[test]